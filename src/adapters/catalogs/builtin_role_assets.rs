@@ -124,6 +124,16 @@ mod tests {
         assert!(catalog.iter().any(|entry| entry.name.as_str() == "recruiter"));
     }
 
+    #[test]
+    fn builtin_roles_carry_summaries() {
+        let catalog = load_builtin_role_catalog().expect("catalog should load");
+        let taxonomy = catalog
+            .iter()
+            .find(|entry| entry.layer == Layer::Observers && entry.name.as_str() == "taxonomy")
+            .expect("taxonomy should be cataloged");
+        assert_eq!(taxonomy.summary, "Establish shared vocabulary and naming consistency.");
+    }
+
     #[test]
     fn reads_builtin_role_file() {
         let content = read_builtin_role_file("observers/language/taxonomy/role.yml").unwrap();
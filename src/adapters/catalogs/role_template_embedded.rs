@@ -98,7 +98,7 @@ fn collect_files(dir: &'static Dir, files: &mut Vec<ScaffoldFile>) {
                         != Some(std::ffi::OsStr::new(INTERNAL_DOC_FILE))
                     {
                         let path = map_scaffold_path(&raw_path);
-                        files.push(ScaffoldFile { path, content: content.to_string() });
+                        files.push(ScaffoldFile::from_asset(path, content.to_string()));
                     }
                 }
             }
@@ -3,8 +3,10 @@ mod asset_collect;
 mod render_plan;
 mod template_engine;
 
+use std::collections::BTreeMap;
+
 use include_dir::{Dir, include_dir};
-use minijinja::context;
+use minijinja::Value;
 
 use crate::domain::config::WorkflowGenerateConfig;
 use crate::domain::{AppError, WorkflowRunnerMode};
@@ -37,14 +39,7 @@ pub fn load_workflow_scaffold(
 
     let env = build_template_environment(&sources)?;
 
-    let runner = mode.runner_label();
-    let ctx = context! {
-        runner => runner,
-        target_branch => &generate_config.target_branch,
-        worker_branch => &generate_config.worker_branch,
-        workflow_schedule_crons => &generate_config.schedule_crons,
-        workflow_wait_minutes_default => generate_config.wait_minutes_default,
-    };
+    let ctx = build_render_context(mode, generate_config)?;
 
     let mut files = render_scaffold_files(&sources, &env, &ctx)?;
 
@@ -65,6 +60,40 @@ pub fn load_workflow_scaffold(
     Ok(WorkflowScaffoldAssets { files, action_dirs })
 }
 
+/// Build the template context: built-in values plus caller-supplied template vars.
+///
+/// Template vars that collide with a built-in key are rejected so generated
+/// workflows never silently diverge from `.jlo/config.toml`.
+fn build_render_context(
+    mode: &WorkflowRunnerMode,
+    generate_config: &WorkflowGenerateConfig,
+) -> Result<Value, AppError> {
+    let mut ctx: BTreeMap<String, Value> = BTreeMap::new();
+    ctx.insert("runner".into(), Value::from(mode.runner_label()));
+    ctx.insert("target_branch".into(), Value::from(generate_config.target_branch.as_str()));
+    ctx.insert("worker_branch".into(), Value::from(generate_config.worker_branch.as_str()));
+    ctx.insert(
+        "workflow_schedule_crons".into(),
+        Value::from_serialize(&generate_config.schedule_crons),
+    );
+    ctx.insert(
+        "workflow_wait_minutes_default".into(),
+        Value::from(generate_config.wait_minutes_default),
+    );
+
+    for (key, value) in &generate_config.template_vars {
+        if ctx.contains_key(key) {
+            return Err(AppError::Validation(format!(
+                "Template var '{}' conflicts with a built-in workflow template variable",
+                key
+            )));
+        }
+        ctx.insert(key.clone(), Value::from_serialize(value));
+    }
+
+    Ok(Value::from_serialize(&ctx))
+}
+
 fn render_scaffold_files(
     sources: &[AssetSourceFile],
     env: &minijinja::Environment<'_>,
@@ -83,7 +112,7 @@ fn render_scaffold_files(
             source.content.clone()
         };
 
-        files.push(ScaffoldFile { path: source.output_path(), content: rendered_content });
+        files.push(ScaffoldFile::from_asset(source.output_path(), rendered_content));
     }
 
     Ok(files)
@@ -105,4 +134,28 @@ mod tests {
                 .expect("self-hosted assets");
         assert!(!self_hosted.files.is_empty(), "self-hosted scaffold should have files");
     }
+
+    #[test]
+    fn template_vars_are_available_to_templates() {
+        let mut generate_config = WorkflowGenerateConfig::default();
+        generate_config.template_vars.insert("team_slug".into(), serde_json::json!("platform"));
+        let ctx = build_render_context(&WorkflowRunnerMode::remote(), &generate_config).unwrap();
+
+        let mut env = minijinja::Environment::new();
+        env.add_template("custom", "team={{ team_slug }} worker={{ worker_branch }}").unwrap();
+        let rendered = render_template_by_name(&env, "custom", &ctx).unwrap();
+
+        assert_eq!(rendered, "team=platform worker=jules");
+    }
+
+    #[test]
+    fn template_vars_cannot_shadow_builtin_context() {
+        let mut generate_config = WorkflowGenerateConfig::default();
+        generate_config.template_vars.insert("worker_branch".into(), serde_json::json!("other"));
+
+        let err = build_render_context(&WorkflowRunnerMode::remote(), &generate_config)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("'worker_branch' conflicts"), "{}", err);
+    }
 }
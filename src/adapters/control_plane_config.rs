@@ -1,31 +1,20 @@
 use crate::domain::config::WorkflowGenerateConfig;
-use crate::domain::config::parse_config_content;
+use crate::domain::config::parse_config_file;
 use crate::domain::config::paths;
 use crate::domain::{AppError, ConfigError, ControlPlaneConfig, WorkflowRunnerMode};
-use crate::ports::RepositoryFilesystem;
+use crate::ports::{JloStore, RepositoryFilesystem};
 use std::path::Path;
 
-fn load_control_plane_config(
-    repository: &impl RepositoryFilesystem,
-) -> Result<ControlPlaneConfig, AppError> {
-    let config_path_buf = paths::config(Path::new(""));
-    let config_path = config_path_buf
-        .to_str()
-        .ok_or_else(|| AppError::InternalError("Config path not UTF-8".into()))?;
-
-    if !repository.file_exists(config_path) {
-        return Err(AppError::ControlPlaneConfigMissing);
-    }
-
-    let content = repository.read_file(config_path)?;
-    parse_config_content(&content)
+fn load_control_plane_config(repository: &impl JloStore) -> Result<ControlPlaneConfig, AppError> {
+    let content = repository.read_config()?;
+    parse_config_file(&repository.config_path().display().to_string(), &content)
 }
 
 /// Read workflow generate configuration from `.jlo/config.toml`.
 ///
 /// Errors on missing or invalid configuration to avoid silent fallbacks.
 pub fn load_workflow_generate_config(
-    repository: &impl RepositoryFilesystem,
+    repository: &impl JloStore,
 ) -> Result<WorkflowGenerateConfig, AppError> {
     let config = load_control_plane_config(repository)?;
     let workflow = config.workflow;
@@ -63,6 +52,7 @@ pub fn load_workflow_generate_config(
         worker_branch: config.run.jules_worker_branch,
         schedule_crons,
         wait_minutes_default,
+        template_vars: Default::default(),
     })
 }
 
@@ -71,7 +61,7 @@ pub fn load_workflow_generate_config(
 /// The control-plane configuration is the authoritative source for selecting
 /// remote vs self-hosted workflow scaffolds.
 pub fn load_workflow_runner_mode(
-    repository: &impl RepositoryFilesystem,
+    repository: &impl JloStore,
 ) -> Result<WorkflowRunnerMode, AppError> {
     let config = load_control_plane_config(repository)?;
     let workflow = config.workflow;
@@ -88,6 +78,24 @@ fn parse_workflow_runner_mode(raw: Option<&str>) -> Result<WorkflowRunnerMode, A
 pub fn persist_workflow_runner_mode(
     repository: &impl RepositoryFilesystem,
     mode: &WorkflowRunnerMode,
+) -> Result<(), AppError> {
+    replace_config_string(repository, "workflow", "runner_mode", mode.label())
+}
+
+/// Set `run.jlo_target_branch` in `.jlo/config.toml`, keeping surrounding formatting.
+pub fn persist_target_branch(
+    repository: &impl RepositoryFilesystem,
+    branch: &str,
+) -> Result<(), AppError> {
+    replace_config_string(repository, "run", "jlo_target_branch", branch)
+}
+
+/// Replace an existing `[section] key` string value, preserving its decoration.
+fn replace_config_string(
+    repository: &impl RepositoryFilesystem,
+    section: &str,
+    key: &str,
+    desired_value: &str,
 ) -> Result<(), AppError> {
     let config_path_buf = paths::config(Path::new(""));
     let config_path = config_path_buf
@@ -99,21 +107,19 @@ pub fn persist_workflow_runner_mode(
         .parse::<toml_edit::DocumentMut>()
         .map_err(|e| ConfigError::Invalid(format!("Failed to parse {}: {}", config_path, e)))?;
 
-    let desired_value = mode.label();
-
-    let workflow_table = doc["workflow"].as_table_mut().ok_or_else(|| {
-        ConfigError::Invalid(format!("Missing [workflow] section in {}.", config_path))
+    let table = doc[section].as_table_mut().ok_or_else(|| {
+        ConfigError::Invalid(format!("Missing [{}] section in {}.", section, config_path))
     })?;
 
-    if !workflow_table.contains_key("runner_mode") {
+    if !table.contains_key(key) {
         return Err(ConfigError::Invalid(format!(
-            "Missing workflow.runner_mode in {}.",
-            config_path
+            "Missing {}.{} in {}.",
+            section, key, config_path
         ))
         .into());
     }
 
-    let item = &mut workflow_table["runner_mode"];
+    let item = &mut table[key];
     if let Some(current_val) = item.as_value_mut() {
         let mut new_val = toml_edit::Value::from(desired_value);
         *new_val.decor_mut() = current_val.decor().clone();
@@ -175,4 +181,25 @@ jules_worker_branch = "jules"
             persist_workflow_runner_mode(&repository, &WorkflowRunnerMode::remote()).unwrap_err();
         assert!(err.to_string().contains("Missing [workflow] section"));
     }
+
+    #[test]
+    fn persist_target_branch_updates_only_run_value() {
+        let temp = TempDir::new().unwrap();
+        let repository = LocalRepositoryAdapter::new(temp.path().to_path_buf());
+        repository
+            .write_file(
+                ".jlo/config.toml",
+                r#"[run]
+jlo_target_branch = "main" # control branch
+jules_worker_branch = "jules"
+"#,
+            )
+            .unwrap();
+
+        persist_target_branch(&repository, "trunk").unwrap();
+        let updated = fs::read_to_string(temp.path().join(".jlo/config.toml")).unwrap();
+
+        assert!(updated.contains("jlo_target_branch = \"trunk\" # control branch"));
+        assert!(updated.contains("jules_worker_branch = \"jules\""));
+    }
 }
@@ -1,6 +1,6 @@
 use crate::domain::jlo_paths;
 use crate::domain::{AppError, IoErrorKind};
-use crate::ports::{Git, GitWorkspace};
+use crate::ports::{CommitInfo, Git, GitWorkspace, StatusEntry};
 use std::path::{Path, PathBuf};
 use std::process::{Command, Output};
 use std::time::{SystemTime, UNIX_EPOCH};
@@ -55,6 +55,28 @@ impl Git for GitCommandAdapter {
         self.run(&["branch", "--show-current"], None)
     }
 
+    fn get_default_branch(&self) -> Result<String, AppError> {
+        let head = self.run(&["symbolic-ref", "--short", "refs/remotes/origin/HEAD"], None)?;
+        match head.strip_prefix("origin/") {
+            Some(branch) if !branch.is_empty() => Ok(branch.to_string()),
+            _ => Err(AppError::GitError {
+                command: "git symbolic-ref --short refs/remotes/origin/HEAD".to_string(),
+                details: format!("unexpected origin HEAD '{}'", head),
+            }),
+        }
+    }
+
+    fn blame_last_author(&self, rev: &str, path: &str) -> Result<String, AppError> {
+        let author = self.run(&["log", "-1", "--format=%an", rev, "--", path], None)?;
+        if author.is_empty() {
+            return Err(AppError::GitError {
+                command: format!("git log -1 --format=%an {} -- {}", rev, path),
+                details: "no commit touches this path".to_string(),
+            });
+        }
+        Ok(author)
+    }
+
     fn commit_exists(&self, sha: &str) -> bool {
         self.run_output(&["cat-file", "-e", sha], None).is_ok()
     }
@@ -86,6 +108,32 @@ impl Git for GitCommandAdapter {
             })
     }
 
+    fn rev_parse(&self, rev: &str) -> Result<String, AppError> {
+        let spec = format!("{}^{{commit}}", rev);
+        let output =
+            self.run_output(&["rev-parse", "--verify", "--quiet", &spec], None).map_err(|_| {
+                AppError::GitError {
+                    command: format!("git rev-parse {}", rev),
+                    details: format!("Cannot resolve '{}' to a commit", rev),
+                }
+            })?;
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
+    fn commit_message(&self, sha: &str) -> Result<String, AppError> {
+        self.run(&["log", "-1", "--format=%B", sha], None)
+    }
+
+    fn log_range(&self, from: &str, to: &str) -> Result<Vec<CommitInfo>, AppError> {
+        let range = format!("{}..{}", from, to);
+        let output = self.run(&["log", "--format=%H%x1f%s", &range], None)?;
+        Ok(output
+            .lines()
+            .filter_map(|line| line.split_once('\x1f'))
+            .map(|(sha, subject)| CommitInfo { sha: sha.to_string(), subject: subject.to_string() })
+            .collect())
+    }
+
     fn has_changes(&self, from: &str, to: &str, pathspec: &[&str]) -> Result<bool, AppError> {
         let range = format!("{}..{}", from, to);
         let mut args = vec!["diff", "--name-only", &range, "--"];
@@ -94,6 +142,13 @@ impl Git for GitCommandAdapter {
         Ok(!output.stdout.is_empty())
     }
 
+    fn status(&self, pathspec: &[&str]) -> Result<Vec<StatusEntry>, AppError> {
+        let mut args = vec!["status", "--porcelain=v1", "-z", "--"];
+        args.extend(pathspec);
+        let output = self.run_output(&args, None)?;
+        Ok(parse_porcelain_status(&String::from_utf8_lossy(&output.stdout)))
+    }
+
     fn checkout_branch(&self, branch: &str, create: bool) -> Result<(), AppError> {
         let args = if create { vec!["checkout", "-b", branch] } else { vec!["checkout", branch] };
         self.run_output(&args, None)?;
@@ -142,6 +197,90 @@ impl Git for GitCommandAdapter {
         Ok(())
     }
 
+    fn fetch_prune(&self, remote: &str) -> Result<(), AppError> {
+        self.run_output(&["fetch", "--prune", remote], None)?;
+        Ok(())
+    }
+
+    fn remote_exists(&self, name: &str) -> Result<bool, AppError> {
+        let output = self.run(&["remote"], None)?;
+        Ok(output.lines().any(|line| line.trim() == name))
+    }
+
+    fn get_config(&self, key: &str) -> Result<Option<String>, AppError> {
+        let args = ["config", "--local", "--get", key];
+        let output =
+            Command::new("git").args(args).current_dir(&self.root).output().map_err(|e| {
+                AppError::GitError {
+                    command: format!("git {}", args.join(" ")),
+                    details: e.to_string(),
+                }
+            })?;
+        // `git config --get` exits 1 when the key is unset; an empty value still exits 0.
+        match output.status.code() {
+            Some(0) => {
+                Ok(Some(String::from_utf8_lossy(&output.stdout).trim_end_matches('\n').to_string()))
+            }
+            Some(1) => Ok(None),
+            _ => {
+                let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+                Err(AppError::GitError {
+                    command: format!("git {}", args.join(" ")),
+                    details: if stderr.is_empty() { "Unknown error".to_string() } else { stderr },
+                })
+            }
+        }
+    }
+
+    fn set_config(&self, key: &str, value: &str) -> Result<(), AppError> {
+        self.run_output(&["config", "--local", key, value], None)?;
+        Ok(())
+    }
+
+    fn unset_config(&self, key: &str) -> Result<(), AppError> {
+        self.run_output(&["config", "--local", "--unset", key], None)?;
+        Ok(())
+    }
+
+    fn abort_merge(&self) -> Result<(), AppError> {
+        if self.run_output(&["rev-parse", "-q", "--verify", "MERGE_HEAD"], None).is_err() {
+            return Ok(());
+        }
+        self.run_output(&["merge", "--abort"], None)?;
+        Ok(())
+    }
+
+    fn cherry_pick(&self, rev: &str) -> Result<(), AppError> {
+        if rev.starts_with('-') {
+            return Err(AppError::Validation(format!("Invalid cherry-pick revision '{}'", rev)));
+        }
+        match self.run_output(&["cherry-pick", rev], None) {
+            Ok(_) => Ok(()),
+            Err(AppError::GitError { details, .. })
+                if self
+                    .run_output(&["rev-parse", "-q", "--verify", "CHERRY_PICK_HEAD"], None)
+                    .is_ok() =>
+            {
+                Err(AppError::CherryPickConflict { rev: rev.to_string(), details })
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    fn cherry_pick_abort(&self) -> Result<(), AppError> {
+        if self.run_output(&["rev-parse", "-q", "--verify", "CHERRY_PICK_HEAD"], None).is_err() {
+            return Ok(());
+        }
+        self.run_output(&["cherry-pick", "--abort"], None)?;
+        Ok(())
+    }
+
+    fn reset_hard_to(&self, branch: &str, rev: &str) -> Result<(), AppError> {
+        self.run_output(&["checkout", branch], None)?;
+        self.run_output(&["reset", "--hard", rev], None)?;
+        Ok(())
+    }
+
     fn delete_branch(&self, branch: &str, force: bool) -> Result<bool, AppError> {
         let output = self.run_output(&["branch", "--list", branch], None)?;
         if output.stdout.is_empty() {
@@ -153,6 +292,35 @@ impl Git for GitCommandAdapter {
         Ok(true)
     }
 
+    fn create_tag(
+        &self,
+        name: &str,
+        message: Option<&str>,
+        rev: Option<&str>,
+    ) -> Result<(), AppError> {
+        if name.starts_with('-') || rev.is_some_and(|rev| rev.starts_with('-')) {
+            return Err(AppError::Validation(format!(
+                "Invalid tag name or revision for '{}'",
+                name
+            )));
+        }
+        let tag_ref = format!("refs/tags/{}", name);
+        if self.run_output(&["rev-parse", "-q", "--verify", &tag_ref], None).is_ok() {
+            return Err(AppError::TagExists(name.to_string()));
+        }
+
+        let mut args = vec!["tag"];
+        if let Some(message) = message {
+            args.extend(["-a", "-m", message]);
+        }
+        args.push(name);
+        if let Some(rev) = rev {
+            args.push(rev);
+        }
+        self.run_output(&args, None)?;
+        Ok(())
+    }
+
     fn create_workspace(&self, branch: &str) -> Result<Box<dyn GitWorkspace>, AppError> {
         let workspaces_dir = jlo_paths::workspaces_dir(&self.root);
         std::fs::create_dir_all(&workspaces_dir).map_err(|e| AppError::Io {
@@ -196,6 +364,14 @@ impl Git for GitWorktreeWorkspace {
         self.adapter.get_current_branch()
     }
 
+    fn get_default_branch(&self) -> Result<String, AppError> {
+        self.adapter.get_default_branch()
+    }
+
+    fn blame_last_author(&self, rev: &str, path: &str) -> Result<String, AppError> {
+        self.adapter.blame_last_author(rev, path)
+    }
+
     fn commit_exists(&self, sha: &str) -> bool {
         self.adapter.commit_exists(sha)
     }
@@ -208,10 +384,26 @@ impl Git for GitWorktreeWorkspace {
         self.adapter.get_first_commit(commit)
     }
 
+    fn rev_parse(&self, rev: &str) -> Result<String, AppError> {
+        self.adapter.rev_parse(rev)
+    }
+
+    fn commit_message(&self, sha: &str) -> Result<String, AppError> {
+        self.adapter.commit_message(sha)
+    }
+
+    fn log_range(&self, from: &str, to: &str) -> Result<Vec<CommitInfo>, AppError> {
+        self.adapter.log_range(from, to)
+    }
+
     fn has_changes(&self, from: &str, to: &str, pathspec: &[&str]) -> Result<bool, AppError> {
         self.adapter.has_changes(from, to, pathspec)
     }
 
+    fn status(&self, pathspec: &[&str]) -> Result<Vec<StatusEntry>, AppError> {
+        self.adapter.status(pathspec)
+    }
+
     fn run_command(&self, args: &[&str], cwd: Option<&Path>) -> Result<String, AppError> {
         self.adapter.run_command(args, cwd)
     }
@@ -236,10 +428,55 @@ impl Git for GitWorktreeWorkspace {
         self.adapter.fetch(remote)
     }
 
+    fn fetch_prune(&self, remote: &str) -> Result<(), AppError> {
+        self.adapter.fetch_prune(remote)
+    }
+
+    fn remote_exists(&self, name: &str) -> Result<bool, AppError> {
+        self.adapter.remote_exists(name)
+    }
+
+    fn get_config(&self, key: &str) -> Result<Option<String>, AppError> {
+        self.adapter.get_config(key)
+    }
+
+    fn set_config(&self, key: &str, value: &str) -> Result<(), AppError> {
+        self.adapter.set_config(key, value)
+    }
+
+    fn unset_config(&self, key: &str) -> Result<(), AppError> {
+        self.adapter.unset_config(key)
+    }
+
+    fn abort_merge(&self) -> Result<(), AppError> {
+        self.adapter.abort_merge()
+    }
+
+    fn cherry_pick(&self, rev: &str) -> Result<(), AppError> {
+        self.adapter.cherry_pick(rev)
+    }
+
+    fn cherry_pick_abort(&self) -> Result<(), AppError> {
+        self.adapter.cherry_pick_abort()
+    }
+
+    fn reset_hard_to(&self, branch: &str, rev: &str) -> Result<(), AppError> {
+        self.adapter.reset_hard_to(branch, rev)
+    }
+
     fn delete_branch(&self, branch: &str, force: bool) -> Result<bool, AppError> {
         self.adapter.delete_branch(branch, force)
     }
 
+    fn create_tag(
+        &self,
+        name: &str,
+        message: Option<&str>,
+        rev: Option<&str>,
+    ) -> Result<(), AppError> {
+        self.adapter.create_tag(name, message, rev)
+    }
+
     fn create_workspace(&self, branch: &str) -> Result<Box<dyn GitWorkspace>, AppError> {
         self.adapter.create_workspace(branch)
     }
@@ -263,3 +500,61 @@ impl Drop for GitWorktreeWorkspace {
             .output();
     }
 }
+
+/// Parse `git status --porcelain=v1 -z` output.
+///
+/// Records are NUL-terminated `XY <path>`; renames and copies are followed by
+/// an extra record holding the source path, which is skipped.
+fn parse_porcelain_status(output: &str) -> Vec<StatusEntry> {
+    let mut entries = Vec::new();
+    let mut records = output.split('\0').filter(|record| !record.is_empty());
+    while let Some(record) = records.next() {
+        let mut chars = record.chars();
+        let (Some(index_state), Some(worktree_state)) = (chars.next(), chars.next()) else {
+            continue;
+        };
+        let Some(path) = record.get(3..) else {
+            continue;
+        };
+        if matches!(index_state, 'R' | 'C') {
+            records.next();
+        }
+        entries.push(StatusEntry { path: path.to_string(), index_state, worktree_state });
+    }
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_porcelain_status_records() {
+        let output = " M .jules/a.yml\0A  .jules/b.yml\0R  .jules/new name.yml\0.jules/old.yml\0?? .jules/c.yml\0";
+
+        let entries = parse_porcelain_status(output);
+
+        let entry = |path: &str, index_state, worktree_state| StatusEntry {
+            path: path.to_string(),
+            index_state,
+            worktree_state,
+        };
+        assert_eq!(
+            entries,
+            vec![
+                entry(".jules/a.yml", ' ', 'M'),
+                entry(".jules/b.yml", 'A', ' '),
+                entry(".jules/new name.yml", 'R', ' '),
+                entry(".jules/c.yml", '?', '?'),
+            ]
+        );
+        assert!(!entries[0].is_staged());
+        assert!(entries[1].is_staged());
+        assert!(entries[3].is_untracked() && !entries[3].is_staged());
+    }
+
+    #[test]
+    fn empty_status_has_no_entries() {
+        assert!(parse_porcelain_status("").is_empty());
+    }
+}
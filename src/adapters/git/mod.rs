@@ -1,3 +1,5 @@
 mod git_command;
+mod recording;
 
 pub use self::git_command::GitCommandAdapter;
+pub use self::recording::RecordingGit;
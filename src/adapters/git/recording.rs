@@ -0,0 +1,254 @@
+//! Recording decorator that captures repository-changing git calls into a run transcript.
+
+use std::ops::Deref;
+use std::path::Path;
+use std::sync::Arc;
+
+use serde_json::{Value, json};
+
+use crate::adapters::transcript::RunTranscript;
+use crate::domain::AppError;
+use crate::ports::{CommitInfo, Git, GitWorkspace, StatusEntry};
+
+/// Records calls that change the repository or its remotes before returning the inner
+/// result; read-only queries are delegated without recording.
+pub struct RecordingGit<G> {
+    inner: G,
+    transcript: Arc<RunTranscript>,
+}
+
+impl<G> RecordingGit<G>
+where
+    G: Deref,
+    G::Target: Git,
+{
+    pub fn new(inner: G, transcript: Arc<RunTranscript>) -> Self {
+        Self { inner, transcript }
+    }
+
+    fn record<T>(&self, operation: &'static str, request: Value, outcome: &Result<T, AppError>) {
+        self.transcript.record_outcome("git", operation, request, outcome, |_| Value::Null);
+    }
+}
+
+impl<G> Git for RecordingGit<G>
+where
+    G: Deref,
+    G::Target: Git,
+{
+    fn get_head_sha(&self) -> Result<String, AppError> {
+        self.inner.get_head_sha()
+    }
+
+    fn get_current_branch(&self) -> Result<String, AppError> {
+        self.inner.get_current_branch()
+    }
+
+    fn get_default_branch(&self) -> Result<String, AppError> {
+        self.inner.get_default_branch()
+    }
+
+    fn blame_last_author(&self, rev: &str, path: &str) -> Result<String, AppError> {
+        self.inner.blame_last_author(rev, path)
+    }
+
+    fn commit_exists(&self, sha: &str) -> bool {
+        self.inner.commit_exists(sha)
+    }
+
+    fn get_nth_ancestor(&self, commit: &str, n: usize) -> Result<Option<String>, AppError> {
+        self.inner.get_nth_ancestor(commit, n)
+    }
+
+    fn get_first_commit(&self, commit: &str) -> Result<String, AppError> {
+        self.inner.get_first_commit(commit)
+    }
+
+    fn rev_parse(&self, rev: &str) -> Result<String, AppError> {
+        self.inner.rev_parse(rev)
+    }
+
+    fn commit_message(&self, sha: &str) -> Result<String, AppError> {
+        self.inner.commit_message(sha)
+    }
+
+    fn log_range(&self, from: &str, to: &str) -> Result<Vec<CommitInfo>, AppError> {
+        self.inner.log_range(from, to)
+    }
+
+    fn has_changes(&self, from: &str, to: &str, pathspec: &[&str]) -> Result<bool, AppError> {
+        self.inner.has_changes(from, to, pathspec)
+    }
+
+    fn status(&self, pathspec: &[&str]) -> Result<Vec<StatusEntry>, AppError> {
+        self.inner.status(pathspec)
+    }
+
+    fn run_command(&self, args: &[&str], cwd: Option<&Path>) -> Result<String, AppError> {
+        let outcome = self.inner.run_command(args, cwd);
+        self.record(
+            "run_command",
+            json!({ "args": args, "cwd": cwd.map(|cwd| cwd.display().to_string()) }),
+            &outcome,
+        );
+        outcome
+    }
+
+    fn checkout_branch(&self, branch: &str, create: bool) -> Result<(), AppError> {
+        let outcome = self.inner.checkout_branch(branch, create);
+        self.record("checkout_branch", json!({ "branch": branch, "create": create }), &outcome);
+        outcome
+    }
+
+    fn push_branch(&self, branch: &str, force: bool) -> Result<(), AppError> {
+        let outcome = self.inner.push_branch(branch, force);
+        self.record("push_branch", json!({ "branch": branch, "force": force }), &outcome);
+        outcome
+    }
+
+    fn push_branch_from_rev(&self, rev: &str, branch: &str, force: bool) -> Result<(), AppError> {
+        let outcome = self.inner.push_branch_from_rev(rev, branch, force);
+        self.record(
+            "push_branch_from_rev",
+            json!({ "rev": rev, "branch": branch, "force": force }),
+            &outcome,
+        );
+        outcome
+    }
+
+    fn commit_files(&self, message: &str, files: &[&Path]) -> Result<String, AppError> {
+        let outcome = self.inner.commit_files(message, files);
+        let files: Vec<String> = files.iter().map(|file| file.display().to_string()).collect();
+        self.transcript.record_outcome(
+            "git",
+            "commit_files",
+            json!({ "message": message, "files": files }),
+            &outcome,
+            |sha| json!(sha),
+        );
+        outcome
+    }
+
+    fn fetch(&self, remote: &str) -> Result<(), AppError> {
+        let outcome = self.inner.fetch(remote);
+        self.record("fetch", json!({ "remote": remote }), &outcome);
+        outcome
+    }
+
+    fn fetch_prune(&self, remote: &str) -> Result<(), AppError> {
+        let outcome = self.inner.fetch_prune(remote);
+        self.record("fetch_prune", json!({ "remote": remote }), &outcome);
+        outcome
+    }
+
+    fn remote_exists(&self, name: &str) -> Result<bool, AppError> {
+        self.inner.remote_exists(name)
+    }
+
+    fn get_config(&self, key: &str) -> Result<Option<String>, AppError> {
+        self.inner.get_config(key)
+    }
+
+    fn set_config(&self, key: &str, value: &str) -> Result<(), AppError> {
+        let outcome = self.inner.set_config(key, value);
+        self.record("set_config", json!({ "key": key, "value": value }), &outcome);
+        outcome
+    }
+
+    fn unset_config(&self, key: &str) -> Result<(), AppError> {
+        let outcome = self.inner.unset_config(key);
+        self.record("unset_config", json!({ "key": key }), &outcome);
+        outcome
+    }
+
+    fn abort_merge(&self) -> Result<(), AppError> {
+        let outcome = self.inner.abort_merge();
+        self.record("abort_merge", Value::Null, &outcome);
+        outcome
+    }
+
+    fn cherry_pick(&self, rev: &str) -> Result<(), AppError> {
+        let outcome = self.inner.cherry_pick(rev);
+        self.record("cherry_pick", json!({ "rev": rev }), &outcome);
+        outcome
+    }
+
+    fn cherry_pick_abort(&self) -> Result<(), AppError> {
+        let outcome = self.inner.cherry_pick_abort();
+        self.record("cherry_pick_abort", Value::Null, &outcome);
+        outcome
+    }
+
+    fn reset_hard_to(&self, branch: &str, rev: &str) -> Result<(), AppError> {
+        let outcome = self.inner.reset_hard_to(branch, rev);
+        self.record("reset_hard_to", json!({ "branch": branch, "rev": rev }), &outcome);
+        outcome
+    }
+
+    fn delete_branch(&self, branch: &str, force: bool) -> Result<bool, AppError> {
+        let outcome = self.inner.delete_branch(branch, force);
+        self.transcript.record_outcome(
+            "git",
+            "delete_branch",
+            json!({ "branch": branch, "force": force }),
+            &outcome,
+            |deleted| json!(deleted),
+        );
+        outcome
+    }
+
+    fn create_tag(
+        &self,
+        name: &str,
+        message: Option<&str>,
+        rev: Option<&str>,
+    ) -> Result<(), AppError> {
+        let outcome = self.inner.create_tag(name, message, rev);
+        self.record(
+            "create_tag",
+            json!({ "name": name, "message": message, "rev": rev }),
+            &outcome,
+        );
+        outcome
+    }
+
+    fn create_workspace(&self, branch: &str) -> Result<Box<dyn GitWorkspace>, AppError> {
+        let outcome = self.inner.create_workspace(branch);
+        self.record("create_workspace", json!({ "branch": branch }), &outcome);
+        // Calls made inside the workspace land in the same transcript.
+        outcome.map(|workspace| {
+            Box::new(RecordingGit::new(workspace, Arc::clone(&self.transcript)))
+                as Box<dyn GitWorkspace>
+        })
+    }
+}
+
+impl GitWorkspace for RecordingGit<Box<dyn GitWorkspace>> {
+    fn path(&self) -> &Path {
+        self.inner.path()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::FakeGit;
+
+    #[test]
+    fn records_changes_but_not_queries() {
+        let transcript = Arc::new(RunTranscript::new());
+        let fake = FakeGit::new();
+        let git = RecordingGit::new(&fake as &dyn Git, Arc::clone(&transcript));
+
+        git.get_current_branch().unwrap();
+        git.checkout_branch("feature", true).unwrap();
+        git.push_branch("feature", false).unwrap();
+
+        let calls = transcript.calls();
+        let operations: Vec<&str> = calls.iter().map(|call| call.operation).collect();
+        assert_eq!(operations, ["checkout_branch", "push_branch"]);
+        assert!(calls.iter().all(|call| call.adapter == "git"));
+        assert_eq!(calls[0].request["branch"], "feature");
+        assert_eq!(calls[0].result, json!({ "ok": null }));
+    }
+}
@@ -2,7 +2,10 @@ use std::io::Write;
 use std::process::{Command, Stdio};
 
 use crate::domain::AppError;
-use crate::ports::{GitHub, IssueInfo, PrComment, PullRequestDetail, PullRequestInfo};
+use crate::ports::{
+    GitHub, IssueInfo, MergeStrategy, OPEN_PULL_REQUESTS_LIMIT, PrComment, PullRequestDetail,
+    PullRequestInfo, PullRequestSummary,
+};
 
 #[derive(Debug, Clone, Default)]
 pub struct GitHubCommandAdapter;
@@ -176,6 +179,38 @@ impl GitHub for GitHubCommandAdapter {
         })
     }
 
+    fn list_open_pull_requests(&self, base: &str) -> Result<Vec<PullRequestSummary>, AppError> {
+        let limit = OPEN_PULL_REQUESTS_LIMIT.to_string();
+        let output = self.run_gh(&[
+            "pr",
+            "list",
+            "--base",
+            base,
+            "--state",
+            "open",
+            "--limit",
+            &limit,
+            "--json",
+            "number,headRefName,baseRefName,createdAt",
+        ])?;
+        let json: Vec<serde_json::Value> =
+            serde_json::from_str(&output).map_err(|e| AppError::ParseError {
+                what: "PR list JSON".into(),
+                details: format!("Failed to parse gh pr list output: {}", e),
+            })?;
+        Ok(json
+            .iter()
+            .filter_map(|pr| {
+                Some(PullRequestSummary {
+                    number: pr["number"].as_u64()?,
+                    head: pr["headRefName"].as_str().unwrap_or_default().to_string(),
+                    base: pr["baseRefName"].as_str().unwrap_or_default().to_string(),
+                    created_at: pr["createdAt"].as_str().unwrap_or_default().to_string(),
+                })
+            })
+            .collect())
+    }
+
     fn list_pr_comments(&self, pr_number: u64) -> Result<Vec<PrComment>, AppError> {
         let pr_num_str = pr_number.to_string();
         // Use gh api to list issue comments on a PR
@@ -256,9 +291,9 @@ impl GitHub for GitHubCommandAdapter {
         Ok(())
     }
 
-    fn merge_pull_request(&self, pr_number: u64) -> Result<(), AppError> {
+    fn merge_pull_request(&self, pr_number: u64, strategy: MergeStrategy) -> Result<(), AppError> {
         let pr_num_str = pr_number.to_string();
-        self.run_gh(&["pr", "merge", &pr_num_str, "--squash", "--delete-branch"])?;
+        self.run_gh(&merge_args(&pr_num_str, strategy))?;
         Ok(())
     }
 
@@ -269,3 +304,29 @@ impl GitHub for GitHubCommandAdapter {
         Ok(files)
     }
 }
+
+fn merge_args(pr_number: &str, strategy: MergeStrategy) -> [&str; 5] {
+    ["pr", "merge", pr_number, strategy.gh_flag(), "--delete-branch"]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn merge_args_select_strategy_flag() {
+        assert_eq!(
+            merge_args("7", MergeStrategy::Squash),
+            ["pr", "merge", "7", "--squash", "--delete-branch"]
+        );
+        assert_eq!(
+            merge_args("7", MergeStrategy::Merge),
+            ["pr", "merge", "7", "--merge", "--delete-branch"]
+        );
+        assert_eq!(
+            merge_args("7", MergeStrategy::Rebase),
+            ["pr", "merge", "7", "--rebase", "--delete-branch"]
+        );
+        assert_eq!(MergeStrategy::default(), MergeStrategy::Squash);
+    }
+}
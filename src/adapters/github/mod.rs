@@ -1,3 +1,5 @@
 mod gh_command;
+mod recording;
 
 pub use self::gh_command::GitHubCommandAdapter;
+pub use self::recording::RecordingGitHub;
@@ -0,0 +1,177 @@
+//! Recording decorator that captures state-changing GitHub calls into a run transcript.
+
+use std::sync::Arc;
+
+use serde_json::{Value, json};
+
+use crate::adapters::transcript::RunTranscript;
+use crate::domain::AppError;
+use crate::ports::{
+    GitHub, IssueInfo, MergeStrategy, PrComment, PullRequestDetail, PullRequestInfo,
+    PullRequestSummary,
+};
+
+/// Records calls that create or change pull requests, issues, labels, comments, and remote
+/// branches; read-only queries are delegated without recording.
+pub struct RecordingGitHub<'a> {
+    inner: &'a dyn GitHub,
+    transcript: Arc<RunTranscript>,
+}
+
+impl<'a> RecordingGitHub<'a> {
+    pub fn new(inner: &'a dyn GitHub, transcript: Arc<RunTranscript>) -> Self {
+        Self { inner, transcript }
+    }
+
+    fn record<T>(&self, operation: &'static str, request: Value, outcome: &Result<T, AppError>) {
+        self.transcript.record_outcome("github", operation, request, outcome, |_| Value::Null);
+    }
+}
+
+impl GitHub for RecordingGitHub<'_> {
+    fn create_pull_request(
+        &self,
+        head: &str,
+        base: &str,
+        title: &str,
+        body: &str,
+    ) -> Result<PullRequestInfo, AppError> {
+        let outcome = self.inner.create_pull_request(head, base, title, body);
+        self.transcript.record_outcome(
+            "github",
+            "create_pull_request",
+            json!({ "head": head, "base": base, "title": title, "body": body }),
+            &outcome,
+            |pr| json!({ "number": pr.number, "url": pr.url }),
+        );
+        outcome
+    }
+
+    fn close_pull_request(&self, pr_number: u64) -> Result<(), AppError> {
+        let outcome = self.inner.close_pull_request(pr_number);
+        self.record("close_pull_request", json!({ "pr_number": pr_number }), &outcome);
+        outcome
+    }
+
+    fn delete_branch(&self, branch: &str) -> Result<(), AppError> {
+        let outcome = self.inner.delete_branch(branch);
+        self.record("delete_branch", json!({ "branch": branch }), &outcome);
+        outcome
+    }
+
+    fn create_issue(
+        &self,
+        title: &str,
+        body: &str,
+        labels: &[&str],
+    ) -> Result<IssueInfo, AppError> {
+        let outcome = self.inner.create_issue(title, body, labels);
+        self.transcript.record_outcome(
+            "github",
+            "create_issue",
+            json!({ "title": title, "body": body, "labels": labels }),
+            &outcome,
+            |issue| json!({ "number": issue.number, "url": issue.url }),
+        );
+        outcome
+    }
+
+    fn get_pr_detail(&self, pr_number: u64) -> Result<PullRequestDetail, AppError> {
+        self.inner.get_pr_detail(pr_number)
+    }
+
+    fn list_open_pull_requests(&self, base: &str) -> Result<Vec<PullRequestSummary>, AppError> {
+        self.inner.list_open_pull_requests(base)
+    }
+
+    fn list_pr_comments(&self, pr_number: u64) -> Result<Vec<PrComment>, AppError> {
+        self.inner.list_pr_comments(pr_number)
+    }
+
+    fn create_pr_comment(&self, pr_number: u64, body: &str) -> Result<u64, AppError> {
+        let outcome = self.inner.create_pr_comment(pr_number, body);
+        self.transcript.record_outcome(
+            "github",
+            "create_pr_comment",
+            json!({ "pr_number": pr_number, "body": body }),
+            &outcome,
+            |comment_id| json!(comment_id),
+        );
+        outcome
+    }
+
+    fn update_pr_comment(&self, comment_id: u64, body: &str) -> Result<(), AppError> {
+        let outcome = self.inner.update_pr_comment(comment_id, body);
+        self.record(
+            "update_pr_comment",
+            json!({ "comment_id": comment_id, "body": body }),
+            &outcome,
+        );
+        outcome
+    }
+
+    fn ensure_label(&self, label: &str, color: Option<&str>) -> Result<(), AppError> {
+        let outcome = self.inner.ensure_label(label, color);
+        self.record("ensure_label", json!({ "label": label, "color": color }), &outcome);
+        outcome
+    }
+
+    fn add_label_to_pr(&self, pr_number: u64, label: &str) -> Result<(), AppError> {
+        let outcome = self.inner.add_label_to_pr(pr_number, label);
+        self.record("add_label_to_pr", json!({ "pr_number": pr_number, "label": label }), &outcome);
+        outcome
+    }
+
+    fn add_label_to_issue(&self, issue_number: u64, label: &str) -> Result<(), AppError> {
+        let outcome = self.inner.add_label_to_issue(issue_number, label);
+        self.record(
+            "add_label_to_issue",
+            json!({ "issue_number": issue_number, "label": label }),
+            &outcome,
+        );
+        outcome
+    }
+
+    fn enable_automerge(&self, pr_number: u64) -> Result<(), AppError> {
+        let outcome = self.inner.enable_automerge(pr_number);
+        self.record("enable_automerge", json!({ "pr_number": pr_number }), &outcome);
+        outcome
+    }
+
+    fn merge_pull_request(&self, pr_number: u64, strategy: MergeStrategy) -> Result<(), AppError> {
+        let outcome = self.inner.merge_pull_request(pr_number, strategy);
+        self.record(
+            "merge_pull_request",
+            json!({ "pr_number": pr_number, "strategy": strategy.gh_flag() }),
+            &outcome,
+        );
+        outcome
+    }
+
+    fn list_pr_files(&self, pr_number: u64) -> Result<Vec<String>, AppError> {
+        self.inner.list_pr_files(pr_number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::FakeGitHub;
+
+    #[test]
+    fn records_pull_request_creation_but_not_queries() {
+        let transcript = Arc::new(RunTranscript::new());
+        let fake = FakeGitHub::new();
+        let github = RecordingGitHub::new(&fake, Arc::clone(&transcript));
+
+        github.list_open_pull_requests("jules").unwrap();
+        let pr = github.create_pull_request("feature", "jules", "title", "body").unwrap();
+
+        let calls = transcript.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].adapter, "github");
+        assert_eq!(calls[0].operation, "create_pull_request");
+        assert_eq!(calls[0].request["head"], "feature");
+        assert_eq!(calls[0].result["ok"]["number"], pr.number);
+    }
+}
@@ -105,6 +105,7 @@ impl HttpJulesClient {
             return Ok(SessionResponse {
                 session_id,
                 status: api_response.status.unwrap_or_else(|| "created".to_string()),
+                url: api_response.url,
             });
         }
 
@@ -164,6 +165,8 @@ struct ApiResponse {
     id: Option<String>,
     #[serde(default)]
     status: Option<String>,
+    #[serde(default)]
+    url: Option<String>,
 }
 
 fn extract_error_message(body: &str) -> Option<String> {
@@ -244,6 +247,27 @@ impl JulesClient for HttpJulesClient {
 
         self.send_request(&api_request)
     }
+
+    fn health(&self) -> Result<(), AppError> {
+        let mut url = self.api_url.clone();
+        url.query_pairs_mut().append_pair("pageSize", "1");
+        let response =
+            self.client.get(url).header(X_GOOG_API_KEY, &self.api_key).send().map_err(|e| {
+                AppError::JulesApiError {
+                    message: format!("HTTP request failed: {}", e),
+                    status: None,
+                }
+            })?;
+
+        let status = response.status();
+        if status.is_success() {
+            return Ok(());
+        }
+        Err(AppError::JulesApiError {
+            message: format!("Health check returned {}", status),
+            status: Some(status.as_u16()),
+        })
+    }
 }
 
 #[cfg(test)]
@@ -266,7 +290,9 @@ mod tests {
             .mock("POST", "/")
             .with_status(200)
             .with_header("content-type", "application/json")
-            .with_body(r#"{"sessionId": "test-session", "status": "created"}"#)
+            .with_body(
+                r#"{"sessionId": "test-session", "status": "created", "url": "https://jules.google.com/session/test-session"}"#,
+            )
             .create();
 
         let config = JulesApiConfig {
@@ -274,6 +300,7 @@ mod tests {
             max_retries: 3,
             retry_delay_ms: 1,
             timeout_secs: 1,
+            ..Default::default()
         };
 
         let client = HttpJulesClient::new("fake-key".to_string(), &config).unwrap();
@@ -285,9 +312,9 @@ mod tests {
             automation_mode: AutomationMode::None,
         };
 
-        let result = client.create_session(request);
-        assert!(result.is_ok());
-        assert_eq!(result.unwrap().session_id, "test-session");
+        let response = client.create_session(request).unwrap();
+        assert_eq!(response.session_id, "test-session");
+        assert_eq!(response.url.as_deref(), Some("https://jules.google.com/session/test-session"));
     }
 
     #[test]
@@ -300,6 +327,7 @@ mod tests {
             max_retries: 3,
             retry_delay_ms: 1,
             timeout_secs: 1,
+            ..Default::default()
         };
 
         let client = HttpJulesClient::new("fake-key".to_string(), &config).unwrap();
@@ -326,6 +354,7 @@ mod tests {
             max_retries: 3,
             retry_delay_ms: 1,
             timeout_secs: 1,
+            ..Default::default()
         };
 
         let client = HttpJulesClient::new("fake-key".to_string(), &config).unwrap();
@@ -353,6 +382,7 @@ mod tests {
             max_retries: 3,
             retry_delay_ms: 1,
             timeout_secs: 1,
+            ..Default::default()
         };
 
         let client = HttpJulesClient::new("fake-key".to_string(), &config).unwrap();
@@ -385,6 +415,7 @@ mod tests {
             max_retries: 3,
             retry_delay_ms: 1,
             timeout_secs: 1,
+            ..Default::default()
         };
         let client = HttpJulesClient::new("fake-key".to_string(), &config).unwrap();
 
@@ -423,6 +454,7 @@ mod tests {
             max_retries: 3,
             retry_delay_ms: 1,
             timeout_secs: 1,
+            ..Default::default()
         };
         let client = HttpJulesClient::new("fake-key".to_string(), &config).unwrap();
 
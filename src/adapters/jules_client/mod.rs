@@ -1,5 +1,11 @@
 pub mod http;
+pub mod prompt_guard;
+pub mod recording;
 pub mod retrying;
 
 pub use self::http::HttpJulesClient;
-pub use self::retrying::{RetryPolicy, RetryingJulesClient};
+pub use self::prompt_guard::PromptSizeGuardClient;
+pub use self::recording::RecordingJulesClient;
+pub use self::retrying::{
+    CircuitBreaker, DEFAULT_CIRCUIT_FAILURE_THRESHOLD, RetryPolicy, RetryingJulesClient,
+};
@@ -0,0 +1,124 @@
+//! Prompt-size guard for Jules API session creation.
+
+use crate::domain::AppError;
+use crate::ports::{JulesClient, SessionRequest, SessionResponse};
+
+/// Rejects (or truncates) prompts longer than `max_chars` before they reach the API.
+pub struct PromptSizeGuardClient {
+    inner: Box<dyn JulesClient>,
+    max_chars: usize,
+    allow_truncate: bool,
+}
+
+impl PromptSizeGuardClient {
+    pub fn new(inner: Box<dyn JulesClient>, max_chars: usize, allow_truncate: bool) -> Self {
+        Self { inner, max_chars, allow_truncate }
+    }
+}
+
+impl JulesClient for PromptSizeGuardClient {
+    fn create_session(&self, mut request: SessionRequest) -> Result<SessionResponse, AppError> {
+        request.prompt = guard_prompt(request.prompt, self.max_chars, self.allow_truncate)?;
+        self.inner.create_session(request)
+    }
+
+    fn health(&self) -> Result<(), AppError> {
+        self.inner.health()
+    }
+}
+
+/// Enforce the prompt size limit, counting characters rather than bytes.
+fn guard_prompt(
+    prompt: String,
+    max_chars: usize,
+    allow_truncate: bool,
+) -> Result<String, AppError> {
+    let length = prompt.chars().count();
+    if length <= max_chars {
+        return Ok(prompt);
+    }
+
+    if !allow_truncate {
+        return Err(AppError::Validation(format!(
+            "Assembled prompt is {} chars, exceeding jules_api.max_prompt_chars = {}. \
+             Shorten the role definition, raise the limit in .jlo/config.toml, or pass --allow-truncate.",
+            length, max_chars
+        )));
+    }
+
+    eprintln!(
+        "Warning: assembled prompt is {} chars; truncating to jules_api.max_prompt_chars = {}.",
+        length, max_chars
+    );
+    Ok(prompt.chars().take(max_chars).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use super::*;
+
+    struct RecordingClient {
+        prompts: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl JulesClient for RecordingClient {
+        fn create_session(&self, request: SessionRequest) -> Result<SessionResponse, AppError> {
+            self.prompts.lock().unwrap().push(request.prompt);
+            Ok(SessionResponse {
+                session_id: "s-1".to_string(),
+                status: "created".to_string(),
+                url: None,
+            })
+        }
+
+        fn health(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+    }
+
+    fn request(prompt: &str) -> SessionRequest {
+        SessionRequest {
+            prompt: prompt.to_string(),
+            source: "sources/github/owner/repo".to_string(),
+            starting_branch: "main".to_string(),
+            require_plan_approval: false,
+            automation_mode: crate::ports::AutomationMode::None,
+        }
+    }
+
+    fn guarded(allow_truncate: bool) -> (PromptSizeGuardClient, Arc<Mutex<Vec<String>>>) {
+        let prompts = Arc::new(Mutex::new(Vec::new()));
+        let inner = RecordingClient { prompts: Arc::clone(&prompts) };
+        (PromptSizeGuardClient::new(Box::new(inner), 5, allow_truncate), prompts)
+    }
+
+    #[test]
+    fn over_limit_prompt_errors_without_reaching_api() {
+        let (client, prompts) = guarded(false);
+
+        let err = client.create_session(request("abcdefgh")).unwrap_err();
+
+        assert!(err.to_string().contains("8 chars, exceeding jules_api.max_prompt_chars = 5"));
+        assert!(prompts.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn over_limit_prompt_is_truncated_when_allowed() {
+        let (client, prompts) = guarded(true);
+
+        client.create_session(request("ああいいうえお")).unwrap();
+
+        assert_eq!(*prompts.lock().unwrap(), vec!["ああいいう".to_string()]);
+    }
+
+    #[test]
+    fn prompt_within_limit_passes_through() {
+        let (client, prompts) = guarded(false);
+
+        client.create_session(request("abcde")).unwrap();
+
+        assert_eq!(*prompts.lock().unwrap(), vec!["abcde".to_string()]);
+    }
+}
@@ -0,0 +1,120 @@
+//! Recording decorator that captures Jules API calls into a run transcript.
+
+use std::sync::Arc;
+
+use serde_json::{Value, json};
+
+use crate::adapters::transcript::RunTranscript;
+use crate::domain::AppError;
+use crate::ports::{JulesClient, SessionRequest, SessionResponse};
+
+/// Records `create_session` and `health` calls before delegating to the inner client.
+pub struct RecordingJulesClient {
+    inner: Box<dyn JulesClient>,
+    transcript: Arc<RunTranscript>,
+}
+
+impl RecordingJulesClient {
+    pub fn new(inner: Box<dyn JulesClient>, transcript: Arc<RunTranscript>) -> Self {
+        Self { inner, transcript }
+    }
+}
+
+impl JulesClient for RecordingJulesClient {
+    fn create_session(&self, request: SessionRequest) -> Result<SessionResponse, AppError> {
+        let recorded_request = json!({
+            "prompt": request.prompt,
+            "source": request.source,
+            "starting_branch": request.starting_branch,
+            "require_plan_approval": request.require_plan_approval,
+            "automation_mode": request.automation_mode.as_str(),
+        });
+        let response = self.inner.create_session(request);
+        self.transcript.record_outcome(
+            "jules",
+            "create_session",
+            recorded_request,
+            &response,
+            |session| json!({ "session_id": session.session_id, "status": session.status }),
+        );
+        response
+    }
+
+    fn health(&self) -> Result<(), AppError> {
+        let response = self.inner.health();
+        self.transcript.record_outcome("jules", "health", Value::Null, &response, |_| Value::Null);
+        response
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct StubClient {
+        fail: bool,
+    }
+
+    impl JulesClient for StubClient {
+        fn create_session(&self, _request: SessionRequest) -> Result<SessionResponse, AppError> {
+            if self.fail {
+                return Err(AppError::JulesApiError {
+                    message: "rejected key sk-secret".to_string(),
+                    status: Some(401),
+                });
+            }
+            Ok(SessionResponse {
+                session_id: "s-1".to_string(),
+                status: "created".to_string(),
+                url: None,
+            })
+        }
+
+        fn health(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+    }
+
+    fn request() -> SessionRequest {
+        SessionRequest {
+            prompt: "observe".to_string(),
+            source: "sources/github/owner/repo".to_string(),
+            starting_branch: "jules".to_string(),
+            require_plan_approval: false,
+            automation_mode: crate::ports::AutomationMode::AutoCreatePr,
+        }
+    }
+
+    #[test]
+    fn transcript_captures_session_create_calls() {
+        let transcript = Arc::new(RunTranscript::new());
+        let client =
+            RecordingJulesClient::new(Box::new(StubClient { fail: false }), transcript.clone());
+
+        client.create_session(request()).unwrap();
+        client.create_session(request()).unwrap();
+
+        let json = transcript.to_json(json!({ "layer": "observers" }), Value::Null, &[]);
+        let calls = json["calls"].as_array().unwrap();
+        assert_eq!(calls.len(), 2);
+        assert_eq!(calls[0]["adapter"], "jules");
+        assert_eq!(calls[0]["operation"], "create_session");
+        assert_eq!(calls[0]["request"]["starting_branch"], "jules");
+        assert_eq!(calls[0]["result"]["ok"]["session_id"], "s-1");
+        assert_eq!(json["inputs"]["layer"], "observers");
+    }
+
+    #[test]
+    fn transcript_redacts_secrets_and_records_failures() {
+        let transcript = Arc::new(RunTranscript::new());
+        let client =
+            RecordingJulesClient::new(Box::new(StubClient { fail: true }), transcript.clone());
+
+        assert!(client.create_session(request()).is_err());
+
+        let rendered =
+            transcript.to_json(Value::Null, Value::Null, &["sk-secret".to_string()]).to_string();
+        assert!(!rendered.contains("sk-secret"));
+        assert!(rendered.contains("rejected key [REDACTED]"));
+    }
+}
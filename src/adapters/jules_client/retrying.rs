@@ -1,5 +1,7 @@
 //! Retry wrapper for Jules API client operations.
 
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU32, Ordering};
 use std::thread;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
@@ -9,6 +11,8 @@ use crate::ports::{JulesClient, SessionRequest, SessionResponse};
 const DEFAULT_MAX_DELAY_MS: u64 = 30_000;
 const RETRY_AFTER_TOKEN: &str = "retry_after_ms=";
 const MAX_LOG_ERROR_CHARS: usize = 512;
+/// Consecutive failed `create_session` calls that open the circuit.
+pub const DEFAULT_CIRCUIT_FAILURE_THRESHOLD: u32 = 3;
 
 #[derive(Debug, Clone, Copy)]
 pub struct RetryPolicy {
@@ -40,19 +44,89 @@ impl RetryPolicy {
     }
 }
 
+/// Counts consecutive transient failures across the clients of a single run.
+///
+/// Once `threshold` calls in a row have failed after exhausting their retries,
+/// the circuit opens and later calls fail fast with `AppError::JulesCircuitOpen`.
+/// A success before then resets the count. There is no half-open probe: once open,
+/// the circuit stays open for the rest of the run.
+#[derive(Debug)]
+pub struct CircuitBreaker {
+    threshold: u32,
+    consecutive_failures: AtomicU32,
+}
+
+impl CircuitBreaker {
+    pub fn new(threshold: u32) -> Self {
+        Self { threshold: threshold.max(1), consecutive_failures: AtomicU32::new(0) }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.consecutive_failures.load(Ordering::SeqCst) >= self.threshold
+    }
+
+    fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::SeqCst);
+    }
+
+    fn record_failure(&self) {
+        self.consecutive_failures.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(DEFAULT_CIRCUIT_FAILURE_THRESHOLD)
+    }
+}
+
 pub struct RetryingJulesClient {
     inner: Box<dyn JulesClient>,
     policy: RetryPolicy,
+    breaker: Arc<CircuitBreaker>,
 }
 
 impl RetryingJulesClient {
     pub fn new(inner: Box<dyn JulesClient>, policy: RetryPolicy) -> Self {
-        Self { inner, policy }
+        Self::with_breaker(inner, policy, Arc::new(CircuitBreaker::default()))
+    }
+
+    /// Share `breaker` so sustained failures trip it across clients of one run.
+    pub fn with_breaker(
+        inner: Box<dyn JulesClient>,
+        policy: RetryPolicy,
+        breaker: Arc<CircuitBreaker>,
+    ) -> Self {
+        Self { inner, policy, breaker }
     }
 }
 
 impl JulesClient for RetryingJulesClient {
     fn create_session(&self, request: SessionRequest) -> Result<SessionResponse, AppError> {
+        if self.breaker.is_open() {
+            return Err(AppError::JulesCircuitOpen { failures: self.breaker.threshold });
+        }
+
+        let result = self.create_session_with_retries(request);
+        match &result {
+            Ok(_) => self.breaker.record_success(),
+            Err(error) if is_retryable_error(error) => self.breaker.record_failure(),
+            Err(_) => {}
+        }
+        result
+    }
+
+    fn health(&self) -> Result<(), AppError> {
+        // A health probe reports the first failure; retrying would hide flakiness.
+        self.inner.health()
+    }
+}
+
+impl RetryingJulesClient {
+    fn create_session_with_retries(
+        &self,
+        request: SessionRequest,
+    ) -> Result<SessionResponse, AppError> {
         let mut last_error: Option<AppError> = None;
 
         for attempt in 1..=self.policy.max_attempts {
@@ -172,7 +246,7 @@ fn sanitize_and_truncate_for_log(input: &str) -> String {
 
 #[cfg(test)]
 mod tests {
-    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::atomic::AtomicUsize;
 
     use super::*;
 
@@ -199,6 +273,22 @@ mod tests {
             }
             guard.remove(0)
         }
+
+        fn health(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+    }
+
+    struct SharedClient(std::sync::Arc<SequenceClient>);
+
+    impl JulesClient for SharedClient {
+        fn create_session(&self, request: SessionRequest) -> Result<SessionResponse, AppError> {
+            self.0.create_session(request)
+        }
+
+        fn health(&self) -> Result<(), AppError> {
+            Ok(())
+        }
     }
 
     fn test_request() -> SessionRequest {
@@ -223,6 +313,7 @@ mod tests {
             Ok(SessionResponse {
                 session_id: "session-123".to_string(),
                 status: "created".to_string(),
+                url: None,
             }),
         ]);
         let client = RetryingJulesClient::new(Box::new(inner), policy(3));
@@ -261,6 +352,55 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn circuit_opens_after_consecutive_failures_and_fails_fast() {
+        let server_error = || {
+            Err(AppError::JulesApiError { message: "server error".to_string(), status: Some(500) })
+        };
+        let inner = std::sync::Arc::new(SequenceClient::new(vec![
+            server_error(),
+            server_error(),
+            server_error(),
+        ]));
+        let client = RetryingJulesClient::with_breaker(
+            Box::new(SharedClient(inner.clone())),
+            policy(1),
+            Arc::new(CircuitBreaker::new(3)),
+        );
+
+        for _ in 0..3 {
+            assert!(matches!(
+                client.create_session(test_request()),
+                Err(AppError::JulesApiError { .. })
+            ));
+        }
+        let err = client.create_session(test_request()).unwrap_err();
+        assert!(matches!(err, AppError::JulesCircuitOpen { failures: 3 }));
+        assert_eq!(inner.attempts.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn success_resets_circuit_failure_count() {
+        let server_error = || {
+            Err(AppError::JulesApiError { message: "server error".to_string(), status: Some(500) })
+        };
+        let created = || {
+            Ok(SessionResponse {
+                session_id: "s".to_string(),
+                status: "created".to_string(),
+                url: None,
+            })
+        };
+        let inner = SequenceClient::new(vec![server_error(), created(), server_error(), created()]);
+        let breaker = Arc::new(CircuitBreaker::new(2));
+        let client = RetryingJulesClient::with_breaker(Box::new(inner), policy(1), breaker.clone());
+
+        for _ in 0..4 {
+            let _ = client.create_session(test_request());
+        }
+        assert!(!breaker.is_open());
+    }
+
     #[test]
     fn log_format_sanitizes_control_characters() {
         let err = AppError::JulesApiError {
@@ -3,6 +3,8 @@
 use std::fs;
 use std::path::PathBuf;
 
+use sha2::{Digest, Sha256};
+
 use crate::domain::{AppError, JLO_DIR, Layer, RoleId};
 use crate::ports::{DiscoveredRole, JloStore, RepositoryFilesystem};
 
@@ -17,6 +19,28 @@ impl JloStore for LocalRepositoryAdapter {
         self.root.join(JLO_DIR)
     }
 
+    fn config_path(&self) -> PathBuf {
+        match &self.config_path {
+            Some(path) => self.root.join(path),
+            None => crate::domain::config::paths::config(&self.root),
+        }
+    }
+
+    fn read_config(&self) -> Result<String, AppError> {
+        // An explicit `--config` file may live outside the repository root.
+        let path = self.config_path();
+        match fs::read_to_string(&path) {
+            Ok(content) => Ok(content),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => match self.config_path {
+                Some(_) => {
+                    Err(AppError::Validation(format!("Config file not found: {}", path.display())))
+                }
+                None => Err(AppError::ControlPlaneConfigMissing),
+            },
+            Err(err) => Err(err.into()),
+        }
+    }
+
     fn jlo_write_version(&self, version: &str) -> Result<(), AppError> {
         let path = format!("{}/{}", JLO_DIR, crate::domain::VERSION_FILE);
         self.write_file(&path, &format!("{}\n", version))
@@ -43,14 +67,9 @@ impl JloStore for LocalRepositoryAdapter {
                 continue;
             }
 
-            for entry in fs::read_dir(&layer_dir)? {
-                let entry = entry?;
-                if !entry.path().is_dir() {
-                    continue;
-                }
-                let role_id_str = entry.file_name().to_string_lossy().to_string();
+            for role_id_str in self.list_dirs(&layer_dir.to_string_lossy())? {
                 if let Ok(role_id) = RoleId::new(&role_id_str)
-                    && entry.path().join("role.yml").exists()
+                    && layer_dir.join(&role_id_str).join("role.yml").exists()
                 {
                     roles.push(DiscoveredRole { layer, id: role_id });
                 }
@@ -91,6 +110,11 @@ impl JloStore for LocalRepositoryAdapter {
         }
     }
 
+    fn role_exists(&self, layer: Layer, role: &str) -> bool {
+        RoleId::new(role).is_ok()
+            && crate::domain::roles::paths::role_yml(&self.root, layer, role).is_file()
+    }
+
     fn role_path(&self, role: &DiscoveredRole) -> Option<PathBuf> {
         let path = crate::domain::roles::paths::role_dir(&self.root, role.layer, role.id.as_str());
         if path.exists() { Some(path) } else { None }
@@ -101,12 +125,19 @@ impl JloStore for LocalRepositoryAdapter {
         let rel = path.strip_prefix(&self.root).unwrap_or(&path);
         self.write_file(&rel.to_string_lossy(), content)
     }
+
+    fn file_hash(&self, path: &str) -> Result<String, AppError> {
+        let full_path = self.resolve_path(path);
+        self.validate_path_within_root(&full_path)?;
+        let bytes = fs::read(full_path)?;
+        Ok(format!("{:x}", Sha256::digest(&bytes)))
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::super::tests::test_store;
-    use crate::domain::Layer;
+    use crate::domain::{AppError, Layer};
     use crate::ports::{JloStore, RepositoryFilesystem};
 
     #[test]
@@ -154,6 +185,36 @@ mod tests {
         assert!(store.find_role_fuzzy("nonexistent").unwrap().is_none());
     }
 
+    #[test]
+    fn role_exists_requires_valid_name_and_role_yml() {
+        let (_dir, store) = test_store();
+
+        store.write_file(".jlo/roles/observers/taxonomy/role.yml", "role: taxonomy").unwrap();
+        store.create_dir_all(".jlo/roles/observers/empty").unwrap();
+
+        assert!(store.role_exists(Layer::Observers, "taxonomy"));
+        assert!(!store.role_exists(Layer::Innovators, "taxonomy"));
+        assert!(!store.role_exists(Layer::Observers, "empty"));
+        assert!(!store.role_exists(Layer::Observers, "missing"));
+        assert!(!store.role_exists(Layer::Observers, "../observers/taxonomy"));
+        assert!(!store.role_exists(Layer::Observers, ""));
+    }
+
+    #[test]
+    fn file_hash_tracks_content() {
+        let (_dir, store) = test_store();
+
+        store.write_file(".jlo/a.yml", "role: taxonomy\n").unwrap();
+        store.write_file(".jlo/b.yml", "role: taxonomy\n").unwrap();
+        store.write_file(".jlo/c.yml", "role: taxonomx\n").unwrap();
+
+        let a = store.file_hash(".jlo/a.yml").unwrap();
+        assert_eq!(a.len(), 64);
+        assert_eq!(a, store.file_hash(".jlo/b.yml").unwrap());
+        assert_ne!(a, store.file_hash(".jlo/c.yml").unwrap());
+        assert!(store.file_hash(".jlo/missing.yml").is_err());
+    }
+
     #[test]
     fn version_roundtrip() {
         let (_dir, store) = test_store();
@@ -164,4 +225,20 @@ mod tests {
         store.jlo_write_version("1.2.3").unwrap();
         assert_eq!(store.jlo_read_version().unwrap(), Some("1.2.3".to_string()));
     }
+
+    #[test]
+    fn read_config_follows_the_config_path() {
+        let (dir, store) = test_store();
+        assert!(matches!(store.read_config(), Err(AppError::ControlPlaneConfigMissing)));
+        store.write_file(".jlo/config.toml", "[run]\n").unwrap();
+        assert_eq!(store.read_config().unwrap(), "[run]\n");
+
+        store.write_file("configs/alt.toml", "[workflow]\n").unwrap();
+        let store = store.with_config_path(Some("configs/alt.toml".into()));
+        assert_eq!(store.config_path(), dir.path().join("configs/alt.toml"));
+        assert_eq!(store.read_config().unwrap(), "[workflow]\n");
+
+        let missing = store.with_config_path(Some(dir.path().join("missing.toml")));
+        assert!(missing.read_config().unwrap_err().to_string().contains("Config file not found"));
+    }
 }
@@ -3,6 +3,8 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::adapters::catalogs::scaffold_assets::list_event_states;
+use crate::domain::exchange::events::paths::event_transition_paths;
 use crate::domain::{AppError, JULES_DIR, Layer, PromptAssetLoader, VERSION_FILE};
 use crate::ports::{JulesStore, RepositoryFilesystem, ScaffoldFile};
 
@@ -45,6 +47,9 @@ impl JulesStore for LocalRepositoryAdapter {
                 fs::create_dir_all(parent)?;
             }
             fs::write(&path, &entry.content)?;
+            if entry.executable {
+                self.set_executable(&entry.path)?;
+            }
         }
 
         // Create schemas directories for layers that have schemas
@@ -71,6 +76,33 @@ impl JulesStore for LocalRepositoryAdapter {
         let content = self.read_file(&path)?;
         Ok(Some(content.trim().to_string()))
     }
+
+    fn move_event(&self, event: &str, from_state: &str, to_state: &str) -> Result<(), AppError> {
+        let (source, dest) = event_transition_paths(
+            &self.jules_path(),
+            event,
+            from_state,
+            to_state,
+            &list_event_states()?,
+        )?;
+        if !source.is_file() {
+            return Err(AppError::Validation(format!(
+                "Event '{}' not found in '{}'",
+                event, from_state
+            )));
+        }
+        if dest.exists() {
+            return Err(AppError::Validation(format!(
+                "Event '{}' already exists in '{}'",
+                event, to_state
+            )));
+        }
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::rename(&source, &dest)?;
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -85,6 +117,7 @@ mod tests {
         let files = vec![ScaffoldFile {
             path: ".jules/README.md".to_string(),
             content: "# Test".to_string(),
+            executable: false,
         }];
         store.create_structure(&files).unwrap();
 
@@ -93,6 +126,57 @@ mod tests {
         assert!(store.jules_path().join("README.md").exists());
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn create_structure_sets_executable_bit_for_marked_files() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let (_dir, store) = test_store();
+        let files = vec![
+            ScaffoldFile {
+                path: ".jules/scripts/run.sh".to_string(),
+                content: "echo run\n".to_string(),
+                executable: true,
+            },
+            ScaffoldFile {
+                path: ".jules/README.md".to_string(),
+                content: "# Test".to_string(),
+                executable: false,
+            },
+        ];
+        store.create_structure(&files).unwrap();
+
+        let mode = |name: &str| {
+            std::fs::metadata(store.jules_path().join(name)).unwrap().permissions().mode()
+        };
+        assert_ne!(mode("scripts/run.sh") & 0o111, 0);
+        assert_eq!(mode("README.md") & 0o111, 0);
+    }
+
+    #[test]
+    fn move_event_moves_pending_event_to_decided() {
+        let (_dir, store) = test_store();
+        let pending = store.jules_path().join("exchange/events/pending");
+        std::fs::create_dir_all(&pending).unwrap();
+        std::fs::write(pending.join("event-a.yml"), "id: aaaaaa\n").unwrap();
+
+        store.move_event("event-a", "pending", "decided").unwrap();
+
+        let decided = store.jules_path().join("exchange/events/decided/event-a.yml");
+        assert!(!pending.join("event-a.yml").exists());
+        assert_eq!(std::fs::read_to_string(decided).unwrap(), "id: aaaaaa\n");
+    }
+
+    #[test]
+    fn move_event_rejects_unknown_state_and_missing_event() {
+        let (_dir, store) = test_store();
+
+        let unknown = store.move_event("event-a", "pending", "archived").unwrap_err();
+        assert!(unknown.to_string().contains("Unknown event state 'archived'"), "{}", unknown);
+        let missing = store.move_event("event-a", "pending", "decided").unwrap_err();
+        assert!(missing.to_string().contains("not found in 'pending'"), "{}", missing);
+    }
+
     #[test]
     fn create_structure_creates_schema_directories() {
         let (_dir, store) = test_store();
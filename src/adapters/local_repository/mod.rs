@@ -20,12 +20,22 @@ use crate::domain::AppError;
 #[derive(Debug, Clone)]
 pub struct LocalRepositoryAdapter {
     root: PathBuf,
+    /// Control-plane config read instead of `.jlo/config.toml` (`--config`).
+    config_path: Option<PathBuf>,
 }
 
 impl LocalRepositoryAdapter {
     /// Create a store rooted at the given directory.
     pub fn new(root: PathBuf) -> Self {
-        Self { root }
+        Self { root, config_path: None }
+    }
+
+    /// Read the control-plane config from `config_path` instead of `.jlo/config.toml`.
+    ///
+    /// Relative paths resolve against the root. Writes to the config are unaffected.
+    pub fn with_config_path(mut self, config_path: Option<PathBuf>) -> Self {
+        self.config_path = config_path;
+        self
     }
 
     /// Create a store rooted at the current working directory.
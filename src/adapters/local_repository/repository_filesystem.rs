@@ -42,6 +42,23 @@ impl RepositoryFilesystem for LocalRepositoryAdapter {
         Ok(())
     }
 
+    fn rename(&self, from: &str, to: &str) -> Result<(), AppError> {
+        let from_path = self.resolve_path(from);
+        let to_path = self.resolve_path(to);
+        self.validate_path_within_root(&from_path)?;
+        self.validate_path_within_root(&to_path)?;
+        if to_path.exists() {
+            return Err(AppError::Validation(format!(
+                "Cannot rename '{}' to '{}': destination already exists",
+                from, to
+            )));
+        }
+        if let Some(parent) = to_path.parent() {
+            fs::create_dir_all(parent).map_err(AppError::from)?;
+        }
+        fs::rename(from_path, to_path).map_err(AppError::from)
+    }
+
     fn list_dir(&self, path: &str) -> Result<Vec<PathBuf>, AppError> {
         let full_path = self.resolve_path(path);
         self.validate_path_within_root(&full_path)?;
@@ -100,3 +117,44 @@ impl RepositoryFilesystem for LocalRepositoryAdapter {
         fs::canonicalize(p).map_err(AppError::from)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use tempfile::TempDir;
+
+    use super::*;
+
+    #[test]
+    fn list_dirs_is_sorted_and_skips_dot_directories() {
+        let dir = TempDir::new().expect("temp dir");
+        let store = LocalRepositoryAdapter::new(dir.path().to_path_buf());
+        store.write_file("roles/zeta/role.yml", "").unwrap();
+        store.write_file("roles/alpha/role.yml", "").unwrap();
+        store.write_file("roles/.git/HEAD", "").unwrap();
+        store.write_file("roles/notes.md", "").unwrap();
+
+        assert_eq!(store.list_dirs("roles").unwrap(), vec!["alpha", "zeta"]);
+        assert_eq!(
+            store.list_dirs_including_hidden("roles").unwrap(),
+            vec![".git", "alpha", "zeta"]
+        );
+    }
+
+    #[test]
+    fn rename_moves_directory_and_refuses_to_overwrite() {
+        let dir = TempDir::new().expect("temp dir");
+        let store = LocalRepositoryAdapter::new(dir.path().to_path_buf());
+        store.write_file("a/one/role.yml", "role: one\n").unwrap();
+        store.write_file("a/two/role.yml", "role: two\n").unwrap();
+
+        store.rename("a/one", "a/three").unwrap();
+        assert!(!store.file_exists("a/one"));
+        assert_eq!(store.read_file("a/three/role.yml").unwrap(), "role: one\n");
+
+        let err = store.rename("a/three", "a/two").unwrap_err();
+        assert!(err.to_string().contains("destination already exists"));
+        assert_eq!(store.read_file("a/two/role.yml").unwrap(), "role: two\n");
+
+        assert!(matches!(store.rename("a/two", "../escaped"), Err(AppError::PathTraversal(_))));
+    }
+}
@@ -4,4 +4,5 @@ pub mod git;
 pub mod github;
 pub mod jules_client;
 pub mod local_repository;
+pub mod transcript;
 pub mod workflow_installer;
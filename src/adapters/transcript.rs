@@ -0,0 +1,139 @@
+//! Run transcript shared by the recording Jules, Git, and GitHub adapters.
+
+use std::path::Path;
+use std::sync::Mutex;
+
+use serde::Serialize;
+use serde_json::{Value, json};
+
+use crate::domain::AppError;
+
+/// Placeholder written in place of secret values.
+const REDACTED: &str = "[REDACTED]";
+
+/// One adapter call and its outcome, in the order it was made.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordedCall {
+    pub adapter: &'static str,
+    pub operation: &'static str,
+    pub request: Value,
+    pub result: Value,
+}
+
+/// Run transcript shared by every recording client of a run.
+#[derive(Debug, Default)]
+pub struct RunTranscript {
+    calls: Mutex<Vec<RecordedCall>>,
+}
+
+impl RunTranscript {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn record(&self, call: RecordedCall) {
+        self.calls.lock().expect("transcript lock poisoned").push(call);
+    }
+
+    /// Record `outcome` as `{"ok": ...}` (rendered by `ok`) or `{"error": ...}`.
+    pub fn record_outcome<T>(
+        &self,
+        adapter: &'static str,
+        operation: &'static str,
+        request: Value,
+        outcome: &Result<T, AppError>,
+        ok: impl FnOnce(&T) -> Value,
+    ) {
+        let result = match outcome {
+            Ok(value) => json!({ "ok": ok(value) }),
+            Err(err) => json!({ "error": err.to_string() }),
+        };
+        self.record(RecordedCall { adapter, operation, request, result });
+    }
+
+    pub fn calls(&self) -> Vec<RecordedCall> {
+        self.calls.lock().expect("transcript lock poisoned").clone()
+    }
+
+    /// Render the transcript as JSON, replacing every occurrence of `secrets` in string values.
+    pub fn to_json(&self, inputs: Value, outcome: Value, secrets: &[String]) -> Value {
+        let mut transcript = json!({
+            "schema_version": 1,
+            "inputs": inputs,
+            "calls": self.calls(),
+            "outcome": outcome,
+        });
+        redact(&mut transcript, secrets);
+        transcript
+    }
+
+    /// Write the redacted transcript to `path` as pretty JSON.
+    pub fn write(
+        &self,
+        path: &Path,
+        inputs: Value,
+        outcome: Value,
+        secrets: &[String],
+    ) -> Result<(), AppError> {
+        let transcript = self.to_json(inputs, outcome, secrets);
+        let content = serde_json::to_string_pretty(&transcript).map_err(|e| {
+            AppError::InternalError(format!("Failed to serialize run transcript: {}", e))
+        })?;
+        if let Some(parent) = path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, format!("{}\n", content))?;
+        Ok(())
+    }
+}
+
+fn redact(value: &mut Value, secrets: &[String]) {
+    match value {
+        Value::String(text) => {
+            for secret in secrets.iter().filter(|secret| !secret.is_empty()) {
+                if text.contains(secret.as_str()) {
+                    *text = text.replace(secret.as_str(), REDACTED);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(|item| redact(item, secrets)),
+        Value::Object(fields) => fields.values_mut().for_each(|field| redact(field, secrets)),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn to_json_redacts_every_secret_in_nested_values() {
+        let transcript = RunTranscript::new();
+        transcript.record_outcome::<()>(
+            "github",
+            "create_pull_request",
+            json!({ "body": ["token ghp-one", { "note": "key sk-two" }] }),
+            &Err(AppError::Validation("ghp-one rejected".to_string())),
+            |_| Value::Null,
+        );
+
+        let rendered = transcript
+            .to_json(Value::Null, Value::Null, &["ghp-one".to_string(), "sk-two".to_string()])
+            .to_string();
+        assert!(!rendered.contains("ghp-one"), "{}", rendered);
+        assert!(!rendered.contains("sk-two"), "{}", rendered);
+        assert!(rendered.contains("token [REDACTED]"), "{}", rendered);
+    }
+
+    #[test]
+    fn write_creates_parent_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("records/run.json");
+
+        RunTranscript::new().write(&path, Value::Null, Value::Null, &[]).unwrap();
+
+        let written: Value = serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(written["schema_version"], 1);
+        assert_eq!(written["calls"], json!([]));
+    }
+}
@@ -26,6 +26,9 @@ pub fn install_workflow_scaffold(
 
     for file in &scaffold.files {
         repository.write_file(&file.path, &file.content)?;
+        if file.executable {
+            repository.set_executable(&file.path)?;
+        }
     }
 
     Ok(())
@@ -24,16 +24,23 @@ use crate::domain::PromptAssetLoader;
 use crate::ports::{Git, GitHub, JloStore, JulesStore, RepositoryFilesystem, RoleTemplateStore};
 
 pub use crate::app::commands::deinit::DeinitOutcome;
-pub use crate::app::commands::doctor::{DoctorOptions, DoctorOutcome};
-pub use crate::app::commands::role::{RoleAddOutcome, RoleCreateOutcome, RoleDeleteOutcome};
-use crate::app::commands::run::RunRuntimeOptions;
-pub use crate::app::commands::run::{RunOptions, RunResult};
+pub use crate::app::commands::doctor::{
+    DoctorOptions, DoctorOutcome, DoctorOutputFormat, Rule as DoctorRule,
+};
+pub use crate::app::commands::role::{
+    RoleAddOutcome, RoleCreateOutcome, RoleDeleteOutcome, RoleRenameOutcome, RoleToggleOutcome,
+};
+pub use crate::app::commands::run::{
+    ReportStream, RunOptions, RunPlan, RunResult, RunRuntimeOptions,
+};
 pub use crate::app::commands::setup::list::{
-    EnvVarInfo, SetupComponentDetail, SetupComponentSummary,
+    EnvVarInfo, EnvVarReference, SetupComponentDetail, SetupComponentSummary,
 };
+pub use crate::app::commands::setup::{SetupGenOptions, SetupValidationReport};
 pub use crate::app::commands::update::UpdateResult;
 pub use crate::app::commands::upgrade::{UpgradeOptions, UpgradeResult};
 pub use crate::app::commands::workflow::WorkflowBootstrapManagedFilesOutput;
+pub use crate::app::style::ColorChoice;
 pub use crate::domain::AppError;
 pub use crate::domain::WorkflowRunnerMode;
 pub use crate::domain::{BuiltinRoleEntry, Layer};
@@ -125,7 +132,22 @@ pub fn role_add_at(
     root: std::path::PathBuf,
 ) -> Result<RoleAddOutcome, AppError> {
     let ctx = create_context(root);
-    role::add_role(&ctx, layer, name)
+    role::add_role(&ctx, layer, name, false)
+}
+
+/// Plan a built-in role install without writing, returning the config diff.
+pub fn role_add_dry_run(layer: &str, name: &str) -> Result<RoleAddOutcome, AppError> {
+    role_add_dry_run_at(layer, name, std::env::current_dir()?)
+}
+
+/// Plan a built-in role install at the specified path without writing.
+pub fn role_add_dry_run_at(
+    layer: &str,
+    name: &str,
+    root: std::path::PathBuf,
+) -> Result<RoleAddOutcome, AppError> {
+    let ctx = create_context(root);
+    role::add_role(&ctx, layer, name, true)
 }
 
 /// Delete a role directory and schedule entry in `.jlo/config.toml`.
@@ -143,6 +165,42 @@ pub fn role_delete_at(
     role::delete_role(&ctx, layer, name)
 }
 
+/// Enable or disable a scheduled role in `.jlo/config.toml`.
+pub fn role_set_enabled(
+    layer: &str,
+    name: &str,
+    enabled: bool,
+) -> Result<RoleToggleOutcome, AppError> {
+    role_set_enabled_at(layer, name, enabled, std::env::current_dir()?)
+}
+
+/// Enable or disable a scheduled role at the specified path.
+pub fn role_set_enabled_at(
+    layer: &str,
+    name: &str,
+    enabled: bool,
+    root: std::path::PathBuf,
+) -> Result<RoleToggleOutcome, AppError> {
+    let ctx = create_context(root);
+    role::set_role_enabled(&ctx, layer, name, enabled)
+}
+
+/// Rename a role directory and its schedule entry in `.jlo/config.toml`.
+pub fn role_rename(layer: &str, from: &str, to: &str) -> Result<RoleRenameOutcome, AppError> {
+    role_rename_at(layer, from, to, std::env::current_dir()?)
+}
+
+/// Rename a role at the specified path.
+pub fn role_rename_at(
+    layer: &str,
+    from: &str,
+    to: &str,
+    root: std::path::PathBuf,
+) -> Result<RoleRenameOutcome, AppError> {
+    let ctx = create_context(root);
+    role::rename_role(&ctx, layer, from, to)
+}
+
 /// List the built-in role catalog.
 pub fn builtin_role_catalog() -> Result<Vec<BuiltinRoleEntry>, AppError> {
     let store = EmbeddedRoleTemplateStore::new();
@@ -170,52 +228,20 @@ pub fn discover_roles_at(root: std::path::PathBuf) -> Result<Vec<ExistingRoleEnt
 
 /// Execute Jules agents for a layer.
 ///
-/// # Arguments
-/// * `layer` - Target layer (observers, decider, planner, implementer, integrator)
-/// * `role` - Specific role to run (required for observers/decider/innovators)
-/// * `prompt_preview` - Show prompts without executing
-/// * `branch` - Override the starting branch
-/// * `requirement` - Local requirement file path (required for planner/implementer)
-/// * `mock` - Run in mock mode (no Jules API, tag from JULES_MOCK_TAG env)
-/// * `task` - Innovator task selector (expected: create_three_proposals)
-#[allow(clippy::too_many_arguments)]
-pub fn run(
-    layer: Layer,
-    role: Option<String>,
-    prompt_preview: bool,
-    branch: Option<String>,
-    requirement: Option<std::path::PathBuf>,
-    mock: bool,
-    task: Option<String>,
-    no_cleanup: bool,
-) -> Result<RunResult, AppError> {
-    run_at(
-        layer,
-        role,
-        prompt_preview,
-        branch,
-        requirement,
-        mock,
-        task,
-        no_cleanup,
-        std::env::current_dir()?,
-    )
+/// `target` selects what runs (layer, role, requirement, task, role file); `runtime`
+/// carries the execution flags such as prompt preview, mock mode, and push behavior.
+pub fn run(target: RunOptions, runtime: RunRuntimeOptions) -> Result<RunResult, AppError> {
+    run_at(target, runtime, std::env::current_dir()?)
 }
 
-#[allow(clippy::too_many_arguments)]
 pub fn run_at(
-    layer: Layer,
-    role: Option<String>,
-    prompt_preview: bool,
-    branch: Option<String>,
-    requirement: Option<std::path::PathBuf>,
-    mock: bool,
-    task: Option<String>,
-    no_cleanup: bool,
+    target: RunOptions,
+    runtime: RunRuntimeOptions,
     root: impl Into<PathBuf>,
 ) -> Result<RunResult, AppError> {
     let root = root.into();
-    let repository = LocalRepositoryAdapter::new(root.clone());
+    let repository =
+        LocalRepositoryAdapter::new(root.clone()).with_config_path(runtime.config.clone());
     if !repository.jules_exists() {
         return Err(AppError::JulesNotFound);
     }
@@ -223,11 +249,47 @@ pub fn run_at(
     let git = GitCommandAdapter::new(root);
     let github = GitHubCommandAdapter::new();
 
-    let target = RunOptions { layer, role, requirement, task };
-    let runtime = RunRuntimeOptions { prompt_preview, branch, mock, no_cleanup };
     run::execute(&repository.jules_path(), target, runtime, &git, &github, &repository)
 }
 
+/// Validate a run's inputs and describe the roles and sessions it will create.
+///
+/// Nothing is written and no Jules client is created.
+pub fn plan_run(target: &RunOptions, runtime: &RunRuntimeOptions) -> Result<RunPlan, AppError> {
+    let root = std::env::current_dir()?;
+    let repository =
+        LocalRepositoryAdapter::new(root.clone()).with_config_path(runtime.config.clone());
+    if !repository.jules_exists() {
+        return Err(AppError::JulesNotFound);
+    }
+
+    let git = GitCommandAdapter::new(root);
+    run::plan(target, runtime, &git, &repository)
+}
+
+/// Validate an inline requirement document and stage it under `.jules/exchange/requirements/`.
+///
+/// Returns the staged path, suitable as the `requirement` argument of [`run`].
+pub fn stage_requirement_input(content: &str) -> Result<PathBuf, AppError> {
+    let repository = LocalRepositoryAdapter::new(std::env::current_dir()?);
+    if !repository.jules_exists() {
+        return Err(AppError::JulesNotFound);
+    }
+    run::stage_requirement_input(content, &repository)
+}
+
+/// Validate an inline requirement document without writing it.
+///
+/// Returns the path [`stage_requirement_input`] would write, for use as the `requirement`
+/// argument of [`run`] alongside `RunRuntimeOptions::requirement_input` in a prompt preview.
+pub fn validate_requirement_input(content: &str) -> Result<PathBuf, AppError> {
+    let repository = LocalRepositoryAdapter::new(std::env::current_dir()?);
+    if !repository.jules_exists() {
+        return Err(AppError::JulesNotFound);
+    }
+    run::validate_requirement_input(content, &repository)
+}
+
 // =============================================================================
 // Setup Compiler API
 // =============================================================================
@@ -241,12 +303,45 @@ pub fn run_at(
 ///
 /// Returns the list of resolved component names in installation order.
 pub fn setup_gen(path: Option<&Path>) -> Result<Vec<String>, AppError> {
+    setup_gen_with_options(path, SetupGenOptions::default())
+}
+
+/// Like [`setup_gen`], with `setup.lock` output and collected validation controlled by `options`.
+pub fn setup_gen_with_options(
+    path: Option<&Path>,
+    options: SetupGenOptions,
+) -> Result<Vec<String>, AppError> {
+    let store = if let Some(p) = path {
+        LocalRepositoryAdapter::new(p.to_path_buf())
+    } else {
+        LocalRepositoryAdapter::current()?
+    };
+    if options.collect {
+        setup::generate_collect(&store, options.lock)
+    } else {
+        setup::generate(&store, options.lock)
+    }
+}
+
+/// Verify `setup.lock` matches the current `tools.yml` and `vars.toml` without writing.
+pub fn setup_check_lock(path: Option<&Path>) -> Result<Vec<String>, AppError> {
     let store = if let Some(p) = path {
         LocalRepositoryAdapter::new(p.to_path_buf())
     } else {
         LocalRepositoryAdapter::current()?
     };
-    setup::generate(&store)
+    setup::check_lockfile(&store)
+}
+
+/// Validate `tools.yml` (parsing, dependency resolution, cycles, env var names)
+/// without generating or comparing any artifact.
+pub fn setup_validate(path: Option<&Path>) -> Result<SetupValidationReport, AppError> {
+    let store = if let Some(p) = path {
+        LocalRepositoryAdapter::new(p.to_path_buf())
+    } else {
+        LocalRepositoryAdapter::current()?
+    };
+    setup::validate_config(&store)
 }
 
 /// List all available components.
@@ -279,9 +374,23 @@ pub fn upgrade_at(
     path: impl Into<PathBuf>,
     prompt_preview: bool,
 ) -> Result<UpgradeResult, AppError> {
-    let repository = LocalRepositoryAdapter::new(path.into());
+    upgrade_with_options_at(path, UpgradeOptions { prompt_preview, ..Default::default() })
+}
+
+/// Upgrade the current repository with every [`UpgradeOptions`] field available,
+/// such as a preview baseline or refreshing managed `.jules/` files.
+pub fn upgrade_with_options(options: UpgradeOptions) -> Result<UpgradeResult, AppError> {
+    upgrade_with_options_at(std::env::current_dir()?, options)
+}
+
+/// Upgrade the repository at the specified path with explicit [`UpgradeOptions`].
+pub fn upgrade_with_options_at(
+    path: impl Into<PathBuf>,
+    options: UpgradeOptions,
+) -> Result<UpgradeResult, AppError> {
+    let repository =
+        LocalRepositoryAdapter::new(path.into()).with_config_path(options.config.clone());
     let templates = EmbeddedRoleTemplateStore::new();
-    let options = UpgradeOptions { prompt_preview };
     upgrade::execute(&repository, options, &templates)
 }
 
@@ -294,10 +403,28 @@ pub fn update() -> Result<UpdateResult, AppError> {
     update::execute()
 }
 
+/// Check that `path` holds a parseable control-plane config, as `--config` requires.
+pub fn validate_config_file(path: &Path) -> Result<(), AppError> {
+    let repository = LocalRepositoryAdapter::current()?.with_config_path(Some(path.to_path_buf()));
+    crate::app::config::load_config(&repository)
+        .map(|_| ())
+        .map_err(|err| err.context(format!("while loading --config {}", path.display())))
+}
+
+/// JSON Schema describing `.jlo/config.toml`, for editor validation.
+pub fn config_schema() -> serde_json::Value {
+    crate::domain::config::json_schema::control_plane_config_schema()
+}
+
 // =============================================================================
 // Doctor Command API
 // =============================================================================
 
+/// List every check doctor performs, without running any.
+pub fn doctor_rules() -> &'static [DoctorRule] {
+    doctor::RULES
+}
+
 /// Validate the `.jules/` repository structure and content.
 pub fn doctor(options: DoctorOptions) -> Result<DoctorOutcome, AppError> {
     doctor_at(std::env::current_dir()?, options)
@@ -320,8 +447,12 @@ pub fn doctor_at(
 pub fn workflow_bootstrap_managed_files_at(
     path: impl Into<PathBuf>,
 ) -> Result<WorkflowBootstrapManagedFilesOutput, AppError> {
-    let options =
-        crate::app::commands::workflow::WorkflowBootstrapManagedFilesOptions { root: path.into() };
+    let options = crate::app::commands::workflow::WorkflowBootstrapManagedFilesOptions {
+        root: path.into(),
+        prune: false,
+        verify: false,
+        verbose: false,
+    };
     crate::app::commands::workflow::bootstrap_managed_files(options)
 }
 
@@ -387,7 +518,7 @@ where
     R: RoleTemplateStore,
 {
     let ctx = AppContext::new(repository, templates);
-    role::add_role(&ctx, layer, name)
+    role::add_role(&ctx, layer, name, false)
 }
 
 /// Delete a role with prebuilt repository and template-store adapters.
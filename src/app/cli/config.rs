@@ -0,0 +1,24 @@
+//! Config command implementation.
+
+use clap::Subcommand;
+
+use crate::domain::AppError;
+
+#[derive(Subcommand)]
+pub enum ConfigCommands {
+    /// Print a JSON Schema for .jlo/config.toml (for editor validation)
+    Schema,
+}
+
+pub fn run_config(command: ConfigCommands) -> Result<(), AppError> {
+    match command {
+        ConfigCommands::Schema => {
+            let schema = crate::app::api::config_schema();
+            let json = serde_json::to_string_pretty(&schema).map_err(|e| {
+                AppError::InternalError(format!("Failed to serialize config schema: {}", e))
+            })?;
+            println!("{}", json);
+            Ok(())
+        }
+    }
+}
@@ -1,9 +1,39 @@
 //! Doctor command implementation.
 
+use super::DoctorFormat;
 use crate::domain::AppError;
 
-pub fn run_doctor(strict: bool) -> Result<i32, AppError> {
-    let options = crate::DoctorOptions { strict };
+pub fn run_list_rules(format: DoctorFormat) -> Result<(), AppError> {
+    let rules = crate::app::api::doctor_rules();
+    match format {
+        DoctorFormat::Sarif => {
+            return Err(AppError::Validation(
+                "--format sarif applies to doctor reports, not --list-rules".to_string(),
+            ));
+        }
+        DoctorFormat::Text => {
+            for rule in rules {
+                println!(
+                    "{:<26} {:<10} {:<8} {} [{}]",
+                    rule.id,
+                    rule.category.as_str(),
+                    rule.severity.as_str(),
+                    rule.summary,
+                    rule.codes.join(", ")
+                );
+            }
+        }
+        DoctorFormat::Json => {
+            let json = serde_json::to_string_pretty(rules).map_err(|e| {
+                AppError::InternalError(format!("Failed to serialize doctor rules: {}", e))
+            })?;
+            println!("{}", json);
+        }
+    }
+    Ok(())
+}
+
+pub fn run_doctor(options: crate::DoctorOptions) -> Result<i32, AppError> {
     let outcome = crate::app::api::doctor(options)?;
 
     Ok(outcome.exit_code)
@@ -1,5 +1,6 @@
 //! CLI Adapter.
 
+mod config;
 mod deinit;
 mod doctor;
 mod init;
@@ -21,6 +22,31 @@ use clap::{Parser, Subcommand};
 struct Cli {
     #[command(subcommand)]
     command: Commands,
+    /// Colorize human-readable output (respects NO_COLOR in auto mode)
+    #[arg(long, global = true, value_enum, default_value_t = ColorMode::Auto)]
+    color: ColorMode,
+    /// Load the control-plane config from this path instead of .jlo/config.toml
+    #[arg(long, global = true, value_name = "PATH")]
+    config: Option<std::path::PathBuf>,
+}
+
+/// When to color human-readable output.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorMode {
+    /// Color when the stream is a terminal and NO_COLOR is unset
+    Auto,
+    Always,
+    Never,
+}
+
+impl From<ColorMode> for crate::app::api::ColorChoice {
+    fn from(mode: ColorMode) -> Self {
+        match mode {
+            ColorMode::Auto => Self::Auto,
+            ColorMode::Always => Self::Always,
+            ColorMode::Never => Self::Never,
+        }
+    }
 }
 
 #[derive(clap::ValueEnum, Clone, Debug)]
@@ -29,6 +55,35 @@ pub enum InitMode {
     SelfHosted,
 }
 
+/// Output format for commands that can emit machine-readable results.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+/// Output format for `doctor`: the common formats plus SARIF.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DoctorFormat {
+    #[default]
+    Text,
+    /// Stable JSON report (schema_version, counts, diagnostics, applied fixes)
+    Json,
+    /// SARIF 2.1.0 log for GitHub code scanning
+    Sarif,
+}
+
+impl From<DoctorFormat> for crate::app::api::DoctorOutputFormat {
+    fn from(format: DoctorFormat) -> Self {
+        match format {
+            DoctorFormat::Text => Self::Text,
+            DoctorFormat::Json => Self::Json,
+            DoctorFormat::Sarif => Self::Sarif,
+        }
+    }
+}
+
 #[derive(Subcommand)]
 enum Commands {
     /// Initialize .jlo/ control plane and install workflow scaffold
@@ -46,6 +101,12 @@ enum Commands {
         /// Show planned changes without applying
         #[arg(long)]
         prompt_preview: bool,
+        /// With --prompt-preview, plan against another checkout instead of this one
+        #[arg(long, value_name = "DIR", requires = "prompt_preview")]
+        baseline: Option<std::path::PathBuf>,
+        /// Also refresh managed .jules/ framework files (never exchange artifacts); use on the worker branch
+        #[arg(long)]
+        include_runtime: bool,
     },
     /// Manage role lifecycle in .jlo/
     #[clap(visible_alias = "r")]
@@ -63,6 +124,26 @@ enum Commands {
     Run {
         #[command(subcommand)]
         layer: run::RunLayer,
+        /// Truncate prompts over jules_api.max_prompt_chars with a warning instead of failing
+        #[arg(long, global = true)]
+        allow_truncate: bool,
+        /// Skip the confirmation prompt before a real (non-mock, non-preview) run
+        #[arg(long, short = 'y', global = true)]
+        yes: bool,
+        /// Output format for the run result; `json` prints it as a single JSON line
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text, global = true)]
+        format: OutputFormat,
+        /// Shorthand for `--format json`
+        #[arg(long, global = true, conflicts_with = "format")]
+        json: bool,
+        /// Write a redacted JSON transcript of run inputs and Jules API calls to this path
+        #[arg(long, value_name = "PATH", global = true)]
+        record: Option<std::path::PathBuf>,
+    },
+    /// Inspect the .jlo/config.toml format
+    Config {
+        #[command(subcommand)]
+        command: config::ConfigCommands,
     },
     /// Workflow orchestration primitives for GitHub Actions
     #[clap(visible_alias = "wf")]
@@ -75,6 +156,36 @@ enum Commands {
         /// Treat warnings as failures
         #[arg(long)]
         strict: bool,
+        /// Repair fixable issues in place (e.g. tab indentation in role.yml)
+        #[arg(long)]
+        fix: bool,
+        /// Write a JSON summary of diagnostic counts to this path
+        #[arg(long, value_name = "PATH")]
+        summary_json: Option<std::path::PathBuf>,
+        /// Also probe Jules API reachability and credentials (makes a network call)
+        #[arg(long)]
+        check_api: bool,
+        /// Warn when managed workflow files under .github/ drift from the scaffold for the configured runner mode
+        #[arg(long)]
+        check_workflows: bool,
+        /// List every rule id with its category and severity without running checks
+        #[arg(long)]
+        list_rules: bool,
+        /// Output format for the doctor report (sarif for GitHub code scanning) or --list-rules
+        #[arg(long, value_enum, default_value_t = DoctorFormat::Text)]
+        format: DoctorFormat,
+        /// Silence a diagnostic code, or downgrade its errors with CODE=warning (repeatable)
+        #[arg(long, value_name = "CODE[=warning]")]
+        allow: Vec<String>,
+        /// Worker threads for schema validation (default: JLO_MAX_CONCURRENCY, then run.max_concurrency, then available cores; 1 runs sequentially)
+        #[arg(long, value_name = "N")]
+        jobs: Option<std::num::NonZeroUsize>,
+        /// Run only these check categories (structure, schema, naming, semantic, quality)
+        #[arg(long, value_name = "CATEGORY", value_delimiter = ',')]
+        only: Vec<String>,
+        /// Skip these check categories; cannot be combined with --only
+        #[arg(long, value_name = "CATEGORY", value_delimiter = ',')]
+        skip: Vec<String>,
     },
     /// Remove jlo-managed assets (branch + workflows)
     Deinit,
@@ -84,18 +195,64 @@ enum Commands {
 pub fn run() {
     let cli = Cli::parse();
 
+    let config = match config_flag(cli.config.as_deref(), &cli.command) {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    };
+
     let result: Result<i32, AppError> = match cli.command {
         Commands::Init { mode } => init::run_init(mode).map(|_| 0),
         Commands::Update => run_update().map(|_| 0),
-        Commands::Upgrade { prompt_preview } => run_upgrade(prompt_preview).map(|_| 0),
+        Commands::Upgrade { prompt_preview, baseline, include_runtime } => {
+            run_upgrade(prompt_preview, baseline, include_runtime, config).map(|_| 0)
+        }
         Commands::Role { command } => role::run_role(command).map(|_| 0),
         Commands::Setup { command } => match command {
-            setup::SetupCommands::Gen { path } => setup::run_setup_gen(path).map(|_| 0),
-            setup::SetupCommands::List { detail } => setup::run_setup_list(detail).map(|_| 0),
+            setup::SetupCommands::Gen { path, validate_only, lock, check, collect } => {
+                setup::run_setup_gen(path, validate_only, lock, check, collect).map(|_| 0)
+            }
+            setup::SetupCommands::List { detail, format } => {
+                setup::run_setup_list(detail, format).map(|_| 0)
+            }
         },
-        Commands::Run { layer } => run::run_agents(layer).map(|_| 0),
-        Commands::Workflow { command } => workflow::run_workflow(command).map(|_| 0),
-        Commands::Doctor { strict } => doctor::run_doctor(strict),
+        Commands::Run { layer, allow_truncate, yes, format, json, record } => {
+            let format = if json { OutputFormat::Json } else { format };
+            run::run_agents(layer, allow_truncate, yes, format, record, config).map(|_| 0)
+        }
+        Commands::Config { command } => config::run_config(command).map(|_| 0),
+        Commands::Workflow { command } => workflow::run_workflow(command, config).map(|_| 0),
+        Commands::Doctor { list_rules: true, format, .. } => {
+            doctor::run_list_rules(format).map(|_| 0)
+        }
+        Commands::Doctor {
+            strict,
+            fix,
+            summary_json,
+            check_api,
+            check_workflows,
+            format,
+            allow,
+            jobs,
+            only,
+            skip,
+            ..
+        } => doctor::run_doctor(crate::DoctorOptions {
+            strict,
+            fix,
+            summary_json,
+            color: cli.color.into(),
+            check_api,
+            check_workflows,
+            format: format.into(),
+            allow,
+            jobs,
+            only,
+            skip,
+            config,
+        }),
         Commands::Deinit => deinit::run_deinit().map(|_| 0),
     };
 
@@ -112,6 +269,30 @@ pub fn run() {
     }
 }
 
+/// Validate the global `--config` path for commands that read the control-plane config.
+///
+/// The path is made absolute so every store opened by the command resolves the same file.
+fn config_flag(
+    path: Option<&std::path::Path>,
+    command: &Commands,
+) -> Result<Option<std::path::PathBuf>, AppError> {
+    let Some(path) = path else { return Ok(None) };
+    if !matches!(
+        command,
+        Commands::Doctor { .. }
+            | Commands::Run { .. }
+            | Commands::Upgrade { .. }
+            | Commands::Workflow { .. }
+    ) {
+        return Err(AppError::Validation(
+            "--config is only supported by doctor, run, upgrade, and workflow".to_string(),
+        ));
+    }
+    let path = std::path::absolute(path)?;
+    crate::app::api::validate_config_file(&path)?;
+    Ok(Some(path))
+}
+
 fn run_update() -> Result<(), AppError> {
     let result = crate::app::api::update()?;
     if result.updated {
@@ -125,8 +306,18 @@ fn run_update() -> Result<(), AppError> {
     Ok(())
 }
 
-fn run_upgrade(prompt_preview: bool) -> Result<(), AppError> {
-    let result = crate::app::api::upgrade(prompt_preview)?;
+fn run_upgrade(
+    prompt_preview: bool,
+    baseline: Option<std::path::PathBuf>,
+    include_runtime: bool,
+    config: Option<std::path::PathBuf>,
+) -> Result<(), AppError> {
+    let result = crate::app::api::upgrade_with_options(crate::app::api::UpgradeOptions {
+        prompt_preview,
+        baseline,
+        include_runtime,
+        config,
+    })?;
 
     if !result.prompt_preview {
         if !result.warnings.is_empty() {
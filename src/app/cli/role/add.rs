@@ -1,17 +1,49 @@
 use super::layer_selection::{parse_multi_role_layer, prompt_multi_role_layer};
 
+use crate::app::api::RoleAddOutcome;
 use crate::domain::{AppError, BuiltinRoleEntry, Layer};
 use dialoguer::Select;
 use std::collections::BTreeMap;
+use std::io::{BufRead, IsTerminal, Write};
 
 const BACK_OPTION_LABEL: &str = "[back]";
 
-pub fn run(layer: Option<String>, roles: Vec<String>) -> Result<(), AppError> {
-    let Some((layer, roles)) = resolve_inputs(layer, roles)? else {
+pub fn run(
+    layer: Option<String>,
+    roles: Vec<String>,
+    interactive: bool,
+    dry_run: bool,
+) -> Result<(), AppError> {
+    if interactive && !std::io::stdin().is_terminal() {
+        return Err(AppError::Validation(
+            "--interactive requires a terminal; pass the role explicitly: jlo role add <layer> <role>"
+                .to_string(),
+        ));
+    }
+    let resolved = if interactive {
+        resolve_interactive(layer, &mut std::io::stdin().lock(), &mut std::io::stderr())?
+    } else {
+        resolve_inputs(layer, roles)?
+    };
+    let Some((layer, roles)) = resolved else {
         return Ok(());
     };
 
     for role in roles {
+        if dry_run {
+            let outcome = crate::app::api::role_add_dry_run(&layer, &role)?;
+            if let RoleAddOutcome::Planned { config_diff, .. } = &outcome {
+                println!(
+                    "Would add {} '{}' in layer '{}' to {}:",
+                    outcome.entity_type(),
+                    role,
+                    layer,
+                    outcome.display_path()
+                );
+                print!("{}", config_diff);
+            }
+            continue;
+        }
         let outcome = crate::app::api::role_add(&layer, &role)?;
         println!(
             "✅ Added {} '{}' in layer '{}' to {}",
@@ -66,6 +98,77 @@ fn resolve_inputs(
     }
 }
 
+/// Resolve the layer, then pick one builtin role from a numbered list read from `input`.
+fn resolve_interactive<R: BufRead, W: Write>(
+    layer: Option<String>,
+    input: &mut R,
+    output: &mut W,
+) -> Result<Option<(String, Vec<String>)>, AppError> {
+    let layer_enum = match layer {
+        Some(value) => parse_multi_role_layer(&value)?,
+        None => match prompt_multi_role_layer()? {
+            Some(value) => value,
+            None => return Ok(None),
+        },
+    };
+    let catalog = crate::app::api::builtin_role_catalog()?;
+    Ok(pick_builtin_role(&catalog, layer_enum, input, output)?
+        .map(|role| (layer_enum.dir_name().to_string(), vec![role])))
+}
+
+/// Numbered picker over the layer's builtin roles.
+///
+/// Accepts a list number or a role name; an empty line or end of input cancels.
+fn pick_builtin_role<R: BufRead, W: Write>(
+    catalog: &[BuiltinRoleEntry],
+    layer: Layer,
+    input: &mut R,
+    output: &mut W,
+) -> Result<Option<String>, AppError> {
+    let roles: Vec<&BuiltinRoleEntry> =
+        builtin_roles_by_category(catalog, layer)?.into_values().flatten().collect();
+
+    writeln!(output, "Built-in {} roles:", layer.dir_name())?;
+    for (index, entry) in roles.iter().enumerate() {
+        writeln!(
+            output,
+            "  {:>2}) {} [{}]: {}",
+            index + 1,
+            entry.name.as_str(),
+            entry.category,
+            entry.summary
+        )?;
+    }
+
+    loop {
+        write!(output, "Select a role [1-{}] (empty to cancel): ", roles.len())?;
+        output.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+        let answer = line.trim();
+        if answer.is_empty() {
+            return Ok(None);
+        }
+
+        let selected = match answer.parse::<usize>() {
+            Ok(number) => number.checked_sub(1).and_then(|index| roles.get(index)),
+            Err(_) => roles.iter().find(|entry| entry.name.as_str() == answer),
+        };
+        match selected {
+            Some(entry) => return Ok(Some(entry.name.as_str().to_string())),
+            None => writeln!(
+                output,
+                "'{}' is not a listed role; enter a number from 1 to {}.",
+                answer,
+                roles.len()
+            )?,
+        }
+    }
+}
+
 enum BuiltinRoleSelection {
     Selected(String),
     BackToLayer,
@@ -77,21 +180,7 @@ fn prompt_builtin_role(
     layer: Layer,
     allow_layer_back: bool,
 ) -> Result<BuiltinRoleSelection, AppError> {
-    let entries_by_category = catalog.iter().filter(|entry| entry.layer == layer).fold(
-        BTreeMap::<&str, Vec<&BuiltinRoleEntry>>::new(),
-        |mut map, entry| {
-            map.entry(entry.category.as_str()).or_default().push(entry);
-            map
-        },
-    );
-
-    if entries_by_category.is_empty() {
-        return Err(AppError::Validation(format!(
-            "No builtin roles available for layer '{}'",
-            layer.dir_name()
-        )));
-    }
-
+    let entries_by_category = builtin_roles_by_category(catalog, layer)?;
     let categories: Vec<&str> = entries_by_category.keys().copied().collect();
     loop {
         let mut category_items: Vec<String> =
@@ -118,11 +207,7 @@ fn prompt_builtin_role(
         let selected_category = categories[category_index];
         let roles = entries_by_category.get(selected_category).expect("category exists");
 
-        let mut role_items: Vec<String> = roles
-            .iter()
-            .map(|entry| format!("{}: {}", entry.name.as_str(), entry.summary))
-            .collect();
-        role_items.push(BACK_OPTION_LABEL.to_string());
+        let role_items = role_item_labels(roles);
 
         let role_index = Select::new()
             .with_prompt("Select role")
@@ -142,3 +227,132 @@ fn prompt_builtin_role(
         return Ok(BuiltinRoleSelection::Selected(roles[role_index].name.as_str().to_string()));
     }
 }
+
+/// Group the layer's builtin roles by category, failing when the layer has none.
+fn builtin_roles_by_category(
+    catalog: &[BuiltinRoleEntry],
+    layer: Layer,
+) -> Result<BTreeMap<&str, Vec<&BuiltinRoleEntry>>, AppError> {
+    let entries_by_category = catalog.iter().filter(|entry| entry.layer == layer).fold(
+        BTreeMap::<&str, Vec<&BuiltinRoleEntry>>::new(),
+        |mut map, entry| {
+            map.entry(entry.category.as_str()).or_default().push(entry);
+            map
+        },
+    );
+
+    if entries_by_category.is_empty() {
+        return Err(AppError::Validation(format!(
+            "No builtin roles available for layer '{}'",
+            layer.dir_name()
+        )));
+    }
+    Ok(entries_by_category)
+}
+
+/// Role picker items: `name: summary` for each role, then the back option.
+fn role_item_labels(roles: &[&BuiltinRoleEntry]) -> Vec<String> {
+    let mut items: Vec<String> =
+        roles.iter().map(|entry| format!("{}: {}", entry.name.as_str(), entry.summary)).collect();
+    items.push(BACK_OPTION_LABEL.to_string());
+    items
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::RoleId;
+
+    fn entry(layer: Layer, name: &str, summary: &str) -> BuiltinRoleEntry {
+        BuiltinRoleEntry {
+            layer,
+            name: RoleId::new(name).unwrap(),
+            category: "general".to_string(),
+            summary: summary.to_string(),
+            path: format!("{}/general/{}/role.yml", layer.dir_name(), name),
+        }
+    }
+
+    fn catalog() -> Vec<BuiltinRoleEntry> {
+        vec![
+            entry(Layer::Observers, "taxonomy", "Naming consistency."),
+            entry(Layer::Observers, "gopher", "Go idioms."),
+            entry(Layer::Innovators, "recruiter", "Hiring ideas."),
+        ]
+    }
+
+    #[test]
+    fn builtin_roles_are_grouped_for_the_requested_layer_only() {
+        let catalog = catalog();
+        let grouped = builtin_roles_by_category(&catalog, Layer::Observers).unwrap();
+
+        let names: Vec<&str> = grouped["general"].iter().map(|entry| entry.name.as_str()).collect();
+        assert_eq!(names, ["taxonomy", "gopher"]);
+        assert_eq!(grouped.len(), 1);
+    }
+
+    #[test]
+    fn layer_without_builtin_roles_is_rejected() {
+        let err = builtin_roles_by_category(&catalog(), Layer::Decider).unwrap_err();
+        assert!(err.to_string().contains("No builtin roles available for layer 'decider'"));
+    }
+
+    #[test]
+    fn role_items_pair_names_with_summaries_and_end_with_back() {
+        let catalog = catalog();
+        let grouped = builtin_roles_by_category(&catalog, Layer::Observers).unwrap();
+
+        assert_eq!(
+            role_item_labels(&grouped["general"]),
+            ["taxonomy: Naming consistency.", "gopher: Go idioms.", BACK_OPTION_LABEL]
+        );
+    }
+
+    #[test]
+    fn numbered_picker_selects_role_by_number_after_invalid_input() {
+        let mut input = std::io::Cursor::new("7\nnope\n2\n");
+        let mut output = Vec::new();
+
+        let selected =
+            pick_builtin_role(&catalog(), Layer::Observers, &mut input, &mut output).unwrap();
+
+        assert_eq!(selected.as_deref(), Some("gopher"));
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("   1) taxonomy [general]: Naming consistency."), "{}", output);
+        assert!(output.contains("   2) gopher [general]: Go idioms."), "{}", output);
+        assert!(output.contains("'7' is not a listed role"), "{}", output);
+        assert!(output.contains("'nope' is not a listed role"), "{}", output);
+        assert!(!output.contains("recruiter"), "{}", output);
+    }
+
+    #[test]
+    fn numbered_picker_accepts_role_name_and_cancels_on_empty_input() {
+        let mut output = Vec::new();
+
+        let by_name = pick_builtin_role(
+            &catalog(),
+            Layer::Observers,
+            &mut std::io::Cursor::new("taxonomy\n"),
+            &mut output,
+        )
+        .unwrap();
+        let cancelled = pick_builtin_role(
+            &catalog(),
+            Layer::Observers,
+            &mut std::io::Cursor::new("\n"),
+            &mut output,
+        )
+        .unwrap();
+        let at_eof = pick_builtin_role(
+            &catalog(),
+            Layer::Observers,
+            &mut std::io::Cursor::new(""),
+            &mut output,
+        )
+        .unwrap();
+
+        assert_eq!(by_name.as_deref(), Some("taxonomy"));
+        assert_eq!(cancelled, None);
+        assert_eq!(at_eof, None);
+    }
+}
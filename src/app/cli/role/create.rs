@@ -1,17 +1,77 @@
 use super::layer_selection::{parse_multi_role_layer, prompt_multi_role_layer};
-use crate::domain::AppError;
+use crate::app::api::RoleCreateOutcome;
+use crate::app::cli::OutputFormat;
+use crate::domain::{AppError, Layer};
 use dialoguer::{Error as DialoguerError, Input};
 use std::io::ErrorKind;
 
-pub fn run(layer: Option<String>, role: Option<String>) -> Result<(), AppError> {
+pub fn run(
+    layer: Option<String>,
+    role: Option<String>,
+    format: OutputFormat,
+) -> Result<(), AppError> {
     let Some((layer, role)) = resolve_inputs(layer, role)? else {
         return Ok(());
     };
     let outcome = crate::app::api::role_create(&layer, &role)?;
-    println!("✅ Created new {} at {}/", outcome.entity_type(), outcome.display_path());
+    match format {
+        OutputFormat::Text => {
+            println!("✅ Created new {} at {}/", outcome.entity_type(), outcome.display_path())
+        }
+        OutputFormat::Json => println!("{}", outcome_json(&outcome)?),
+    }
+    Ok(())
+}
+
+/// Print every layer with its role kind and the command that creates or runs it.
+pub fn run_list(format: OutputFormat) -> Result<(), AppError> {
+    match format {
+        OutputFormat::Text => {
+            for layer in Layer::ALL {
+                println!("{:<12} {:<12} {}", layer.dir_name(), role_kind(layer), usage(layer));
+            }
+        }
+        OutputFormat::Json => {
+            let layers: Vec<serde_json::Value> = Layer::ALL
+                .into_iter()
+                .map(|layer| {
+                    serde_json::json!({
+                        "layer": layer.dir_name(),
+                        "kind": role_kind(layer),
+                        "custom_roles": !layer.is_single_role(),
+                        "usage": usage(layer),
+                    })
+                })
+                .collect();
+            println!("{}", serde_json::Value::Array(layers));
+        }
+    }
     Ok(())
 }
 
+fn role_kind(layer: Layer) -> &'static str {
+    if layer.is_single_role() { "single-role" } else { "multi-role" }
+}
+
+fn usage(layer: Layer) -> String {
+    let name = layer.dir_name();
+    if !layer.is_single_role() {
+        format!("jlo role create {} <role>; run with `jlo run {} --role <role>`", name, name)
+    } else if layer.is_requirement_driven() {
+        format!("built-in role only; run with `jlo run {} --requirement <path>`", name)
+    } else {
+        format!("built-in role only; run with `jlo run {}`", name)
+    }
+}
+
+fn outcome_json(outcome: &RoleCreateOutcome) -> Result<String, AppError> {
+    let mut value = serde_json::to_value(outcome).map_err(|e| {
+        AppError::InternalError(format!("Failed to serialize role create outcome: {}", e))
+    })?;
+    value["path"] = serde_json::Value::String(outcome.display_path());
+    Ok(value.to_string())
+}
+
 fn resolve_inputs(
     layer: Option<String>,
     role: Option<String>,
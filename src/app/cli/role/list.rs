@@ -0,0 +1,38 @@
+use super::layer_selection::parse_multi_role_layer;
+
+use crate::domain::{AppError, Layer};
+
+pub fn run(layer: Option<String>, available: bool) -> Result<(), AppError> {
+    let layer_filter = layer.as_deref().map(parse_multi_role_layer).transpose()?;
+    let matches = |value: Layer| layer_filter.is_none_or(|filter| filter == value);
+
+    if available {
+        let mut catalog = crate::app::api::builtin_role_catalog()?;
+        catalog.retain(|entry| matches(entry.layer));
+        catalog.sort_by(|a, b| {
+            (a.layer.dir_name(), a.category.as_str(), a.name.as_str()).cmp(&(
+                b.layer.dir_name(),
+                b.category.as_str(),
+                b.name.as_str(),
+            ))
+        });
+        for entry in catalog {
+            println!(
+                "{}/{} [{}] - {}",
+                entry.layer.dir_name(),
+                entry.name,
+                entry.category,
+                entry.summary
+            );
+        }
+        return Ok(());
+    }
+
+    let mut discovered = crate::app::api::discover_roles()?;
+    discovered.retain(|entry| matches(entry.layer));
+    discovered.sort_by(|a, b| (a.layer.dir_name(), &a.role).cmp(&(b.layer.dir_name(), &b.role)));
+    for entry in discovered {
+        println!("{}/{}", entry.layer.dir_name(), entry.role);
+    }
+    Ok(())
+}
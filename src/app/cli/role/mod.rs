@@ -2,7 +2,11 @@ mod add;
 mod create;
 mod delete;
 mod layer_selection;
+mod list;
+mod rename;
+mod toggle;
 
+use crate::app::cli::OutputFormat;
 use crate::domain::AppError;
 use clap::Subcommand;
 
@@ -15,6 +19,12 @@ pub enum RoleCommands {
         layer: Option<String>,
         /// Built-in role name(s)
         roles: Vec<String>,
+        /// Pick a built-in role from a numbered list read from stdin (requires a terminal)
+        #[arg(long, conflicts_with = "roles")]
+        interactive: bool,
+        /// Print the .jlo/config.toml change without writing anything
+        #[arg(long)]
+        dry_run: bool,
     },
     /// Create a new role under .jlo/
     #[clap(visible_aliases = ["c", "cr"])]
@@ -23,6 +33,21 @@ pub enum RoleCommands {
         layer: Option<String>,
         /// Name for the new role
         role: Option<String>,
+        /// List which layers accept custom roles and how each layer is run, without creating anything
+        #[arg(long, conflicts_with_all = ["layer", "role"])]
+        list: bool,
+        /// Output format (json emits the outcome for scripted scaffolding)
+        #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+        format: OutputFormat,
+    },
+    /// List roles installed under .jlo/ (or builtin roles with --available)
+    #[clap(visible_aliases = ["l", "ls"])]
+    List {
+        /// Layer filter (observers, innovators)
+        layer: Option<String>,
+        /// List builtin roles that can be added, with summaries
+        #[arg(long)]
+        available: bool,
     },
     /// Delete a role from .jlo/
     #[clap(visible_aliases = ["d", "dl"])]
@@ -32,12 +57,43 @@ pub enum RoleCommands {
         /// Role name to delete
         role: Option<String>,
     },
+    /// Rename a role under .jlo/ and its schedule entry
+    #[clap(visible_aliases = ["mv"])]
+    Rename {
+        /// Layer (observers, innovators)
+        layer: String,
+        /// Current role name
+        from: String,
+        /// New role name
+        to: String,
+    },
+    /// Enable a scheduled role in .jlo/config.toml
+    Enable {
+        /// Layer (observers, innovators)
+        layer: String,
+        /// Role name to enable
+        role: String,
+    },
+    /// Disable a scheduled role in .jlo/config.toml without deleting it
+    Disable {
+        /// Layer (observers, innovators)
+        layer: String,
+        /// Role name to disable
+        role: String,
+    },
 }
 
 pub fn run_role(command: RoleCommands) -> Result<(), AppError> {
     match command {
-        RoleCommands::Add { layer, roles } => add::run(layer, roles),
-        RoleCommands::Create { layer, role } => create::run(layer, role),
+        RoleCommands::Add { layer, roles, interactive, dry_run } => {
+            add::run(layer, roles, interactive, dry_run)
+        }
+        RoleCommands::Create { list: true, format, .. } => create::run_list(format),
+        RoleCommands::Create { layer, role, format, .. } => create::run(layer, role, format),
+        RoleCommands::List { layer, available } => list::run(layer, available),
         RoleCommands::Delete { layer, role } => delete::run(layer, role),
+        RoleCommands::Rename { layer, from, to } => rename::run(layer, from, to),
+        RoleCommands::Enable { layer, role } => toggle::run(layer, role, true),
+        RoleCommands::Disable { layer, role } => toggle::run(layer, role, false),
     }
 }
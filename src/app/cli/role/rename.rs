@@ -0,0 +1,17 @@
+use super::layer_selection::parse_multi_role_layer;
+
+use crate::domain::AppError;
+
+pub fn run(layer: String, from: String, to: String) -> Result<(), AppError> {
+    let layer = parse_multi_role_layer(&layer)?.dir_name().to_string();
+    let outcome = crate::app::api::role_rename(&layer, &from, &to)?;
+    println!(
+        "✅ Renamed {} '{}' to '{}' in layer '{}' ({}) and updated .jlo/config.toml",
+        outcome.entity_type(),
+        from,
+        to,
+        layer,
+        outcome.display_path()
+    );
+    Ok(())
+}
@@ -0,0 +1,20 @@
+use super::layer_selection::parse_multi_role_layer;
+
+use crate::domain::AppError;
+
+pub fn run(layer: String, role: String, enabled: bool) -> Result<(), AppError> {
+    let layer = parse_multi_role_layer(&layer)?.dir_name().to_string();
+    let outcome = crate::app::api::role_set_enabled(&layer, &role, enabled)?;
+    let state = if outcome.enabled { "enabled" } else { "disabled" };
+    if outcome.changed {
+        println!(
+            "✅ {} role '{}' in layer '{}' (.jlo/config.toml)",
+            if outcome.enabled { "Enabled" } else { "Disabled" },
+            outcome.role,
+            outcome.layer
+        );
+    } else {
+        println!("✅ Role '{}' in layer '{}' is already {}", outcome.role, outcome.layer, state);
+    }
+    Ok(())
+}
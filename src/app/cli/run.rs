@@ -1,9 +1,16 @@
 //! Run command implementation.
 
+use std::io::IsTerminal;
 use std::path::PathBuf;
 
-use crate::domain::AppError;
+use crate::app::api::{ReportStream, RunOptions, RunPlan, RunRuntimeOptions};
+use crate::app::cli::OutputFormat;
+use crate::domain::{AppError, Layer, RunResult};
 use clap::Subcommand;
+use dialoguer::Confirm;
+
+/// Environment variable that answers the run confirmation prompt in non-interactive contexts.
+const ASSUME_YES_ENV: &str = "JLO_ASSUME_YES";
 
 #[derive(Subcommand)]
 pub enum RunLayer {
@@ -19,6 +26,9 @@ pub enum RunLayer {
         /// Run in mock mode (no Jules API, real git/GitHub operations)
         #[arg(long, conflicts_with = "prompt_preview")]
         mock: bool,
+        /// Name the last author of the most-changed files in the prompt (runs git log per file)
+        #[arg(long)]
+        attribute_authors: bool,
     },
     /// Run observers layer (requires role)
     #[clap(visible_alias = "o", alias = "observer")]
@@ -26,6 +36,9 @@ pub enum RunLayer {
         /// Role to run
         #[arg(short = 'r', long)]
         role: String,
+        /// Load the role definition from this file instead of .jlo/roles/
+        #[arg(long, value_name = "PATH", conflicts_with = "mock")]
+        role_file: Option<PathBuf>,
         /// Show assembled prompts without executing
         #[arg(long, conflicts_with = "mock")]
         prompt_preview: bool,
@@ -48,12 +61,15 @@ pub enum RunLayer {
         /// Run in mock mode (no Jules API, real git/GitHub operations)
         #[arg(long, conflicts_with = "prompt_preview")]
         mock: bool,
+        /// Collapse pending events with identical summaries before deciding
+        #[arg(long)]
+        dedupe_events: bool,
     },
     /// Run planner layer (requirement-driven)
     #[clap(visible_alias = "p", alias = "planners")]
     Planner {
-        /// Local requirement file path (required)
-        #[arg(long, short = 'r')]
+        /// Local requirement file path, or `-` to read and validate a requirement from stdin
+        #[arg(long, short = 'r', alias = "input", value_name = "PATH|-")]
         requirement: PathBuf,
         /// Show assembled prompts without executing
         #[arg(long, conflicts_with = "mock")]
@@ -68,8 +84,8 @@ pub enum RunLayer {
     /// Run implementer layer (requirement-driven)
     #[clap(visible_alias = "i", alias = "implementers")]
     Implementer {
-        /// Local requirement file path (required)
-        #[arg(long, short = 'r')]
+        /// Local requirement file path, or `-` to read and validate a requirement from stdin
+        #[arg(long, short = 'r', alias = "input", value_name = "PATH|-")]
         requirement: PathBuf,
         /// Show assembled prompts without executing
         #[arg(long, conflicts_with = "mock")]
@@ -83,6 +99,9 @@ pub enum RunLayer {
         /// Skip post-execution cleanup (requirement deletion and worker-branch push)
         #[arg(long, short = 'C', visible_alias = "nc")]
         no_cleanup: bool,
+        /// Commit locally but skip every push and PR creation; without --mock, skip the Jules session
+        #[arg(long)]
+        no_push: bool,
     },
     /// Run innovators layer (requires role)
     #[clap(visible_alias = "x", alias = "innovator")]
@@ -112,27 +131,62 @@ pub enum RunLayer {
         /// Override the starting branch
         #[arg(long)]
         branch: Option<String>,
+        /// Abort when more than N implementer branches are candidates (default: run.integrator_max_prs)
+        #[arg(long, value_name = "N")]
+        max_prs: Option<std::num::NonZeroUsize>,
+        /// Run the local trial merges but skip the Jules session, which pushes and opens a PR
+        #[arg(long)]
+        no_push: bool,
+        /// Tag the integration commit once the session pushes its branch (waits up to an hour)
+        #[arg(long, value_name = "NAME", conflicts_with_all = ["prompt_preview", "no_push"])]
+        tag: Option<String>,
     },
 }
 
-pub fn run_agents(layer: RunLayer) -> Result<(), AppError> {
-    use crate::domain::Layer;
+pub fn run_agents(
+    layer: RunLayer,
+    allow_truncate: bool,
+    yes: bool,
+    format: OutputFormat,
+    record: Option<PathBuf>,
+    config: Option<PathBuf>,
+) -> Result<(), AppError> {
+    let role_file = match &layer {
+        RunLayer::Observers { role_file, .. } => role_file.clone(),
+        _ => None,
+    };
+    let dedupe_events = matches!(layer, RunLayer::Decider { dedupe_events: true, .. });
+    let no_push = matches!(
+        layer,
+        RunLayer::Implementer { no_push: true, .. } | RunLayer::Integrator { no_push: true, .. }
+    );
+    let attribute_authors = matches!(layer, RunLayer::Narrator { attribute_authors: true, .. });
+    let max_prs = match &layer {
+        RunLayer::Integrator { max_prs, .. } => max_prs.map(std::num::NonZeroUsize::get),
+        _ => None,
+    };
+    let tag = match &layer {
+        RunLayer::Integrator { tag, .. } => tag.clone(),
+        _ => None,
+    };
 
     let (target_layer, role, prompt_preview, branch, requirement, mock, task, no_cleanup) =
         match layer {
-            RunLayer::Narrator { prompt_preview, branch, mock } => {
+            RunLayer::Narrator { prompt_preview, branch, mock, .. } => {
                 (Layer::Narrator, None, prompt_preview, branch, None, mock, None, false)
             }
-            RunLayer::Observers { role, prompt_preview, branch, mock } => {
+            RunLayer::Observers { role, prompt_preview, branch, mock, .. } => {
                 (Layer::Observers, Some(role), prompt_preview, branch, None, mock, None, false)
             }
-            RunLayer::Decider { prompt_preview, branch, mock } => {
+            RunLayer::Decider { prompt_preview, branch, mock, .. } => {
                 (Layer::Decider, None, prompt_preview, branch, None, mock, None, false)
             }
             RunLayer::Planner { prompt_preview, branch, requirement, mock } => {
                 (Layer::Planner, None, prompt_preview, branch, Some(requirement), mock, None, false)
             }
-            RunLayer::Implementer { prompt_preview, branch, requirement, mock, no_cleanup } => (
+            RunLayer::Implementer {
+                prompt_preview, branch, requirement, mock, no_cleanup, ..
+            } => (
                 Layer::Implementer,
                 None,
                 prompt_preview,
@@ -145,25 +199,220 @@ pub fn run_agents(layer: RunLayer) -> Result<(), AppError> {
             RunLayer::Innovators { role, task, prompt_preview, branch, mock } => {
                 (Layer::Innovators, Some(role), prompt_preview, branch, None, mock, task, false)
             }
-            RunLayer::Integrator { prompt_preview, branch } => {
+            RunLayer::Integrator { prompt_preview, branch, .. } => {
                 (Layer::Integrator, None, prompt_preview, branch, None, false, None, false)
             }
         };
 
-    let result = crate::app::api::run(
-        target_layer,
-        role,
+    let (requirement, requirement_input) = match requirement {
+        Some(path) => {
+            let (path, input) = resolve_requirement(path)?;
+            (Some(path), input)
+        }
+        None => (None, None),
+    };
+
+    let mut target = RunOptions { layer: target_layer, role, requirement, task, role_file };
+    let mut runtime = RunRuntimeOptions {
         prompt_preview,
         branch,
-        requirement,
         mock,
-        task,
         no_cleanup,
-    )?;
+        dedupe_events,
+        allow_truncate,
+        no_push,
+        record,
+        attribute_authors,
+        max_prs,
+        tag,
+        requirement_input,
+        report_stream: if format == OutputFormat::Json {
+            ReportStream::Stderr
+        } else {
+            ReportStream::Stdout
+        },
+        config,
+        ..Default::default()
+    };
+
+    if !prompt_preview && !mock {
+        let plan = crate::app::api::plan_run(&target, &runtime)?;
+        if !confirm_run(target_layer, &plan, yes)? {
+            eprintln!("Aborted.");
+            return Ok(());
+        }
+    }
+
+    // A real run reads the requirement from its staged file, written only once confirmed.
+    if !prompt_preview && let Some(content) = runtime.requirement_input.take() {
+        target.requirement = Some(crate::app::api::stage_requirement_input(&content)?);
+    }
+
+    let result = crate::app::api::run(target, runtime)?;
+
+    if format == OutputFormat::Json {
+        let json = serde_json::to_string(&result).map_err(|e| {
+            AppError::InternalError(format!("Failed to serialize run result: {}", e))
+        })?;
+        println!("{}", json);
+        return Ok(());
+    }
 
-    if !result.prompt_preview && !result.roles.is_empty() && !result.sessions.is_empty() {
-        println!("✅ Created {} Jules session(s)", result.sessions.len());
+    for line in created_sessions_summary(&result) {
+        println!("{}", line);
     }
 
     Ok(())
 }
+
+/// Summary lines for created sessions: the count followed by each session's link, or
+/// its id when Jules reported no link.
+fn created_sessions_summary(result: &RunResult) -> Vec<String> {
+    if result.prompt_preview || result.roles.is_empty() || result.sessions.is_empty() {
+        return Vec::new();
+    }
+
+    let mut lines = vec![format!("✅ Created {} Jules session(s)", result.sessions.len())];
+    for (index, session) in result.sessions.iter().enumerate() {
+        match result.session_urls.get(index).cloned().flatten() {
+            Some(url) => lines.push(format!("   🔗 {}", url)),
+            None => lines.push(format!("   {} (no link reported)", session)),
+        }
+    }
+    lines
+}
+
+/// Resolve `--requirement -` by reading and validating stdin; other paths pass through.
+///
+/// Returns the path a real run stages the document at, alongside its content. Nothing
+/// is written here, so a run that is declined or fails validation leaves no file behind.
+fn resolve_requirement(requirement: PathBuf) -> Result<(PathBuf, Option<String>), AppError> {
+    if requirement.as_os_str() != "-" {
+        return Ok((requirement, None));
+    }
+
+    let content = std::io::read_to_string(std::io::stdin())?;
+    let path = crate::app::api::validate_requirement_input(&content)?;
+    Ok((path, Some(content)))
+}
+
+/// Ask before a real run creates Jules sessions; `--yes` or `JLO_ASSUME_YES` skips the prompt.
+///
+/// A run that creates no session is not asked about. Without a terminal the prompt
+/// cannot be answered, so an explicit opt-in is required.
+fn confirm_run(layer: Layer, plan: &RunPlan, yes: bool) -> Result<bool, AppError> {
+    if plan.sessions == 0 || yes || assume_yes_from_env() {
+        return Ok(true);
+    }
+
+    if !std::io::stdin().is_terminal() {
+        return Err(AppError::Validation(format!(
+            "Refusing to run {} without confirmation in a non-interactive context; pass --yes or set {}=1",
+            layer.dir_name(),
+            ASSUME_YES_ENV
+        )));
+    }
+
+    let confirmed = Confirm::new()
+        .with_prompt(confirmation_prompt(layer, plan))
+        .default(false)
+        .interact_opt()
+        .map_err(|err| AppError::Validation(format!("Failed to read confirmation: {}", err)))?;
+    Ok(confirmed.unwrap_or(false))
+}
+
+/// Confirmation question naming the roles, branch, and session count of a planned run.
+fn confirmation_prompt(layer: Layer, plan: &RunPlan) -> String {
+    format!(
+        "Run {} ({}: {}) on branch '{}'? This will create {}",
+        layer.dir_name(),
+        plural(plan.roles.len(), "role"),
+        plan.roles.join(", "),
+        plan.starting_branch,
+        plural(plan.sessions, "Jules session"),
+    )
+}
+
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 { format!("1 {}", noun) } else { format!("{} {}s", count, noun) }
+}
+
+fn assume_yes_from_env() -> bool {
+    std::env::var(ASSUME_YES_ENV).is_ok_and(|value| {
+        !matches!(value.trim().to_ascii_lowercase().as_str(), "" | "0" | "false")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result_with_sessions(urls: &[Option<&str>]) -> RunResult {
+        RunResult {
+            roles: vec!["taxonomy".to_string()],
+            prompt_preview: false,
+            sessions: vec!["sessions/1".to_string(), "sessions/2".to_string()],
+            session_urls: urls.iter().map(|url| url.map(str::to_string)).collect(),
+            cleanup_requirement: None,
+        }
+    }
+
+    #[test]
+    fn summary_lists_session_urls_after_count() {
+        let result = result_with_sessions(&[
+            Some("https://jules.google.com/session/1"),
+            Some("https://jules.google.com/session/2"),
+        ]);
+
+        assert_eq!(
+            created_sessions_summary(&result),
+            vec![
+                "✅ Created 2 Jules session(s)".to_string(),
+                "   🔗 https://jules.google.com/session/1".to_string(),
+                "   🔗 https://jules.google.com/session/2".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn summary_keeps_links_paired_with_their_sessions() {
+        let result = result_with_sessions(&[None, Some("https://jules.google.com/session/2")]);
+
+        assert_eq!(
+            created_sessions_summary(&result),
+            vec![
+                "✅ Created 2 Jules session(s)".to_string(),
+                "   sessions/1 (no link reported)".to_string(),
+                "   🔗 https://jules.google.com/session/2".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn summary_is_empty_for_prompt_preview() {
+        let result =
+            RunResult { prompt_preview: true, sessions: vec![], ..result_with_sessions(&[]) };
+
+        assert!(created_sessions_summary(&result).is_empty());
+    }
+
+    #[test]
+    fn confirmation_prompt_reports_planned_roles_and_sessions() {
+        let plan = RunPlan {
+            roles: vec!["taxonomy".to_string()],
+            starting_branch: "jules".to_string(),
+            sessions: 1,
+        };
+
+        assert_eq!(
+            confirmation_prompt(Layer::Observers, &plan),
+            "Run observers (1 role: taxonomy) on branch 'jules'? This will create 1 Jules session"
+        );
+
+        let plan = RunPlan { roles: vec!["a".to_string(), "b".to_string()], sessions: 2, ..plan };
+        assert_eq!(
+            confirmation_prompt(Layer::Observers, &plan),
+            "Run observers (2 roles: a, b) on branch 'jules'? This will create 2 Jules sessions"
+        );
+    }
+}
@@ -3,7 +3,22 @@
 use std::path::PathBuf;
 
 use crate::domain::AppError;
-use clap::Subcommand;
+use clap::{Subcommand, ValueEnum};
+
+/// Fallback table width when stdout is not a terminal or `COLUMNS` is unset.
+const DEFAULT_TABLE_WIDTH: usize = 100;
+
+/// Output layout for `setup list`.
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SetupListFormat {
+    /// One `name - summary` line per component
+    #[default]
+    Text,
+    /// Aligned NAME | SUMMARY | DEPS columns fitted to the terminal width
+    Table,
+    /// Component summaries as a JSON array
+    Json,
+}
 
 #[derive(Subcommand)]
 pub enum SetupCommands {
@@ -12,6 +27,18 @@ pub enum SetupCommands {
     Gen {
         /// Project directory containing .jlo/setup/ (defaults to current directory)
         path: Option<PathBuf>,
+        /// Validate tools.yml (dependencies, cycles, env var names) without writing artifacts
+        #[arg(long)]
+        validate_only: bool,
+        /// Also write setup.lock recording resolved component order and version pins
+        #[arg(long, conflicts_with = "validate_only")]
+        lock: bool,
+        /// Verify setup.lock is current without writing artifacts
+        #[arg(long, conflicts_with_all = ["validate_only", "lock"])]
+        check: bool,
+        /// Report every tools.yml problem together instead of failing on the first
+        #[arg(long, conflicts_with_all = ["validate_only", "check"])]
+        collect: bool,
     },
     /// List available components
     #[clap(visible_alias = "ls")]
@@ -19,19 +46,55 @@ pub enum SetupCommands {
         /// Show detailed info for a specific component
         #[arg(long)]
         detail: Option<String>,
+        /// Output format
+        #[arg(long, value_enum, default_value_t = SetupListFormat::Text, conflicts_with = "detail")]
+        format: SetupListFormat,
     },
 }
 
-pub fn run_setup_gen(path: Option<PathBuf>) -> Result<(), AppError> {
-    let components = crate::app::api::setup_gen(path.as_deref())?;
+pub fn run_setup_gen(
+    path: Option<PathBuf>,
+    validate_only: bool,
+    lock: bool,
+    check: bool,
+    collect: bool,
+) -> Result<(), AppError> {
+    if validate_only {
+        let report = crate::app::api::setup_validate(path.as_deref())?;
+        if report.is_valid() {
+            println!("✅ tools.yml is valid ({} component(s))", report.components.len());
+            return Ok(());
+        }
+        for error in &report.errors {
+            eprintln!("  ✗ {}", error);
+        }
+        return Err(AppError::Validation(format!(
+            "tools.yml has {} validation error(s)",
+            report.errors.len()
+        )));
+    }
+
+    if check {
+        let components = crate::app::api::setup_check_lock(path.as_deref())?;
+        println!("✅ setup.lock is up to date ({} component(s))", components.len());
+        return Ok(());
+    }
+
+    let components = crate::app::api::setup_gen_with_options(
+        path.as_deref(),
+        crate::app::api::SetupGenOptions { lock, collect },
+    )?;
     println!("✅ Generated install.sh with {} component(s)", components.len());
+    if lock {
+        println!("✅ Wrote setup.lock");
+    }
     for (i, name) in components.iter().enumerate() {
         println!("  {}. {}", i + 1, name);
     }
     Ok(())
 }
 
-pub fn run_setup_list(detail: Option<String>) -> Result<(), AppError> {
+pub fn run_setup_list(detail: Option<String>, format: SetupListFormat) -> Result<(), AppError> {
     if let Some(component) = detail {
         let info = crate::app::api::setup_detail(&component)?;
         println!("{}: {}", info.name, info.summary);
@@ -50,16 +113,56 @@ pub fn run_setup_list(detail: Option<String>) -> Result<(), AppError> {
                 if !env.description.is_empty() {
                     println!("    {}", env.description);
                 }
+                for reference in &env.references {
+                    match &reference.component {
+                        Some(component) => println!(
+                            "    ↳ references ${{{}}} from component '{}'",
+                            reference.name, component
+                        ),
+                        None => println!(
+                            "    ⚠ references ${{{}}}, which no setup component defines",
+                            reference.name
+                        ),
+                    }
+                }
             }
         }
         println!("\nInstall Script:");
         println!("{}", info.script_content);
     } else {
         let components = crate::app::api::setup_list()?;
-        println!("Available components:");
-        for comp in components {
-            println!("  {} - {}", comp.name, comp.summary);
+        match format {
+            SetupListFormat::Text => {
+                println!("Available components:");
+                for comp in components {
+                    println!("  {} - {}", comp.name, comp.summary);
+                }
+            }
+            SetupListFormat::Table => {
+                use crate::app::commands::setup::list::render_table;
+                print!("{}", render_table(&components, table_width()));
+            }
+            SetupListFormat::Json => {
+                let json = serde_json::to_string(&components).map_err(|e| {
+                    AppError::InternalError(format!("Failed to serialize components: {}", e))
+                })?;
+                println!("{}", json);
+            }
         }
     }
     Ok(())
 }
+
+/// Terminal width from `COLUMNS` when stdout is a terminal, else a fixed width.
+fn table_width() -> usize {
+    use std::io::IsTerminal;
+
+    if !std::io::stdout().is_terminal() {
+        return DEFAULT_TABLE_WIDTH;
+    }
+    std::env::var("COLUMNS")
+        .ok()
+        .and_then(|value| value.parse::<usize>().ok())
+        .filter(|width| *width > 0)
+        .unwrap_or(DEFAULT_TABLE_WIDTH)
+}
@@ -4,9 +4,23 @@ use clap::Subcommand;
 #[derive(Subcommand)]
 pub enum WorkflowBootstrapCommands {
     /// Ensure/sync worker branch from target branch
-    WorkerBranch,
+    WorkerBranch {
+        /// Re-materialize jlo-managed .jules/ files from the embedded scaffold (exchange artifacts are kept)
+        #[arg(long)]
+        force: bool,
+    },
     /// Materialize managed files from embedded scaffold
-    ManagedFiles,
+    ManagedFiles {
+        /// Remove managed files (under .jules/schemas/) the current scaffold no longer ships
+        #[arg(long)]
+        prune: bool,
+        /// Report managed files that drifted from the embedded scaffold without writing; fails on drift
+        #[arg(long, conflicts_with = "prune")]
+        verify: bool,
+        /// With --verify, include a unified diff for each drifted file
+        #[arg(long, requires = "verify")]
+        verbose: bool,
+    },
     /// Remove `.jules/exchange/changes.yml` for fresh narrator summary
     ExchangeChanges,
 }
@@ -18,15 +32,23 @@ pub fn run_workflow_bootstrap(command: WorkflowBootstrapCommands) -> Result<(),
         .map_err(|e| AppError::InternalError(format!("Failed to get current directory: {}", e)))?;
 
     match command {
-        WorkflowBootstrapCommands::WorkerBranch => {
-            let options = workflow::WorkflowBootstrapWorkerBranchOptions { root };
+        WorkflowBootstrapCommands::WorkerBranch { force } => {
+            let options = workflow::WorkflowBootstrapWorkerBranchOptions { root, force };
             let output = workflow::bootstrap_worker_branch(options)?;
             workflow::write_workflow_output(&output)
         }
-        WorkflowBootstrapCommands::ManagedFiles => {
-            let options = workflow::WorkflowBootstrapManagedFilesOptions { root };
+        WorkflowBootstrapCommands::ManagedFiles { prune, verify, verbose } => {
+            let options =
+                workflow::WorkflowBootstrapManagedFilesOptions { root, prune, verify, verbose };
             let output = workflow::bootstrap_managed_files(options)?;
-            workflow::write_workflow_output(&output)
+            workflow::write_workflow_output(&output)?;
+            if !output.drifted.is_empty() {
+                return Err(AppError::Validation(format!(
+                    "Managed files drifted from the embedded scaffold: {}. Run 'jlo workflow bootstrap managed-files' to restore them.",
+                    output.drifted.join(", ")
+                )));
+            }
+            Ok(())
         }
         WorkflowBootstrapCommands::ExchangeChanges => {
             let options = workflow::WorkflowBootstrapExchangeChangesOptions { root };
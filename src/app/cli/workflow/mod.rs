@@ -4,14 +4,25 @@ mod bootstrap;
 mod process;
 mod push;
 
-use crate::domain::AppError;
+use crate::domain::{AppError, Layer};
 use clap::Subcommand;
+use clap::builder::ArgPredicate;
 use std::path::PathBuf;
 
 pub use bootstrap::WorkflowBootstrapCommands;
 pub use process::WorkflowProcessCommands;
 pub use push::WorkflowPushCommands;
 
+/// Output shape for `workflow inspect-exchange`.
+#[derive(clap::ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum InspectExchangeFormat {
+    /// A single JSON document
+    #[default]
+    Json,
+    /// One JSON object per event or requirement
+    Ndjson,
+}
+
 #[derive(Subcommand)]
 pub enum WorkflowCommands {
     /// Bootstrap the .jules/ runtime repository on the current branch
@@ -23,7 +34,8 @@ pub enum WorkflowCommands {
     Doctor,
     /// Run a layer and return wait-gating metadata
     Run {
-        /// Target layer (narrator, observers, decider, planner, implementer, integrator, innovators)
+        /// Target layer (narrator, observers, decider, planner, implementer, integrator, innovators),
+        /// or `all` to run the pipeline layers in order
         layer: String,
         /// Run in mock mode (requires JULES_MOCK_TAG)
         #[arg(long)]
@@ -34,6 +46,35 @@ pub enum WorkflowCommands {
         /// Task selector for innovators (expected: create_three_proposals)
         #[arg(long)]
         task: Option<String>,
+        /// Print the prompts a real run would send to stderr (requires --mock)
+        #[arg(long, requires = "mock")]
+        print_prompts: bool,
+        /// Seed mock ids and timestamps for reproducible mock artifacts (requires --mock)
+        #[arg(long, value_name = "N", requires = "mock")]
+        mock_seed: Option<u64>,
+        /// With `all`, keep running later layers after a layer fails; with `observers`, keep
+        /// running the remaining roles
+        #[arg(long)]
+        continue_on_error: bool,
+        /// Label echoed into the output JSON to correlate CI matrix entries
+        #[arg(long, value_name = "LABEL")]
+        label: Option<String>,
+        /// Skip observers/innovators roles whose names match this glob (repeatable)
+        #[arg(long, value_name = "GLOB")]
+        exclude: Vec<String>,
+        /// JSON array of event bodies the mock observer writes to pending instead of
+        /// generated events (observers, requires --mock)
+        #[arg(long, value_name = "JSON", requires = "mock")]
+        emit_events: Option<String>,
+        /// Run even when remote mock branches for this layer already carry JULES_MOCK_TAG
+        #[arg(long, requires = "mock")]
+        allow_tag_reuse: bool,
+        /// Print whether each requirement is selected for planning, and why, to stderr (planner)
+        #[arg(long)]
+        explain_routing: bool,
+        /// Merge pending events with matching dedupe keys before triage (decider)
+        #[arg(long)]
+        dedupe_events: bool,
     },
     /// Generate workflow scaffold files to an output directory
     #[clap(visible_alias = "g")]
@@ -43,6 +84,9 @@ pub enum WorkflowCommands {
         /// Output directory override (default: repository .github/)
         #[arg(short = 'o', long = "output-dir")]
         output_dir: Option<String>,
+        /// Extra template variables as a JSON object (must not shadow built-in variables)
+        #[arg(long, value_name = "JSON")]
+        template_vars: Option<String>,
     },
 
     /// Process GitHub workflow actions
@@ -60,7 +104,11 @@ pub enum WorkflowCommands {
     /// Remove a processed requirement and its source events
     CleanRequirement {
         /// Path to the requirement file
-        requirement_file: PathBuf,
+        #[arg(required_unless_present = "batch", conflicts_with = "batch")]
+        requirement_file: Option<PathBuf>,
+        /// JSON array of requirement files to clean with a single commit and push
+        #[arg(long, value_name = "JSON")]
+        batch: Option<String>,
     },
 
     /// Clean up mock artifacts
@@ -77,10 +125,54 @@ pub enum WorkflowCommands {
     },
 
     /// Inspect exchange and output JSON
-    InspectExchange,
+    InspectExchange {
+        /// Hidden alias of `--format ndjson`
+        #[arg(long, hide = true, conflicts_with = "format")]
+        stream: bool,
+        /// Compare against a saved inspect-exchange JSON document and output per-field deltas
+        #[arg(long, value_name = "BASELINE_JSON", conflicts_with_all = ["stream", "format"])]
+        diff: Option<std::path::PathBuf>,
+        /// Report only counts; leave `items` and `pending_files` empty
+        #[arg(long, conflicts_with_all = ["stream", "format", "diff"])]
+        count_only: bool,
+        /// Scope to events authored by this observer role and the requirements citing them
+        #[arg(long)]
+        role: Option<String>,
+        /// Output format; `ndjson` streams one artifact per line
+        #[arg(
+            long,
+            value_enum,
+            default_value_t = InspectExchangeFormat::Json,
+            default_value_if("stream", ArgPredicate::IsPresent, "ndjson")
+        )]
+        format: InspectExchangeFormat,
+    },
+
+    /// List open PRs on a base branch as JSON (PR discovery for wait steps)
+    ListPrs {
+        /// Base branch the PRs target
+        #[arg(long)]
+        base: String,
+        /// Only include PRs created at or after this RFC 3339 timestamp (e.g. run_started_at)
+        #[arg(long, value_name = "TIMESTAMP")]
+        after: Option<String>,
+    },
+
+    /// Emit a layer's enabled roles from .jlo/config.toml as a GitHub Actions matrix
+    MatrixRoles {
+        /// Multi-role layer (observers, innovators)
+        layer: String,
+    },
 
     /// Publish merged proposals as GitHub issues
-    PublishProposals,
+    PublishProposals {
+        /// Source attribution recorded in each issue body (e.g. workstation or runner name)
+        #[arg(long)]
+        author: Option<String>,
+        /// Also apply an `author/<name>` label to each created issue
+        #[arg(long, requires = "author")]
+        label_author: bool,
+    },
 }
 
 pub fn parse_layer(value: &str) -> Result<crate::domain::Layer, AppError> {
@@ -88,12 +180,16 @@ pub fn parse_layer(value: &str) -> Result<crate::domain::Layer, AppError> {
         .ok_or_else(|| crate::domain::RoleError::InvalidLayer { name: value.to_string() }.into())
 }
 
-pub fn run_workflow(command: WorkflowCommands) -> Result<(), AppError> {
+/// Dispatch a workflow subcommand; `config` is the global `--config` path, if given.
+pub fn run_workflow(
+    command: WorkflowCommands,
+    config: Option<std::path::PathBuf>,
+) -> Result<(), AppError> {
     match command {
         WorkflowCommands::Bootstrap { command } => bootstrap::run_workflow_bootstrap(command),
         WorkflowCommands::Doctor => {
             use crate::app::commands::workflow;
-            let options = workflow::WorkflowDoctorOptions {};
+            let options = workflow::WorkflowDoctorOptions { config };
             let output = workflow::doctor(options)?;
             workflow::write_workflow_output(&output)?;
             if !output.ok {
@@ -101,19 +197,139 @@ pub fn run_workflow(command: WorkflowCommands) -> Result<(), AppError> {
             }
             Ok(())
         }
-        WorkflowCommands::Run { layer, mock, branch, task } => {
+        WorkflowCommands::Run {
+            layer,
+            mock,
+            branch,
+            task,
+            print_prompts,
+            mock_seed,
+            continue_on_error,
+            label,
+            exclude,
+            emit_events,
+            allow_tag_reuse,
+            explain_routing,
+            dedupe_events,
+        } => {
             use crate::app::commands::workflow;
-            let layer = parse_layer(&layer)?;
             let mock_tag = std::env::var("JULES_MOCK_TAG").ok();
+            if emit_events.is_some() && layer != "observers" {
+                return Err(AppError::Validation(
+                    "--emit-events is only supported for 'observers'".to_string(),
+                ));
+            }
+
+            if layer == "all" {
+                if label.is_some() {
+                    return Err(AppError::Validation(
+                        "--label is not supported with 'all'".to_string(),
+                    ));
+                }
+                if !exclude.is_empty() {
+                    return Err(AppError::Validation(
+                        "--exclude is not supported with 'all'".to_string(),
+                    ));
+                }
+                if dedupe_events {
+                    return Err(AppError::Validation(
+                        "--dedupe-events is not supported with 'all'".to_string(),
+                    ));
+                }
+                if explain_routing {
+                    return Err(AppError::Validation(
+                        "--explain-routing is not supported with 'all'".to_string(),
+                    ));
+                }
+                if task.is_some() {
+                    return Err(AppError::Validation(
+                        "--task is not supported with 'all' (innovators are not part of the pipeline)"
+                            .to_string(),
+                    ));
+                }
+                let options = workflow::WorkflowRunPipelineOptions {
+                    mock,
+                    branch,
+                    mock_tag,
+                    print_prompts,
+                    mock_seed,
+                    continue_on_error,
+                    allow_tag_reuse,
+                    config,
+                };
+                let output = workflow::run_all(options)?;
+                workflow::write_workflow_output(&output)?;
+                if let Some(step) = output.first_failure() {
+                    return Err(AppError::Validation(format!(
+                        "Pipeline layer '{}' failed: {}",
+                        step.layer.dir_name(),
+                        step.error.as_deref().unwrap_or("unknown error")
+                    )));
+                }
+                return Ok(());
+            }
+            let layer = parse_layer(&layer)?;
+            if continue_on_error && layer != Layer::Observers {
+                return Err(AppError::Validation(format!(
+                    "--continue-on-error is only supported with 'all' or 'observers', not '{}'",
+                    layer.dir_name()
+                )));
+            }
+            if explain_routing && layer != Layer::Planner {
+                return Err(AppError::Validation(format!(
+                    "--explain-routing is only supported for 'planner', not '{}'",
+                    layer.dir_name()
+                )));
+            }
+            if dedupe_events && layer != Layer::Decider {
+                return Err(AppError::Validation(format!(
+                    "--dedupe-events is only supported for 'decider', not '{}'",
+                    layer.dir_name()
+                )));
+            }
+            if !exclude.is_empty() && layer.is_single_role() {
+                return Err(AppError::Validation(format!(
+                    "--exclude is only supported for multi-role layers, not '{}'",
+                    layer.dir_name()
+                )));
+            }
 
-            let options = workflow::WorkflowRunOptions { layer, mock, branch, mock_tag, task };
+            let options = workflow::WorkflowRunOptions {
+                layer,
+                mock,
+                branch,
+                mock_tag,
+                task,
+                print_prompts,
+                mock_seed,
+                label,
+                exclude,
+                allow_tag_reuse,
+                explain_routing,
+                dedupe_events,
+                continue_on_error,
+                emit_events: emit_events
+                    .as_deref()
+                    .map(serde_json::from_str::<Vec<serde_json::Value>>)
+                    .transpose()
+                    .map_err(|e| {
+                        AppError::Validation(format!("Invalid --emit-events JSON: {}", e))
+                    })?,
+                config,
+            };
             let output = workflow::run(options)?;
             workflow::write_workflow_output(&output)
         }
-        WorkflowCommands::Generate { mode, output_dir } => {
+        WorkflowCommands::Generate { mode, output_dir, template_vars } => {
             use crate::app::commands::workflow;
             let output_dir = output_dir.map(std::path::PathBuf::from);
-            let options = workflow::WorkflowGenerateOptions { mode, output_dir };
+            let template_vars = template_vars
+                .as_deref()
+                .map(workflow::parse_template_vars)
+                .transpose()?
+                .unwrap_or_default();
+            let options =
+                workflow::WorkflowGenerateOptions { mode, output_dir, template_vars, config };
             let output = workflow::generate(options)?;
             workflow::write_workflow_output(&output)
         }
@@ -122,9 +338,32 @@ pub fn run_workflow(command: WorkflowCommands) -> Result<(), AppError> {
             process::run_workflow_process(&github, command)
         }
         WorkflowCommands::Push { command } => push::run_workflow_push(command),
-        WorkflowCommands::CleanRequirement { requirement_file } => {
+        WorkflowCommands::CleanRequirement { requirement_file, batch } => {
             use crate::app::commands::workflow;
-            let requirement_file = requirement_file.to_string_lossy().to_string();
+            if let Some(json_str) = batch {
+                let requirement_files: Vec<String> = serde_json::from_str(&json_str)
+                    .map_err(|e| AppError::Validation(format!("Invalid batch JSON: {}", e)))?;
+                let options =
+                    workflow::exchange::ExchangeCleanRequirementBatchOptions { requirement_files };
+                let output = workflow::exchange::clean_requirement_batch(options)?;
+                workflow::write_workflow_output(&output)?;
+                if !output.failed.is_empty() {
+                    let failed: Vec<&str> =
+                        output.failed.iter().map(|f| f.requirement_file.as_str()).collect();
+                    return Err(AppError::Validation(format!(
+                        "Failed to clean {} requirement(s): {}",
+                        failed.len(),
+                        failed.join(", ")
+                    )));
+                }
+                return Ok(());
+            }
+            let requirement_file = requirement_file
+                .ok_or_else(|| {
+                    AppError::Validation("requirement file or --batch is required".to_string())
+                })?
+                .to_string_lossy()
+                .to_string();
             let options = workflow::exchange::ExchangeCleanRequirementOptions { requirement_file };
             let output = workflow::exchange::clean_requirement(options)?;
             workflow::write_workflow_output(&output)
@@ -157,15 +396,39 @@ pub fn run_workflow(command: WorkflowCommands) -> Result<(), AppError> {
             let output = workflow::exchange::clean_mock(options)?;
             workflow::write_workflow_output(&output)
         }
-        WorkflowCommands::InspectExchange => {
+        WorkflowCommands::InspectExchange { stream: _, diff, count_only, role, format } => {
             use crate::app::commands::workflow;
-            let options = workflow::exchange::ExchangeInspectOptions {};
+            let options = workflow::exchange::ExchangeInspectOptions { count_only, role, config };
+            if let Some(baseline) = diff {
+                let output = workflow::exchange::inspect_diff(options, &baseline)?;
+                return workflow::write_workflow_output(&output);
+            }
+            if format == InspectExchangeFormat::Ndjson {
+                let stdout = std::io::stdout();
+                let mut out = std::io::BufWriter::new(stdout.lock());
+                workflow::exchange::inspect_stream(options, &mut out)?;
+                return Ok(());
+            }
             let output = workflow::exchange::inspect(options)?;
             workflow::write_workflow_output(&output)
         }
-        WorkflowCommands::PublishProposals => {
+        WorkflowCommands::ListPrs { base, after } => {
+            use crate::app::commands::workflow;
+            let options = workflow::WorkflowListPrsOptions { base, after };
+            let output = workflow::list_prs(options)?;
+            workflow::write_workflow_output(&output)
+        }
+        WorkflowCommands::MatrixRoles { layer } => {
+            use crate::app::commands::workflow;
+            let options =
+                workflow::WorkflowMatrixRolesOptions { layer: parse_layer(&layer)?, config };
+            let output = workflow::matrix_roles(options)?;
+            workflow::write_workflow_output(&output)
+        }
+        WorkflowCommands::PublishProposals { author, label_author } => {
             use crate::app::commands::workflow;
-            let options = workflow::exchange::ExchangePublishProposalsOptions {};
+            let options =
+                workflow::exchange::ExchangePublishProposalsOptions { author, label_author };
             let output = workflow::exchange::publish_proposals(options)?;
             workflow::write_workflow_output(&output)
         }
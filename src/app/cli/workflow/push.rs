@@ -17,6 +17,15 @@ pub enum WorkflowPushCommands {
         /// Pull request body
         #[arg(long)]
         pr_body: String,
+        /// Squash-merge the pull request (default)
+        #[arg(long, conflicts_with_all = ["merge", "rebase"])]
+        squash: bool,
+        /// Merge the pull request with a merge commit
+        #[arg(long, conflicts_with = "rebase")]
+        merge: bool,
+        /// Rebase-merge the pull request
+        #[arg(long)]
+        rebase: bool,
     },
 }
 
@@ -24,12 +33,28 @@ pub fn run_workflow_push(command: WorkflowPushCommands) -> Result<(), AppError>
     use crate::app::commands::workflow;
 
     match command {
-        WorkflowPushCommands::WorkerBranch { change_token, commit_message, pr_title, pr_body } => {
+        WorkflowPushCommands::WorkerBranch {
+            change_token,
+            commit_message,
+            pr_title,
+            pr_body,
+            squash: _,
+            merge,
+            rebase,
+        } => {
+            let merge_strategy = if merge {
+                workflow::push::MergeStrategy::Merge
+            } else if rebase {
+                workflow::push::MergeStrategy::Rebase
+            } else {
+                workflow::push::MergeStrategy::Squash
+            };
             let output = workflow::push::execute(workflow::push::PushWorkerBranchOptions {
                 change_token,
                 commit_message,
                 pr_title,
                 pr_body,
+                merge_strategy,
             })?;
             workflow::write_workflow_output(&output)
         }
@@ -0,0 +1,104 @@
+//! Opt-in Jules API reachability check (`jlo doctor --check-api`).
+
+use std::fmt;
+
+use crate::domain::AppError;
+use crate::ports::JulesClient;
+
+/// Outcome of probing the Jules API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ApiHealth {
+    Healthy,
+    /// No API key is available to build a client.
+    MissingCredentials(String),
+    /// The request never produced an HTTP response.
+    Unreachable(String),
+    /// The API rejected the credentials (401/403).
+    AuthFailed(String),
+    /// Any other API error.
+    Failed(String),
+}
+
+impl ApiHealth {
+    pub fn is_healthy(&self) -> bool {
+        matches!(self, ApiHealth::Healthy)
+    }
+}
+
+impl fmt::Display for ApiHealth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ApiHealth::Healthy => write!(f, "reachable and authenticated"),
+            ApiHealth::MissingCredentials(detail) => write!(f, "missing credentials: {}", detail),
+            ApiHealth::Unreachable(detail) => write!(f, "unreachable: {}", detail),
+            ApiHealth::AuthFailed(detail) => write!(f, "authentication failed: {}", detail),
+            ApiHealth::Failed(detail) => write!(f, "request failed: {}", detail),
+        }
+    }
+}
+
+/// Probe the API with `client`, or classify the error that prevented building one.
+pub fn check_api(client: Result<Box<dyn JulesClient>, AppError>) -> ApiHealth {
+    match client.and_then(|client| client.health()) {
+        Ok(()) => ApiHealth::Healthy,
+        Err(AppError::EnvironmentVariableMissing(name)) => {
+            ApiHealth::MissingCredentials(format!("{} is not set", name))
+        }
+        Err(AppError::JulesApiError { message, status: None }) => ApiHealth::Unreachable(message),
+        Err(AppError::JulesApiError { message, status: Some(401 | 403) }) => {
+            ApiHealth::AuthFailed(message)
+        }
+        Err(err) => ApiHealth::Failed(err.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::{SessionRequest, SessionResponse};
+
+    struct FakeClient {
+        health: fn() -> Result<(), AppError>,
+    }
+
+    impl JulesClient for FakeClient {
+        fn create_session(&self, _request: SessionRequest) -> Result<SessionResponse, AppError> {
+            unreachable!("health checks must not create sessions")
+        }
+
+        fn health(&self) -> Result<(), AppError> {
+            (self.health)()
+        }
+    }
+
+    fn client(health: fn() -> Result<(), AppError>) -> Result<Box<dyn JulesClient>, AppError> {
+        Ok(Box::new(FakeClient { health }))
+    }
+
+    #[test]
+    fn healthy_client_reports_healthy() {
+        assert_eq!(check_api(client(|| Ok(()))), ApiHealth::Healthy);
+    }
+
+    #[test]
+    fn failures_are_classified_distinctly() {
+        let missing = check_api(Err(AppError::EnvironmentVariableMissing("JULES_API_KEY".into())));
+        assert_eq!(missing, ApiHealth::MissingCredentials("JULES_API_KEY is not set".into()));
+
+        let network = check_api(client(|| {
+            Err(AppError::JulesApiError { message: "connection refused".into(), status: None })
+        }));
+        assert_eq!(network, ApiHealth::Unreachable("connection refused".into()));
+
+        let auth = check_api(client(|| {
+            Err(AppError::JulesApiError { message: "bad key".into(), status: Some(403) })
+        }));
+        assert_eq!(auth, ApiHealth::AuthFailed("bad key".into()));
+
+        let server = check_api(client(|| {
+            Err(AppError::JulesApiError { message: "boom".into(), status: Some(500) })
+        }));
+        assert!(matches!(server, ApiHealth::Failed(_)));
+        assert!(!server.is_healthy());
+    }
+}
@@ -0,0 +1,128 @@
+//! Embedded layer contracts, checked in the structure phase.
+//!
+//! Contracts are the prompt-entry declarations (`<layer>/contracts.yml`) every
+//! session prompt starts from, so they are validated regardless of whether the
+//! schema category runs.
+
+use serde_yaml::Mapping;
+use std::path::Path;
+
+use crate::app::validation::diagnostics::{Diagnostics, codes};
+use crate::app::validation::yaml::get_string;
+use crate::domain::Layer;
+
+/// Validate the contracts.yml of every layer, read through `read_asset`.
+pub fn contract_checks<R>(read_asset: R, diagnostics: &mut Diagnostics)
+where
+    R: Fn(&str) -> Option<String>,
+{
+    for layer in Layer::ALL {
+        let catalog_path = format!("{}/contracts.yml", layer.dir_name());
+        let label = format!("prompt-assemble://{}", catalog_path);
+        let Some(content) = read_asset(&catalog_path) else {
+            diagnostics.push_error(codes::STRUCT_LAYER_CONTRACT, label, "Missing contracts.yml");
+            continue;
+        };
+        match serde_yaml::from_str::<serde_yaml::Value>(&content) {
+            Ok(serde_yaml::Value::Mapping(mapping)) => {
+                validate_contracts(&mapping, Path::new(&label), layer, diagnostics);
+            }
+            Ok(_) => diagnostics.push_error(
+                codes::STRUCT_LAYER_CONTRACT,
+                label,
+                "contracts.yml must be a YAML mapping",
+            ),
+            Err(err) => diagnostics.push_error(
+                codes::STRUCT_LAYER_CONTRACT,
+                label,
+                format!("Invalid contracts.yml: {}", err),
+            ),
+        }
+    }
+}
+
+fn validate_contracts(data: &Mapping, path: &Path, layer: Layer, diagnostics: &mut Diagnostics) {
+    let layer_value = get_string(data, "layer").unwrap_or_default();
+    if layer_value != layer.dir_name() {
+        diagnostics.push_error(
+            codes::STRUCT_LAYER_CONTRACT,
+            path.display().to_string(),
+            format!("layer '{}' does not match directory '{}'", layer_value, layer.dir_name()),
+        );
+    }
+
+    let prefix = get_string(data, "branch_prefix").unwrap_or_default();
+    let layer_slug = layer.dir_name().trim_end_matches('s');
+    if !prefix.starts_with(&format!("jules-{}-", layer_slug)) {
+        diagnostics.push_error(
+            codes::STRUCT_LAYER_CONTRACT,
+            path.display().to_string(),
+            "branch_prefix is invalid",
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::validation::diagnostics::Category;
+
+    #[test]
+    fn embedded_contracts_are_valid() {
+        let mut diagnostics = Diagnostics::default();
+
+        contract_checks(
+            crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset,
+            &mut diagnostics,
+        );
+
+        assert_eq!(diagnostics.error_count(), 0, "{:?}", diagnostics.errors());
+    }
+
+    #[test]
+    fn broken_contracts_are_reported_as_structure_diagnostics() {
+        let mut diagnostics = Diagnostics::default();
+
+        contract_checks(
+            |path| match path {
+                "decider/contracts.yml" => {
+                    Some("layer: planner\nbranch_prefix: jules-other-\n".to_string())
+                }
+                "planner/contracts.yml" => None,
+                "narrator/contracts.yml" => Some("layer: [unclosed\n".to_string()),
+                _ => crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset(
+                    path,
+                ),
+            },
+            &mut diagnostics,
+        );
+
+        let errors: Vec<(&str, &str)> = diagnostics
+            .errors()
+            .iter()
+            .map(|diagnostic| (diagnostic.file.as_str(), diagnostic.message.as_str()))
+            .collect();
+        assert!(errors.contains(&(
+            "prompt-assemble://decider/contracts.yml",
+            "layer 'planner' does not match directory 'decider'"
+        )));
+        assert!(
+            errors
+                .contains(&("prompt-assemble://decider/contracts.yml", "branch_prefix is invalid"))
+        );
+        assert!(
+            errors.contains(&("prompt-assemble://planner/contracts.yml", "Missing contracts.yml"))
+        );
+        assert!(
+            errors
+                .iter()
+                .any(|(file, message)| *file == "prompt-assemble://narrator/contracts.yml"
+                    && message.starts_with("Invalid contracts.yml"))
+        );
+        assert_eq!(errors.len(), 4, "{:?}", errors);
+        assert!(diagnostics.errors().iter().all(|diagnostic| {
+            diagnostic.code == codes::STRUCT_LAYER_CONTRACT
+                && diagnostic.category == Category::Structure
+        }));
+    }
+}
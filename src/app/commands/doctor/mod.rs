@@ -1,24 +1,76 @@
-mod diagnostics;
+mod api_check;
+mod contracts;
 mod naming;
 mod quality;
+mod report;
+mod rules;
+mod sarif;
 mod schemas;
 mod semantic;
 mod structure;
+mod summary;
+mod workflows;
 mod yaml;
 
-use std::path::Path;
+use std::num::NonZeroUsize;
+use std::path::{Path, PathBuf};
 
+use crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset;
 use crate::adapters::catalogs::scaffold_assets::{
     list_event_states, list_issue_labels, read_enum_values,
 };
-use crate::domain::AppError;
+use crate::adapters::git::GitCommandAdapter;
+use crate::adapters::jules_client::HttpJulesClient;
+use crate::adapters::local_repository::LocalRepositoryAdapter;
+use crate::app::style::ColorChoice;
+use crate::app::validation::diagnostics::codes;
+use crate::domain::config::MAX_CONCURRENCY_ENV;
+use crate::domain::{AppError, ControlPlaneConfig, ExecutionConfig};
+use crate::ports::{JloStore, JulesClient};
 
 #[allow(unused_imports)]
-pub use diagnostics::{Diagnostic, Diagnostics, Severity};
+pub use crate::app::validation::diagnostics::{
+    AllowRule, Category, Diagnostic, Diagnostics, Severity,
+};
+pub use rules::{RULES, Rule};
 
 #[derive(Debug, Clone, Default)]
 pub struct DoctorOptions {
     pub strict: bool,
+    /// Repair fixable issues in place instead of reporting them.
+    pub fix: bool,
+    /// Write a JSON summary of diagnostic counts to this path.
+    pub summary_json: Option<PathBuf>,
+    /// Colorize diagnostic severity prefixes.
+    pub color: ColorChoice,
+    /// Probe Jules API reachability and credentials (makes a network call).
+    pub check_api: bool,
+    /// Warn when committed workflow files drift from the embedded scaffold.
+    pub check_workflows: bool,
+    /// Report format printed on stdout; text keeps the human-readable lines.
+    pub format: DoctorOutputFormat,
+    /// Diagnostic codes to silence (`CODE`) or downgrade to warnings (`CODE=warning`).
+    pub allow: Vec<String>,
+    /// Worker threads for schema validation; `None` defers to `JLO_MAX_CONCURRENCY`, then
+    /// `run.max_concurrency`, then the available parallelism. 1 runs sequentially.
+    pub jobs: Option<NonZeroUsize>,
+    /// Run only these categories (`structure`, `schema`, `naming`, `semantic`, `quality`).
+    pub only: Vec<String>,
+    /// Skip these categories; cannot be combined with `only`.
+    pub skip: Vec<String>,
+    /// Control-plane config to check instead of `.jlo/config.toml`; `fix` never rewrites it.
+    pub config: Option<PathBuf>,
+}
+
+/// Output format of the doctor report.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DoctorOutputFormat {
+    #[default]
+    Text,
+    /// Stable JSON report (schema_version, counts, diagnostics, applied fixes).
+    Json,
+    /// SARIF 2.1.0 log for GitHub code scanning.
+    Sarif,
 }
 
 #[derive(Debug, Clone)]
@@ -26,14 +78,118 @@ pub struct DoctorOutcome {
     pub errors: usize,
     pub warnings: usize,
     pub exit_code: i32,
+    /// Human-readable descriptions of repairs made in `--fix` mode.
+    pub applied_fixes: Vec<String>,
+    /// Every error and warning reported, errors first.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Structure phase: layout, control-branch artifacts, and the opt-in API and workflow probes.
+///
+/// Returns the exchange directories created under `--fix`.
+fn structural_phase<R: Fn(&str) -> Option<String>>(
+    read_contract: R,
+    options: &DoctorOptions,
+    store: &LocalRepositoryAdapter,
+    run_config: &ControlPlaneConfig,
+    jules_path: &Path,
+    event_states: &[String],
+    diagnostics: &mut Diagnostics,
+) -> Vec<String> {
+    let root = store.root();
+    let config_path = store.config_path();
+    let structural_inputs =
+        structure::StructuralInputs { jules_path, root, config_path: &config_path, event_states };
+    structure::structural_checks(structural_inputs, diagnostics);
+    contracts::contract_checks(read_contract, diagnostics);
+    let applied_fixes =
+        structure::exchange_layout_checks(&structural_inputs, options.fix, diagnostics);
+    structure::control_branch_artifact_checks(
+        &GitCommandAdapter::new(root.to_path_buf()),
+        &structural_inputs,
+        &run_config.run,
+        diagnostics,
+    );
+
+    if options.check_api {
+        let client = HttpJulesClient::from_env_with_config(&run_config.jules_api)
+            .map(|client| Box::new(client) as Box<dyn JulesClient>);
+        let health = api_check::check_api(client);
+        if health.is_healthy() {
+            if options.format == DoctorOutputFormat::Text {
+                println!("Jules API: {}", health);
+            }
+        } else {
+            diagnostics.push_error(
+                codes::STRUCT_API_HEALTH,
+                run_config.jules_api.api_url.to_string(),
+                health.to_string(),
+            );
+        }
+    }
+
+    if options.check_workflows {
+        workflows::workflow_drift_checks(root, store, diagnostics);
+    }
+    applied_fixes
+}
+
+/// Categories to run given `--only` / `--skip`; the two are mutually exclusive.
+fn selected_categories(only: &[String], skip: &[String]) -> Result<Vec<Category>, AppError> {
+    if !only.is_empty() && !skip.is_empty() {
+        return Err(AppError::Validation("--only and --skip cannot be combined".to_string()));
+    }
+    let parse = |names: &[String]| -> Result<Vec<Category>, AppError> {
+        names
+            .iter()
+            .map(|name| {
+                Category::from_name(name.trim()).ok_or_else(|| {
+                    AppError::Validation(format!(
+                        "Unknown doctor category '{}': expected one of {}",
+                        name,
+                        Category::ALL.map(|category| category.as_str()).join(", ")
+                    ))
+                })
+            })
+            .collect()
+    };
+
+    if !only.is_empty() {
+        let only = parse(only)?;
+        return Ok(Category::ALL.into_iter().filter(|category| only.contains(category)).collect());
+    }
+    let skip = parse(skip)?;
+    Ok(Category::ALL.into_iter().filter(|category| !skip.contains(category)).collect())
+}
+
+fn resolve_jobs(jobs: Option<NonZeroUsize>, run: &ExecutionConfig) -> Result<usize, AppError> {
+    let flag = jobs.map(NonZeroUsize::get);
+    let env = std::env::var(MAX_CONCURRENCY_ENV).ok();
+    // Validation is local CPU work, so an unconfigured doctor uses every core.
+    let available = std::thread::available_parallelism().map_or(1, NonZeroUsize::get);
+    Ok(run.effective_max_concurrency(flag, env.as_deref(), available)?)
 }
 
 pub fn execute(jules_path: &Path, options: DoctorOptions) -> Result<DoctorOutcome, AppError> {
+    execute_with_contracts(jules_path, options, read_prompt_assemble_asset)
+}
+
+/// [`execute`] with layer contracts read through `read_contract`.
+fn execute_with_contracts<R: Fn(&str) -> Option<String>>(
+    jules_path: &Path,
+    options: DoctorOptions,
+    read_contract: R,
+) -> Result<DoctorOutcome, AppError> {
     if !jules_path.exists() {
         return Err(AppError::JulesNotFound);
     }
 
+    let selected = selected_categories(&options.only, &options.skip)?;
+    let allow_rules =
+        options.allow.iter().map(|entry| AllowRule::parse(entry)).collect::<Result<Vec<_>, _>>()?;
     let root = jules_path.parent().unwrap_or(Path::new(".")).to_path_buf();
+    let store = LocalRepositoryAdapter::new(root.clone()).with_config_path(options.config.clone());
+    let config_path = store.config_path();
     let issue_labels = list_issue_labels()?;
     let event_states = list_event_states()?;
     let event_confidence = read_enum_values(".jules/schemas/observers/event.yml", "confidence")?;
@@ -41,33 +197,83 @@ pub fn execute(jules_path: &Path, options: DoctorOptions) -> Result<DoctorOutcom
 
     let mut diagnostics = Diagnostics::default();
 
-    let _run_config = structure::read_control_plane_config(&root, &mut diagnostics)?;
-
-    structure::structural_checks(
-        structure::StructuralInputs { jules_path, root: &root, event_states: &event_states },
-        &mut diagnostics,
-    );
+    // Later phases need the config even when structure diagnostics are not selected.
+    let mut config_diagnostics = Diagnostics::default();
+    let run_config = structure::read_control_plane_config(
+        &config_path,
+        if selected.contains(&Category::Structure) {
+            &mut diagnostics
+        } else {
+            &mut config_diagnostics
+        },
+    )
+    .map_err(|err| err.context("while loading control-plane config for doctor"))?;
+    let jobs = resolve_jobs(options.jobs, &run_config.run)?;
 
-    schemas::schema_checks(
-        schemas::SchemaInputs {
+    let mut applied_fixes = Vec::new();
+    if selected.contains(&Category::Structure) {
+        applied_fixes = structural_phase(
+            read_contract,
+            &options,
+            &store,
+            &run_config,
             jules_path,
-            root: &root,
-            issue_labels: &issue_labels,
-            event_states: &event_states,
-            event_confidence: &event_confidence,
-            issue_priorities: &issue_priorities,
-        },
-        &mut diagnostics,
-    );
+            &event_states,
+            &mut diagnostics,
+        );
+    }
 
-    naming::naming_checks(jules_path, &event_states, &mut diagnostics);
+    if selected.contains(&Category::Schema) {
+        diagnostics.set_category(Category::Schema);
+        schemas::schema_checks(
+            schemas::SchemaInputs {
+                jules_path,
+                root: &root,
+                issue_labels: &issue_labels,
+                event_states: &event_states,
+                event_confidence: &event_confidence,
+                issue_priorities: &issue_priorities,
+                jobs,
+            },
+            &mut diagnostics,
+        );
+    }
 
-    let semantic_context = semantic::semantic_context(jules_path, &mut diagnostics);
-    semantic::semantic_checks(jules_path, &semantic_context, &mut diagnostics);
+    if selected.contains(&Category::Naming) {
+        diagnostics.set_category(Category::Naming);
+        naming::naming_checks(jules_path, &event_states, &mut diagnostics);
+    }
 
-    quality::quality_checks(jules_path, &event_states, &mut diagnostics);
+    if selected.contains(&Category::Semantic) {
+        diagnostics.set_category(Category::Semantic);
+        let semantic_context = semantic::semantic_context(jules_path, &mut diagnostics);
+        semantic::semantic_checks(jules_path, &store, &semantic_context, &mut diagnostics);
+    }
 
-    diagnostics.emit();
+    if selected.contains(&Category::Quality) {
+        diagnostics.set_category(Category::Quality);
+        quality::quality_checks(jules_path, &event_states, &mut diagnostics);
+        applied_fixes.extend(quality::config_section_order_checks(
+            &config_path,
+            options.config.is_none(),
+            options.fix,
+            &mut diagnostics,
+        ));
+        // Opt-in: tab indentation is only checked under --strict or repaired under --fix.
+        if options.strict || options.fix {
+            applied_fixes.extend(quality::role_indentation_checks(
+                &root,
+                options.fix,
+                &mut diagnostics,
+            ));
+        }
+    }
+
+    let silenced = diagnostics.apply_allow(&allow_rules);
+
+    if let Some(path) = &options.summary_json {
+        summary::write_summary(path, &diagnostics)?;
+    }
 
     let errors = diagnostics.error_count();
     let warnings = diagnostics.warning_count();
@@ -79,13 +285,97 @@ pub fn execute(jules_path: &Path, options: DoctorOptions) -> Result<DoctorOutcom
         0
     };
 
-    if errors == 0 && warnings == 0 {
-        println!("All checks passed.");
-    } else if errors == 0 && !options.strict {
-        eprintln!("Check completed with {} warning(s).", warnings);
-    } else {
-        eprintln!("Check failed: {} error(s), {} warning(s) found.", errors, warnings);
+    if options.format == DoctorOutputFormat::Text {
+        diagnostics.emit(options.color.enabled_for_stderr());
+        for fix in &applied_fixes {
+            println!("Fixed: {}", fix);
+        }
+        if silenced > 0 {
+            eprintln!("{} diagnostic(s) silenced by --allow.", silenced);
+        }
+
+        if errors == 0 && warnings == 0 {
+            println!("All checks passed.");
+        } else if errors == 0 && !options.strict {
+            eprintln!("Check completed with {} warning(s).", warnings);
+        } else {
+            eprintln!("Check failed: {} error(s), {} warning(s) found.", errors, warnings);
+        }
+    }
+
+    let diagnostics = diagnostics.errors().iter().chain(diagnostics.warnings()).cloned().collect();
+    let outcome = DoctorOutcome { errors, warnings, exit_code, applied_fixes, diagnostics };
+    match options.format {
+        DoctorOutputFormat::Text => {}
+        DoctorOutputFormat::Json => report::print_report(&outcome)?,
+        DoctorOutputFormat::Sarif => sarif::print_sarif(&outcome.diagnostics, &root)?,
     }
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    Ok(DoctorOutcome { errors, warnings, exit_code })
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn selection_defaults_to_every_category() {
+        assert_eq!(selected_categories(&[], &[]).unwrap(), Category::ALL);
+    }
+
+    #[test]
+    fn selection_applies_only_and_skip_in_execution_order() {
+        assert_eq!(
+            selected_categories(&names(&["quality", "schema"]), &[]).unwrap(),
+            [Category::Schema, Category::Quality]
+        );
+        assert_eq!(
+            selected_categories(&[], &names(&["naming", "quality"])).unwrap(),
+            [Category::Structure, Category::Schema, Category::Semantic]
+        );
+    }
+
+    #[test]
+    fn selection_rejects_combined_flags_and_unknown_categories() {
+        let err = selected_categories(&names(&["schema"]), &names(&["naming"])).unwrap_err();
+        assert!(matches!(err, AppError::Validation(ref msg) if msg.contains("cannot be combined")));
+
+        let err = selected_categories(&[], &names(&["lint"])).unwrap_err();
+        assert!(matches!(err, AppError::Validation(ref msg) if msg.contains("'lint'")));
+    }
+
+    #[test]
+    fn skipping_schema_still_reports_broken_layer_contracts() {
+        let temp = tempfile::tempdir().unwrap();
+        let jules_path = temp.path().join(".jules");
+        std::fs::create_dir_all(&jules_path).unwrap();
+        let options = DoctorOptions {
+            skip: names(&["schema"]),
+            jobs: NonZeroUsize::new(1),
+            ..Default::default()
+        };
+
+        let outcome = execute_with_contracts(&jules_path, options, |path| match path {
+            "decider/contracts.yml" => {
+                Some("layer: planner\nbranch_prefix: jules-decider-\n".into())
+            }
+            _ => read_prompt_assemble_asset(path),
+        })
+        .unwrap();
+
+        let contract_errors: Vec<&Diagnostic> = outcome
+            .diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.code == codes::STRUCT_LAYER_CONTRACT)
+            .collect();
+        assert_eq!(contract_errors.len(), 1, "{:?}", outcome.diagnostics);
+        assert_eq!(contract_errors[0].file, "prompt-assemble://decider/contracts.yml");
+        assert_eq!(contract_errors[0].category, Category::Structure);
+        assert!(
+            outcome.diagnostics.iter().all(|diagnostic| diagnostic.category != Category::Schema)
+        );
+    }
 }
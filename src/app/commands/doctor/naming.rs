@@ -1,7 +1,10 @@
 use std::path::Path;
 
-use super::diagnostics::Diagnostics;
-use super::yaml::is_kebab_case;
+use crate::app::validation::diagnostics::{Diagnostics, codes};
+use crate::app::validation::yaml::is_kebab_case;
+
+/// Short description of this check family, used for SARIF rule metadata.
+pub const RULE_DESCRIPTION: &str = "Exchange and proposal files follow the naming conventions.";
 
 pub fn naming_checks(jules_path: &Path, event_states: &[String], diagnostics: &mut Diagnostics) {
     for state in event_states {
@@ -34,21 +37,34 @@ fn validate_proposal_filename(path: &Path, diagnostics: &mut Diagnostics) {
     }
 
     if path.extension().and_then(|ext| ext.to_str()) != Some("yml") {
-        diagnostics.push_error(path.display().to_string(), "proposal file must be .yml");
+        diagnostics.push_error(
+            codes::NAMING_EXTENSION,
+            path.display().to_string(),
+            "proposal file must be .yml",
+        );
         return;
     }
 
     let Some(stem) = path.file_stem().and_then(|s| s.to_str()) else {
-        diagnostics.push_error(path.display().to_string(), "proposal filename is invalid");
+        diagnostics.push_error(
+            codes::NAMING_PROPOSAL_FORMAT,
+            path.display().to_string(),
+            "proposal filename is invalid",
+        );
         return;
     };
     if stem.is_empty() {
-        diagnostics.push_error(path.display().to_string(), "proposal filename must not be empty");
+        diagnostics.push_error(
+            codes::NAMING_PROPOSAL_FORMAT,
+            path.display().to_string(),
+            "proposal filename must not be empty",
+        );
         return;
     }
 
     if !stem.contains('-') {
         diagnostics.push_error(
+            codes::NAMING_PROPOSAL_FORMAT,
             path.display().to_string(),
             "proposal filename must include '<role>-<slug>'",
         );
@@ -57,6 +73,7 @@ fn validate_proposal_filename(path: &Path, diagnostics: &mut Diagnostics) {
         let parts: Vec<&str> = stem.splitn(2, '-').collect();
         if parts.len() != 2 || parts[0].is_empty() || parts[1].is_empty() {
             diagnostics.push_error(
+                codes::NAMING_PROPOSAL_FORMAT,
                 path.display().to_string(),
                 "proposal filename must be in the format '<role>-<slug>'",
             );
@@ -65,6 +82,7 @@ fn validate_proposal_filename(path: &Path, diagnostics: &mut Diagnostics) {
 
     if !stem.chars().all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '-') {
         diagnostics.push_error(
+            codes::NAMING_KEBAB_CASE,
             path.display().to_string(),
             "proposal filename must use kebab-case (lowercase ASCII, digits, or '-')",
         );
@@ -77,13 +95,18 @@ fn validate_filename(path: &Path, diagnostics: &mut Diagnostics, kind: &str) {
     }
 
     if path.extension().and_then(|ext| ext.to_str()) != Some("yml") {
-        diagnostics.push_error(path.display().to_string(), format!("{} file must be .yml", kind));
+        diagnostics.push_error(
+            codes::NAMING_EXTENSION,
+            path.display().to_string(),
+            format!("{} file must be .yml", kind),
+        );
         return;
     }
 
     let file_stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
     if !is_kebab_case(file_stem) {
         diagnostics.push_error(
+            codes::NAMING_KEBAB_CASE,
             path.display().to_string(),
             format!("{} filename must be kebab-case", kind),
         );
@@ -104,6 +127,7 @@ fn list_files(dir: &Path, diagnostics: &mut Diagnostics) -> Vec<std::path::PathB
                     }
                     Err(err) => {
                         diagnostics.push_error(
+                            codes::NAMING_UNREADABLE_DIR,
                             dir.display().to_string(),
                             format!("Failed to read directory entry: {}", err),
                         );
@@ -113,6 +137,7 @@ fn list_files(dir: &Path, diagnostics: &mut Diagnostics) -> Vec<std::path::PathB
         }
         Err(err) => {
             diagnostics.push_error(
+                codes::NAMING_UNREADABLE_DIR,
                 dir.display().to_string(),
                 format!("Failed to read directory: {}", err),
             );
@@ -125,7 +150,7 @@ fn list_files(dir: &Path, diagnostics: &mut Diagnostics) -> Vec<std::path::PathB
 mod tests {
     use std::path::PathBuf;
 
-    use crate::app::commands::doctor::diagnostics::Diagnostics;
+    use crate::app::validation::diagnostics::Diagnostics;
 
     use super::*;
 
@@ -1,13 +1,18 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use super::diagnostics::Diagnostics;
 use super::yaml::{read_yaml_files, read_yaml_string, read_yaml_strings};
+use crate::app::validation::diagnostics::{Diagnostics, codes};
+
+/// Short description of this check family, used for SARIF rule metadata.
+pub const RULE_DESCRIPTION: &str = "Content is substantive and files are consistently formatted.";
 
 const MIN_STATEMENT_LEN: usize = 20;
 const MIN_PROBLEM_LEN: usize = 20;
 const MIN_IMPACT_LEN: usize = 20;
 const MIN_DESIRED_OUTCOME_LEN: usize = 20;
 const MIN_ACCEPTANCE_CRITERIA_LEN: usize = 8;
+/// Spaces substituted for each leading tab when fixing role.yml indentation.
+const TAB_WIDTH: usize = 2;
 
 pub fn quality_checks(jules_path: &Path, event_states: &[String], diagnostics: &mut Diagnostics) {
     let events_dir = crate::domain::exchange::events::paths::events_dir(jules_path);
@@ -16,8 +21,11 @@ pub fn quality_checks(jules_path: &Path, event_states: &[String], diagnostics: &
             if let Some(statement) = read_yaml_string(&entry, "statement", diagnostics)
                 && statement.trim().len() < MIN_STATEMENT_LEN
             {
-                diagnostics
-                    .push_warning(entry.display().to_string(), "statement appears too short");
+                diagnostics.push_warning(
+                    codes::QUALITY_SHORT_CONTENT,
+                    entry.display().to_string(),
+                    "statement appears too short",
+                );
             }
         }
     }
@@ -28,24 +36,36 @@ pub fn quality_checks(jules_path: &Path, event_states: &[String], diagnostics: &
         if let Some(problem) = read_yaml_string(&entry, "problem", diagnostics)
             && problem.trim().len() < MIN_PROBLEM_LEN
         {
-            diagnostics.push_warning(entry.display().to_string(), "problem appears too short");
+            diagnostics.push_warning(
+                codes::QUALITY_SHORT_CONTENT,
+                entry.display().to_string(),
+                "problem appears too short",
+            );
         }
         if let Some(impact) = read_yaml_string(&entry, "impact", diagnostics)
             && impact.trim().len() < MIN_IMPACT_LEN
         {
-            diagnostics.push_warning(entry.display().to_string(), "impact appears too short");
+            diagnostics.push_warning(
+                codes::QUALITY_SHORT_CONTENT,
+                entry.display().to_string(),
+                "impact appears too short",
+            );
         }
         if let Some(desired) = read_yaml_string(&entry, "desired_outcome", diagnostics)
             && desired.trim().len() < MIN_DESIRED_OUTCOME_LEN
         {
-            diagnostics
-                .push_warning(entry.display().to_string(), "desired_outcome appears too short");
+            diagnostics.push_warning(
+                codes::QUALITY_SHORT_CONTENT,
+                entry.display().to_string(),
+                "desired_outcome appears too short",
+            );
         }
 
         if let Some(criteria) = read_yaml_strings(&entry, "acceptance_criteria", diagnostics) {
             for item in criteria {
                 if item.trim().len() < MIN_ACCEPTANCE_CRITERIA_LEN {
                     diagnostics.push_warning(
+                        codes::QUALITY_SHORT_CONTENT,
                         entry.display().to_string(),
                         "acceptance_criteria entry appears too short",
                     );
@@ -63,6 +83,7 @@ pub fn quality_checks(jules_path: &Path, event_states: &[String], diagnostics: &
                     || lowered.contains("tbd")
                 {
                     diagnostics.push_warning(
+                        codes::QUALITY_VERIFICATION_CRITERIA,
                         entry.display().to_string(),
                         "verification_criteria entry looks incomplete",
                     );
@@ -72,3 +93,220 @@ pub fn quality_checks(jules_path: &Path, event_states: &[String], diagnostics: &
         }
     }
 }
+
+/// Flag `.jlo/roles/**/role.yml` lines whose indentation contains a tab.
+///
+/// With `fix`, leading tabs are rewritten as spaces and the fixed file is
+/// returned instead of emitting a warning.
+pub fn role_indentation_checks(
+    root: &Path,
+    fix: bool,
+    diagnostics: &mut Diagnostics,
+) -> Vec<String> {
+    let mut applied_fixes = Vec::new();
+
+    for path in role_yml_files(root) {
+        let Ok(content) = std::fs::read_to_string(&path) else { continue };
+        let tab_lines: Vec<usize> = content
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| leading_whitespace(line).contains('\t'))
+            .map(|(index, _)| index + 1)
+            .collect();
+        if tab_lines.is_empty() {
+            continue;
+        }
+
+        if fix {
+            match std::fs::write(&path, expand_leading_tabs(&content)) {
+                Ok(()) => applied_fixes
+                    .push(format!("{}: converted leading tabs to spaces", path.display())),
+                Err(err) => diagnostics.push_error(
+                    codes::QUALITY_TAB_INDENTATION,
+                    path.display().to_string(),
+                    format!("failed to rewrite indentation: {}", err),
+                ),
+            }
+            continue;
+        }
+
+        for line in tab_lines {
+            diagnostics.push_warning(
+                codes::QUALITY_TAB_INDENTATION,
+                path.display().to_string(),
+                format!("line {}: indentation contains a tab character", line),
+            );
+        }
+    }
+
+    applied_fixes
+}
+
+/// Warn about control-plane config sections out of canonical order, or reorder them when `fix`.
+///
+/// A `--config` file is checked but never rewritten: pass `fixable = false` for it.
+pub fn config_section_order_checks(
+    path: &Path,
+    fixable: bool,
+    fix: bool,
+    diagnostics: &mut Diagnostics,
+) -> Vec<String> {
+    let Ok(content) = std::fs::read_to_string(path) else { return Vec::new() };
+    // Unparseable configs are already reported by the control-plane-config rule.
+    let Ok(Some(normalized)) = crate::app::commands::role::normalize_config_section_order(&content)
+    else {
+        return Vec::new();
+    };
+
+    if !fixable {
+        diagnostics.push_warning(
+            codes::QUALITY_SECTION_ORDER,
+            path.display().to_string(),
+            "top-level sections are not in canonical order (reorder them by hand; `jlo doctor --fix` does not rewrite a --config file)",
+        );
+        return Vec::new();
+    }
+    if !fix {
+        diagnostics.push_warning(
+            codes::QUALITY_SECTION_ORDER,
+            path.display().to_string(),
+            "top-level sections are not in canonical order (run `jlo doctor --fix`)",
+        );
+        return Vec::new();
+    }
+    match std::fs::write(path, normalized) {
+        Ok(()) => vec![format!("{}: reordered top-level sections", path.display())],
+        Err(err) => {
+            diagnostics.push_error(
+                codes::QUALITY_SECTION_ORDER,
+                path.display().to_string(),
+                format!("failed to reorder sections: {}", err),
+            );
+            Vec::new()
+        }
+    }
+}
+
+fn role_yml_files(root: &Path) -> Vec<PathBuf> {
+    let roles_dir = crate::domain::roles::paths::roles_dir(root);
+    let mut files = Vec::new();
+    for layer_dir in sorted_subdirectories(&roles_dir) {
+        for role_dir in sorted_subdirectories(&layer_dir) {
+            let path = role_dir.join(crate::domain::roles::paths::ROLE_FILENAME);
+            if path.is_file() {
+                files.push(path);
+            }
+        }
+    }
+    files
+}
+
+fn sorted_subdirectories(dir: &Path) -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(dir) else { return Vec::new() };
+    let mut dirs: Vec<PathBuf> =
+        entries.filter_map(|entry| entry.ok().map(|e| e.path())).filter(|p| p.is_dir()).collect();
+    dirs.sort();
+    dirs
+}
+
+fn leading_whitespace(line: &str) -> &str {
+    let end = line.find(|c: char| c != ' ' && c != '\t').unwrap_or(line.len());
+    &line[..end]
+}
+
+fn expand_leading_tabs(content: &str) -> String {
+    content
+        .split_inclusive('\n')
+        .map(|line| {
+            let indent = leading_whitespace(line);
+            if !indent.contains('\t') {
+                return line.to_string();
+            }
+            let expanded = indent.replace('\t', &" ".repeat(TAB_WIDTH));
+            format!("{}{}", expanded, &line[indent.len()..])
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expand_leading_tabs_only_touches_indentation() {
+        let content = "role: qa\nprofile:\n\tfocus: \"a\\tb\"\n \tdepth: 1\n";
+        assert_eq!(
+            expand_leading_tabs(content),
+            "role: qa\nprofile:\n  focus: \"a\\tb\"\n   depth: 1\n"
+        );
+    }
+
+    #[test]
+    fn role_indentation_checks_warns_with_line_numbers_and_fixes() {
+        let dir = tempfile::tempdir().unwrap();
+        let role_dir = dir.path().join(".jlo/roles/observers/qa");
+        std::fs::create_dir_all(&role_dir).unwrap();
+        let role_yml = role_dir.join("role.yml");
+        std::fs::write(&role_yml, "role: qa\nprofile:\n\tfocus: tests\n").unwrap();
+
+        let mut diagnostics = Diagnostics::default();
+        let fixes = role_indentation_checks(dir.path(), false, &mut diagnostics);
+        assert!(fixes.is_empty());
+        assert_eq!(diagnostics.warning_count(), 1);
+        assert!(diagnostics.warnings()[0].message.contains("line 3"));
+
+        let mut diagnostics = Diagnostics::default();
+        let fixes = role_indentation_checks(dir.path(), true, &mut diagnostics);
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(diagnostics.warning_count(), 0);
+        assert_eq!(
+            std::fs::read_to_string(&role_yml).unwrap(),
+            "role: qa\nprofile:\n  focus: tests\n"
+        );
+    }
+
+    #[test]
+    fn config_section_order_checks_warns_and_fixes_scrambled_sections() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join(".jlo/config.toml");
+        std::fs::create_dir_all(config.parent().unwrap()).unwrap();
+        std::fs::write(
+            &config,
+            "[observers]\nroles = []\n\n[run]\njlo_target_branch = \"main\"\n\n[workflow]\nrunner_mode = \"remote\"\n",
+        )
+        .unwrap();
+
+        let mut diagnostics = Diagnostics::default();
+        let fixes = config_section_order_checks(&config, true, false, &mut diagnostics);
+        assert!(fixes.is_empty());
+        assert_eq!(diagnostics.warning_count(), 1);
+        assert!(diagnostics.warnings()[0].message.contains("canonical order"));
+
+        let mut diagnostics = Diagnostics::default();
+        let fixes = config_section_order_checks(&config, true, true, &mut diagnostics);
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(diagnostics.warning_count(), 0);
+        let fixed = std::fs::read_to_string(&config).unwrap();
+        let headers: Vec<&str> = fixed.lines().filter(|line| line.starts_with('[')).collect();
+        assert_eq!(headers, vec!["[run]", "[workflow]", "[observers]"]);
+
+        let mut diagnostics = Diagnostics::default();
+        assert!(config_section_order_checks(&config, true, false, &mut diagnostics).is_empty());
+        assert_eq!(diagnostics.warning_count(), 0);
+    }
+
+    #[test]
+    fn config_section_order_checks_never_rewrites_a_non_fixable_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("alt.toml");
+        let scrambled = "[observers]\nroles = []\n\n[run]\njlo_target_branch = \"main\"\n";
+        std::fs::write(&config, scrambled).unwrap();
+
+        let mut diagnostics = Diagnostics::default();
+        let fixes = config_section_order_checks(&config, false, true, &mut diagnostics);
+        assert!(fixes.is_empty());
+        assert_eq!(diagnostics.warning_count(), 1);
+        assert!(diagnostics.warnings()[0].message.contains("does not rewrite a --config file"));
+        assert_eq!(std::fs::read_to_string(&config).unwrap(), scrambled);
+    }
+}
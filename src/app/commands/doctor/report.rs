@@ -0,0 +1,33 @@
+use serde::Serialize;
+
+use super::DoctorOutcome;
+use crate::app::validation::diagnostics::Diagnostic;
+use crate::domain::AppError;
+
+/// Stable JSON report printed by `jlo doctor --format json`.
+#[derive(Debug, Serialize)]
+struct DoctorReport<'a> {
+    schema_version: u32,
+    errors: usize,
+    warnings: usize,
+    exit_code: i32,
+    diagnostics: &'a [Diagnostic],
+    applied_fixes: &'a [String],
+}
+
+pub fn print_report(outcome: &DoctorOutcome) -> Result<(), AppError> {
+    let report = DoctorReport {
+        schema_version: 1,
+        errors: outcome.errors,
+        warnings: outcome.warnings,
+        exit_code: outcome.exit_code,
+        diagnostics: &outcome.diagnostics,
+        applied_fixes: &outcome.applied_fixes,
+    };
+
+    let json = serde_json::to_string_pretty(&report).map_err(|e| {
+        AppError::InternalError(format!("Failed to serialize doctor report: {}", e))
+    })?;
+    println!("{}", json);
+    Ok(())
+}
@@ -0,0 +1,239 @@
+//! Static registry of the checks doctor performs.
+//!
+//! Each rule id names one family of diagnostics and lists the `JLO-*` codes it
+//! emits (the values `--allow` accepts); the registry is listed by
+//! `jlo doctor --list-rules` without running any checks.
+
+use serde::Serialize;
+
+use crate::app::validation::diagnostics::codes::*;
+use crate::app::validation::diagnostics::{Category, Severity};
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Rule {
+    pub id: &'static str,
+    pub category: Category,
+    pub severity: Severity,
+    pub summary: &'static str,
+    /// Diagnostic codes this rule emits; a code may be shared by several rules.
+    pub codes: &'static [&'static str],
+}
+
+const fn rule(
+    id: &'static str,
+    category: Category,
+    severity: Severity,
+    summary: &'static str,
+    codes: &'static [&'static str],
+) -> Rule {
+    Rule { id, category, severity, summary, codes }
+}
+
+use Category::{Naming, Quality, Schema, Semantic, Structure};
+use Severity::{Error, Warning};
+
+pub const RULES: &[Rule] = &[
+    rule(
+        "control-plane-config",
+        Structure,
+        Error,
+        ".jlo/config.toml exists and parses",
+        &[STRUCT_CONFIG_MISSING, STRUCT_CONFIG_INVALID],
+    ),
+    rule(
+        "required-layout",
+        Structure,
+        Error,
+        "required .jules directories and files exist",
+        &[STRUCT_MISSING_DIR, STRUCT_MISSING_FILE, STRUCT_UNREADABLE_DIR],
+    ),
+    rule(
+        "version-pin",
+        Structure,
+        Error,
+        ".jules version is valid and not newer than jlo",
+        &[STRUCT_VERSION_INVALID, STRUCT_VERSION_NEWER],
+    ),
+    rule(
+        "stale-workspace",
+        Structure,
+        Warning,
+        "no temporary workspaces left behind",
+        &[STRUCT_STALE_WORKSPACE],
+    ),
+    rule(
+        "api-health",
+        Structure,
+        Error,
+        "Jules API accepts the credentials (--check-api)",
+        &[STRUCT_API_HEALTH],
+    ),
+    rule(
+        "workflow-drift",
+        Structure,
+        Warning,
+        "workflow files match the embedded scaffold (--check-workflows)",
+        &[STRUCT_WORKFLOW_DRIFT],
+    ),
+    rule(
+        "control-branch-artifacts",
+        Structure,
+        Warning,
+        "runtime exchange artifacts are not on the control branch",
+        &[STRUCT_CONTROL_BRANCH_ARTIFACTS],
+    ),
+    rule(
+        "layer-contracts",
+        Structure,
+        Error,
+        "each layer's embedded contracts.yml declares its layer and branch prefix",
+        &[STRUCT_LAYER_CONTRACT],
+    ),
+    rule(
+        "yaml-mapping",
+        Schema,
+        Error,
+        "YAML documents parse with a mapping root",
+        &[SCHEMA_YAML_INVALID, SCHEMA_UNREADABLE],
+    ),
+    rule(
+        "role-schema",
+        Schema,
+        Error,
+        "role.yml declares matching layer, role, and profile",
+        &[SCHEMA_REQUIRED_FIELD, SCHEMA_INVALID_VALUE, SCHEMA_MISMATCH],
+    ),
+    rule(
+        "event-schema",
+        Schema,
+        Error,
+        "observer events carry required fields and enums",
+        &[SCHEMA_REQUIRED_FIELD, SCHEMA_INVALID_VALUE, SCHEMA_INVALID_ENUM, SCHEMA_EVENT_STATE],
+    ),
+    rule(
+        "requirement-schema",
+        Schema,
+        Error,
+        "requirements carry required fields and labels",
+        &[SCHEMA_REQUIRED_FIELD, SCHEMA_INVALID_VALUE, SCHEMA_INVALID_ENUM],
+    ),
+    rule(
+        "proposal-schema",
+        Schema,
+        Error,
+        "innovator proposals carry required fields",
+        &[SCHEMA_REQUIRED_FIELD, SCHEMA_MISMATCH],
+    ),
+    rule(
+        "changes-schema",
+        Schema,
+        Error,
+        "narrator changes.yml carries required fields",
+        &[SCHEMA_REQUIRED_FIELD, SCHEMA_INVALID_VALUE],
+    ),
+    rule(
+        "placeholders",
+        Schema,
+        Error,
+        "template placeholders have been replaced",
+        &[SCHEMA_PLACEHOLDER, SCHEMA_UNREADABLE],
+    ),
+    rule("date-format", Schema, Error, "date fields use YYYY-MM-DD", &[SCHEMA_DATE_FORMAT]),
+    rule(
+        "yaml-filename",
+        Naming,
+        Error,
+        "exchange files use kebab-case .yml names",
+        &[NAMING_EXTENSION, NAMING_KEBAB_CASE, NAMING_UNREADABLE_DIR],
+    ),
+    rule(
+        "proposal-filename",
+        Naming,
+        Error,
+        "proposal files are named <role>-<slug>.yml",
+        &[NAMING_PROPOSAL_FORMAT],
+    ),
+    rule(
+        "schedule",
+        Semantic,
+        Error,
+        "scheduled roles exist and the schedule is valid",
+        &[SEMANTIC_SCHEDULE],
+    ),
+    rule(
+        "event-requirement-links",
+        Semantic,
+        Error,
+        "events and requirements cross-reference",
+        &[
+            SEMANTIC_MISSING_REQUIREMENT,
+            SEMANTIC_MISSING_EVENT,
+            SEMANTIC_EVENT_OWNERSHIP,
+            SEMANTIC_UNREADABLE,
+        ],
+    ),
+    rule(
+        "stale-planner-request",
+        Semantic,
+        Warning,
+        "requirements do not await planning long",
+        &[SEMANTIC_PLANNER_REASON, SEMANTIC_STALE_PLANNER_REQUEST],
+    ),
+    rule(
+        "pipeline-upstream",
+        Semantic,
+        Warning,
+        "at least one observer feeds downstream layers",
+        &[SEMANTIC_NO_OBSERVERS],
+    ),
+    rule(
+        "content-length",
+        Quality,
+        Warning,
+        "free-text fields are not suspiciously short",
+        &[QUALITY_SHORT_CONTENT],
+    ),
+    rule(
+        "verification-criteria",
+        Quality,
+        Warning,
+        "verification criteria look complete",
+        &[QUALITY_VERIFICATION_CRITERIA],
+    ),
+    rule(
+        "config-section-order",
+        Quality,
+        Warning,
+        ".jlo/config.toml sections are in canonical order",
+        &[QUALITY_SECTION_ORDER],
+    ),
+    rule(
+        "tab-indentation",
+        Quality,
+        Warning,
+        "role.yml uses spaces for indentation (--strict)",
+        &[QUALITY_TAB_INDENTATION],
+    ),
+];
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn rule_ids_are_unique() {
+        let ids: HashSet<&str> = RULES.iter().map(|rule| rule.id).collect();
+        assert_eq!(ids.len(), RULES.len());
+    }
+
+    #[test]
+    fn every_code_belongs_to_a_rule() {
+        let listed: HashSet<&str> = RULES.iter().flat_map(|rule| rule.codes).copied().collect();
+        for code in ALL {
+            assert!(listed.contains(code), "{} is not listed under any rule", code);
+        }
+        assert!(listed.iter().all(|code| ALL.contains(code)));
+    }
+}
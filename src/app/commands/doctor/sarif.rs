@@ -0,0 +1,139 @@
+//! SARIF 2.1.0 rendering of doctor diagnostics for GitHub code scanning.
+//!
+//! Each check category is one SARIF rule; results point at the offending file
+//! relative to the repository root.
+
+use std::path::Path;
+
+use serde_json::{Value, json};
+
+use super::{naming, quality, schemas, semantic, structure};
+use crate::app::validation::diagnostics::{Category, Diagnostic};
+use crate::domain::AppError;
+
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+fn rule_description(category: Category) -> &'static str {
+    match category {
+        Category::Structure => structure::RULE_DESCRIPTION,
+        Category::Schema => schemas::RULE_DESCRIPTION,
+        Category::Naming => naming::RULE_DESCRIPTION,
+        Category::Semantic => semantic::RULE_DESCRIPTION,
+        Category::Quality => quality::RULE_DESCRIPTION,
+    }
+}
+
+/// Build a SARIF log with one run holding every diagnostic.
+pub fn sarif_log(diagnostics: &[Diagnostic], root: &Path) -> Value {
+    let rules: Vec<Value> = Category::ALL
+        .iter()
+        .map(|category| {
+            json!({
+                "id": category.as_str(),
+                "shortDescription": { "text": rule_description(*category) },
+            })
+        })
+        .collect();
+    let results: Vec<Value> =
+        diagnostics.iter().map(|diagnostic| result(diagnostic, root)).collect();
+
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": "jlo",
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "rules": rules,
+                }
+            },
+            "results": results,
+        }]
+    })
+}
+
+pub fn print_sarif(diagnostics: &[Diagnostic], root: &Path) -> Result<(), AppError> {
+    let json = serde_json::to_string_pretty(&sarif_log(diagnostics, root))
+        .map_err(|e| AppError::InternalError(format!("Failed to serialize SARIF log: {}", e)))?;
+    println!("{}", json);
+    Ok(())
+}
+
+fn result(diagnostic: &Diagnostic, root: &Path) -> Value {
+    let mut result = json!({
+        "ruleId": diagnostic.category.as_str(),
+        "ruleIndex": Category::ALL.iter().position(|category| *category == diagnostic.category),
+        "level": diagnostic.severity.as_str(),
+        "message": { "text": diagnostic.message },
+        "properties": { "code": diagnostic.code },
+    });
+    // Non-file subjects (e.g. the Jules API URL) have no physical location.
+    if !diagnostic.file.is_empty() && !diagnostic.file.contains("://") {
+        let path = Path::new(&diagnostic.file);
+        let uri = path.strip_prefix(root).unwrap_or(path).to_string_lossy().replace('\\', "/");
+        result["locations"] = json!([{
+            "physicalLocation": {
+                "artifactLocation": { "uri": uri, "uriBaseId": "%SRCROOT%" }
+            }
+        }]);
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::app::commands::doctor::Severity;
+    use crate::app::validation::diagnostics::codes;
+
+    fn diagnostic(file: &str, severity: Severity, category: Category) -> Diagnostic {
+        Diagnostic {
+            code: codes::SCHEMA_INVALID_VALUE,
+            file: file.to_string(),
+            message: "bad".to_string(),
+            severity,
+            category,
+        }
+    }
+
+    #[test]
+    fn results_map_category_severity_and_relative_location() {
+        let root = Path::new("/repo");
+        let log = sarif_log(
+            &[diagnostic(
+                "/repo/.jules/exchange/events/pending/a.yml",
+                Severity::Error,
+                Category::Schema,
+            )],
+            root,
+        );
+
+        let result = &log["runs"][0]["results"][0];
+        assert_eq!(log["version"], "2.1.0");
+        assert_eq!(result["ruleId"], "schema");
+        assert_eq!(result["ruleIndex"], 1);
+        assert_eq!(result["level"], "error");
+        assert_eq!(result["properties"]["code"], "JLO-SCHEMA-003");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            ".jules/exchange/events/pending/a.yml"
+        );
+    }
+
+    #[test]
+    fn driver_registers_every_category_and_skips_non_file_locations() {
+        let log = sarif_log(
+            &[diagnostic("https://example.test/v1", Severity::Warning, Category::Structure)],
+            Path::new("/repo"),
+        );
+
+        let rules = log["runs"][0]["tool"]["driver"]["rules"].as_array().unwrap();
+        let ids: Vec<&str> = rules.iter().map(|rule| rule["id"].as_str().unwrap()).collect();
+        assert_eq!(ids, ["structure", "schema", "naming", "semantic", "quality"]);
+        assert!(rules.iter().all(|rule| rule["shortDescription"]["text"].as_str().is_some()));
+        let result = &log["runs"][0]["results"][0];
+        assert_eq!(result["level"], "warning");
+        assert!(result.get("locations").is_none());
+    }
+}
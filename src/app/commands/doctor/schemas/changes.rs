@@ -1,11 +1,10 @@
 use serde_yaml::Mapping;
 use std::path::Path;
 
-use crate::app::commands::doctor::diagnostics::Diagnostics;
-use crate::app::commands::doctor::schemas::dates::ensure_date;
-use crate::app::commands::doctor::yaml::{
-    ensure_int, ensure_non_empty_sequence, load_yaml_mapping,
-};
+use crate::app::commands::doctor::yaml::load_yaml_mapping;
+use crate::app::validation::dates::ensure_date;
+use crate::app::validation::diagnostics::Diagnostics;
+use crate::app::validation::yaml::{ensure_int, ensure_non_empty_sequence};
 
 /// Validate .jules/exchange/changes.yml schema.
 pub fn validate_changes_file(path: &Path, diagnostics: &mut Diagnostics) {
@@ -29,7 +28,7 @@ pub fn validate_changes_data(data: &Mapping, path: &Path, diagnostics: &mut Diag
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::app::commands::doctor::diagnostics::Diagnostics;
+    use crate::app::validation::diagnostics::Diagnostics;
     use std::path::PathBuf;
 
     #[test]
@@ -1,26 +1,26 @@
 pub mod changes;
-pub mod contracts;
-mod dates;
-pub mod events;
 pub mod placeholders;
 pub mod proposals;
-pub mod requirements;
-pub mod roles;
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+use rayon::prelude::*;
 
-use crate::app::commands::doctor::diagnostics::Diagnostics;
 use crate::app::commands::doctor::structure::list_subdirs;
-use crate::app::commands::doctor::yaml::read_yaml_files;
+use crate::app::commands::doctor::yaml::{load_yaml_mapping, read_yaml_files};
+use crate::app::validation::diagnostics::Diagnostics;
+use crate::app::validation::events::validate_event;
+use crate::app::validation::requirements::validate_requirement;
+use crate::app::validation::roles::{validate_innovator_role, validate_role};
 use crate::domain::Layer;
 
 use self::changes::validate_changes_file;
-use self::contracts::validate_contracts;
-use self::events::validate_event_file;
 use self::placeholders::check_placeholders_file;
 use self::proposals::validate_innovator_proposal;
-use self::requirements::validate_requirement_file;
-use self::roles::{validate_innovator_role_file, validate_role_file};
+
+/// Short description of this check family, used for SARIF rule metadata.
+pub const RULE_DESCRIPTION: &str =
+    "YAML documents match the role, event, requirement, proposal, and changes schemas.";
 
 pub struct SchemaInputs<'a> {
     pub jules_path: &'a Path,
@@ -29,6 +29,44 @@ pub struct SchemaInputs<'a> {
     pub event_states: &'a [String],
     pub event_confidence: &'a [String],
     pub issue_priorities: &'a [String],
+    /// Worker threads for exchange file validation; 1 validates sequentially.
+    pub jobs: usize,
+}
+
+/// One exchange document; each is validated independently of the others.
+enum ExchangeFile {
+    Event { path: PathBuf, state: String },
+    Requirement(PathBuf),
+    Proposal(PathBuf),
+}
+
+impl ExchangeFile {
+    fn validate(&self, inputs: &SchemaInputs<'_>, diagnostics: &mut Diagnostics) {
+        match self {
+            Self::Event { path, state } => {
+                if let Some(data) = load_yaml_mapping(path, diagnostics) {
+                    validate_event(&data, path, state, inputs.event_confidence, diagnostics);
+                }
+                check_placeholders_file(path, diagnostics);
+            }
+            Self::Requirement(path) => {
+                if let Some(data) = load_yaml_mapping(path, diagnostics) {
+                    validate_requirement(
+                        &data,
+                        path,
+                        inputs.issue_labels,
+                        inputs.issue_priorities,
+                        diagnostics,
+                    );
+                }
+                check_placeholders_file(path, diagnostics);
+            }
+            Self::Proposal(path) => {
+                validate_innovator_proposal(path, diagnostics);
+                check_placeholders_file(path, diagnostics);
+            }
+        }
+    }
 }
 
 pub fn schema_checks(inputs: SchemaInputs<'_>, diagnostics: &mut Diagnostics) {
@@ -37,28 +75,17 @@ pub fn schema_checks(inputs: SchemaInputs<'_>, diagnostics: &mut Diagnostics) {
         validate_changes_file(&changes_path, diagnostics);
     }
 
-    // Validate embedded contracts for each layer
     for layer in Layer::ALL {
-        let catalog_path = format!("{}/contracts.yml", layer.dir_name());
-        if let Some(content) =
-            crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset(
-                &catalog_path,
-            )
-            && let Ok(data) = serde_yaml::from_str::<serde_yaml::Value>(&content)
-            && let Some(mapping) = data.as_mapping()
-        {
-            let label = format!("prompt-assemble://{}", catalog_path);
-            validate_contracts(mapping, Path::new(&label), layer, diagnostics);
-        }
-
         // Validate role definitions in .jlo/roles/ for multi-role layers
         if layer == Layer::Observers {
             let jlo_layer_dir = crate::domain::roles::paths::layer_dir(inputs.root, layer);
             if jlo_layer_dir.exists() {
                 for role_dir in list_subdirs(&jlo_layer_dir, diagnostics) {
                     let role_path = role_dir.join("role.yml");
-                    if role_path.exists() {
-                        validate_role_file(&role_path, &role_dir, diagnostics);
+                    if role_path.exists()
+                        && let Some(data) = load_yaml_mapping(&role_path, diagnostics)
+                    {
+                        validate_role(&data, &role_path, &role_dir, diagnostics);
                     }
                 }
             }
@@ -69,8 +96,10 @@ pub fn schema_checks(inputs: SchemaInputs<'_>, diagnostics: &mut Diagnostics) {
             if jlo_layer_dir.exists() {
                 for role_dir in list_subdirs(&jlo_layer_dir, diagnostics) {
                     let role_path = role_dir.join("role.yml");
-                    if role_path.exists() {
-                        validate_innovator_role_file(&role_path, &role_dir, diagnostics);
+                    if role_path.exists()
+                        && let Some(data) = load_yaml_mapping(&role_path, diagnostics)
+                    {
+                        validate_innovator_role(&data, &role_path, &role_dir, diagnostics);
                     }
                 }
             }
@@ -78,30 +107,55 @@ pub fn schema_checks(inputs: SchemaInputs<'_>, diagnostics: &mut Diagnostics) {
     }
 
     // Validate flat exchange directory
+    let exchange_files = collect_exchange_files(&inputs, diagnostics);
+    let parts = validate_exchange_files(&exchange_files, &inputs, diagnostics);
+    diagnostics.merge_sorted(parts);
+}
+
+fn collect_exchange_files(
+    inputs: &SchemaInputs<'_>,
+    diagnostics: &mut Diagnostics,
+) -> Vec<ExchangeFile> {
+    let mut files = Vec::new();
     for state in inputs.event_states {
         let state_dir =
             crate::domain::exchange::events::paths::events_state_dir(inputs.jules_path, state);
-        for entry in read_yaml_files(&state_dir, diagnostics) {
-            validate_event_file(&entry, state, inputs.event_confidence, diagnostics);
-            check_placeholders_file(&entry, diagnostics);
-        }
+        files.extend(
+            read_yaml_files(&state_dir, diagnostics)
+                .into_iter()
+                .map(|path| ExchangeFile::Event { path, state: state.clone() }),
+        );
     }
 
     let requirements_dir =
         crate::domain::exchange::requirements::paths::requirements_dir(inputs.jules_path);
-    for entry in read_yaml_files(&requirements_dir, diagnostics) {
-        validate_requirement_file(
-            &entry,
-            inputs.issue_labels,
-            inputs.issue_priorities,
-            diagnostics,
-        );
-        check_placeholders_file(&entry, diagnostics);
-    }
+    files.extend(
+        read_yaml_files(&requirements_dir, diagnostics).into_iter().map(ExchangeFile::Requirement),
+    );
 
     let proposals_dir = crate::domain::exchange::proposals::paths::proposals_dir(inputs.jules_path);
-    for proposal_path in read_yaml_files(&proposals_dir, diagnostics) {
-        validate_innovator_proposal(&proposal_path, diagnostics);
-        check_placeholders_file(&proposal_path, diagnostics);
+    files.extend(
+        read_yaml_files(&proposals_dir, diagnostics).into_iter().map(ExchangeFile::Proposal),
+    );
+    files
+}
+
+/// Validate each file into its own collector, on a rayon pool when `jobs > 1`.
+fn validate_exchange_files(
+    files: &[ExchangeFile],
+    inputs: &SchemaInputs<'_>,
+    diagnostics: &Diagnostics,
+) -> Vec<Diagnostics> {
+    let validate = |file: &ExchangeFile| {
+        let mut part = diagnostics.fork();
+        file.validate(inputs, &mut part);
+        part
+    };
+
+    if inputs.jobs > 1
+        && let Ok(pool) = rayon::ThreadPoolBuilder::new().num_threads(inputs.jobs).build()
+    {
+        return pool.install(|| files.par_iter().map(validate).collect());
     }
+    files.iter().map(validate).collect()
 }
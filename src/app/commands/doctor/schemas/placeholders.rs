@@ -1,7 +1,7 @@
 use std::fs;
 use std::path::Path;
 
-use crate::app::commands::doctor::diagnostics::Diagnostics;
+use crate::app::validation::diagnostics::{Diagnostics, codes};
 
 const DATE_PLACEHOLDER: &str = "YYYY-MM-DD";
 
@@ -9,8 +9,11 @@ pub fn check_placeholders_file(path: &Path, diagnostics: &mut Diagnostics) {
     let content = match fs::read_to_string(path) {
         Ok(content) => content,
         Err(err) => {
-            diagnostics
-                .push_error(path.display().to_string(), format!("Failed to read file: {}", err));
+            diagnostics.push_error(
+                codes::SCHEMA_UNREADABLE,
+                path.display().to_string(),
+                format!("Failed to read file: {}", err),
+            );
             return;
         }
     };
@@ -31,6 +34,7 @@ pub fn check_placeholders(content: &str, path: &Path, diagnostics: &mut Diagnost
     for placeholder in placeholders {
         if content.contains(placeholder) {
             diagnostics.push_error(
+                codes::SCHEMA_PLACEHOLDER,
                 path.display().to_string(),
                 format!("placeholder '{}' must be replaced", placeholder),
             );
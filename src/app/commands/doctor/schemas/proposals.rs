@@ -1,14 +1,13 @@
 use serde_yaml::Mapping;
 use std::path::Path;
 
-use crate::app::commands::doctor::diagnostics::Diagnostics;
-use crate::app::commands::doctor::yaml::{
+use crate::app::commands::doctor::yaml::load_yaml_mapping;
+use crate::app::validation::dates::ensure_date;
+use crate::app::validation::diagnostics::{Diagnostics, codes};
+use crate::app::validation::yaml::{
     ensure_id, ensure_int, ensure_non_empty_sequence, ensure_non_empty_string, get_string,
-    load_yaml_mapping,
 };
 
-use super::dates::ensure_date;
-
 pub fn validate_innovator_proposal(path: &Path, diagnostics: &mut Diagnostics) {
     if let Some(data) = load_yaml_mapping(path, diagnostics) {
         validate_innovator_document_common_fields(&data, path, diagnostics);
@@ -27,6 +26,7 @@ pub fn validate_innovator_proposal(path: &Path, diagnostics: &mut Diagnostics) {
                 crate::domain::exchange::proposals::paths::proposal_filename_role_segment(&role);
             if !stem.starts_with(&format!("{}-", expected_role_segment)) {
                 diagnostics.push_error(
+                    codes::SCHEMA_MISMATCH,
                     path.display().to_string(),
                     format!(
                         "proposal filename must start with normalized role '{}-'",
@@ -6,9 +6,14 @@ use chrono::{NaiveDate, Utc};
 use crate::app::config::load_schedule;
 use crate::domain::config::schedule::ScheduleLayer;
 use crate::domain::{AppError, Layer};
+use crate::ports::JloStore;
 
-use super::diagnostics::Diagnostics;
 use super::yaml::{read_yaml_bool, read_yaml_files, read_yaml_string, read_yaml_strings};
+use crate::app::validation::diagnostics::{Diagnostics, codes};
+
+/// Short description of this check family, used for SARIF rule metadata.
+pub const RULE_DESCRIPTION: &str =
+    "Schedules, events, and requirements are consistent with each other.";
 
 const STALE_IMPLEMENTATION_PENDING_THRESHOLD_DAYS: i64 = 7;
 
@@ -51,6 +56,7 @@ pub fn semantic_context(jules_path: &Path, diagnostics: &mut Diagnostics) -> Sem
 
 pub fn semantic_checks(
     jules_path: &Path,
+    store: &impl JloStore,
     context: &SemanticContext,
     diagnostics: &mut Diagnostics,
 ) {
@@ -61,6 +67,7 @@ pub fn semantic_checks(
             && let Some(path) = context.decided_events.get(event_id)
         {
             diagnostics.push_error(
+                codes::SEMANTIC_MISSING_REQUIREMENT,
                 path.display().to_string(),
                 format!("requirement_id '{}' does not exist", requirement_id),
             );
@@ -73,6 +80,7 @@ pub fn semantic_checks(
                 && let Some(path) = context.requirements.get(requirement_id)
             {
                 diagnostics.push_error(
+                    codes::SEMANTIC_MISSING_EVENT,
                     path.display().to_string(),
                     format!("source_events refers to missing event '{}'", source),
                 );
@@ -86,6 +94,7 @@ pub fn semantic_checks(
             for requirement_id in requirement_ids {
                 if let Some(path) = context.requirements.get(requirement_id) {
                     diagnostics.push_error(
+                        codes::SEMANTIC_EVENT_OWNERSHIP,
                         path.display().to_string(),
                         format!(
                             "event '{}' is referenced by multiple requirements in source_events: {}",
@@ -103,6 +112,7 @@ pub fn semantic_checks(
                 && let Some(path) = context.decided_events.get(event_id)
             {
                 diagnostics.push_error(
+                    codes::SEMANTIC_EVENT_OWNERSHIP,
                     path.display().to_string(),
                     format!(
                         "event '{}' requirement_id '{}' does not match requirement source owner(s): {}",
@@ -114,6 +124,7 @@ pub fn semantic_checks(
             }
         } else if let Some(path) = context.decided_events.get(event_id) {
             diagnostics.push_error(
+                codes::SEMANTIC_EVENT_OWNERSHIP,
                 path.display().to_string(),
                 format!(
                     "event '{}' has requirement_id '{}' but is not referenced by any requirement source_events",
@@ -130,6 +141,7 @@ pub fn semantic_checks(
                 && let Some(path) = context.requirements.get(requirement_id)
             {
                 diagnostics.push_error(
+                    codes::SEMANTIC_EVENT_OWNERSHIP,
                     path.display().to_string(),
                     format!(
                         "source event '{}' belongs to requirement '{}' via event.requirement_id, but was found in requirement '{}'",
@@ -149,6 +161,7 @@ pub fn semantic_checks(
         Some(p) => p,
         None => {
             diagnostics.push_error(
+                codes::SEMANTIC_UNREADABLE,
                 jules_path.display().to_string(),
                 "Could not determine parent directory of .jules path".to_string(),
             );
@@ -175,6 +188,7 @@ pub fn semantic_checks(
                 }
                 Err(err) => {
                     diagnostics.push_error(
+                        codes::SEMANTIC_UNREADABLE,
                         layer_dir.display().to_string(),
                         format!("Failed to read directory: {}", err),
                     );
@@ -184,9 +198,7 @@ pub fn semantic_checks(
         }
     }
 
-    let store = crate::adapters::local_repository::LocalRepositoryAdapter::new(root.to_path_buf());
-
-    match load_schedule(&store) {
+    match load_schedule(store) {
         Ok(schedule) => {
             validate_scheduled_layer(
                 Layer::Observers,
@@ -194,6 +206,7 @@ pub fn semantic_checks(
                 &existing_roles,
                 diagnostics,
             );
+            check_pipeline_upstream(&schedule.observers, diagnostics);
             if let Some(ref innovators) = schedule.innovators {
                 validate_scheduled_layer(
                     Layer::Innovators,
@@ -208,12 +221,17 @@ pub fn semantic_checks(
         }
         Err(AppError::Schedule(err)) => {
             diagnostics.push_error(
+                codes::SEMANTIC_SCHEDULE,
                 "config.toml".to_string(),
                 format!("Invalid schedule in .jlo/config.toml: {}", err),
             );
         }
         Err(err) => {
-            diagnostics.push_error("config.toml".to_string(), err.to_string());
+            diagnostics.push_error(
+                codes::SEMANTIC_SCHEDULE,
+                "config.toml".to_string(),
+                err.to_string(),
+            );
         }
     }
 
@@ -226,6 +244,7 @@ pub fn semantic_checks(
                 Some(reason) if !reason.trim().is_empty() => {}
                 _ => {
                     diagnostics.push_error(
+                        codes::SEMANTIC_PLANNER_REASON,
                         path.display().to_string(),
                         "implementation_ready false without planner_request_reason",
                     );
@@ -238,6 +257,7 @@ pub fn semantic_checks(
                 let days = (Utc::now().date_naive() - parsed).num_days();
                 if days > STALE_IMPLEMENTATION_PENDING_THRESHOLD_DAYS {
                     diagnostics.push_warning(
+                        codes::SEMANTIC_STALE_PLANNER_REQUEST,
                         path.display().to_string(),
                         format!("implementation_ready false for {} days", days),
                     );
@@ -247,6 +267,26 @@ pub fn semantic_checks(
     }
 }
 
+/// Warn when the observers layer is fully disabled, starving every layer after it.
+fn check_pipeline_upstream(observers: &ScheduleLayer, diagnostics: &mut Diagnostics) {
+    if !observers.enabled_roles().is_empty() {
+        return;
+    }
+    let Some(index) = Layer::Observers.pipeline_index() else {
+        return;
+    };
+    let downstream: Vec<&str> =
+        Layer::PIPELINE[index + 1..].iter().map(|layer| layer.dir_name()).collect();
+    diagnostics.push_warning(
+        codes::SEMANTIC_NO_OBSERVERS,
+        "config.toml".to_string(),
+        format!(
+            "No enabled observers roles: downstream layers ({}) will have no new events to process",
+            downstream.join(", ")
+        ),
+    );
+}
+
 fn validate_scheduled_layer(
     layer: Layer,
     schedule_layer: &ScheduleLayer,
@@ -260,6 +300,7 @@ fn validate_scheduled_layer(
 
         if !exists_as_custom {
             diagnostics.push_error(
+                codes::SEMANTIC_SCHEDULE,
                 role_name.to_string(),
                 format!(
                     "{} role listed in .jlo/config.toml schedule but missing from .jlo/roles/{}/<role>/role.yml",
@@ -289,6 +330,7 @@ fn build_event_source_index(context: &SemanticContext) -> HashMap<String, Vec<St
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::adapters::local_repository::LocalRepositoryAdapter;
     use std::fs;
     use tempfile::tempdir;
 
@@ -319,6 +361,43 @@ roles = [
         .expect("write config");
     }
 
+    #[test]
+    fn semantic_checks_warn_when_observers_are_all_disabled() {
+        let dir = tempdir().expect("tempdir");
+        let root = dir.path();
+        write_minimal_workspace(root);
+
+        let mut diagnostics = Diagnostics::default();
+        let context = semantic_context(&root.join(".jules"), &mut diagnostics);
+        semantic_checks(
+            &root.join(".jules"),
+            &LocalRepositoryAdapter::new(root.to_path_buf()),
+            &context,
+            &mut diagnostics,
+        );
+        assert!(!diagnostics.warnings().iter().any(|diag| diag.message.contains("downstream")));
+
+        let config = fs::read_to_string(root.join(".jlo/config.toml")).expect("read config");
+        fs::write(
+            root.join(".jlo/config.toml"),
+            config.replace("enabled = true", "enabled = false"),
+        )
+        .expect("write config");
+        let mut diagnostics = Diagnostics::default();
+        let context = semantic_context(&root.join(".jules"), &mut diagnostics);
+        semantic_checks(
+            &root.join(".jules"),
+            &LocalRepositoryAdapter::new(root.to_path_buf()),
+            &context,
+            &mut diagnostics,
+        );
+
+        assert!(diagnostics.warnings().iter().any(|diag| {
+            diag.message.contains("No enabled observers roles")
+                && diag.message.contains("decider, planner, implementer, integrator")
+        }));
+    }
+
     #[test]
     fn semantic_checks_reject_event_referenced_by_multiple_requirements() {
         let dir = tempdir().expect("tempdir");
@@ -343,7 +422,12 @@ roles = [
 
         let mut diagnostics = Diagnostics::default();
         let context = semantic_context(&root.join(".jules"), &mut diagnostics);
-        semantic_checks(&root.join(".jules"), &context, &mut diagnostics);
+        semantic_checks(
+            &root.join(".jules"),
+            &LocalRepositoryAdapter::new(root.to_path_buf()),
+            &context,
+            &mut diagnostics,
+        );
 
         assert!(diagnostics.errors().iter().any(|diag| {
             diag.message.contains("referenced by multiple requirements in source_events")
@@ -379,7 +463,12 @@ roles = [
 
         let mut diagnostics = Diagnostics::default();
         let context = semantic_context(&root.join(".jules"), &mut diagnostics);
-        semantic_checks(&root.join(".jules"), &context, &mut diagnostics);
+        semantic_checks(
+            &root.join(".jules"),
+            &LocalRepositoryAdapter::new(root.to_path_buf()),
+            &context,
+            &mut diagnostics,
+        );
 
         assert!(
             diagnostics
@@ -1,49 +1,67 @@
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::domain::config::parse::parse_config_content;
+use crate::domain::config::parse::parse_config_file;
 use crate::domain::jlo_paths;
-use crate::domain::{AppError, ControlPlaneConfig, Layer, Version};
+use crate::domain::{AppError, ControlPlaneConfig, ExecutionConfig, Layer, Version};
+use crate::ports::Git;
 
-use super::diagnostics::Diagnostics;
+use crate::app::validation::diagnostics::{Diagnostics, codes};
+
+/// Short description of this check family, used for SARIF rule metadata.
+pub const RULE_DESCRIPTION: &str = "Required .jules/ layout, control-plane config, version pin, and layer contracts are present and valid.";
 
 pub fn read_control_plane_config(
-    root: &Path,
+    config_path: &Path,
     diagnostics: &mut Diagnostics,
 ) -> Result<ControlPlaneConfig, AppError> {
-    let config_path = crate::domain::config::paths::config(root);
     if !config_path.exists() {
-        diagnostics.push_error(config_path.display().to_string(), "Missing .jlo/config.toml");
+        diagnostics.push_error(
+            codes::STRUCT_CONFIG_MISSING,
+            config_path.display().to_string(),
+            "Missing .jlo/config.toml",
+        );
         return Ok(ControlPlaneConfig::default());
     }
 
-    let content = match fs::read_to_string(&config_path) {
+    let content = match fs::read_to_string(config_path) {
         Ok(content) => content,
         Err(err) => {
-            diagnostics.push_error(config_path.display().to_string(), err.to_string());
+            diagnostics.push_error(
+                codes::STRUCT_CONFIG_INVALID,
+                config_path.display().to_string(),
+                err.to_string(),
+            );
             return Ok(ControlPlaneConfig::default());
         }
     };
 
-    match parse_config_content(&content) {
+    match parse_config_file(&config_path.display().to_string(), &content) {
         Ok(config) => Ok(config),
         Err(err) => {
-            diagnostics.push_error(config_path.display().to_string(), err.to_string());
+            diagnostics.push_error(
+                codes::STRUCT_CONFIG_INVALID,
+                config_path.display().to_string(),
+                err.to_string(),
+            );
             Ok(ControlPlaneConfig::default())
         }
     }
 }
 
+#[derive(Clone, Copy)]
 pub struct StructuralInputs<'a> {
     pub jules_path: &'a Path,
     pub root: &'a Path,
+    /// Control-plane config doctor reads: the `--config` file or `.jlo/config.toml`.
+    pub config_path: &'a Path,
     pub event_states: &'a [String],
 }
 
 pub fn structural_checks(inputs: StructuralInputs<'_>, diagnostics: &mut Diagnostics) {
     ensure_file_exists(&crate::domain::jules_paths::jules_readme(inputs.root), diagnostics);
     ensure_file_exists(&crate::domain::jules_paths::project_readme(inputs.root), diagnostics);
-    ensure_file_exists(&crate::domain::config::paths::config(inputs.root), diagnostics);
+    ensure_file_exists(inputs.config_path, diagnostics);
     ensure_file_exists(&crate::domain::jules_paths::version_file(inputs.root), diagnostics);
 
     check_version_file(inputs.jules_path, env!("CARGO_PKG_VERSION"), diagnostics);
@@ -53,8 +71,11 @@ pub fn structural_checks(inputs: StructuralInputs<'_>, diagnostics: &mut Diagnos
         if layer.has_schemas() {
             let schemas_dir = crate::domain::layers::paths::schemas_dir(inputs.jules_path, layer);
             if !schemas_dir.exists() {
-                diagnostics
-                    .push_error(schemas_dir.display().to_string(), "Missing schemas directory");
+                diagnostics.push_error(
+                    codes::STRUCT_MISSING_DIR,
+                    schemas_dir.display().to_string(),
+                    "Missing schemas directory",
+                );
             }
         }
 
@@ -64,8 +85,11 @@ pub fn structural_checks(inputs: StructuralInputs<'_>, diagnostics: &mut Diagnos
                 let change_template =
                     crate::domain::layers::paths::narrator_change_schema(inputs.jules_path);
                 if !change_template.exists() {
-                    diagnostics
-                        .push_error(change_template.display().to_string(), "Missing changes.yml");
+                    diagnostics.push_error(
+                        codes::STRUCT_MISSING_FILE,
+                        change_template.display().to_string(),
+                        "Missing changes.yml",
+                    );
                 }
             }
         } else {
@@ -76,43 +100,109 @@ pub fn structural_checks(inputs: StructuralInputs<'_>, diagnostics: &mut Diagnos
                 for entry in list_subdirs(&jlo_layer_dir, diagnostics) {
                     let role_file = entry.join("role.yml");
                     if !role_file.exists() {
-                        diagnostics.push_error(role_file.display().to_string(), "Missing role.yml");
+                        diagnostics.push_error(
+                            codes::STRUCT_MISSING_FILE,
+                            role_file.display().to_string(),
+                            "Missing role.yml",
+                        );
                     }
                 }
             }
         }
     }
 
-    // Flat exchange directory structure
-    {
-        ensure_directory_exists(
-            crate::domain::exchange::paths::exchange_dir(inputs.jules_path),
-            diagnostics,
-        );
+    check_workspaces(inputs.root, diagnostics);
+}
 
-        ensure_directory_exists(
-            crate::domain::exchange::events::paths::events_dir(inputs.jules_path),
-            diagnostics,
-        );
-        for state in inputs.event_states {
-            ensure_directory_exists(
-                crate::domain::exchange::events::paths::events_state_dir(inputs.jules_path, state),
-                diagnostics,
-            );
+/// Check the flat exchange directory layout; with `fix`, create missing directories.
+///
+/// Returns a description of each directory created.
+pub fn exchange_layout_checks(
+    inputs: &StructuralInputs<'_>,
+    fix: bool,
+    diagnostics: &mut Diagnostics,
+) -> Vec<String> {
+    let jules_path = inputs.jules_path;
+    let mut dirs = vec![
+        crate::domain::exchange::paths::exchange_dir(jules_path),
+        crate::domain::exchange::events::paths::events_dir(jules_path),
+    ];
+    dirs.extend(
+        inputs.event_states.iter().map(|state| {
+            crate::domain::exchange::events::paths::events_state_dir(jules_path, state)
+        }),
+    );
+    dirs.push(crate::domain::exchange::requirements::paths::requirements_dir(jules_path));
+    dirs.push(crate::domain::exchange::proposals::paths::proposals_dir(jules_path));
+
+    let mut fixes = Vec::new();
+    for dir in dirs {
+        if dir.exists() {
+            continue;
+        }
+        if !fix {
+            ensure_directory_exists(dir, diagnostics);
+            continue;
+        }
+        match fs::create_dir_all(&dir) {
+            Ok(()) => fixes.push(format!("{}: created missing directory", dir.display())),
+            Err(err) => diagnostics.push_error(
+                codes::STRUCT_MISSING_DIR,
+                dir.display().to_string(),
+                format!("Missing directory (could not create: {})", err),
+            ),
         }
+    }
+    fixes
+}
 
-        ensure_directory_exists(
-            crate::domain::exchange::requirements::paths::requirements_dir(inputs.jules_path),
-            diagnostics,
-        );
+/// Warn about runtime exchange artifacts while the control branch is checked out.
+///
+/// Skipped when the current branch cannot be determined or is not `jlo_target_branch`.
+pub fn control_branch_artifact_checks(
+    git: &dyn Git,
+    inputs: &StructuralInputs<'_>,
+    run: &ExecutionConfig,
+    diagnostics: &mut Diagnostics,
+) {
+    let Ok(branch) = git.get_current_branch() else {
+        return;
+    };
+    if branch.trim() != run.jlo_target_branch {
+        return;
+    }
 
-        ensure_directory_exists(
-            crate::domain::exchange::proposals::paths::proposals_dir(inputs.jules_path),
-            diagnostics,
+    let jules_path = inputs.jules_path;
+    let mut artifact_dirs: Vec<PathBuf> = inputs
+        .event_states
+        .iter()
+        .map(|state| crate::domain::exchange::events::paths::events_state_dir(jules_path, state))
+        .collect();
+    artifact_dirs.push(crate::domain::exchange::requirements::paths::requirements_dir(jules_path));
+    artifact_dirs.push(crate::domain::exchange::proposals::paths::proposals_dir(jules_path));
+
+    let mut artifacts: Vec<PathBuf> = artifact_dirs
+        .iter()
+        .filter_map(|dir| fs::read_dir(dir).ok())
+        .flat_map(|entries| entries.filter_map(|entry| entry.ok().map(|entry| entry.path())))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "yml"))
+        .collect();
+    let changes = crate::domain::exchange::paths::exchange_changes(jules_path);
+    if changes.is_file() {
+        artifacts.push(changes);
+    }
+    artifacts.sort();
+
+    for artifact in artifacts {
+        diagnostics.push_warning(
+            codes::STRUCT_CONTROL_BRANCH_ARTIFACTS,
+            artifact.display().to_string(),
+            format!(
+                "Runtime exchange artifact on control branch '{}'; it belongs on worker branch '{}'",
+                run.jlo_target_branch, run.jules_worker_branch
+            ),
         );
     }
-
-    check_workspaces(inputs.root, diagnostics);
 }
 
 fn check_version_file(jules_path: &Path, current_version: &str, diagnostics: &mut Diagnostics) {
@@ -124,24 +214,36 @@ fn check_version_file(jules_path: &Path, current_version: &str, diagnostics: &mu
     let content = match fs::read_to_string(&version_path) {
         Ok(content) => content.trim().to_string(),
         Err(err) => {
-            diagnostics.push_error(version_path.display().to_string(), err.to_string());
+            diagnostics.push_error(
+                codes::STRUCT_VERSION_INVALID,
+                version_path.display().to_string(),
+                err.to_string(),
+            );
             return;
         }
     };
 
     let Some(runtime_version) = Version::parse(&content) else {
-        diagnostics.push_error(version_path.display().to_string(), "Invalid version format");
+        diagnostics.push_error(
+            codes::STRUCT_VERSION_INVALID,
+            version_path.display().to_string(),
+            "Invalid version format",
+        );
         return;
     };
 
     let Some(current_version_obj) = Version::parse(current_version) else {
-        diagnostics
-            .push_error(version_path.display().to_string(), "Current binary version is invalid");
+        diagnostics.push_error(
+            codes::STRUCT_VERSION_INVALID,
+            version_path.display().to_string(),
+            "Current binary version is invalid",
+        );
         return;
     };
 
     if runtime_version > current_version_obj {
         diagnostics.push_error(
+            codes::STRUCT_VERSION_NEWER,
             version_path.display().to_string(),
             "Repository version is newer than the binary",
         );
@@ -150,13 +252,21 @@ fn check_version_file(jules_path: &Path, current_version: &str, diagnostics: &mu
 
 fn ensure_directory_exists(path: PathBuf, diagnostics: &mut Diagnostics) {
     if !path.exists() {
-        diagnostics.push_error(path.display().to_string(), "Missing directory");
+        diagnostics.push_error(
+            codes::STRUCT_MISSING_DIR,
+            path.display().to_string(),
+            "Missing directory",
+        );
     }
 }
 
 fn ensure_file_exists(path: &Path, diagnostics: &mut Diagnostics) {
     if !path.exists() {
-        diagnostics.push_error(path.display().to_string(), "Missing required file");
+        diagnostics.push_error(
+            codes::STRUCT_MISSING_FILE,
+            path.display().to_string(),
+            "Missing required file",
+        );
     }
 }
 
@@ -174,6 +284,7 @@ fn check_workspaces(root: &Path, diagnostics: &mut Diagnostics) {
                         let path = entry.path();
                         if path.is_dir() {
                             diagnostics.push_warning(
+                                codes::STRUCT_STALE_WORKSPACE,
                                 path.display().to_string(),
                                 "Temporary workspace found. These are normally cleaned up automatically but may remain after a crash.",
                             );
@@ -181,6 +292,7 @@ fn check_workspaces(root: &Path, diagnostics: &mut Diagnostics) {
                     }
                     Err(err) => {
                         diagnostics.push_error(
+                            codes::STRUCT_UNREADABLE_DIR,
                             workspaces_dir.display().to_string(),
                             format!("Failed to read workspace entry: {}", err),
                         );
@@ -190,6 +302,7 @@ fn check_workspaces(root: &Path, diagnostics: &mut Diagnostics) {
         }
         Err(err) => {
             diagnostics.push_error(
+                codes::STRUCT_UNREADABLE_DIR,
                 workspaces_dir.display().to_string(),
                 format!("Failed to read workspaces directory: {}", err),
             );
@@ -211,6 +324,7 @@ pub fn list_subdirs(path: &Path, diagnostics: &mut Diagnostics) -> Vec<PathBuf>
                     }
                     Err(err) => {
                         diagnostics.push_error(
+                            codes::STRUCT_UNREADABLE_DIR,
                             path.display().to_string(),
                             format!("Failed to read directory entry: {}", err),
                         );
@@ -220,6 +334,7 @@ pub fn list_subdirs(path: &Path, diagnostics: &mut Diagnostics) -> Vec<PathBuf>
         }
         Err(err) => {
             diagnostics.push_error(
+                codes::STRUCT_UNREADABLE_DIR,
                 path.display().to_string(),
                 format!("Failed to read directory: {}", err),
             );
@@ -232,7 +347,7 @@ pub fn list_subdirs(path: &Path, diagnostics: &mut Diagnostics) -> Vec<PathBuf>
 mod tests {
     use assert_fs::prelude::*;
 
-    use crate::app::commands::doctor::diagnostics::Diagnostics;
+    use crate::app::validation::diagnostics::Diagnostics;
 
     use super::*;
 
@@ -332,6 +447,7 @@ mod tests {
         let inputs = StructuralInputs {
             jules_path: &temp.path().join(".jules"),
             root: temp.path(),
+            config_path: &temp.path().join(".jlo/config.toml"),
             event_states: &event_states,
         };
 
@@ -344,6 +460,70 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_control_branch_artifacts_warn_only_on_target_branch() {
+        let temp = assert_fs::TempDir::new().unwrap();
+        create_valid_repository(&temp);
+        temp.child(".jules/exchange/events/pending/stray-event.yml")
+            .write_str("id: abc123\n")
+            .unwrap();
+        let event_states = vec!["pending".to_string()];
+        let jules_path = temp.path().join(".jules");
+        let inputs = StructuralInputs {
+            jules_path: &jules_path,
+            root: temp.path(),
+            config_path: &temp.path().join(".jlo/config.toml"),
+            event_states: &event_states,
+        };
+        let run = ExecutionConfig::default();
+        let git = crate::testing::FakeGit::new();
+
+        *git.current_branch.lock().unwrap() = "main".to_string();
+        let mut diagnostics = Diagnostics::default();
+        control_branch_artifact_checks(&git, &inputs, &run, &mut diagnostics);
+        assert_eq!(diagnostics.warning_count(), 1, "{:?}", diagnostics.warnings());
+        let warning = &diagnostics.warnings()[0];
+        assert_eq!(warning.code, codes::STRUCT_CONTROL_BRANCH_ARTIFACTS);
+        assert!(warning.file.ends_with("stray-event.yml"));
+        assert!(warning.message.contains("control branch 'main'"));
+
+        *git.current_branch.lock().unwrap() = "jules".to_string();
+        let mut diagnostics = Diagnostics::default();
+        control_branch_artifact_checks(&git, &inputs, &run, &mut diagnostics);
+        assert_eq!(diagnostics.warning_count(), 0);
+    }
+
+    #[test]
+    fn test_exchange_layout_fix_creates_missing_state_dirs() {
+        let temp = assert_fs::TempDir::new().unwrap();
+        create_valid_repository(&temp);
+        // Half-built exchange: events/ exists but its states and requirements/ do not.
+        std::fs::remove_dir_all(temp.path().join(".jules/exchange/events/pending")).unwrap();
+        std::fs::remove_dir_all(temp.path().join(".jules/exchange/requirements")).unwrap();
+        let event_states = vec!["pending".to_string(), "decided".to_string()];
+        let jules_path = temp.path().join(".jules");
+        let inputs = StructuralInputs {
+            jules_path: &jules_path,
+            root: temp.path(),
+            config_path: &temp.path().join(".jlo/config.toml"),
+            event_states: &event_states,
+        };
+
+        let mut diagnostics = Diagnostics::default();
+        assert!(exchange_layout_checks(&inputs, false, &mut diagnostics).is_empty());
+        assert_eq!(diagnostics.error_count(), 3, "{:?}", diagnostics.errors());
+        assert!(!jules_path.join("exchange/events/pending").exists());
+
+        let mut diagnostics = Diagnostics::default();
+        let fixes = exchange_layout_checks(&inputs, true, &mut diagnostics);
+        assert_eq!(diagnostics.error_count(), 0, "{:?}", diagnostics.errors());
+        assert_eq!(fixes.len(), 3, "{:?}", fixes);
+        for dir in ["events/pending", "events/decided", "requirements"] {
+            assert!(jules_path.join("exchange").join(dir).is_dir(), "{} should exist", dir);
+        }
+        assert!(fixes.iter().all(|fix| fix.ends_with(": created missing directory")));
+    }
+
     #[test]
     fn test_structural_checks_missing_critical_files() {
         let temp = assert_fs::TempDir::new().unwrap();
@@ -360,6 +540,7 @@ mod tests {
         let inputs = StructuralInputs {
             jules_path: &temp.path().join(".jules"),
             root: temp.path(),
+            config_path: &temp.path().join(".jlo/config.toml"),
             event_states: &event_states,
         };
 
@@ -385,6 +566,7 @@ mod tests {
         let inputs = StructuralInputs {
             jules_path: &temp.path().join(".jules"),
             root: temp.path(),
+            config_path: &temp.path().join(".jlo/config.toml"),
             event_states: &event_states,
         };
 
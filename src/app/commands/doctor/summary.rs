@@ -0,0 +1,40 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use chrono::Utc;
+use serde::Serialize;
+
+use crate::app::validation::diagnostics::Diagnostics;
+use crate::domain::AppError;
+
+/// Diagnostic counts artifact for trend tracking in CI.
+#[derive(Debug, Serialize)]
+struct DoctorSummary {
+    errors: usize,
+    warnings: usize,
+    /// Errors and warnings per diagnostic code (`JLO-…`).
+    by_rule: BTreeMap<String, usize>,
+    /// Errors and warnings per check category.
+    by_category: BTreeMap<String, usize>,
+    timestamp: String,
+}
+
+pub fn write_summary(path: &Path, diagnostics: &Diagnostics) -> Result<(), AppError> {
+    let summary = DoctorSummary {
+        errors: diagnostics.error_count(),
+        warnings: diagnostics.warning_count(),
+        by_rule: diagnostics.counts_by_code(),
+        by_category: diagnostics.counts_by_category(),
+        timestamp: Utc::now().to_rfc3339(),
+    };
+
+    let json = serde_json::to_string_pretty(&summary).map_err(|e| {
+        AppError::InternalError(format!("Failed to serialize doctor summary: {}", e))
+    })?;
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        std::fs::create_dir_all(parent)?;
+    }
+    std::fs::write(path, json)?;
+    Ok(())
+}
@@ -0,0 +1,52 @@
+//! Opt-in drift check between committed workflow files and the embedded scaffold
+//! (`jlo doctor --check-workflows`).
+
+use std::fs;
+use std::path::Path;
+
+use crate::adapters::catalogs::workflow_scaffold::load_workflow_scaffold;
+use crate::adapters::control_plane_config::{
+    load_workflow_generate_config, load_workflow_runner_mode,
+};
+use crate::ports::JloStore;
+
+use crate::app::validation::diagnostics::{Diagnostics, codes};
+
+/// Warn for each managed workflow file that is missing or differs from the scaffold
+/// this jlo version renders for the configured runner mode.
+pub fn workflow_drift_checks(root: &Path, store: &impl JloStore, diagnostics: &mut Diagnostics) {
+    let config_path = store.config_path().display().to_string();
+    let scaffold = load_workflow_runner_mode(store).and_then(|mode| {
+        let generate_config = load_workflow_generate_config(store)?;
+        Ok((load_workflow_scaffold(&mode, &generate_config)?, mode))
+    });
+    let (scaffold, mode) = match scaffold {
+        Ok(rendered) => rendered,
+        Err(err) => {
+            diagnostics.push_warning(
+                codes::STRUCT_WORKFLOW_DRIFT,
+                config_path,
+                format!("Cannot render workflow scaffold: {}", err),
+            );
+            return;
+        }
+    };
+
+    for file in &scaffold.files {
+        let path = root.join(&file.path);
+        let message = match fs::read_to_string(&path) {
+            Ok(content) if content == file.content => continue,
+            Ok(_) => format!(
+                "Workflow differs from the jlo {} scaffold for runner mode '{}'; run `jlo upgrade` to refresh it",
+                env!("CARGO_PKG_VERSION"),
+                mode.label()
+            ),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => format!(
+                "Managed workflow file is missing for runner mode '{}'; run `jlo upgrade` to restore it",
+                mode.label()
+            ),
+            Err(err) => format!("Cannot read managed workflow file: {}", err),
+        };
+        diagnostics.push_warning(codes::STRUCT_WORKFLOW_DRIFT, file.path.clone(), message);
+    }
+}
@@ -7,6 +7,9 @@ use crate::domain::{AppError, WorkflowRunnerMode};
 use crate::domain::{JLO_DIR, VERSION_FILE};
 use crate::ports::{Git, JloStore, JulesStore, RepositoryFilesystem, RoleTemplateStore};
 
+/// Target branch used when the remote's default branch cannot be detected.
+const DEFAULT_TARGET_BRANCH: &str = "main";
+
 /// Execute the unified init command.
 ///
 /// Creates the `.jlo/` control plane, the `.jules/` runtime repository, and
@@ -38,10 +41,15 @@ where
     let control_plane_files = ctx.templates().control_plane_files();
     for entry in &control_plane_files {
         ctx.repository().write_file(&entry.path, &entry.content)?;
+        if entry.executable {
+            ctx.repository().set_executable(&entry.path)?;
+        }
     }
 
     // Delegate config persistence
     control_plane_config::persist_workflow_runner_mode(ctx.repository(), mode)?;
+    let target_branch = git.get_default_branch().unwrap_or_else(|_| DEFAULT_TARGET_BRANCH.into());
+    control_plane_config::persist_target_branch(ctx.repository(), &target_branch)?;
 
     seed_scheduled_builtin_roles(ctx)?;
 
@@ -55,7 +63,7 @@ where
 
     // Generate setup artifacts immediately in control plane.
     // Hard-fail init when setup generation fails.
-    crate::app::commands::setup::generate(ctx.repository())?;
+    crate::app::commands::setup::generate(ctx.repository(), false)?;
 
     Ok(())
 }
@@ -113,3 +121,38 @@ where
     ctx.repository().write_role(layer, role, &content)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::catalogs::EmbeddedRoleTemplateStore;
+    use crate::adapters::local_repository::LocalRepositoryAdapter;
+    use crate::testing::FakeGit;
+    use tempfile::TempDir;
+
+    fn init_with_default_branch(default_branch: Option<&str>) -> String {
+        let temp = TempDir::new().unwrap();
+        let ctx = AppContext::new(
+            LocalRepositoryAdapter::new(temp.path().to_path_buf()),
+            EmbeddedRoleTemplateStore::new(),
+        );
+        let git = FakeGit::new();
+        *git.current_branch.lock().unwrap() = "main".to_string();
+        *git.default_branch.lock().unwrap() = default_branch.map(str::to_string);
+
+        execute(&ctx, &git, &WorkflowRunnerMode::remote()).unwrap();
+        std::fs::read_to_string(temp.path().join(".jlo/config.toml")).unwrap()
+    }
+
+    #[test]
+    fn init_targets_the_remote_default_branch() {
+        let config = init_with_default_branch(Some("trunk"));
+        assert!(config.contains("jlo_target_branch = \"trunk\""), "{}", config);
+    }
+
+    #[test]
+    fn init_falls_back_to_main_when_default_branch_is_undetectable() {
+        let config = init_with_default_branch(None);
+        assert!(config.contains("jlo_target_branch = \"main\""), "{}", config);
+    }
+}
@@ -5,7 +5,7 @@ use crate::domain::PromptAssetLoader;
 use crate::domain::{AppError, Layer, RoleError, RoleId};
 use crate::ports::{JloStore, JulesStore, RepositoryFilesystem, RoleTemplateStore};
 
-use super::schedule::ensure_role_scheduled;
+use super::schedule::{line_diff, plan_role_scheduled};
 
 use super::RoleAddOutcome;
 
@@ -13,6 +13,7 @@ pub fn execute<W, R>(
     ctx: &AppContext<W, R>,
     layer: &str,
     role: &str,
+    dry_run: bool,
 ) -> Result<RoleAddOutcome, AppError>
 where
     W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
@@ -48,27 +49,25 @@ where
         )));
     }
 
-    let inserted = ensure_role_scheduled(ctx.repository(), layer_enum, &role_id)?;
-    if !inserted {
+    let config_path = ".jlo/config.toml";
+    let current = ctx.repository().read_file(config_path)?;
+    let Some(updated) = plan_role_scheduled(&current, layer_enum, &role_id)? else {
         return Err(RoleError::AlreadyExists {
             role: role_id.as_str().to_string(),
             layer: layer_enum.dir_name().to_string(),
         }
         .into());
+    };
+    if dry_run {
+        return Ok(RoleAddOutcome::Planned {
+            layer: layer_enum.dir_name().to_string(),
+            role: role_id.as_str().to_string(),
+            config_diff: line_diff(&current, &updated),
+        });
     }
+    ctx.repository().write_file(config_path, &updated)?;
 
-    let jlo_path = ctx.repository().jlo_path();
-    let root = jlo_path.parent().ok_or_else(|| {
-        AppError::InvalidPath(format!("Invalid .jlo path (missing parent): {}", jlo_path.display()))
-    })?;
-    let role_path = crate::domain::roles::paths::role_yml(root, layer_enum, role_id.as_str());
-    let role_path_str = role_path.to_str().ok_or_else(|| {
-        AppError::InvalidPath(format!(
-            "Role path contains invalid unicode: {}",
-            role_path.display()
-        ))
-    })?;
-    if !ctx.repository().file_exists(role_path_str) {
+    if !ctx.repository().role_exists(layer_enum, role_id.as_str()) {
         let content = ctx.templates().builtin_role_content(layer_enum, role_id.as_str())?;
         ctx.repository().write_role(layer_enum, role_id.as_str(), &content)?;
     }
@@ -45,7 +45,7 @@ where
         ))
     })?;
 
-    if ctx.repository().file_exists(role_dir_str) {
+    if ctx.repository().role_exists(layer_enum, role_id.as_str()) {
         return Err(AppError::Validation(format!(
             "Role '{}' already exists in layer '{}' at {}",
             role_id.as_str(),
@@ -52,7 +52,7 @@ where
         ))
     })?;
 
-    if !ctx.repository().file_exists(role_yml_str) {
+    if !ctx.repository().role_exists(layer_enum, role_id.as_str()) {
         return Err(RoleError::NotFound(format!(
             "{}/{} (missing {})",
             layer_enum.dir_name(),
@@ -3,35 +3,53 @@
 mod add;
 mod create;
 mod delete;
+mod rename;
 mod schedule;
+mod toggle;
+
+pub(crate) use schedule::normalize_config_section_order;
 
 use crate::app::AppContext;
 use crate::domain::AppError;
+use serde::Serialize;
+
 use crate::domain::PromptAssetLoader;
 use crate::ports::{JloStore, JulesStore, RepositoryFilesystem, RoleTemplateStore};
 
 /// Outcome of a role add operation.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RoleAddOutcome {
-    Role { layer: String, role: String },
+    Role {
+        layer: String,
+        role: String,
+    },
+    /// `--dry-run`: nothing was written; `config_diff` is the pending config change.
+    Planned {
+        layer: String,
+        role: String,
+        config_diff: String,
+    },
 }
 
 impl RoleAddOutcome {
     pub fn display_path(&self) -> String {
         match self {
-            RoleAddOutcome::Role { .. } => ".jlo/config.toml".to_string(),
+            RoleAddOutcome::Role { .. } | RoleAddOutcome::Planned { .. } => {
+                ".jlo/config.toml".to_string()
+            }
         }
     }
 
     pub fn entity_type(&self) -> &'static str {
         match self {
-            RoleAddOutcome::Role { .. } => "role",
+            RoleAddOutcome::Role { .. } | RoleAddOutcome::Planned { .. } => "role",
         }
     }
 }
 
 /// Outcome of a role create operation.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
 pub enum RoleCreateOutcome {
     Role { layer: String, role: String },
 }
@@ -76,17 +94,50 @@ impl RoleDeleteOutcome {
     }
 }
 
+/// Outcome of a role rename operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RoleRenameOutcome {
+    Role { layer: String, from: String, to: String },
+}
+
+impl RoleRenameOutcome {
+    pub fn display_path(&self) -> String {
+        let relative = match self {
+            RoleRenameOutcome::Role { layer, to, .. } => role_relative_path(layer, to),
+        };
+        format!(".jlo/{}", relative.display())
+    }
+
+    pub fn entity_type(&self) -> &'static str {
+        match self {
+            RoleRenameOutcome::Role { .. } => "role",
+        }
+    }
+}
+
+/// Outcome of a role enable/disable operation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RoleToggleOutcome {
+    pub layer: String,
+    pub role: String,
+    /// The requested `enabled` value.
+    pub enabled: bool,
+    /// `false` when the schedule entry already had the requested value.
+    pub changed: bool,
+}
+
 /// Register a built-in role in `.jlo/config.toml`.
 pub fn add_role<W, R>(
     ctx: &AppContext<W, R>,
     layer: &str,
     role: &str,
+    dry_run: bool,
 ) -> Result<RoleAddOutcome, AppError>
 where
     W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
     R: RoleTemplateStore,
 {
-    add::execute(ctx, layer, role)
+    add::execute(ctx, layer, role, dry_run)
 }
 
 /// Create a new role under `.jlo/roles/<layer>/<name>/`.
@@ -114,3 +165,31 @@ where
 {
     delete::execute(ctx, layer, role)
 }
+
+/// Rename a role directory and its schedule entry.
+pub fn rename_role<W, R>(
+    ctx: &AppContext<W, R>,
+    layer: &str,
+    from: &str,
+    to: &str,
+) -> Result<RoleRenameOutcome, AppError>
+where
+    W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
+    R: RoleTemplateStore,
+{
+    rename::execute(ctx, layer, from, to)
+}
+
+/// Set a scheduled role's `enabled` flag in `.jlo/config.toml`.
+pub fn set_role_enabled<W, R>(
+    ctx: &AppContext<W, R>,
+    layer: &str,
+    role: &str,
+    enabled: bool,
+) -> Result<RoleToggleOutcome, AppError>
+where
+    W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
+    R: RoleTemplateStore,
+{
+    toggle::execute(ctx, layer, role, enabled)
+}
@@ -0,0 +1,211 @@
+//! Rename role under `.jlo/roles/<layer>/<name>/` and its schedule entry.
+
+use crate::app::AppContext;
+use crate::domain::PromptAssetLoader;
+use crate::domain::{AppError, Layer, RoleError, RoleId};
+use crate::ports::{JloStore, JulesStore, RepositoryFilesystem, RoleTemplateStore};
+
+use super::RoleRenameOutcome;
+use super::schedule::rename_role_scheduled;
+
+pub fn execute<W, R>(
+    ctx: &AppContext<W, R>,
+    layer: &str,
+    from: &str,
+    to: &str,
+) -> Result<RoleRenameOutcome, AppError>
+where
+    W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
+    R: RoleTemplateStore,
+{
+    if !ctx.repository().jlo_exists() {
+        return Err(AppError::Validation(
+            "repository is not initialized. Run 'jlo init' first.".to_string(),
+        ));
+    }
+
+    let layer_enum = Layer::from_dir_name(layer)
+        .ok_or_else(|| RoleError::InvalidLayer { name: layer.to_string() })?;
+    if layer_enum.is_single_role() {
+        return Err(RoleError::SingleRoleLayerTemplate(layer_enum.dir_name().to_string()).into());
+    }
+
+    let from_id = RoleId::new(from)?;
+    let to_id = RoleId::new(to)?;
+    if from_id == to_id {
+        return Err(AppError::Validation(format!(
+            "Cannot rename role '{}' to itself",
+            from_id.as_str()
+        )));
+    }
+
+    let jlo_path = ctx.repository().jlo_path();
+    let root = jlo_path.parent().ok_or_else(|| {
+        AppError::InvalidPath(format!("Invalid .jlo path (missing parent): {}", jlo_path.display()))
+    })?;
+    let relative = |path: std::path::PathBuf| -> Result<String, AppError> {
+        let relative = path.strip_prefix(root).unwrap_or(&path);
+        relative.to_str().map(ToString::to_string).ok_or_else(|| {
+            AppError::InvalidPath(format!(
+                "Role path contains invalid unicode: {}",
+                relative.display()
+            ))
+        })
+    };
+    let from_dir =
+        relative(crate::domain::roles::paths::role_dir(root, layer_enum, from_id.as_str()))?;
+    let to_dir = relative(crate::domain::roles::paths::role_dir(root, layer_enum, to_id.as_str()))?;
+    let from_yml =
+        relative(crate::domain::roles::paths::role_yml(root, layer_enum, from_id.as_str()))?;
+    let to_yml = relative(crate::domain::roles::paths::role_yml(root, layer_enum, to_id.as_str()))?;
+
+    if !ctx.repository().role_exists(layer_enum, from_id.as_str()) {
+        return Err(RoleError::NotFound(format!(
+            "{}/{} (missing {})",
+            layer_enum.dir_name(),
+            from_id.as_str(),
+            from_yml
+        ))
+        .into());
+    }
+    if ctx.repository().role_exists(layer_enum, to_id.as_str()) {
+        return Err(RoleError::AlreadyExists {
+            role: to_id.as_str().to_string(),
+            layer: layer_enum.dir_name().to_string(),
+        }
+        .into());
+    }
+
+    let rescheduled = rename_role_scheduled(ctx.repository(), layer_enum, &from_id, &to_id)?;
+
+    if let Err(rename_err) = ctx.repository().rename(&from_dir, &to_dir) {
+        if rescheduled
+            && let Err(rollback_err) =
+                rename_role_scheduled(ctx.repository(), layer_enum, &to_id, &from_id)
+        {
+            return Err(AppError::Validation(format!(
+                "Failed to rename role directory '{}': {}. Failed to restore schedule entry for '{}': {}",
+                from_dir,
+                rename_err,
+                from_id.as_str(),
+                rollback_err
+            )));
+        }
+        return Err(rename_err);
+    }
+
+    let content = ctx.repository().read_file(&to_yml)?;
+    let updated = rename_role_field(&content, from_id.as_str(), to_id.as_str());
+    if updated != content {
+        ctx.repository().write_file(&to_yml, &updated)?;
+    }
+
+    Ok(RoleRenameOutcome::Role {
+        layer: layer_enum.dir_name().to_string(),
+        from: from_id.as_str().to_string(),
+        to: to_id.as_str().to_string(),
+    })
+}
+
+/// Rewrite the top-level `role:` field, leaving the rest of the file untouched.
+fn rename_role_field(content: &str, from: &str, to: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    for line in content.split_inclusive('\n') {
+        let body = line.trim_end_matches(['\n', '\r']);
+        let value = body.strip_prefix("role:").map(|v| v.trim().trim_matches(['"', '\'']));
+        if value == Some(from) {
+            output.push_str(&format!("role: {}", to));
+            output.push_str(&line[body.len()..]);
+        } else {
+            output.push_str(line);
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::catalogs::EmbeddedRoleTemplateStore;
+    use crate::ports::RepositoryFilesystem;
+    use crate::testing::TestStore;
+
+    const CONFIG: &str = r#"[run]
+jlo_target_branch = "target_branch"
+jules_worker_branch = "worker_branch"
+
+[observers]
+roles = [
+  { name = "consistency", enabled = true },
+  { name = "taxonomy", enabled = false },
+]
+"#;
+
+    fn context(store: TestStore) -> crate::app::AppContext<TestStore, EmbeddedRoleTemplateStore> {
+        crate::app::AppContext::new(store, EmbeddedRoleTemplateStore::new())
+    }
+
+    fn repository() -> TestStore {
+        TestStore::new()
+            .with_exists(true)
+            .with_file(".jlo/config.toml", CONFIG)
+            .with_file(
+                ".jlo/roles/observers/taxonomy/role.yml",
+                "role: taxonomy\nlayer: observers\n",
+            )
+            .with_file(".jlo/roles/observers/taxonomy/notes.md", "notes\n")
+            .with_file(
+                ".jlo/roles/observers/consistency/role.yml",
+                "role: consistency\nlayer: observers\n",
+            )
+    }
+
+    #[test]
+    fn rename_role_round_trips_directory_schedule_and_role_field() {
+        let repository = repository();
+        let ctx = context(repository.clone());
+
+        let outcome = execute(&ctx, "observers", "taxonomy", "glossary").expect("rename");
+        assert_eq!(outcome.display_path(), ".jlo/roles/observers/glossary");
+        assert!(!repository.file_exists(".jlo/roles/observers/taxonomy/role.yml"));
+        assert_eq!(
+            repository.read_file(".jlo/roles/observers/glossary/role.yml").unwrap(),
+            "role: glossary\nlayer: observers\n"
+        );
+        assert_eq!(
+            repository.read_file(".jlo/roles/observers/glossary/notes.md").unwrap(),
+            "notes\n"
+        );
+        let config = repository.read_file(".jlo/config.toml").unwrap();
+        assert!(config.contains(r#"{ name = "glossary", enabled = false }"#), "{}", config);
+        assert!(!config.contains("taxonomy"));
+
+        execute(&ctx, "observers", "glossary", "taxonomy").expect("rename back");
+        assert_eq!(repository.read_file(".jlo/config.toml").unwrap(), CONFIG);
+        assert_eq!(
+            repository.read_file(".jlo/roles/observers/taxonomy/role.yml").unwrap(),
+            "role: taxonomy\nlayer: observers\n"
+        );
+    }
+
+    #[test]
+    fn rename_role_onto_existing_role_fails_without_changes() {
+        let repository = repository();
+        let ctx = context(repository.clone());
+
+        let err = execute(&ctx, "observers", "taxonomy", "consistency").unwrap_err();
+
+        assert!(matches!(err, AppError::Role(RoleError::AlreadyExists { .. })));
+        assert_eq!(repository.read_file(".jlo/config.toml").unwrap(), CONFIG);
+        assert!(repository.file_exists(".jlo/roles/observers/taxonomy/role.yml"));
+    }
+
+    #[test]
+    fn rename_role_rejects_unsafe_target_name() {
+        let ctx = context(repository());
+
+        let err = execute(&ctx, "observers", "taxonomy", "../escape").unwrap_err();
+
+        assert!(matches!(err, AppError::Role(RoleError::InvalidId(_))));
+    }
+}
@@ -1,5 +1,6 @@
-use crate::domain::{AppError, Layer, RoleId};
+use crate::domain::{AppError, Layer, RoleError, RoleId};
 use crate::ports::RepositoryFilesystem;
+use similar::{ChangeTag, TextDiff};
 use toml_edit::{Array, DocumentMut, InlineTable, Item, Table, Value};
 
 pub fn ensure_role_scheduled<W: RepositoryFilesystem>(
@@ -16,6 +17,21 @@ pub fn ensure_role_scheduled<W: RepositoryFilesystem>(
 
     let config_path = ".jlo/config.toml";
     let content = repository.read_file(config_path)?;
+    match plan_role_scheduled(&content, layer, role)? {
+        Some(updated) => {
+            repository.write_file(config_path, &updated)?;
+            Ok(true)
+        }
+        None => Ok(false),
+    }
+}
+
+/// Config content with `role` scheduled, or `None` when it is already scheduled.
+pub fn plan_role_scheduled(
+    content: &str,
+    layer: Layer,
+    role: &RoleId,
+) -> Result<Option<String>, AppError> {
     let mut doc = content.parse::<DocumentMut>().map_err(|err| {
         AppError::Validation(format!("Failed to parse .jlo/config.toml: {}", err))
     })?;
@@ -23,7 +39,7 @@ pub fn ensure_role_scheduled<W: RepositoryFilesystem>(
     let roles = layer_roles_mut(&mut doc, layer.dir_name())?;
     format_roles_array(roles);
     if contains_role(roles, role)? {
-        return Ok(false);
+        return Ok(None);
     }
 
     let mut value = Value::InlineTable(scheduled_role_entry(role));
@@ -31,8 +47,25 @@ pub fn ensure_role_scheduled<W: RepositoryFilesystem>(
     roles.push_formatted(value);
     format_roles_array(roles);
     normalize_top_level_table_order(&mut doc);
-    repository.write_file(config_path, &doc.to_string())?;
-    Ok(true)
+    Ok(Some(doc.to_string()))
+}
+
+/// Line diff of `before` against `after`: removed lines prefixed `-`, added lines `+`.
+///
+/// Unchanged lines are omitted. Uses the same `similar` line diff as the managed-files
+/// `--verbose` output.
+pub fn line_diff(before: &str, after: &str) -> String {
+    TextDiff::from_lines(before, after)
+        .iter_all_changes()
+        .filter_map(|change| {
+            let sign = match change.tag() {
+                ChangeTag::Delete => '-',
+                ChangeTag::Insert => '+',
+                ChangeTag::Equal => return None,
+            };
+            Some(format!("{}{}\n", sign, change.value().trim_end_matches('\n')))
+        })
+        .collect()
 }
 
 pub fn remove_role_scheduled<W: RepositoryFilesystem>(
@@ -74,6 +107,128 @@ pub fn remove_role_scheduled<W: RepositoryFilesystem>(
     Ok(true)
 }
 
+/// Rename a role's schedule entry in place, keeping its `enabled` flag.
+///
+/// Returns `false` when the role is not scheduled.
+pub fn rename_role_scheduled<W: RepositoryFilesystem>(
+    repository: &W,
+    layer: Layer,
+    from: &RoleId,
+    to: &RoleId,
+) -> Result<bool, AppError> {
+    if layer.is_single_role() {
+        return Err(AppError::Validation(format!(
+            "Layer '{}' does not support scheduling",
+            layer.dir_name()
+        )));
+    }
+
+    let config_path = ".jlo/config.toml";
+    let content = repository.read_file(config_path)?;
+    let mut doc = content.parse::<DocumentMut>().map_err(|err| {
+        AppError::Validation(format!("Failed to parse .jlo/config.toml: {}", err))
+    })?;
+
+    let roles = layer_roles_mut(&mut doc, layer.dir_name())?;
+    if contains_role(roles, to)? {
+        return Err(RoleError::AlreadyExists {
+            role: to.as_str().to_string(),
+            layer: layer.dir_name().to_string(),
+        }
+        .into());
+    }
+
+    let mut renamed = false;
+    for entry in roles.iter_mut() {
+        if role_entry_name(entry)? == from.as_str()
+            && let Some(table) = entry.as_inline_table_mut()
+        {
+            table.insert("name", Value::from(to.as_str()));
+            renamed = true;
+        }
+    }
+
+    if !renamed {
+        return Ok(false);
+    }
+
+    format_roles_array(roles);
+    repository.write_file(config_path, &doc.to_string())?;
+    Ok(true)
+}
+
+/// Set the `enabled` flag on a role's schedule entry, preserving formatting.
+///
+/// Returns `false` when the flag already had the requested value.
+pub fn set_role_enabled<W: RepositoryFilesystem>(
+    repository: &W,
+    layer: Layer,
+    role: &RoleId,
+    enabled: bool,
+) -> Result<bool, AppError> {
+    if layer.is_single_role() {
+        return Err(AppError::Validation(format!(
+            "Layer '{}' does not support scheduling",
+            layer.dir_name()
+        )));
+    }
+
+    let config_path = ".jlo/config.toml";
+    let content = repository.read_file(config_path)?;
+    let mut doc = content.parse::<DocumentMut>().map_err(|err| {
+        AppError::Validation(format!("Failed to parse .jlo/config.toml: {}", err))
+    })?;
+
+    let roles = layer_roles_mut(&mut doc, layer.dir_name())?;
+    let mut found = false;
+    let mut changed = false;
+    for entry in roles.iter_mut() {
+        if role_entry_name(entry)? != role.as_str() {
+            continue;
+        }
+        found = true;
+        if let Some(table) = entry.as_inline_table_mut()
+            && table.get("enabled").and_then(|v| v.as_bool()) != Some(enabled)
+        {
+            match table.get_mut("enabled") {
+                Some(value) => {
+                    let decor = value.decor().clone();
+                    *value = Value::from(enabled);
+                    *value.decor_mut() = decor;
+                }
+                None => {
+                    table.insert("enabled", Value::from(enabled));
+                }
+            }
+            changed = true;
+        }
+    }
+
+    if !found {
+        return Err(RoleError::NotInConfig {
+            role: role.as_str().to_string(),
+            layer: layer.dir_name().to_string(),
+        }
+        .into());
+    }
+    if changed {
+        repository.write_file(config_path, &doc.to_string())?;
+    }
+    Ok(changed)
+}
+
+/// Rewrite `content` with top-level config sections in canonical order.
+///
+/// Returns `None` when the sections are already in canonical order.
+pub(crate) fn normalize_config_section_order(content: &str) -> Result<Option<String>, AppError> {
+    let mut doc = content.parse::<DocumentMut>().map_err(|err| {
+        AppError::Validation(format!("Failed to parse .jlo/config.toml: {}", err))
+    })?;
+    normalize_top_level_table_order(&mut doc);
+    let normalized = doc.to_string();
+    Ok((normalized != content).then_some(normalized))
+}
+
 fn normalize_top_level_table_order(doc: &mut DocumentMut) {
     let preferred = ["run", "workflow", "innovators", "observers", "jules_api"];
     let root = doc.as_table_mut();
@@ -213,6 +368,16 @@ roles = [
         assert_eq!(roles, vec!["consistency".to_string(), "librarian".to_string()]);
     }
 
+    #[test]
+    fn line_diff_reports_only_changed_lines() {
+        let before = "[observers]\nroles = [\n  { name = \"a\", enabled = true },\n]\n";
+        let after = "[observers]\nroles = [\n  { name = \"a\", enabled = true },\n  { name = \"b\", enabled = true },\n]\n";
+
+        assert_eq!(line_diff(before, after), "+  { name = \"b\", enabled = true },\n");
+        assert_eq!(line_diff("x\ny\n", "x\nz\n"), "-y\n+z\n");
+        assert_eq!(line_diff(after, after), "");
+    }
+
     #[test]
     fn ensure_role_scheduled_adds_missing_innovators_section() {
         let repository = TestStore::new().with_file(
@@ -365,4 +530,57 @@ roles = [
         let roles = role_names(&actual, "observers");
         assert!(roles.is_empty(), "roles should be empty after removing the last entry");
     }
+
+    const TOGGLE_CONFIG: &str = r#"[run]
+jlo_target_branch = "main"
+
+[observers]
+roles = [
+  { name = "taxonomy",   enabled = true },  # keep aligned
+  { name = "qa", enabled = false },
+]
+"#;
+
+    #[test]
+    fn set_role_enabled_toggles_both_directions_preserving_formatting() {
+        let repository = TestStore::new().with_file(".jlo/config.toml", TOGGLE_CONFIG);
+        let taxonomy = RoleId::new("taxonomy").unwrap();
+        let qa = RoleId::new("qa").unwrap();
+
+        assert!(set_role_enabled(&repository, Layer::Observers, &taxonomy, false).unwrap());
+        assert!(set_role_enabled(&repository, Layer::Observers, &qa, true).unwrap());
+
+        let actual = repository.read_file(".jlo/config.toml").unwrap();
+        assert_eq!(
+            actual,
+            TOGGLE_CONFIG
+                .replace("enabled = true }", "enabled = false }")
+                .replace("\"qa\", enabled = false", "\"qa\", enabled = true")
+        );
+    }
+
+    #[test]
+    fn set_role_enabled_is_idempotent() {
+        let repository = TestStore::new().with_file(".jlo/config.toml", TOGGLE_CONFIG);
+        let qa = RoleId::new("qa").unwrap();
+
+        assert!(!set_role_enabled(&repository, Layer::Observers, &qa, false).unwrap());
+        assert!(set_role_enabled(&repository, Layer::Observers, &qa, true).unwrap());
+        assert!(!set_role_enabled(&repository, Layer::Observers, &qa, true).unwrap());
+        assert_eq!(
+            repository.read_file(".jlo/config.toml").unwrap().matches("enabled = true").count(),
+            2
+        );
+    }
+
+    #[test]
+    fn set_role_enabled_rejects_unscheduled_role() {
+        let repository = TestStore::new().with_file(".jlo/config.toml", TOGGLE_CONFIG);
+
+        let err =
+            set_role_enabled(&repository, Layer::Observers, &RoleId::new("ghost").unwrap(), true)
+                .unwrap_err();
+
+        assert!(matches!(err, AppError::Role(RoleError::NotInConfig { .. })), "{err}");
+    }
 }
@@ -0,0 +1,42 @@
+//! Enable or disable a scheduled role in `.jlo/config.toml`.
+
+use crate::app::AppContext;
+use crate::domain::PromptAssetLoader;
+use crate::domain::{AppError, Layer, RoleError, RoleId};
+use crate::ports::{JloStore, JulesStore, RepositoryFilesystem, RoleTemplateStore};
+
+use super::RoleToggleOutcome;
+use super::schedule::set_role_enabled;
+
+pub fn execute<W, R>(
+    ctx: &AppContext<W, R>,
+    layer: &str,
+    role: &str,
+    enabled: bool,
+) -> Result<RoleToggleOutcome, AppError>
+where
+    W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
+    R: RoleTemplateStore,
+{
+    if !ctx.repository().jlo_exists() {
+        return Err(AppError::Validation(
+            "repository is not initialized. Run 'jlo init' first.".to_string(),
+        ));
+    }
+
+    let layer_enum = Layer::from_dir_name(layer)
+        .ok_or_else(|| RoleError::InvalidLayer { name: layer.to_string() })?;
+    if layer_enum.is_single_role() {
+        return Err(RoleError::SingleRoleLayerTemplate(layer_enum.dir_name().to_string()).into());
+    }
+
+    let role_id = RoleId::new(role)?;
+    let changed = set_role_enabled(ctx.repository(), layer_enum, &role_id, enabled)?;
+
+    Ok(RoleToggleOutcome {
+        layer: layer_enum.dir_name().to_string(),
+        role: role_id.as_str().to_string(),
+        enabled,
+        changed,
+    })
+}
@@ -4,18 +4,18 @@
 //! `run` execution. Command and layer modules should depend on this boundary
 //! instead of importing lower-level readers directly.
 
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-use crate::app::config;
-use crate::domain::{AppError, ControlPlaneConfig, MockConfig};
-use crate::ports::{Git, RepositoryFilesystem};
+use crate::app::{config, validation};
+use crate::domain::layers::execute::validate_requirement_path;
+use crate::domain::{AppError, ControlPlaneConfig, JULES_DIR, Layer, MockConfig, RoleId};
+use crate::ports::{Git, JloStore, JulesStore, RepositoryFilesystem};
 
-/// Load run configuration from `.jlo/config.toml`.
-pub fn load_control_plane_config<W: RepositoryFilesystem>(
-    jules_path: &Path,
+/// Load run configuration from the store's control-plane config.
+pub fn load_control_plane_config<W: JloStore>(
     repository: &W,
 ) -> Result<ControlPlaneConfig, AppError> {
-    config::load_config(jules_path, repository)
+    config::load_config(repository)
 }
 
 /// Validate runtime prerequisites for mock execution.
@@ -24,14 +24,138 @@ pub fn validate_mock_prerequisites() -> Result<(), AppError> {
 }
 
 /// Load mock execution inputs from repository and environment.
-pub fn load_mock_config<W: RepositoryFilesystem>(
+pub fn load_mock_config<W: RepositoryFilesystem + JloStore>(
     jules_path: &Path,
     repository: &W,
+    seed: Option<u64>,
 ) -> Result<MockConfig, AppError> {
-    config::load_mock_config(jules_path, repository)
+    config::load_mock_config(jules_path, repository, seed)
+}
+
+/// Load a role definition override from a repository file.
+///
+/// The file must satisfy the layer's role schema and declare `role` as its name.
+pub fn load_role_file<W: RepositoryFilesystem>(
+    path: &Path,
+    layer: Layer,
+    role: &RoleId,
+    repository: &W,
+) -> Result<String, AppError> {
+    if layer != Layer::Observers {
+        return Err(AppError::Validation(format!(
+            "--role-file is not supported for layer '{}'",
+            layer.dir_name()
+        )));
+    }
+    let path_str = path.to_string_lossy();
+    if !repository.file_exists(&path_str) || repository.is_dir(&path_str) {
+        return Err(AppError::Validation(format!("Role file not found: {}", path.display())));
+    }
+
+    let content = repository.read_file(&path_str)?;
+    validation::validate_observer_role(&content, path, role.as_str())?;
+    Ok(content)
+}
+
+/// Validate a requirement document supplied inline (e.g. on stdin).
+///
+/// Returns the repository-relative path `.jules/exchange/requirements/<id>.yml` where
+/// [`stage_requirement_input`] would write it. Fails when a file with different content
+/// already exists there. Nothing is written.
+pub fn validate_requirement_input<W: RepositoryFilesystem>(
+    content: &str,
+    repository: &W,
+) -> Result<PathBuf, AppError> {
+    let data: serde_yaml::Mapping = serde_yaml::from_str(content).map_err(|err| {
+        AppError::Validation(format!("Requirement input is not a YAML mapping: {}", err))
+    })?;
+    validation::validate_requirement_document(&data, "input")?;
+
+    let id = data.get("id").and_then(|id| id.as_str()).unwrap_or_default();
+    let path = crate::domain::exchange::requirements::paths::requirements_dir(Path::new(JULES_DIR))
+        .join(format!("{}.yml", id));
+    let path_str = path.to_string_lossy();
+    if repository.file_exists(&path_str) && repository.read_file(&path_str)? != content {
+        return Err(AppError::Validation(format!(
+            "Requirement {} already exists with different content",
+            path.display()
+        )));
+    }
+    Ok(path)
+}
+
+/// Validate an inline requirement document and stage it at the path returned by
+/// [`validate_requirement_input`].
+pub fn stage_requirement_input<W: RepositoryFilesystem>(
+    content: &str,
+    repository: &W,
+) -> Result<PathBuf, AppError> {
+    let path = validate_requirement_input(content, repository)?;
+    repository.write_file(&path.to_string_lossy(), content)?;
+    Ok(path)
+}
+
+/// Requirement content for a run: the validated inline document when given, otherwise
+/// the file at `path`, which must live under `.jules/exchange/requirements/`.
+pub fn read_requirement<W: RepositoryFilesystem + JulesStore>(
+    path: &Path,
+    inline: Option<&str>,
+    repository: &W,
+) -> Result<String, AppError> {
+    if let Some(content) = inline {
+        return Ok(content.to_string());
+    }
+    let requirement_info = validate_requirement_path(path, repository)?;
+    repository.read_file(&requirement_info.requirement_path_str)
 }
 
 /// Detect repository source used by session dispatch.
 pub fn detect_repository_source(git: &(impl Git + ?Sized)) -> Result<String, AppError> {
     config::detect_repository_source(git)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestStore;
+
+    const REQUIREMENT: &str = r#"schema_version: 2
+id: "abc123"
+source_events: ["ev1234"]
+title: "Inline requirement"
+label: "bugs"
+priority: "high"
+summary: "Summary"
+goal: "Goal"
+problem: "Problem"
+impact: "Impact"
+desired_outcome: "Outcome"
+affected_areas: ["src/"]
+acceptance_criteria: ["Done"]
+verification_criteria: ["cargo test"]
+implementation_ready: false
+planner_request_reason: "Needs planner elaboration"
+"#;
+
+    #[test]
+    fn validate_requirement_input_returns_staging_path_without_writing() {
+        let repository = TestStore::new();
+
+        let path = validate_requirement_input(REQUIREMENT, &repository).unwrap();
+
+        assert_eq!(path, PathBuf::from(".jules/exchange/requirements/abc123.yml"));
+        assert!(!repository.file_exists(&path.to_string_lossy()));
+    }
+
+    #[test]
+    fn stage_requirement_input_writes_and_refuses_conflicting_content() {
+        let repository = TestStore::new();
+
+        let path = stage_requirement_input(REQUIREMENT, &repository).unwrap();
+        assert_eq!(repository.read_file(&path.to_string_lossy()).unwrap(), REQUIREMENT);
+
+        let changed = REQUIREMENT.replace("Inline requirement", "Changed title");
+        let err = stage_requirement_input(&changed, &repository).unwrap_err();
+        assert!(err.to_string().contains("already exists with different content"), "{}", err);
+    }
+}
@@ -1,24 +1,28 @@
 use std::collections::HashSet;
 use std::path::{Path, PathBuf};
 
-use chrono::Utc;
-
 use super::super::mock::mock_execution::{
-    MOCK_ASSETS, MockExecutionService, generate_mock_id, list_mock_tagged_files,
-    mock_event_id_from_path,
+    MOCK_ASSETS, MockExecutionService, list_mock_tagged_files, mock_event_id_from_path,
 };
-use crate::app::commands::run::RunRuntimeOptions;
 use crate::app::commands::run::input::{detect_repository_source, load_mock_config};
+use crate::app::commands::run::{ReportStream, RunRuntimeOptions};
+use crate::app::commands::workflow::push::{
+    MergeStrategy, PushWorkerBranchOptions, PushWorkerBranchOutput, execute as push_worker_branch,
+};
+use crate::domain::exchange::events::dedupe::{DuplicateGroup, dedupe_key, find_duplicates};
+use crate::domain::exchange::events::paths::events_pending_dir;
 use crate::domain::layers::execute::starting_branch::resolve_starting_branch;
 use crate::domain::prompt_assemble::{PromptAssetLoader, PromptContext, assemble_prompt};
 use crate::domain::{
-    AppError, ConfigError, ControlPlaneConfig, Layer, MockConfig, MockOutput, RunOptions,
+    AppError, ConfigError, ControlPlaneConfig, IoErrorKind, Layer, MockConfig, MockOutput,
+    RunOptions,
 };
 use crate::ports::{
     AutomationMode, Git, GitHub, JloStore, JulesStore, RepositoryFilesystem, SessionRequest,
 };
 
 use super::super::strategy::{JulesClientFactory, LayerStrategy, RunResult};
+use super::SeedMode;
 
 pub struct DeciderLayer;
 
@@ -45,12 +49,25 @@ where
         client_factory: &dyn JulesClientFactory,
     ) -> Result<RunResult, AppError> {
         if runtime.mock {
-            let mock_config = load_mock_config(jules_path, repository)?;
-            let _output = execute_mock(jules_path, &mock_config, git, github, repository)?;
+            if runtime.print_prompts {
+                let prompt = assemble_decider_prompt(jules_path, repository, SeedMode::Report)?;
+                super::print_prompt_to_stderr(Layer::Decider, None, &prompt);
+            }
+            let mock_config = load_mock_config(jules_path, repository, runtime.mock_seed)?;
+            let output = execute_mock(
+                jules_path,
+                &mock_config,
+                runtime.dedupe_events,
+                git,
+                github,
+                repository,
+            )?;
+            super::super::mock::mock_execution::finish_mock_output(&output, runtime.report_stream)?;
             return Ok(RunResult {
                 roles: vec!["decider".to_string()],
                 prompt_preview: false,
                 sessions: vec![],
+                session_urls: vec![],
                 cleanup_requirement: None,
             });
         }
@@ -58,7 +75,9 @@ where
         execute_real(
             jules_path,
             runtime.prompt_preview,
+            runtime.report_stream,
             runtime.branch.as_deref(),
+            runtime.dedupe_events,
             config,
             git,
             repository,
@@ -67,10 +86,13 @@ where
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn execute_real<G, W>(
     jules_path: &Path,
     prompt_preview: bool,
+    report: ReportStream,
     branch: Option<&str>,
+    dedupe_events: bool,
     config: &ControlPlaneConfig,
     git: &G,
     repository: &W,
@@ -89,26 +111,47 @@ where
 {
     let starting_branch = resolve_starting_branch(Layer::Decider, config, branch);
 
+    let duplicates = if dedupe_events {
+        let pending = list_pending_event_files(repository, &events_pending_dir(jules_path))?;
+        plan_event_dedupe(repository, &pending)?
+    } else {
+        Vec::new()
+    };
+
     if prompt_preview {
-        println!("=== Prompt Preview: Decider ===");
-        println!("Starting branch: {}\n", starting_branch);
+        report.line("=== Prompt Preview: Decider ===");
+        report.line(format_args!("Starting branch: {}\n", starting_branch));
+
+        for group in &duplicates {
+            report.line(format_args!(
+                "  Would merge {} into {}",
+                merged_ids(group).join(", "),
+                group.kept.id
+            ));
+        }
 
-        let prompt = assemble_decider_prompt(jules_path, repository)?;
-        println!("  Assembled prompt: {} chars", prompt.len());
+        let prompt = assemble_decider_prompt(jules_path, repository, SeedMode::Report)?;
+        report.line(format_args!("  Assembled prompt: {} chars", prompt.len()));
 
-        println!("\nWould dispatch workflow");
+        report.line("\nWould dispatch workflow");
         return Ok(RunResult {
             roles: vec!["decider".to_string()],
             prompt_preview: true,
             sessions: vec![],
+            session_urls: vec![],
             cleanup_requirement: None,
         });
     }
 
+    if !duplicates.is_empty() {
+        // The session reads events from the remote worker branch, so publish the merge first.
+        publish_event_dedupe(repository, &duplicates, push_worker_branch)?;
+    }
+
     let source = detect_repository_source(git)?;
     let client = client_factory.create()?;
 
-    let prompt = assemble_decider_prompt(jules_path, repository)?;
+    let prompt = assemble_decider_prompt(jules_path, repository, SeedMode::Apply)?;
 
     let request = SessionRequest {
         prompt,
@@ -118,14 +161,15 @@ where
         automation_mode: AutomationMode::AutoCreatePr,
     };
 
-    println!("Executing: decider...");
+    eprintln!("Executing: decider...");
     let response = client.create_session(request)?;
-    println!("  ✅ Session created: {}", response.session_id);
+    eprintln!("  ✅ Session created: {}", response.session_id);
 
     Ok(RunResult {
         roles: vec!["decider".to_string()],
         prompt_preview: false,
         sessions: vec![response.session_id],
+        session_urls: vec![response.url],
         cleanup_requirement: None,
     })
 }
@@ -142,6 +186,7 @@ fn assemble_decider_prompt<
 >(
     jules_path: &Path,
     repository: &W,
+    seed_mode: SeedMode,
 ) -> Result<String, AppError> {
     let (prompt, seed_ops) = assemble_prompt(
         jules_path,
@@ -151,13 +196,174 @@ fn assemble_decider_prompt<
         crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset,
     )
     .map_err(|e| AppError::InternalError(e.to_string()))?;
-    super::execute_seed_ops(seed_ops, repository)?;
+    super::handle_seed_ops(seed_ops, repository, seed_mode)?;
     Ok(prompt.content)
 }
 
+/// Pending event considered for deduplication.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct PendingEvent {
+    path: PathBuf,
+    id: String,
+}
+
+fn merged_ids(group: &DuplicateGroup<PendingEvent>) -> Vec<&str> {
+    group.merged.iter().map(|event| event.id.as_str()).collect()
+}
+
+fn list_pending_event_files<W: RepositoryFilesystem + ?Sized>(
+    repository: &W,
+    pending_dir: &Path,
+) -> Result<Vec<PathBuf>, AppError> {
+    let dir_str = pending_dir.to_str().ok_or_else(|| {
+        AppError::InvalidPath(format!("Invalid directory path: {}", pending_dir.display()))
+    })?;
+
+    let entries = match repository.list_dir(dir_str) {
+        Ok(entries) => entries,
+        Err(AppError::Io { kind: IoErrorKind::NotFound, .. }) => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut files: Vec<PathBuf> = entries
+        .into_iter()
+        .filter(|path| path.extension().is_some_and(|ext| ext == "yml"))
+        .collect();
+    files.sort();
+    Ok(files)
+}
+
+/// Group pending events whose dedupe keys match, in file-name order.
+///
+/// Fails on events that are not valid YAML; events without a dedupe key or title are
+/// never merged.
+fn plan_event_dedupe<W: RepositoryFilesystem + ?Sized>(
+    repository: &W,
+    files: &[PathBuf],
+) -> Result<Vec<DuplicateGroup<PendingEvent>>, AppError> {
+    let mut keyed = Vec::with_capacity(files.len());
+    for path in files {
+        let content = repository.read_file(&path.to_string_lossy())?;
+        let value: serde_yaml::Value = serde_yaml::from_str(&content).map_err(|err| {
+            AppError::ParseError { what: path.display().to_string(), details: err.to_string() }
+        })?;
+        let id = value["id"].as_str().map(ToString::to_string).unwrap_or_else(|| {
+            path.file_stem().map(|stem| stem.to_string_lossy().to_string()).unwrap_or_default()
+        });
+        let key = dedupe_key(value["dedupe_key"].as_str(), value["title"].as_str());
+        keyed.push((PendingEvent { path: path.clone(), id }, key));
+    }
+    Ok(find_duplicates(&keyed))
+}
+
+/// Merge duplicate events and publish the result to the worker branch.
+///
+/// Event files are restored to their original content when merging or publishing fails,
+/// so a failed push does not leave the merge applied only locally.
+fn publish_event_dedupe<W, P>(
+    repository: &W,
+    groups: &[DuplicateGroup<PendingEvent>],
+    publish: P,
+) -> Result<(), AppError>
+where
+    W: RepositoryFilesystem + ?Sized,
+    P: FnOnce(PushWorkerBranchOptions) -> Result<PushWorkerBranchOutput, AppError>,
+{
+    let mut originals = Vec::new();
+    for group in groups {
+        for event in std::iter::once(&group.kept).chain(&group.merged) {
+            let path = event.path.to_string_lossy().to_string();
+            let content = repository.read_file(&path)?;
+            originals.push((path, content));
+        }
+    }
+
+    let result = apply_event_dedupe(repository, groups)
+        .and_then(|_| publish(event_dedupe_push_options(groups)))
+        .map(|_| ());
+    if let Err(err) = result {
+        for (path, content) in &originals {
+            repository.write_file(path, content).map_err(|restore_err| {
+                AppError::InternalError(format!(
+                    "{}; restoring {} after failed event dedupe also failed: {}",
+                    err, path, restore_err
+                ))
+            })?;
+        }
+        return Err(err);
+    }
+    Ok(())
+}
+
+fn event_dedupe_push_options(groups: &[DuplicateGroup<PendingEvent>]) -> PushWorkerBranchOptions {
+    PushWorkerBranchOptions {
+        change_token: "event-dedupe".to_string(),
+        commit_message: "jules: dedupe pending events".to_string(),
+        pr_title: "chore: dedupe pending events".to_string(),
+        pr_body: format!(
+            "Automated merge of duplicate pending events before decider triage.\n\n{}",
+            groups
+                .iter()
+                .map(|group| format!(
+                    "- `{}` absorbs {}",
+                    group.kept.id,
+                    merged_ids(group)
+                        .iter()
+                        .map(|id| format!("`{}`", id))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ))
+                .collect::<Vec<_>>()
+                .join("\n")
+        ),
+        merge_strategy: MergeStrategy::Squash,
+    }
+}
+
+/// Merge duplicate events into the kept event.
+///
+/// The kept event records absorbed ids under `merged_events`; duplicates are removed.
+/// Returns every path that was touched.
+fn apply_event_dedupe<W: RepositoryFilesystem + ?Sized>(
+    repository: &W,
+    groups: &[DuplicateGroup<PendingEvent>],
+) -> Result<Vec<PathBuf>, AppError> {
+    let mut touched = Vec::new();
+    for group in groups {
+        let kept_path = group.kept.path.to_string_lossy().to_string();
+        let content = repository.read_file(&kept_path)?;
+        let mut value: serde_yaml::Value = serde_yaml::from_str(&content).map_err(|err| {
+            AppError::ParseError { what: kept_path.clone(), details: err.to_string() }
+        })?;
+        let mapping = value.as_mapping_mut().ok_or_else(|| {
+            AppError::Validation(format!("Event is not a YAML mapping: {}", kept_path))
+        })?;
+        let merged = mapping
+            .entry("merged_events".into())
+            .or_insert_with(|| serde_yaml::Value::Sequence(Vec::new()));
+        if let Some(seq) = merged.as_sequence_mut() {
+            seq.extend(group.merged.iter().map(|event| event.id.clone().into()));
+        }
+        let rendered = serde_yaml::to_string(&value).map_err(|err| {
+            AppError::InternalError(format!("Failed to render event YAML {}: {}", kept_path, err))
+        })?;
+        repository.write_file(&kept_path, &rendered)?;
+        touched.push(group.kept.path.clone());
+
+        for event in &group.merged {
+            repository.remove_file(&event.path.to_string_lossy())?;
+            touched.push(event.path.clone());
+        }
+
+        eprintln!("Deduped events: {} absorbed {}", group.kept.id, merged_ids(group).join(", "));
+    }
+    Ok(touched)
+}
+
 fn execute_mock<G, H, W>(
     jules_path: &Path,
     config: &MockConfig,
+    dedupe_events: bool,
     git: &G,
     github: &H,
     repository: &W,
@@ -167,6 +373,7 @@ where
     H: GitHub + ?Sized,
     W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
 {
+    config.simulated_failure(Layer::Decider, None)?;
     if !crate::domain::validation::validate_identifier(&config.mock_tag, false) {
         return Err(ConfigError::Invalid(format!(
             "mock_tag '{}' must be a safe path component (letters, numbers, '-' or '_')",
@@ -177,10 +384,10 @@ where
 
     let service = MockExecutionService::new(jules_path, config, git, github, repository);
 
-    let timestamp = Utc::now().format("%Y%m%d%H%M%S").to_string();
+    let timestamp = config.clock.now().format("%Y%m%d%H%M%S").to_string();
     let branch_name = config.branch_name(Layer::Decider, &timestamp)?;
 
-    println!("Mock decider: creating branch {}", branch_name);
+    eprintln!("Mock decider: creating branch {}", branch_name);
 
     // Fetch and checkout from jules branch
     service.fetch_and_checkout_base(&config.jules_worker_branch)?;
@@ -216,22 +423,21 @@ where
             AppError::InternalError("Invalid UTF-8 in decider_requirement.yml".to_string())
         })?;
 
+    // Collapse duplicate mock events before they are decided
+    let deduped_files = if dedupe_events {
+        let pending = list_mock_tagged_files(repository, &pending_dir, &config.mock_tag)?;
+        apply_event_dedupe(repository, &plan_event_dedupe(repository, &pending)?)?
+    } else {
+        Vec::new()
+    };
+
     // Move any mock pending events to decided first
     let mut moved_src_files: Vec<PathBuf> = Vec::new();
     for path in list_mock_tagged_files(repository, &pending_dir, &config.mock_tag)? {
-        let source = path
-            .to_str()
-            .ok_or_else(|| AppError::InvalidPath("Invalid pending event path".into()))?;
-        let content = repository.read_file(source)?;
-        let dest = decided_dir.join(path.file_name().ok_or_else(|| {
-            AppError::InvalidPath(format!("Pending event missing filename: {}", path.display()))
-        })?);
-        repository.write_file(
-            dest.to_str()
-                .ok_or_else(|| AppError::InvalidPath("Invalid decided event path".into()))?,
-            &content,
-        )?;
-        repository.remove_file(source)?;
+        let event = path.file_stem().and_then(|stem| stem.to_str()).ok_or_else(|| {
+            AppError::InvalidPath(format!("Invalid pending event path: {}", path.display()))
+        })?;
+        repository.move_event(event, "pending", "decided")?;
         moved_src_files.push(path);
     }
 
@@ -241,75 +447,84 @@ where
         .filter_map(|path| mock_event_id_from_path(path, &config.mock_tag))
         .collect();
 
-    if source_event_ids.len() < 2 {
+    // Deduplication can legitimately leave a single event, which routes straight to implementer.
+    let min_events = if dedupe_events { 1 } else { 2 };
+    if source_event_ids.len() < min_events {
         return Err(ConfigError::Invalid(format!(
-            "Mock decider requires at least 2 decided events for tag '{}', found {}",
+            "Mock decider requires at least {} decided events for tag '{}', found {}",
+            min_events,
             config.mock_tag,
             source_event_ids.len()
         ))
         .into());
     }
 
-    let planner_source_event_ids = vec![source_event_ids[0].clone()];
-    let impl_source_event_ids: Vec<String> = source_event_ids[1..].to_vec();
+    let planner_source_event_ids: Vec<String> =
+        if source_event_ids.len() >= 2 { vec![source_event_ids[0].clone()] } else { Vec::new() };
+    let impl_source_event_ids: Vec<String> =
+        source_event_ids[planner_source_event_ids.len()..].to_vec();
 
     // Requirement 1: not implementation-ready (routes to planner)
-    let planner_requirement_id = generate_mock_id();
+    let planner_requirement_id = config.clock.next_id();
     let planner_requirement_file =
         requirements_dir.join(format!("planner-{}.yml", config.mock_tag));
+    let create_planner_requirement = !planner_source_event_ids.is_empty();
 
-    let mut planner_requirement_yaml: serde_yaml::Value =
-        serde_yaml::from_str(mock_requirement_template).map_err(|e| {
-            AppError::InternalError(format!("Failed to parse mock requirement template: {}", e))
-        })?;
+    if create_planner_requirement {
+        let mut planner_requirement_yaml: serde_yaml::Value =
+            serde_yaml::from_str(mock_requirement_template).map_err(|e| {
+                AppError::InternalError(format!("Failed to parse mock requirement template: {}", e))
+            })?;
 
-    if let Some(mapping) = planner_requirement_yaml.as_mapping_mut() {
-        mapping.insert("id".into(), planner_requirement_id.clone().into());
-        mapping.insert("label".into(), label.clone().into());
-        mapping.insert(
-            "summary".into(),
-            format!(
-                "This is a mock requirement created by jlo --mock for workflow-scaffold validation. Mock tag: {}",
-                config.mock_tag
-            )
-            .into(),
-        );
-        let src_events = mapping
-            .entry("source_events".into())
-            .or_insert_with(|| serde_yaml::Value::Sequence(vec![]));
-        if let Some(seq) = src_events.as_sequence_mut() {
-            seq.clear();
-            for event_id in &planner_source_event_ids {
-                seq.push(event_id.clone().into());
+        if let Some(mapping) = planner_requirement_yaml.as_mapping_mut() {
+            mapping.insert("id".into(), planner_requirement_id.clone().into());
+            mapping.insert("label".into(), label.clone().into());
+            mapping.insert(
+                "summary".into(),
+                format!(
+                    "This is a mock requirement created by jlo --mock for workflow-scaffold validation. Mock tag: {}",
+                    config.mock_tag
+                )
+                .into(),
+            );
+            let src_events = mapping
+                .entry("source_events".into())
+                .or_insert_with(|| serde_yaml::Value::Sequence(vec![]));
+            if let Some(seq) = src_events.as_sequence_mut() {
+                seq.clear();
+                for event_id in &planner_source_event_ids {
+                    seq.push(event_id.clone().into());
+                }
             }
+
+            mapping
+                .insert("title".into(), "Mock requirement requiring planner detailization".into());
+            mapping.insert("priority".into(), "high".into());
+            mapping.insert("implementation_ready".into(), false.into());
+            mapping.insert(
+                "planner_request_reason".into(),
+                "Mock requirement needs planner detailization for workflow validation".into(),
+            );
         }
 
-        mapping.insert("title".into(), "Mock requirement requiring planner detailization".into());
-        mapping.insert("priority".into(), "high".into());
-        mapping.insert("implementation_ready".into(), false.into());
-        mapping.insert(
-            "planner_request_reason".into(),
-            "Mock requirement needs planner detailization for workflow validation".into(),
-        );
+        repository.write_file(
+            planner_requirement_file.to_str().ok_or_else(|| {
+                AppError::InvalidPath(format!(
+                    "Invalid planner requirement path: {}",
+                    planner_requirement_file.display()
+                ))
+            })?,
+            &serde_yaml::to_string(&planner_requirement_yaml).map_err(|err| {
+                AppError::InternalError(format!(
+                    "Failed to serialize planner requirement YAML: {}",
+                    err
+                ))
+            })?,
+        )?;
     }
 
-    repository.write_file(
-        planner_requirement_file.to_str().ok_or_else(|| {
-            AppError::InvalidPath(format!(
-                "Invalid planner requirement path: {}",
-                planner_requirement_file.display()
-            ))
-        })?,
-        &serde_yaml::to_string(&planner_requirement_yaml).map_err(|err| {
-            AppError::InternalError(format!(
-                "Failed to serialize planner requirement YAML: {}",
-                err
-            ))
-        })?,
-    )?;
-
     // Requirement 2: ready for implementer
-    let implementer_requirement_id = generate_mock_id();
+    let implementer_requirement_id = config.clock.next_id();
     let implementer_requirement_file =
         requirements_dir.join(format!("impl-{}.yml", config.mock_tag));
 
@@ -373,7 +588,7 @@ where
             let decided_file_str = match decided_file.to_str() {
                 Some(path) => path,
                 None => {
-                    println!(
+                    eprintln!(
                         "::warning::Invalid decided event file path (non UTF-8): {}",
                         decided_file.display()
                     );
@@ -384,7 +599,7 @@ where
             let content = match repository.read_file(decided_file_str) {
                 Ok(content) => content,
                 Err(err) => {
-                    println!(
+                    eprintln!(
                         "::warning::Failed to read decided event file {}: {}",
                         decided_file.display(),
                         err
@@ -396,7 +611,7 @@ where
             let mut yaml_value: serde_yaml::Value = match serde_yaml::from_str(&content) {
                 Ok(value) => value,
                 Err(err) => {
-                    println!(
+                    eprintln!(
                         "::warning::Failed to parse decided event file {} as YAML: {}",
                         decided_file.display(),
                         err
@@ -406,7 +621,7 @@ where
             };
 
             let Some(mapping) = yaml_value.as_mapping_mut() else {
-                println!(
+                eprintln!(
                     "::warning::Decided event file is not a YAML mapping: {}",
                     decided_file.display()
                 );
@@ -421,7 +636,7 @@ where
             let updated_content = match serde_yaml::to_string(&yaml_value) {
                 Ok(value) => value,
                 Err(err) => {
-                    println!(
+                    eprintln!(
                         "::warning::Failed to render decided event YAML {}: {}",
                         decided_file.display(),
                         err
@@ -431,7 +646,7 @@ where
             };
 
             if let Err(err) = repository.write_file(decided_file_str, &updated_content) {
-                println!(
+                eprintln!(
                     "::warning::Failed to write decided event file {}: {}",
                     decided_file.display(),
                     err
@@ -441,8 +656,13 @@ where
     }
 
     // Commit and push (include moved/deleted files and decided updates)
-    let mut files: Vec<&Path> =
-        vec![planner_requirement_file.as_path(), implementer_requirement_file.as_path()];
+    let mut files: Vec<&Path> = vec![implementer_requirement_file.as_path()];
+    if create_planner_requirement {
+        files.push(planner_requirement_file.as_path());
+    }
+    for f in &deduped_files {
+        files.push(f.as_path());
+    }
     for f in &decided_mock_files {
         files.push(f.as_path());
     }
@@ -456,15 +676,23 @@ where
     )?;
 
     // Create PR
+    let mut created_requirements = Vec::new();
+    if create_planner_requirement {
+        created_requirements.push(format!("- `{}` (requires analysis)", planner_requirement_id));
+    }
+    created_requirements.push(format!("- `{}` (ready for impl)", implementer_requirement_id));
     let pr = service.create_pr(
         &branch_name,
         &config.jules_worker_branch,
         &format!("[{}] Decider triage", config.mock_tag),
-        &format!("Mock decider run for workflow validation.\n\nMock tag: `{}`\n\nCreated requirements:\n- `{}` (requires analysis)\n- `{}` (ready for impl)",
-            config.mock_tag, planner_requirement_id, implementer_requirement_id),
+        &format!(
+            "Mock decider run for workflow validation.\n\nMock tag: `{}`\n\nCreated requirements:\n{}",
+            config.mock_tag,
+            created_requirements.join("\n")
+        ),
     )?;
 
-    println!("Mock decider: created PR #{} ({})", pr.number, pr.url);
+    eprintln!("Mock decider: created PR #{} ({})", pr.number, pr.url);
 
     let output = MockOutput {
         mock_branch: branch_name,
@@ -473,14 +701,13 @@ where
         mock_tag: config.mock_tag.clone(),
     };
 
-    service.finish(&output)?;
-
     Ok(output)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::MockClock;
     use crate::ports::RepositoryFilesystem;
     use crate::testing::{FakeGit, FakeGitHub, TestStore};
     use std::collections::HashMap;
@@ -495,6 +722,8 @@ mod tests {
             jlo_target_branch: "main".to_string(),
             jules_worker_branch: "jules".to_string(),
             issue_labels: vec!["bugs".to_string()],
+            clock: MockClock::seeded(0),
+            fail_target: None,
         }
     }
 
@@ -519,7 +748,7 @@ mod tests {
             )
             .unwrap();
 
-        let result = execute_mock(&jules_path, &config, &git, &github, &repository);
+        let result = execute_mock(&jules_path, &config, false, &git, &github, &repository);
         assert!(result.is_ok());
         let output = result.unwrap();
         assert!(output.mock_branch.starts_with("jules-decider-"));
@@ -557,6 +786,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mock_decider_dedupes_identical_events_into_one_requirement() {
+        let jules_path = PathBuf::from(".jules");
+        let repository = TestStore::new().with_exists(true);
+        let git = FakeGit::new();
+        let github = FakeGitHub::new();
+        let config = make_config();
+
+        repository
+            .write_file(
+                ".jules/exchange/events/pending/mock-test-decider-event1.yml",
+                "id: event1\ntitle: Missing error context\n",
+            )
+            .unwrap();
+        repository
+            .write_file(
+                ".jules/exchange/events/pending/mock-test-decider-event2.yml",
+                "id: event2\ntitle: missing  error context.\n",
+            )
+            .unwrap();
+
+        execute_mock(&jules_path, &config, true, &git, &github, &repository)
+            .expect("deduped mock decider should succeed");
+
+        let req_files = repository.list_dir(".jules/exchange/requirements").unwrap();
+        assert_eq!(req_files.len(), 1, "duplicates should produce a single requirement");
+        let impl_content = repository.read_file(&req_files[0].to_string_lossy()).unwrap();
+        let impl_yaml: serde_yaml::Value = serde_yaml::from_str(&impl_content).unwrap();
+        assert_eq!(impl_yaml["implementation_ready"].as_bool(), Some(true));
+        assert_eq!(
+            impl_yaml["source_events"],
+            serde_yaml::from_str::<serde_yaml::Value>("[event1]").unwrap()
+        );
+
+        let kept = repository
+            .read_file(".jules/exchange/events/decided/mock-test-decider-event1.yml")
+            .unwrap();
+        let kept_yaml: serde_yaml::Value = serde_yaml::from_str(&kept).unwrap();
+        assert_eq!(kept_yaml["merged_events"][0].as_str(), Some("event2"));
+        assert!(
+            !repository.file_exists(".jules/exchange/events/pending/mock-test-decider-event2.yml")
+        );
+        assert!(
+            !repository.file_exists(".jules/exchange/events/decided/mock-test-decider-event2.yml")
+        );
+    }
+
     #[test]
     fn mock_decider_fails_with_insufficient_events() {
         let jules_path = PathBuf::from(".jules");
@@ -569,10 +845,98 @@ mod tests {
             .write_file(".jules/exchange/events/pending/mock-test-decider-event1.yml", "id: event1")
             .unwrap();
 
-        let result = execute_mock(&jules_path, &config, &git, &github, &repository);
+        let result = execute_mock(&jules_path, &config, false, &git, &github, &repository);
         assert!(result.is_err());
         assert!(
             matches!(result, Err(AppError::Config(ConfigError::Invalid(ref msg))) if msg.contains("requires at least 2 decided events"))
         );
     }
+
+    fn write_duplicate_pending_events(repository: &TestStore) -> Vec<PathBuf> {
+        repository
+            .write_file(
+                ".jules/exchange/events/pending/aaa111.yml",
+                "id: aaa111\ntitle: Missing error context\n",
+            )
+            .unwrap();
+        repository
+            .write_file(
+                ".jules/exchange/events/pending/bbb222.yml",
+                "id: bbb222\ntitle: missing error context.\n",
+            )
+            .unwrap();
+        list_pending_event_files(repository, Path::new(".jules/exchange/events/pending")).unwrap()
+    }
+
+    #[test]
+    fn plan_event_dedupe_rejects_malformed_event_yaml() {
+        let repository = TestStore::new();
+        repository
+            .write_file(".jules/exchange/events/pending/aaa111.yml", "id: [unclosed\n")
+            .unwrap();
+
+        let err = plan_event_dedupe(
+            &repository,
+            &[PathBuf::from(".jules/exchange/events/pending/aaa111.yml")],
+        )
+        .unwrap_err();
+
+        assert!(
+            matches!(err, AppError::ParseError { ref what, .. } if what.ends_with("aaa111.yml"))
+        );
+    }
+
+    #[test]
+    fn publish_event_dedupe_merges_events_and_pushes_them() {
+        let repository = TestStore::new();
+        let pending = write_duplicate_pending_events(&repository);
+        let groups = plan_event_dedupe(&repository, &pending).unwrap();
+
+        let mut pushed = None;
+        publish_event_dedupe(&repository, &groups, |options| {
+            pushed = Some(options);
+            Ok(PushWorkerBranchOutput {
+                schema_version: 1,
+                applied: true,
+                skipped_reason: None,
+                branch: Some("jules-push-event-dedupe".to_string()),
+                pr_number: Some(7),
+                head_sha: None,
+                merged: true,
+            })
+        })
+        .unwrap();
+
+        let pushed = pushed.expect("merge should be published");
+        assert_eq!(pushed.change_token, "event-dedupe");
+        assert!(pushed.pr_body.contains("- `aaa111` absorbs `bbb222`"));
+        let kept: serde_yaml::Value = serde_yaml::from_str(
+            &repository.read_file(".jules/exchange/events/pending/aaa111.yml").unwrap(),
+        )
+        .unwrap();
+        assert_eq!(kept["merged_events"][0].as_str(), Some("bbb222"));
+        assert!(!repository.file_exists(".jules/exchange/events/pending/bbb222.yml"));
+    }
+
+    #[test]
+    fn publish_event_dedupe_restores_events_when_push_fails() {
+        let repository = TestStore::new();
+        let pending = write_duplicate_pending_events(&repository);
+        let groups = plan_event_dedupe(&repository, &pending).unwrap();
+
+        let err = publish_event_dedupe(&repository, &groups, |_| {
+            Err(AppError::Validation("push rejected".to_string()))
+        })
+        .unwrap_err();
+
+        assert!(err.to_string().contains("push rejected"));
+        assert_eq!(
+            repository.read_file(".jules/exchange/events/pending/aaa111.yml").unwrap(),
+            "id: aaa111\ntitle: Missing error context\n"
+        );
+        assert_eq!(
+            repository.read_file(".jules/exchange/events/pending/bbb222.yml").unwrap(),
+            "id: bbb222\ntitle: missing error context.\n"
+        );
+    }
 }
@@ -1,23 +1,25 @@
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
-use chrono::Utc;
 use serde::Deserialize;
 
 use super::super::mock::mock_execution::MockExecutionService;
-use crate::app::commands::run::RunRuntimeOptions;
-use crate::app::commands::run::input::{detect_repository_source, load_mock_config};
+use crate::app::commands::run::input::{
+    detect_repository_source, load_mock_config, read_requirement,
+};
+use crate::app::commands::run::{ReportStream, RunRuntimeOptions};
+use crate::domain::config::pr_body_template::{PrBodyContext, render_pr_body};
 use crate::domain::layers::execute::starting_branch::resolve_starting_branch;
-use crate::domain::layers::execute::validate_requirement_path;
 use crate::domain::prompt_assemble::{PromptAssetLoader, PromptContext, assemble_prompt};
 use crate::domain::{
     AppError, ConfigError, ControlPlaneConfig, Layer, MockConfig, MockOutput, RunOptions,
 };
 use crate::ports::{
     AutomationMode, Git, GitHub, JloStore, JulesClient, JulesStore, RepositoryFilesystem,
-    SessionRequest,
+    SessionRequest, SessionResponse,
 };
 
 use super::super::strategy::{JulesClientFactory, LayerStrategy, RunResult};
+use super::{SeedMode, load_branch_prefix};
 
 pub struct ImplementerLayer;
 
@@ -44,15 +46,37 @@ where
         client_factory: &dyn JulesClientFactory,
     ) -> Result<RunResult, AppError> {
         if runtime.mock {
-            let mock_config = load_mock_config(jules_path, repository)?;
-            let _output =
-                execute_mock(jules_path, target, runtime, &mock_config, git, github, repository)?;
+            if runtime.print_prompts
+                && let Some(requirement_path) = target.requirement.as_deref()
+            {
+                let requirement_content = read_requirement(requirement_path, None, repository)?;
+                let prompt = assemble_session_prompt(
+                    jules_path,
+                    &requirement_content,
+                    config.implementer.pr_body_template.as_deref(),
+                    repository,
+                    SeedMode::Report,
+                )?;
+                super::print_prompt_to_stderr(Layer::Implementer, None, &prompt);
+            }
+            let mock_config = load_mock_config(jules_path, repository, runtime.mock_seed)?;
+            let output = execute_mock(
+                jules_path,
+                target,
+                runtime,
+                &mock_config,
+                config.implementer.pr_body_template.as_deref(),
+                git,
+                github,
+                repository,
+            )?;
+            super::super::mock::mock_execution::finish_mock_output(&output, runtime.report_stream)?;
             let cleanup_requirement = target.requirement.clone();
-            // Mock output is written by execute_mock's service.finish()
             return Ok(RunResult {
                 roles: vec!["implementer".to_string()],
                 prompt_preview: false,
                 sessions: vec![],
+                session_urls: vec![],
                 cleanup_requirement,
             });
         }
@@ -60,8 +84,11 @@ where
         execute_real(
             jules_path,
             runtime.prompt_preview,
+            runtime.report_stream,
+            runtime.no_push,
             runtime.branch.as_deref(),
             target.requirement.as_deref(),
+            runtime.requirement_input.as_deref(),
             config,
             git,
             repository,
@@ -74,8 +101,11 @@ where
 fn execute_real<G, W>(
     jules_path: &Path,
     prompt_preview: bool,
+    report: ReportStream,
+    no_push: bool,
     branch: Option<&str>,
     requirement_path: Option<&Path>,
+    requirement_input: Option<&str>,
     config: &ControlPlaneConfig,
     git: &G,
     repository: &W,
@@ -95,18 +125,41 @@ where
     let requirement_path = requirement_path.ok_or_else(|| {
         AppError::MissingArgument("Requirement path is required for implementer".to_string())
     })?;
-    let requirement_info = validate_requirement_path(requirement_path, repository)?;
-
-    let requirement_content = repository.read_file(&requirement_info.requirement_path_str)?;
+    let requirement_content = read_requirement(requirement_path, requirement_input, repository)?;
 
     let starting_branch = resolve_starting_branch(Layer::Implementer, config, branch);
 
+    let pr_body_template = config.implementer.pr_body_template.as_deref();
+
     if prompt_preview {
-        execute_prompt_preview(jules_path, &starting_branch, &requirement_content, repository)?;
+        execute_prompt_preview(
+            jules_path,
+            &starting_branch,
+            &requirement_content,
+            pr_body_template,
+            repository,
+            report,
+        )?;
         return Ok(RunResult {
             roles: vec!["implementer".to_string()],
             prompt_preview: true,
             sessions: vec![],
+            session_urls: vec![],
+            cleanup_requirement: None,
+        });
+    }
+
+    // Jules pushes its own branch and opens the PR, so a real session cannot honour
+    // --no-push. The requirement is kept so the run can be repeated.
+    if no_push {
+        eprintln!(
+            "Skipping Jules session (--no-push): Jules pushes and opens a PR for every implementer session"
+        );
+        return Ok(RunResult {
+            roles: vec!["implementer".to_string()],
+            prompt_preview: false,
+            sessions: vec![],
+            session_urls: vec![],
             cleanup_requirement: None,
         });
     }
@@ -114,12 +167,13 @@ where
     let source = detect_repository_source(git)?;
     let client = client_factory.create()?;
 
-    let session_id = execute_session(
+    let response = execute_session(
         jules_path,
         &starting_branch,
         &source,
         client.as_ref(),
         &requirement_content,
+        pr_body_template,
         repository,
     )?;
 
@@ -127,8 +181,9 @@ where
     Ok(RunResult {
         roles: vec!["implementer".to_string()],
         prompt_preview: false,
-        sessions: vec![session_id],
-        cleanup_requirement: Some(PathBuf::from(requirement_info.requirement_path_str)),
+        sessions: vec![response.session_id],
+        session_urls: vec![response.url],
+        cleanup_requirement: Some(requirement_path.to_path_buf()),
     })
 }
 
@@ -148,14 +203,18 @@ fn execute_session<
     source: &str,
     client: &C,
     requirement_content: &str,
+    pr_body_template: Option<&str>,
     repository: &W,
-) -> Result<String, AppError> {
-    println!("Executing {}...", Layer::Implementer.display_name());
+) -> Result<SessionResponse, AppError> {
+    eprintln!("Executing {}...", Layer::Implementer.display_name());
 
-    let mut prompt = assemble_implementer_prompt(jules_path, requirement_content, repository)?;
-
-    prompt.push_str("\n---\n# Requirement Content\n");
-    prompt.push_str(requirement_content);
+    let prompt = assemble_session_prompt(
+        jules_path,
+        requirement_content,
+        pr_body_template,
+        repository,
+        SeedMode::Apply,
+    )?;
 
     let request = SessionRequest {
         prompt,
@@ -166,9 +225,9 @@ fn execute_session<
     };
 
     let response = client.create_session(request)?;
-    println!("  ✅ Session created: {}", response.session_id);
+    eprintln!("  ✅ Session created: {}", response.session_id);
 
-    Ok(response.session_id)
+    Ok(response)
 }
 
 fn assemble_implementer_prompt<
@@ -183,12 +242,31 @@ fn assemble_implementer_prompt<
 >(
     jules_path: &Path,
     requirement_content: &str,
+    pr_body_template: Option<&str>,
     repository: &W,
+    seed_mode: SeedMode,
 ) -> Result<String, AppError> {
     let label = extract_requirement_label(requirement_content)?;
     let task_content = resolve_implementer_task(&label)?;
 
-    let context = PromptContext::new().with_var("task", task_content);
+    // Jules opens the PR itself, so the configured body reaches it through the prompt.
+    // The branch name is only known once the session picks its short description.
+    let pr_body = match pr_body_template {
+        Some(template) => {
+            let branch =
+                format!("{}{}-<short_description>", load_branch_prefix("implementer")?, label);
+            let refs = parse_requirement_refs(requirement_content)?;
+            let body = render_configured_pr_body(template, &refs.id, &refs.source_events, &branch)?;
+            format!(
+                "Use the following text verbatim as the pull request description, filling in \
+                 `<short_description>` if it appears:\n\n{}",
+                body
+            )
+        }
+        None => String::new(),
+    };
+
+    let context = PromptContext::new().with_var("task", task_content).with_var("pr_body", pr_body);
 
     let (prompt, seed_ops) = assemble_prompt(
         jules_path,
@@ -198,10 +276,39 @@ fn assemble_implementer_prompt<
         crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset,
     )
     .map_err(|e| AppError::InternalError(e.to_string()))?;
-    super::execute_seed_ops(seed_ops, repository)?;
+    super::handle_seed_ops(seed_ops, repository, seed_mode)?;
     Ok(prompt.content)
 }
 
+/// Assemble the full session prompt: implementer prompt followed by the requirement.
+fn assemble_session_prompt<
+    W: RepositoryFilesystem
+        + JloStore
+        + JulesStore
+        + PromptAssetLoader
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    jules_path: &Path,
+    requirement_content: &str,
+    pr_body_template: Option<&str>,
+    repository: &W,
+    seed_mode: SeedMode,
+) -> Result<String, AppError> {
+    let mut prompt = assemble_implementer_prompt(
+        jules_path,
+        requirement_content,
+        pr_body_template,
+        repository,
+        seed_mode,
+    )?;
+    prompt.push_str("\n---\n# Requirement Content\n");
+    prompt.push_str(requirement_content);
+    Ok(prompt)
+}
+
 fn extract_requirement_label(requirement_content: &str) -> Result<String, AppError> {
     let value: serde_yaml::Value = serde_yaml::from_str(requirement_content)
         .map_err(|e| AppError::Validation(format!("Failed to parse requirement YAML: {}", e)))?;
@@ -248,30 +355,41 @@ fn execute_prompt_preview<
     jules_path: &Path,
     starting_branch: &str,
     requirement_content: &str,
+    pr_body_template: Option<&str>,
     repository: &W,
+    report: ReportStream,
 ) -> Result<(), AppError> {
-    println!("=== Prompt Preview: {} ===", Layer::Implementer.display_name());
-    println!("Starting branch: {}\n", starting_branch);
-    println!("Requirement content: {} chars\n", requirement_content.len());
+    report.line(format_args!("=== Prompt Preview: {} ===", Layer::Implementer.display_name()));
+    report.line(format_args!("Starting branch: {}\n", starting_branch));
+    report.line(format_args!("Requirement content: {} chars\n", requirement_content.len()));
 
-    println!("Prompt template: implementer/implementer_prompt.j2 (embedded)");
-    println!("Contracts: implementer/contracts.yml (embedded)");
+    report.line("Prompt template: implementer/implementer_prompt.j2 (embedded)");
+    report.line("Contracts: implementer/contracts.yml (embedded)");
 
-    let mut prompt = assemble_implementer_prompt(jules_path, requirement_content, repository)?;
-    prompt.push_str("\n---\n# Requirement Content\n");
-    prompt.push_str(requirement_content);
+    let prompt = assemble_session_prompt(
+        jules_path,
+        requirement_content,
+        pr_body_template,
+        repository,
+        SeedMode::Report,
+    )?;
 
-    println!("Assembled prompt: {} chars (Prompt + No Path + Requirement Content)", prompt.len());
+    report.line(format_args!(
+        "Assembled prompt: {} chars (Prompt + No Path + Requirement Content)",
+        prompt.len()
+    ));
 
-    println!("\nWould execute 1 session");
+    report.line("\nWould execute 1 session");
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn execute_mock<G, H, W>(
     jules_path: &Path,
     options: &RunOptions,
     runtime: &RunRuntimeOptions,
     config: &MockConfig,
+    pr_body_template: Option<&str>,
     git: &G,
     github: &H,
     repository: &W,
@@ -281,6 +399,7 @@ where
     H: GitHub + ?Sized,
     W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
 {
+    config.simulated_failure(Layer::Implementer, None)?;
     let service = MockExecutionService::new(jules_path, config, git, github, repository);
 
     let original_branch = git.get_current_branch()?;
@@ -309,7 +428,7 @@ where
     let prefix = config.branch_prefix(Layer::Implementer)?;
     let branch_name = format!("{}{}-{}", prefix, label, config.mock_tag);
 
-    println!("Mock implementer: creating branch {}", branch_name);
+    eprintln!("Mock implementer: creating branch {}", branch_name);
 
     // Fetch and checkout from default branch (not jules)
     let base_branch = runtime.branch.as_deref().unwrap_or(&config.jlo_target_branch);
@@ -322,36 +441,53 @@ where
         "# Mock implementation marker\n# Mock tag: {}\n# Requirement: {}\n# Created: {}\n",
         config.mock_tag,
         requirement_id,
-        Utc::now().to_rfc3339()
+        config.clock.now().to_rfc3339()
     );
 
     repository.write_file(&mock_file_path, &mock_content)?;
 
-    // Commit and push
     let mock_path = Path::new(&mock_file_path);
     let files: Vec<&Path> = vec![mock_path];
-    service.commit_and_push(
-        &format!("[{}] implementer: mock implementation", config.mock_tag),
-        &files,
-        &branch_name,
-    )?;
+    let commit_message = format!("[{}] implementer: mock implementation", config.mock_tag);
 
-    // Create PR targeting default branch (NOT jules)
-    let pr = service.create_pr(
-        &branch_name,
-        base_branch,
-        &format!("[{}] Implementation: {}", config.mock_tag, label),
-        &format!(
-            "Mock implementer run for workflow validation.\n\nMock tag: `{}`\nRequirement: `{}`\nLabel: `{}`\n\n⚠️ This PR targets `{}` (not `jules`) - requires human review.",
-            config.mock_tag,
-            requirement_id,
-            label,
-            base_branch
-        ),
-    )?;
+    let pr = if runtime.no_push {
+        service.git.commit_files(&commit_message, &files)?;
+        eprintln!(
+            "Mock implementer: --no-push set; committed to local branch {} (no push, no PR)",
+            branch_name
+        );
+        None
+    } else {
+        service.commit_and_push(&commit_message, &files, &branch_name)?;
+
+        let pr_body = match pr_body_template {
+            Some(template) => {
+                let refs = parse_requirement_refs(&requirement_content)?;
+                render_configured_pr_body(
+                    template,
+                    &requirement_id,
+                    &refs.source_events,
+                    &branch_name,
+                )?
+            }
+            None => format!(
+                "Mock implementer run for workflow validation.\n\nMock tag: `{}`\nRequirement: `{}`\nLabel: `{}`\n\n⚠️ This PR targets `{}` (not `jules`) - requires human review.",
+                config.mock_tag, requirement_id, label, base_branch
+            ),
+        };
 
-    // NOTE: Implementer PRs do NOT get auto-merge enabled
-    println!("Mock implementer: created PR #{} ({}) - awaiting label", pr.number, pr.url);
+        // Create PR targeting default branch (NOT jules)
+        let pr = service.create_pr(
+            &branch_name,
+            base_branch,
+            &format!("[{}] Implementation: {}", config.mock_tag, label),
+            &pr_body,
+        )?;
+
+        // NOTE: Implementer PRs do NOT get auto-merge enabled
+        eprintln!("Mock implementer: created PR #{} ({}) - awaiting label", pr.number, pr.url);
+        Some(pr)
+    };
 
     // Restore original branch so post-run cleanup (requirement + source events) runs on
     // the exchange-bearing branch instead of the implementer branch.
@@ -364,16 +500,48 @@ where
 
     let output = MockOutput {
         mock_branch: branch_name,
-        mock_pr_number: pr.number,
-        mock_pr_url: pr.url,
+        mock_pr_number: pr.as_ref().map_or(0, |pr| pr.number),
+        mock_pr_url: pr.map(|pr| pr.url).unwrap_or_default(),
         mock_tag: config.mock_tag.clone(),
     };
 
-    service.finish(&output)?;
-
     Ok(output)
 }
 
+/// Requirement fields referenced by the PR body template.
+#[derive(Deserialize)]
+struct RequirementRefs {
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    source_events: Vec<String>,
+}
+
+fn parse_requirement_refs(content: &str) -> Result<RequirementRefs, AppError> {
+    serde_yaml::from_str(content).map_err(|err| AppError::ParseError {
+        what: "requirement YAML".to_string(),
+        details: err.to_string(),
+    })
+}
+
+/// Render `[implementer] pr_body_template` for one requirement.
+fn render_configured_pr_body(
+    template: &str,
+    requirement_id: &str,
+    source_events: &[String],
+    branch: &str,
+) -> Result<String, AppError> {
+    render_pr_body(template, &PrBodyContext { requirement_id, source_events, branch }).map_err(
+        |reason| {
+            ConfigError::Invalid(format!(
+                "implementer.pr_body_template failed to render: {}",
+                reason
+            ))
+            .into()
+        },
+    )
+}
+
 fn parse_requirement_for_branch(content: &str, path: &Path) -> Result<(String, String), AppError> {
     #[derive(Deserialize)]
     struct RequirementMeta {
@@ -419,9 +587,11 @@ fn parse_requirement_for_branch(content: &str, path: &Path) -> Result<(String, S
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::MockClock;
     use crate::ports::RepositoryFilesystem;
     use crate::testing::{FakeGit, FakeGitHub, TestStore};
     use std::collections::HashMap;
+    use std::path::PathBuf;
 
     fn make_config() -> MockConfig {
         let mut prefixes = HashMap::new();
@@ -432,6 +602,8 @@ mod tests {
             jlo_target_branch: "main".to_string(),
             jules_worker_branch: "jules".to_string(),
             issue_labels: vec!["bugs".to_string()],
+            clock: MockClock::seeded(0),
+            fail_target: None,
         }
     }
 
@@ -451,16 +623,39 @@ mod tests {
             role: None,
             requirement: Some(req_path.clone()),
             task: None,
+            role_file: None,
         };
         let runtime = crate::app::commands::run::RunRuntimeOptions {
             prompt_preview: false,
             branch: None,
             mock: true,
             no_cleanup: false,
+            print_prompts: false,
+            dedupe_events: false,
+            allow_truncate: false,
+            mock_seed: None,
+            no_push: false,
+            mock_events: None,
+            record: None,
+            attribute_authors: false,
+            max_prs: None,
+            tag: None,
+            requirement_input: None,
+            breaker: None,
+            report_stream: Default::default(),
+            config: None,
         };
 
-        let result =
-            execute_mock(&jules_path, &options, &runtime, &config, &git, &github, &repository);
+        let result = execute_mock(
+            &jules_path,
+            &options,
+            &runtime,
+            &config,
+            None,
+            &git,
+            &github,
+            &repository,
+        );
         assert!(result.is_ok());
         let output = result.unwrap();
 
@@ -468,6 +663,216 @@ mod tests {
         assert_eq!(output.mock_pr_number, 101);
     }
 
+    struct UnreachableClientFactory;
+
+    impl JulesClientFactory for UnreachableClientFactory {
+        fn create(&self) -> Result<Box<dyn JulesClient>, AppError> {
+            panic!("--no-push must not create a Jules session");
+        }
+    }
+
+    #[test]
+    fn real_implementer_no_push_skips_session_and_keeps_requirement() {
+        let repository = TestStore::new().with_exists(true);
+        let req_path = PathBuf::from(".jules/exchange/requirements/req.yml");
+        repository.write_file(req_path.to_str().unwrap(), "id: abc123\nlabel: bugs\n").unwrap();
+
+        let result = execute_real(
+            Path::new(".jules"),
+            false,
+            ReportStream::Stdout,
+            true,
+            None,
+            Some(&req_path),
+            None,
+            &ControlPlaneConfig::default(),
+            &FakeGit::new(),
+            &repository,
+            &UnreachableClientFactory,
+        )
+        .expect("no-push run should succeed");
+
+        assert!(result.sessions.is_empty());
+        assert!(result.cleanup_requirement.is_none());
+    }
+
+    #[derive(Default)]
+    struct PromptCapturingClient {
+        prompt: std::sync::Mutex<Option<String>>,
+    }
+
+    impl JulesClient for PromptCapturingClient {
+        fn create_session(&self, request: SessionRequest) -> Result<SessionResponse, AppError> {
+            *self.prompt.lock().unwrap() = Some(request.prompt);
+            Ok(SessionResponse {
+                session_id: "sessions/1".to_string(),
+                status: "created".to_string(),
+                url: None,
+            })
+        }
+
+        fn health(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn real_implementer_session_prompt_carries_rendered_pr_body() {
+        let repository = TestStore::new().with_exists(true);
+        let client = PromptCapturingClient::default();
+        let template = "Resolves {{ requirement_id }} on {{ branch }}\n\
+            {% for event in source_events %}- {{ event }}\n{% endfor %}";
+
+        execute_session(
+            Path::new(".jules"),
+            "main",
+            "sources/github/owner/repo",
+            &client,
+            "id: abc123\nlabel: bugs\nsource_events: [ev0001]\n",
+            Some(template),
+            &repository,
+        )
+        .unwrap();
+
+        let prompt = client.prompt.lock().unwrap().clone().unwrap();
+        assert!(prompt.contains("# Pull Request Description"), "{}", prompt);
+        assert!(
+            prompt.contains(
+                "Resolves abc123 on jules-implementer-bugs-<short_description>\n- ev0001\n"
+            ),
+            "{}",
+            prompt
+        );
+    }
+
+    #[test]
+    fn real_implementer_session_prompt_omits_pr_body_without_template() {
+        let repository = TestStore::new().with_exists(true);
+        let client = PromptCapturingClient::default();
+
+        execute_session(
+            Path::new(".jules"),
+            "main",
+            "sources/github/owner/repo",
+            &client,
+            "id: abc123\nlabel: bugs\n",
+            None,
+            &repository,
+        )
+        .unwrap();
+
+        let prompt = client.prompt.lock().unwrap().clone().unwrap();
+        assert!(!prompt.contains("Pull Request Description"), "{}", prompt);
+    }
+
+    #[test]
+    fn pr_body_template_rejects_malformed_source_events() {
+        let err = parse_requirement_refs("id: abc123\nsource_events: not-a-list\n")
+            .err()
+            .expect("malformed source_events must not render an empty list");
+
+        assert!(matches!(err, AppError::ParseError { .. }), "{}", err);
+    }
+
+    #[test]
+    fn mock_implementer_no_push_commits_locally_without_push_or_pr() {
+        let jules_path = PathBuf::from(".jules");
+        let repository = TestStore::new().with_exists(true);
+        let git = FakeGit::new();
+        let github = FakeGitHub::new();
+        let config = make_config();
+
+        let req_path = PathBuf::from(".jules/exchange/requirements/req.yml");
+        repository.write_file(req_path.to_str().unwrap(), "id: abc123\nlabel: bugs\n").unwrap();
+
+        let options = RunOptions {
+            layer: Layer::Implementer,
+            role: None,
+            requirement: Some(req_path),
+            task: None,
+            role_file: None,
+        };
+        let runtime = crate::app::commands::run::RunRuntimeOptions {
+            mock: true,
+            no_push: true,
+            mock_events: None,
+            record: None,
+            attribute_authors: false,
+            max_prs: None,
+            tag: None,
+            requirement_input: None,
+            ..Default::default()
+        };
+
+        let output = execute_mock(
+            &jules_path,
+            &options,
+            &runtime,
+            &config,
+            None,
+            &git,
+            &github,
+            &repository,
+        )
+        .expect("no-push run should succeed");
+
+        assert!(output.mock_branch.starts_with("jules-implementer-bugs-"));
+        assert_eq!(output.mock_pr_number, 0);
+        assert!(!git.committed_files.lock().unwrap().is_empty());
+        assert!(git.pushed_branches.lock().unwrap().is_empty());
+        assert!(github.created_pull_requests.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn mock_implementer_renders_configured_pr_body_template() {
+        let jules_path = PathBuf::from(".jules");
+        let repository = TestStore::new().with_exists(true);
+        let git = FakeGit::new();
+        let github = FakeGitHub::new();
+        let config = make_config();
+
+        let req_path = PathBuf::from(".jules/exchange/requirements/req.yml");
+        repository
+            .write_file(
+                req_path.to_str().unwrap(),
+                "id: abc123\nlabel: bugs\nsource_events: [ev0001, ev0002]\n",
+            )
+            .unwrap();
+
+        let options = RunOptions {
+            layer: Layer::Implementer,
+            role: None,
+            requirement: Some(req_path),
+            task: None,
+            role_file: None,
+        };
+        let runtime =
+            crate::app::commands::run::RunRuntimeOptions { mock: true, ..Default::default() };
+        let template = "Resolves {{ requirement_id }} from {{ branch }}\n\n\
+            {% for event in source_events %}- [ ] {{ event }}\n{% endfor %}";
+
+        let output = execute_mock(
+            &jules_path,
+            &options,
+            &runtime,
+            &config,
+            Some(template),
+            &git,
+            &github,
+            &repository,
+        )
+        .unwrap();
+
+        let bodies = github.created_pull_request_bodies.lock().unwrap();
+        assert_eq!(
+            bodies.as_slice(),
+            [format!(
+                "Resolves abc123 from {}\n\n- [ ] ev0001\n- [ ] ev0002\n",
+                output.mock_branch
+            )]
+        );
+    }
+
     #[test]
     fn mock_implementer_fails_if_label_not_allowed() {
         let jules_path = PathBuf::from(".jules");
@@ -484,16 +889,39 @@ mod tests {
             role: None,
             requirement: Some(req_path),
             task: None,
+            role_file: None,
         };
         let runtime = crate::app::commands::run::RunRuntimeOptions {
             prompt_preview: false,
             branch: None,
             mock: true,
             no_cleanup: false,
+            print_prompts: false,
+            dedupe_events: false,
+            allow_truncate: false,
+            mock_seed: None,
+            no_push: false,
+            mock_events: None,
+            record: None,
+            attribute_authors: false,
+            max_prs: None,
+            tag: None,
+            requirement_input: None,
+            breaker: None,
+            report_stream: Default::default(),
+            config: None,
         };
 
-        let result =
-            execute_mock(&jules_path, &options, &runtime, &config, &git, &github, &repository);
+        let result = execute_mock(
+            &jules_path,
+            &options,
+            &runtime,
+            &config,
+            None,
+            &git,
+            &github,
+            &repository,
+        );
         assert!(result.is_err());
         assert!(
             matches!(result, Err(AppError::Config(ConfigError::Invalid(ref msg))) if msg.contains("not defined in github-labels.json"))
@@ -1,10 +1,8 @@
 use std::path::Path;
 
-use chrono::Utc;
-
-use super::super::mock::mock_execution::{MOCK_ASSETS, generate_mock_id};
-use crate::app::commands::run::RunRuntimeOptions;
+use super::super::mock::mock_execution::MOCK_ASSETS;
 use crate::app::commands::run::input::{detect_repository_source, load_mock_config};
+use crate::app::commands::run::{ReportStream, RunRuntimeOptions};
 use crate::domain::layers::execute::starting_branch::resolve_starting_branch;
 use crate::domain::prompt_assemble::{PromptAssetLoader, PromptContext, assemble_prompt};
 use crate::domain::{
@@ -14,6 +12,7 @@ use crate::ports::{Git, GitHub, JloStore, JulesStore, RepositoryFilesystem};
 
 use super::super::role_session::{dispatch_session, print_role_preview, validate_role_exists};
 use super::super::strategy::{JulesClientFactory, LayerStrategy, RunResult};
+use super::SeedMode;
 
 pub struct InnovatorsLayer;
 
@@ -50,21 +49,28 @@ where
                 )
             })?;
 
-            let mock_config = load_mock_config(jules_path, repository)?;
+            if runtime.print_prompts {
+                let task_content = resolve_innovator_task(task)?;
+                let prompt = assemble_innovator_prompt(
+                    jules_path,
+                    role.as_str(),
+                    task,
+                    &task_content,
+                    repository,
+                    SeedMode::Report,
+                )?;
+                super::print_prompt_to_stderr(Layer::Innovators, Some(role.as_str()), &prompt);
+            }
+
+            let mock_config = load_mock_config(jules_path, repository, runtime.mock_seed)?;
             let output =
                 execute_mock(jules_path, &role, task, &mock_config, git, github, repository)?;
-            // Write mock output
-            if std::env::var("GITHUB_OUTPUT").is_ok() {
-                super::super::mock::mock_execution::write_github_output(&output).map_err(|e| {
-                    AppError::InternalError(format!("Failed to write GITHUB_OUTPUT: {}", e))
-                })?;
-            } else {
-                super::super::mock::mock_execution::print_local(&output);
-            }
+            super::super::mock::mock_execution::finish_mock_output(&output, runtime.report_stream)?;
             return Ok(RunResult {
                 roles: vec![target.role.clone().unwrap_or_else(|| "mock".to_string())],
                 prompt_preview: false,
                 sessions: vec![],
+                session_urls: vec![],
                 cleanup_requirement: None,
             });
         }
@@ -72,6 +78,7 @@ where
         execute_real(
             jules_path,
             runtime.prompt_preview,
+            runtime.report_stream,
             runtime.branch.as_deref(),
             target.role.as_deref(),
             target.task.as_deref(),
@@ -87,6 +94,7 @@ where
 fn execute_real<G, W>(
     jules_path: &Path,
     prompt_preview: bool,
+    report: ReportStream,
     branch: Option<&str>,
     role: Option<&str>,
     task: Option<&str>,
@@ -122,30 +130,45 @@ where
     let task_content = resolve_innovator_task(task)?;
 
     if prompt_preview {
-        print_role_preview(jules_path, Layer::Innovators, &role_id, &starting_branch, repository);
+        print_role_preview(
+            jules_path,
+            Layer::Innovators,
+            &role_id,
+            &starting_branch,
+            repository,
+            report,
+        );
         let assembled = assemble_innovator_prompt(
             jules_path,
             role_id.as_str(),
             task,
             &task_content,
             repository,
+            SeedMode::Report,
         )?;
-        println!("  Assembled prompt: {} chars", assembled.len());
-        println!("\nWould execute 1 session");
+        report.line(format_args!("  Assembled prompt: {} chars", assembled.len()));
+        report.line("\nWould execute 1 session");
         return Ok(RunResult {
             roles: vec![role.to_string()],
             prompt_preview: true,
             sessions: vec![],
+            session_urls: vec![],
             cleanup_requirement: None,
         });
     }
 
     let source = detect_repository_source(git)?;
-    let assembled =
-        assemble_innovator_prompt(jules_path, role_id.as_str(), task, &task_content, repository)?;
+    let assembled = assemble_innovator_prompt(
+        jules_path,
+        role_id.as_str(),
+        task,
+        &task_content,
+        repository,
+        SeedMode::Apply,
+    )?;
     let client = client_factory.create()?;
 
-    let session_id = dispatch_session(
+    let response = dispatch_session(
         Layer::Innovators,
         &role_id,
         assembled,
@@ -157,7 +180,8 @@ where
     Ok(RunResult {
         roles: vec![role.to_string()],
         prompt_preview: false,
-        sessions: vec![session_id],
+        sessions: vec![response.session_id],
+        session_urls: vec![response.url],
         cleanup_requirement: None,
     })
 }
@@ -177,6 +201,7 @@ fn assemble_innovator_prompt<
     task_name: &str,
     task: &str,
     repository: &W,
+    seed_mode: SeedMode,
 ) -> Result<String, AppError> {
     let context = PromptContext::new()
         .with_var("role", role)
@@ -191,7 +216,7 @@ fn assemble_innovator_prompt<
         crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset,
     )
     .map_err(|e| AppError::InternalError(e.to_string()))?;
-    super::execute_seed_ops(seed_ops, repository)?;
+    super::handle_seed_ops(seed_ops, repository, seed_mode)?;
     Ok(prompt.content)
 }
 
@@ -241,6 +266,7 @@ where
     H: GitHub + ?Sized,
     W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
 {
+    config.simulated_failure(Layer::Innovators, Some(role.as_str()))?;
     if task != "create_three_proposals" {
         return Err(AppError::Validation(format!(
             "Invalid innovator task '{}': expected create_three_proposals",
@@ -254,17 +280,17 @@ where
         .ok_or_else(|| AppError::Validation("Invalid proposals path".to_string()))?;
     repository.create_dir_all(proposals_dir_str)?;
 
-    let timestamp = Utc::now().format("%Y%m%d%H%M%S").to_string();
+    let timestamp = config.clock.now().format("%Y%m%d%H%M%S").to_string();
     let branch_name = config.branch_name(Layer::Innovators, &timestamp)?;
 
     git.fetch("origin")?;
     git.checkout_branch(&format!("origin/{}", config.jules_worker_branch), false)?;
     git.checkout_branch(&branch_name, true)?;
 
-    println!("Mock innovators: task={} for {}", task, role);
+    eprintln!("Mock innovators: task={} for {}", task, role);
 
     let safe_tag = sanitize_yaml_value(&config.mock_tag);
-    let today = Utc::now().format("%Y-%m-%d").to_string();
+    let today = config.clock.now().format("%Y-%m-%d").to_string();
     let mut created_paths = Vec::new();
     let proposal_template = load_mock_asset_text("innovator_proposal.yml")?;
 
@@ -280,7 +306,7 @@ where
             .ok_or_else(|| AppError::Validation("Invalid proposal path".to_string()))?;
         let proposal_title = format!("Mock proposal {} for {}", index, role);
         let proposal_content = proposal_template
-            .replace("__ID__", &generate_mock_id())
+            .replace("__ID__", &config.clock.next_id())
             .replace("__ROLE__", role.as_str())
             .replace("__DATE__", &today)
             .replace("__TITLE__", &proposal_title)
@@ -306,7 +332,7 @@ where
         ),
     )?;
 
-    println!("Mock innovators: created PR #{} ({})", pr.number, pr.url);
+    eprintln!("Mock innovators: created PR #{} ({})", pr.number, pr.url);
 
     Ok(MockOutput {
         mock_branch: branch_name,
@@ -319,6 +345,7 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::MockClock;
     use crate::ports::RepositoryFilesystem;
     use crate::testing::{FakeGit, FakeGitHub, TestStore};
     use std::collections::HashMap;
@@ -333,6 +360,8 @@ mod tests {
             jlo_target_branch: "main".to_string(),
             jules_worker_branch: "jules".to_string(),
             issue_labels: vec![],
+            clock: MockClock::seeded(0),
+            fail_target: None,
         }
     }
 
@@ -1,9 +1,9 @@
 use std::path::Path;
+use std::thread;
+use std::time::Duration;
 
-use serde::Deserialize;
-
-use crate::app::commands::run::RunRuntimeOptions;
 use crate::app::commands::run::input::detect_repository_source;
+use crate::app::commands::run::{ReportStream, RunRuntimeOptions};
 use crate::domain::layers::execute::starting_branch::resolve_starting_branch;
 use crate::domain::prompt_assemble::{PromptAssetLoader, PromptContext, assemble_prompt};
 use crate::domain::validation::validate_identifier;
@@ -13,12 +13,18 @@ use crate::ports::{
 };
 
 use super::super::strategy::{JulesClientFactory, LayerStrategy, RunResult};
+use super::{SeedMode, load_branch_prefix};
 
-#[derive(Deserialize)]
-struct ContractFile {
-    branch_prefix: String,
+/// How long `--tag` waits for the integrator session to push its integration branch.
+#[derive(Debug, Clone, Copy)]
+struct TagWait {
+    attempts: usize,
+    interval: Duration,
 }
 
+/// Poll every 30 seconds for up to an hour.
+const TAG_WAIT: TagWait = TagWait { attempts: 120, interval: Duration::from_secs(30) };
+
 pub struct IntegratorLayer;
 
 impl<W> LayerStrategy<W> for IntegratorLayer
@@ -50,23 +56,34 @@ where
         execute_real(
             jules_path,
             runtime.prompt_preview,
+            runtime.report_stream,
+            runtime.no_push,
             runtime.branch.as_deref(),
+            runtime.max_prs.or(config.run.integrator_max_prs),
+            runtime.tag.as_deref(),
             config,
             git,
             repository,
             client_factory,
+            TAG_WAIT,
         )
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn execute_real<G, W>(
     jules_path: &Path,
     prompt_preview: bool,
+    report: ReportStream,
+    no_push: bool,
     branch: Option<&str>,
+    max_prs: Option<usize>,
+    tag: Option<&str>,
     config: &ControlPlaneConfig,
     git: &G,
     repository: &W,
     client_factory: &dyn JulesClientFactory,
+    tag_wait: TagWait,
 ) -> Result<RunResult, AppError>
 where
     G: Git + ?Sized,
@@ -89,24 +106,46 @@ where
         )));
     }
 
+    // Refuse an existing tag before any session is created
+    if let Some(tag) = tag {
+        ensure_tag_available(git, tag)?;
+    }
+
     let starting_branch = resolve_starting_branch(Layer::Integrator, config, branch);
 
     // Resolve implementer branch prefix from its contracts for discovery
-    let implementer_prefix = load_implementer_branch_prefix()?;
+    let implementer_prefix = load_branch_prefix("implementer")?;
 
     // Preflight: discover candidate branches before Jules API session creation
     let candidates = discover_candidate_branches(git, &implementer_prefix)?;
+    enforce_max_prs(&candidates, max_prs)?;
+    report_merge_conflicts(git, &starting_branch, &candidates)?;
+
+    // The integrator session pushes and opens its PR from Jules, so --no-push stops
+    // after the local trial merges.
+    if no_push && !prompt_preview {
+        eprintln!(
+            "Skipping Jules session (--no-push): Jules pushes and opens a PR for every integrator session"
+        );
+        return Ok(RunResult {
+            roles: vec!["integrator".to_string()],
+            prompt_preview: false,
+            sessions: vec![],
+            session_urls: vec![],
+            cleanup_requirement: None,
+        });
+    }
 
     let source = detect_repository_source(git)?;
 
     if prompt_preview {
-        println!("=== Prompt Preview: Integrator ===");
-        println!("Starting branch: {}", starting_branch);
-        println!("Candidate branches ({}):", candidates.len());
+        report.line("=== Prompt Preview: Integrator ===");
+        report.line(format_args!("Starting branch: {}", starting_branch));
+        report.line(format_args!("Candidate branches ({}):", candidates.len()));
         for branch_name in &candidates {
-            println!("  - {}", branch_name);
+            report.line(format_args!("  - {}", branch_name));
         }
-        println!();
+        report.line("");
 
         let prompt = assemble_integrator_prompt(
             jules_path,
@@ -114,21 +153,36 @@ where
             &candidates,
             &source,
             repository,
+            SeedMode::Report,
         )?;
-        println!("{}", prompt);
+        report.line(&prompt);
 
         return Ok(RunResult {
             roles: vec!["integrator".to_string()],
             prompt_preview: true,
             sessions: vec![],
+            session_urls: vec![],
             cleanup_requirement: None,
         });
     }
 
     let client = client_factory.create()?;
 
-    let prompt =
-        assemble_integrator_prompt(jules_path, &starting_branch, &candidates, &source, repository)?;
+    // Integration branches that predate this session are never tagged
+    let integrator_prefix = load_branch_prefix("integrator")?;
+    let existing_integration_branches = match tag {
+        Some(_) => list_remote_branches(git, &integrator_prefix)?,
+        None => Vec::new(),
+    };
+
+    let prompt = assemble_integrator_prompt(
+        jules_path,
+        &starting_branch,
+        &candidates,
+        &source,
+        repository,
+        SeedMode::Apply,
+    )?;
 
     let request = SessionRequest {
         prompt,
@@ -138,62 +192,39 @@ where
         automation_mode: AutomationMode::AutoCreatePr,
     };
 
-    println!("Executing: integrator ({} candidate branches)...", candidates.len());
+    eprintln!("Executing: integrator ({} candidate branches)...", candidates.len());
     let response = client.create_session(request)?;
-    println!("  ✅ Session created: {}", response.session_id);
+    eprintln!("  ✅ Session created: {}", response.session_id);
+
+    if let Some(tag) = tag {
+        tag_integration_commit(
+            git,
+            tag,
+            &integrator_prefix,
+            &existing_integration_branches,
+            candidates.len(),
+            tag_wait,
+        )?;
+    }
 
     Ok(RunResult {
         roles: vec!["integrator".to_string()],
         prompt_preview: false,
         sessions: vec![response.session_id],
+        session_urls: vec![response.url],
         cleanup_requirement: None,
     })
 }
 
-/// Read the implementer branch prefix from its contracts.yml to drive discovery.
-fn load_implementer_branch_prefix() -> Result<String, AppError> {
-    let content = crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset(
-        "implementer/contracts.yml",
-    )
-    .ok_or_else(|| {
-        AppError::Validation(
-            "Cannot read implementer contracts from embedded catalog: required for branch discovery"
-                .to_string(),
-        )
-    })?;
-
-    let contract: ContractFile = serde_yaml::from_str(&content)
-        .map_err(|e| AppError::Validation(format!("Invalid implementer contracts YAML: {}", e)))?;
-
-    if contract.branch_prefix.trim().is_empty() {
-        return Err(AppError::Validation(
-            "Implementer contracts.yml has an empty 'branch_prefix' field".to_string(),
-        ));
-    }
-
-    Ok(contract.branch_prefix)
-}
-
 /// Discover remote implementer branches matching the branch prefix policy.
 ///
+/// Fetches with prune first so deleted remote branches are not enumerated.
 /// Fails explicitly if no candidate branches exist.
 fn discover_candidate_branches<G: Git + ?Sized>(
     git: &G,
     implementer_prefix: &str,
 ) -> Result<Vec<String>, AppError> {
-    git.fetch("origin")?;
-
-    let pattern = format!("origin/{}*", implementer_prefix);
-    let output =
-        git.run_command(&["branch", "-r", "--list", &pattern, "--format=%(refname:short)"], None)?;
-
-    let candidates: Vec<String> = output
-        .lines()
-        .map(|line| line.trim())
-        .filter(|line| !line.is_empty())
-        .map(|line| line.strip_prefix("origin/").unwrap_or(line).to_string())
-        .filter(|name| validate_identifier(name, false))
-        .collect();
+    let candidates = list_remote_branches(git, implementer_prefix)?;
 
     if candidates.is_empty() {
         return Err(AppError::Validation(format!(
@@ -202,7 +233,7 @@ fn discover_candidate_branches<G: Git + ?Sized>(
         )));
     }
 
-    println!(
+    eprintln!(
         "Preflight: discovered {} candidate branch(es): {}",
         candidates.len(),
         candidates.join(", ")
@@ -211,6 +242,124 @@ fn discover_candidate_branches<G: Git + ?Sized>(
     Ok(candidates)
 }
 
+/// Fetch with prune and list `origin` branches starting with `prefix`, without the remote name.
+fn list_remote_branches<G: Git + ?Sized>(git: &G, prefix: &str) -> Result<Vec<String>, AppError> {
+    git.fetch_prune("origin")?;
+
+    let pattern = format!("origin/{}*", prefix);
+    let output =
+        git.run_command(&["branch", "-r", "--list", &pattern, "--format=%(refname:short)"], None)?;
+
+    Ok(output
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty())
+        .map(|line| line.strip_prefix("origin/").unwrap_or(line).to_string())
+        .filter(|name| validate_identifier(name, false))
+        .collect())
+}
+
+/// Fail with [`AppError::TagExists`] when `tag` already names a local or fetched tag.
+fn ensure_tag_available<G: Git + ?Sized>(git: &G, tag: &str) -> Result<(), AppError> {
+    if tag.is_empty() || tag.starts_with('-') || tag.chars().any(char::is_whitespace) {
+        return Err(AppError::Validation(format!("Invalid tag name '{}'", tag)));
+    }
+    if git.rev_parse(&format!("refs/tags/{}", tag)).is_ok() {
+        return Err(AppError::TagExists(tag.to_string()));
+    }
+    Ok(())
+}
+
+/// Wait for the session's integration branch to reach `origin`, then tag its head and push the tag.
+///
+/// Branches listed in `existing` predate the session and are ignored.
+fn tag_integration_commit<G: Git + ?Sized>(
+    git: &G,
+    tag: &str,
+    integrator_prefix: &str,
+    existing: &[String],
+    candidate_count: usize,
+    wait: TagWait,
+) -> Result<(), AppError> {
+    for attempt in 0..wait.attempts {
+        if attempt > 0 {
+            thread::sleep(wait.interval);
+        }
+        let branches = list_remote_branches(git, integrator_prefix)?;
+        let Some(branch) = branches.iter().find(|branch| !existing.contains(branch)) else {
+            continue;
+        };
+
+        let sha = git.rev_parse(&format!("origin/{}", branch))?;
+        let message =
+            format!("Integration of {} implementer branch(es) on {}", candidate_count, branch);
+        git.create_tag(tag, Some(&message), Some(&sha))?;
+        git.run_command(&["push", "origin", &format!("refs/tags/{}", tag)], None)?;
+        eprintln!("  🏷️  Tagged {} at {} ({})", tag, sha, branch);
+        return Ok(());
+    }
+
+    Err(AppError::Validation(format!(
+        "No new {}* branch appeared on origin after {} checks; tag '{}' was not created",
+        integrator_prefix, wait.attempts, tag
+    )))
+}
+
+/// Refuse to integrate more candidates than the operator allowed.
+fn enforce_max_prs(candidates: &[String], max_prs: Option<usize>) -> Result<(), AppError> {
+    match max_prs {
+        Some(max) if candidates.len() > max => Err(AppError::Validation(format!(
+            "Integrator found {} candidate branches, exceeding the cap of {} (--max-prs / run.integrator_max_prs); review them before integrating: {}",
+            candidates.len(),
+            max,
+            candidates.join(", ")
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Trial-merge candidates onto the starting branch in a detached workspace and report conflicts.
+///
+/// Conflicts are informational; the integrator session still receives every candidate.
+fn report_merge_conflicts<G: Git + ?Sized>(
+    git: &G,
+    starting_branch: &str,
+    candidates: &[String],
+) -> Result<(), AppError> {
+    let workspace = match git.create_workspace(&format!("origin/{}", starting_branch)) {
+        Ok(workspace) => workspace,
+        Err(e) => {
+            eprintln!("Preflight: skipping trial merges ({})", e);
+            return Ok(());
+        }
+    };
+
+    let conflicts = trial_merge_candidates(workspace.as_ref(), candidates)?;
+    for branch_name in &conflicts {
+        eprintln!("Preflight: {} does not merge cleanly into {}", branch_name, starting_branch);
+    }
+    Ok(())
+}
+
+/// Attempt a non-committing merge of each candidate, returning those that fail.
+///
+/// The merge is aborted after every attempt so the next candidate starts from a clean tree.
+fn trial_merge_candidates<G: Git + ?Sized>(
+    git: &G,
+    candidates: &[String],
+) -> Result<Vec<String>, AppError> {
+    let mut conflicts = Vec::new();
+    for branch_name in candidates {
+        let source = format!("origin/{}", branch_name);
+        let merged = git.run_command(&["merge", "--no-commit", "--no-ff", &source], None);
+        git.abort_merge()?;
+        if merged.is_err() {
+            conflicts.push(branch_name.clone());
+        }
+    }
+    Ok(conflicts)
+}
+
 fn assemble_integrator_prompt<
     W: RepositoryFilesystem
         + JloStore
@@ -226,6 +375,7 @@ fn assemble_integrator_prompt<
     candidates: &[String],
     source: &str,
     repository: &W,
+    seed_mode: SeedMode,
 ) -> Result<String, AppError> {
     let candidate_list =
         candidates.iter().map(|b| format!("- {}", b)).collect::<Vec<_>>().join("\n");
@@ -243,6 +393,171 @@ fn assemble_integrator_prompt<
         crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset,
     )
     .map_err(|e| AppError::InternalError(e.to_string()))?;
-    super::execute_seed_ops(seed_ops, repository)?;
+    super::handle_seed_ops(seed_ops, repository, seed_mode)?;
     Ok(prompt.content)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{FakeGit, TestStore};
+
+    #[test]
+    fn discovery_prunes_before_enumerating_branches() {
+        let git = FakeGit::new();
+
+        let result = discover_candidate_branches(&git, "jules-implementer-");
+
+        assert!(result.is_err());
+        let commands = git.commands.lock().unwrap();
+        assert_eq!(commands[0], "fetch --prune origin");
+        assert!(commands[1].starts_with("branch -r --list origin/jules-implementer-*"));
+    }
+
+    struct UnreachableClientFactory;
+
+    impl JulesClientFactory for UnreachableClientFactory {
+        fn create(&self) -> Result<Box<dyn crate::ports::JulesClient>, AppError> {
+            panic!("integrator must abort before creating a Jules session");
+        }
+    }
+
+    #[test]
+    fn run_aborts_when_candidates_exceed_max_prs() {
+        let git = FakeGit::new();
+        let implementer_prefix = load_branch_prefix("implementer").unwrap();
+        for slug in ["a", "b", "c"] {
+            git.remote_heads.lock().unwrap().push(format!("{}{}", implementer_prefix, slug));
+        }
+
+        let err = execute_real(
+            Path::new(".jules"),
+            false,
+            ReportStream::Stdout,
+            false,
+            None,
+            Some(2),
+            None,
+            &ControlPlaneConfig::default(),
+            &git,
+            &TestStore::new(),
+            &UnreachableClientFactory,
+            TAG_WAIT,
+        )
+        .unwrap_err();
+
+        let message = err.to_string();
+        assert!(
+            message.contains("found 3 candidate branches, exceeding the cap of 2"),
+            "{}",
+            message
+        );
+        assert!(message.contains(&format!("{}c", implementer_prefix)), "{}", message);
+        assert!(!git.commands.lock().unwrap().iter().any(|command| command.starts_with("merge")));
+    }
+
+    #[test]
+    fn no_push_skips_session_after_preflight() {
+        let git = FakeGit::new();
+        let implementer_prefix = load_branch_prefix("implementer").unwrap();
+        git.remote_heads.lock().unwrap().push(format!("{}a", implementer_prefix));
+
+        let result = execute_real(
+            Path::new(".jules"),
+            false,
+            ReportStream::Stdout,
+            true,
+            None,
+            None,
+            None,
+            &ControlPlaneConfig::default(),
+            &git,
+            &TestStore::new(),
+            &UnreachableClientFactory,
+            TAG_WAIT,
+        )
+        .unwrap();
+
+        assert!(result.sessions.is_empty());
+        assert!(!result.prompt_preview);
+    }
+
+    #[test]
+    fn run_rejects_existing_tag_before_creating_a_session() {
+        let git = FakeGit::new();
+        git.refs.lock().unwrap().insert("refs/tags/release-1".to_string(), "abc123".to_string());
+
+        let err = execute_real(
+            Path::new(".jules"),
+            false,
+            ReportStream::Stdout,
+            false,
+            None,
+            None,
+            Some("release-1"),
+            &ControlPlaneConfig::default(),
+            &git,
+            &TestStore::new(),
+            &UnreachableClientFactory,
+            TAG_WAIT,
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, AppError::TagExists(ref name) if name == "release-1"), "{}", err);
+        assert!(git.commands.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn tag_marks_head_of_the_new_integration_branch() {
+        let git = FakeGit::new();
+        let integrator_prefix = load_branch_prefix("integrator").unwrap();
+        let old_branch = format!("{}old", integrator_prefix);
+        let new_branch = format!("{}new", integrator_prefix);
+        git.remote_heads.lock().unwrap().extend([old_branch.clone(), new_branch.clone()]);
+        git.refs.lock().unwrap().insert(format!("origin/{}", old_branch), "old000".to_string());
+        git.refs.lock().unwrap().insert(format!("origin/{}", new_branch), "new111".to_string());
+        let wait = TagWait { attempts: 1, interval: Duration::ZERO };
+
+        tag_integration_commit(&git, "release-1", &integrator_prefix, &[old_branch], 2, wait)
+            .unwrap();
+
+        assert_eq!(git.rev_parse("refs/tags/release-1").unwrap(), "new111");
+        assert!(
+            git.commands.lock().unwrap().iter().any(|c| c == "push origin refs/tags/release-1"),
+            "tag must be pushed"
+        );
+    }
+
+    #[test]
+    fn tag_fails_when_no_integration_branch_appears() {
+        let git = FakeGit::new();
+        let wait = TagWait { attempts: 2, interval: Duration::ZERO };
+
+        let err = tag_integration_commit(&git, "release-1", "jules-integrator-", &[], 1, wait)
+            .unwrap_err();
+
+        assert!(err.to_string().contains("tag 'release-1' was not created"), "{}", err);
+        assert!(git.rev_parse("refs/tags/release-1").is_err());
+    }
+
+    #[test]
+    fn max_prs_allows_candidates_up_to_the_cap() {
+        let candidates = vec!["jules-implementer-a".to_string(), "jules-implementer-b".to_string()];
+
+        assert!(enforce_max_prs(&candidates, Some(2)).is_ok());
+        assert!(enforce_max_prs(&candidates, None).is_ok());
+        assert!(enforce_max_prs(&candidates, Some(1)).is_err());
+    }
+
+    #[test]
+    fn trial_merge_aborts_after_conflicting_merge() {
+        let git = FakeGit::new();
+        git.conflicting_merges.lock().unwrap().push("origin/jules-implementer-b".to_string());
+        let candidates = vec!["jules-implementer-a".to_string(), "jules-implementer-b".to_string()];
+
+        let conflicts = trial_merge_candidates(&git, &candidates).unwrap();
+
+        assert_eq!(conflicts, vec!["jules-implementer-b".to_string()]);
+        assert_eq!(*git.merge_aborts.lock().unwrap(), 2);
+    }
+}
@@ -6,18 +6,91 @@ pub mod narrator;
 pub mod observers;
 pub mod planner;
 
-use crate::domain::AppError;
+use serde::Deserialize;
+
 use crate::domain::prompt_assemble::{PromptAssemblyError, PromptAssetLoader, SeedOp};
+use crate::domain::{AppError, Layer};
+
+#[derive(Deserialize)]
+struct ContractFile {
+    branch_prefix: String,
+}
+
+/// Read a layer's branch prefix from its embedded contracts.yml.
+pub(super) fn load_branch_prefix(layer: &str) -> Result<String, AppError> {
+    let content = crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset(
+        &format!("{}/contracts.yml", layer),
+    )
+    .ok_or_else(|| {
+        AppError::Validation(format!(
+            "Cannot read {} contracts from embedded catalog: required for branch discovery",
+            layer
+        ))
+    })?;
+
+    let contract: ContractFile = serde_yaml::from_str(&content)
+        .map_err(|e| AppError::Validation(format!("Invalid {} contracts YAML: {}", layer, e)))?;
+
+    if contract.branch_prefix.trim().is_empty() {
+        return Err(AppError::Validation(format!(
+            "{} contracts.yml has an empty 'branch_prefix' field",
+            layer
+        )));
+    }
+
+    Ok(contract.branch_prefix)
+}
+
+/// How deferred seed operations are handled after prompt assembly.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(super) enum SeedMode {
+    /// Copy schema files into place.
+    Apply,
+    /// List the copies a run would perform on stderr without touching the filesystem
+    /// (prompt preview, `--print-prompts`).
+    Report,
+}
+
+/// Apply or report deferred seed operations according to `mode`.
+pub(super) fn handle_seed_ops<L: PromptAssetLoader>(
+    ops: Vec<SeedOp>,
+    loader: &L,
+    mode: SeedMode,
+) -> Result<(), AppError> {
+    match mode {
+        SeedMode::Apply => execute_seed_ops(ops, loader),
+        SeedMode::Report => {
+            for line in describe_seed_ops(&ops) {
+                eprintln!("{}", line);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Render seed operations as preview lines.
+fn describe_seed_ops(ops: &[SeedOp]) -> Vec<String> {
+    if ops.is_empty() {
+        return Vec::new();
+    }
+    let mut lines = vec![format!("  Seed ops (not applied in preview): {}", ops.len())];
+    for op in ops {
+        lines.push(format!(
+            "    {} -> {}{}",
+            op.from.display(),
+            op.to.display(),
+            if op.required { "" } else { " (optional)" }
+        ));
+    }
+    lines
+}
 
 /// Execute deferred seed operations collected during prompt assembly.
 ///
 /// For each [`SeedOp`], ensures the destination directory exists and copies the
 /// schema file to the target path. Required ops propagate errors; optional ops
 /// ignore failures silently.
-pub(super) fn execute_seed_ops<L: PromptAssetLoader>(
-    ops: Vec<SeedOp>,
-    loader: &L,
-) -> Result<(), AppError> {
+fn execute_seed_ops<L: PromptAssetLoader>(ops: Vec<SeedOp>, loader: &L) -> Result<(), AppError> {
     for op in ops {
         let make_error = |err: std::io::Error| {
             AppError::PromptAssembly(PromptAssemblyError::SchemaSeedError {
@@ -43,3 +116,13 @@ pub(super) fn execute_seed_ops<L: PromptAssetLoader>(
     }
     Ok(())
 }
+
+/// Print an assembled prompt to stderr so stdout stays reserved for machine output.
+pub(super) fn print_prompt_to_stderr(layer: Layer, role: Option<&str>, prompt: &str) {
+    match role {
+        Some(role) => eprintln!("=== Prompt: {} / {} ===", layer.display_name(), role),
+        None => eprintln!("=== Prompt: {} ===", layer.display_name()),
+    }
+    eprintln!("{}", prompt);
+    eprintln!("=== End Prompt ===");
+}
@@ -1,15 +1,17 @@
 use std::path::Path;
 
-use crate::app::commands::run::RunRuntimeOptions;
 use crate::app::commands::run::input::{detect_repository_source, load_mock_config};
+use crate::app::commands::run::{ReportStream, RunRuntimeOptions};
 use crate::domain::layers::execute::starting_branch::resolve_starting_branch;
 use crate::domain::prompt_assemble::{PromptAssetLoader, PromptContext, assemble_prompt};
 use crate::domain::{AppError, ControlPlaneConfig, Layer, MockConfig, MockOutput, RunOptions};
 use crate::ports::{
-    AutomationMode, Git, GitHub, JloStore, JulesStore, RepositoryFilesystem, SessionRequest,
+    AutomationMode, CommitInfo, Git, GitHub, JloStore, JulesStore, RepositoryFilesystem,
+    SessionRequest,
 };
 
 use super::super::strategy::{JulesClientFactory, LayerStrategy, RunResult};
+use super::SeedMode;
 
 pub struct NarratorLayer;
 
@@ -36,20 +38,20 @@ where
         client_factory: &dyn JulesClientFactory,
     ) -> Result<RunResult, AppError> {
         if runtime.mock {
-            let mock_config = load_mock_config(jules_path, repository)?;
-            let output = execute_mock(&mock_config)?;
-            // Write mock output
-            if std::env::var("GITHUB_OUTPUT").is_ok() {
-                super::super::mock::mock_execution::write_github_output(&output).map_err(|e| {
-                    AppError::InternalError(format!("Failed to write GITHUB_OUTPUT: {}", e))
-                })?;
-            } else {
-                super::super::mock::mock_execution::print_local(&output);
+            if runtime.print_prompts {
+                let range = determine_range(git, runtime.attribute_authors)?;
+                let prompt =
+                    assemble_narrator_prompt(jules_path, &range, repository, SeedMode::Report)?;
+                super::print_prompt_to_stderr(Layer::Narrator, None, &prompt);
             }
+            let mock_config = load_mock_config(jules_path, repository, runtime.mock_seed)?;
+            let output = execute_mock(&mock_config)?;
+            super::super::mock::mock_execution::finish_mock_output(&output, runtime.report_stream)?;
             return Ok(RunResult {
                 roles: vec!["narrator".to_string()],
                 prompt_preview: false,
                 sessions: vec![],
+                session_urls: vec![],
                 cleanup_requirement: None,
             });
         }
@@ -57,7 +59,9 @@ where
         execute_real(
             jules_path,
             runtime.prompt_preview,
+            runtime.report_stream,
             runtime.branch.as_deref(),
+            runtime.attribute_authors,
             config,
             git,
             repository,
@@ -67,10 +71,13 @@ where
 }
 
 /// Execute the Narrator layer in real mode.
+#[allow(clippy::too_many_arguments)]
 fn execute_real<G, W>(
     jules_path: &Path,
     prompt_preview: bool,
+    report: ReportStream,
     branch: Option<&str>,
+    attribute_authors: bool,
     config: &ControlPlaneConfig,
     git: &G,
     repository: &W,
@@ -90,18 +97,20 @@ where
     let starting_branch = resolve_starting_branch(Layer::Narrator, config, branch);
 
     // Determine commit range
-    let range = determine_range(git)?;
+    let range = determine_range(git, attribute_authors)?;
 
-    let prompt = assemble_narrator_prompt(jules_path, &range, repository)?;
+    let seed_mode = if prompt_preview { SeedMode::Report } else { SeedMode::Apply };
+    let prompt = assemble_narrator_prompt(jules_path, &range, repository, seed_mode)?;
 
     if prompt_preview {
-        println!("=== Prompt Preview: Narrator ===");
-        println!("Starting branch: {}\n", starting_branch);
-        println!("{}", prompt);
+        report.line("=== Prompt Preview: Narrator ===");
+        report.line(format_args!("Starting branch: {}\n", starting_branch));
+        report.line(&prompt);
         return Ok(RunResult {
             roles: vec!["narrator".to_string()],
             prompt_preview: true,
             sessions: vec![],
+            session_urls: vec![],
             cleanup_requirement: None,
         });
     }
@@ -120,24 +129,26 @@ where
 
     match client.create_session(request) {
         Ok(response) => {
-            println!("✅ Narrator session created: {}", response.session_id);
+            eprintln!("✅ Narrator session created: {}", response.session_id);
             Ok(RunResult {
                 roles: vec!["narrator".to_string()],
                 prompt_preview: false,
                 sessions: vec![response.session_id],
+                session_urls: vec![response.url],
                 cleanup_requirement: None,
             })
         }
         Err(e) => {
-            println!("❌ Failed to create Narrator session: {}", e);
+            eprintln!("❌ Failed to create Narrator session: {}", e);
             Err(e)
         }
     }
 }
 
 fn execute_mock(config: &MockConfig) -> Result<MockOutput, AppError> {
+    config.simulated_failure(Layer::Narrator, None)?;
     let _ = config.branch_prefix(Layer::Narrator)?;
-    println!("Mock narrator: no-op (preserving existing .jules/exchange/changes.yml)");
+    eprintln!("Mock narrator: no-op (preserving existing .jules/exchange/changes.yml)");
 
     Ok(MockOutput {
         mock_branch: String::new(),
@@ -162,6 +173,7 @@ fn assemble_narrator_prompt<
     jules_path: &Path,
     range: &RangeContext,
     repository: &W,
+    seed_mode: SeedMode,
 ) -> Result<String, AppError> {
     let prompt_context =
         PromptContext::new().with_var("range_description", build_range_description(range));
@@ -174,7 +186,7 @@ fn assemble_narrator_prompt<
         crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset,
     )
     .map_err(|e| AppError::InternalError(e.to_string()))?;
-    super::execute_seed_ops(seed_ops, repository)?;
+    super::handle_seed_ops(seed_ops, repository, seed_mode)?;
     Ok(prompt.content)
 }
 
@@ -183,21 +195,78 @@ fn assemble_narrator_prompt<
 /// Number of commits to summarize for narrator.
 pub const BOOTSTRAP_COMMIT_COUNT: usize = 20;
 
+/// Most-changed files whose last author is looked up with `--attribute-authors`.
+pub const ATTRIBUTION_FILE_LIMIT: usize = 5;
+
 #[derive(Debug, PartialEq)]
 struct RangeContext {
     from_commit: String,
     to_commit: String,
+    /// Commits in the range, newest first, so the summary can reflect stated intent.
+    commits: Vec<CommitInfo>,
+    /// `(path, author)` for the most-changed files, when attribution is enabled.
+    authors: Vec<(String, String)>,
 }
 
-fn determine_range<G>(git: &G) -> Result<RangeContext, AppError>
+fn determine_range<G>(git: &G, attribute_authors: bool) -> Result<RangeContext, AppError>
 where
     G: Git + ?Sized,
 {
     let head_sha = git.get_head_sha()?;
-    determine_range_strategy(&head_sha, |sha, n| match git.get_nth_ancestor(sha, n)? {
-        Some(commit) => Ok(commit),
-        None => git.get_first_commit(sha),
-    })
+    let mut range =
+        determine_range_strategy(&head_sha, |sha, n| match git.get_nth_ancestor(sha, n)? {
+            Some(commit) => Ok(commit),
+            None => git.get_first_commit(sha),
+        })?;
+    range.commits = git.log_range(&range.from_commit, &range.to_commit)?;
+    if attribute_authors {
+        let numstat = git.run_command(
+            &[
+                "diff",
+                "--numstat",
+                &range.from_commit,
+                &range.to_commit,
+                "--",
+                ".",
+                ":(exclude).jules",
+                ":(exclude).jlo",
+            ],
+            None,
+        )?;
+        let files = most_changed_files(&numstat, ATTRIBUTION_FILE_LIMIT);
+        range.authors = attribute_last_authors(git, &range.to_commit, &files);
+    }
+    Ok(range)
+}
+
+/// Paths from `git diff --numstat` ordered by lines changed (binary files last), capped at `limit`.
+fn most_changed_files(numstat: &str, limit: usize) -> Vec<String> {
+    let mut changes: Vec<(u64, String)> = numstat
+        .lines()
+        .filter_map(|line| {
+            let mut fields = line.splitn(3, '\t');
+            let added = fields.next()?.parse::<u64>().unwrap_or(0);
+            let deleted = fields.next()?.parse::<u64>().unwrap_or(0);
+            let path = fields.next()?.trim();
+            (!path.is_empty()).then(|| (added + deleted, path.to_string()))
+        })
+        .collect();
+    changes.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    changes.into_iter().take(limit).map(|(_, path)| path).collect()
+}
+
+/// Last author per file as of `rev`; files without history there (e.g. deleted or
+/// renamed) are skipped.
+fn attribute_last_authors<G>(git: &G, rev: &str, files: &[String]) -> Vec<(String, String)>
+where
+    G: Git + ?Sized,
+{
+    files
+        .iter()
+        .filter_map(|path| {
+            git.blame_last_author(rev, path).ok().map(|author| (path.clone(), author))
+        })
+        .collect()
 }
 
 fn determine_range_strategy(
@@ -205,24 +274,45 @@ fn determine_range_strategy(
     get_bootstrap_commit: impl Fn(&str, usize) -> Result<String, AppError>,
 ) -> Result<RangeContext, AppError> {
     let bootstrap_from = get_bootstrap_commit(head_sha, BOOTSTRAP_COMMIT_COUNT)?;
-    Ok(RangeContext { from_commit: bootstrap_from, to_commit: head_sha.to_string() })
+    Ok(RangeContext {
+        from_commit: bootstrap_from,
+        to_commit: head_sha.to_string(),
+        commits: Vec::new(),
+        authors: Vec::new(),
+    })
 }
 
 fn build_range_description(range: &RangeContext) -> String {
     let short_from = &range.from_commit[..7.min(range.from_commit.len())];
     let short_to = &range.to_commit[..7.min(range.to_commit.len())];
-    format!(
+    let mut description = format!(
         "Summarize the most recent {} commits with non-.jules/.jlo scope (commits {}..{}).",
         BOOTSTRAP_COMMIT_COUNT, short_from, short_to
-    )
+    );
+    if !range.commits.is_empty() {
+        description.push_str("\nCommit subjects (newest first):");
+        for commit in &range.commits {
+            let short_sha = &commit.sha[..7.min(commit.sha.len())];
+            description.push_str(&format!("\n- {} {}", short_sha, commit.subject));
+        }
+    }
+    if !range.authors.is_empty() {
+        description.push_str("\nLast authors of the most-changed files:");
+        for (path, author) in &range.authors {
+            description.push_str(&format!("\n- {} ({})", path, author));
+        }
+    }
+    description
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::MockClock;
     use crate::ports::GitWorkspace;
     use crate::ports::{
-        DiscoveredRole, JloStore, JulesStore, PullRequestInfo, RepositoryFilesystem, ScaffoldFile,
+        DiscoveredRole, JloStore, JulesStore, MergeStrategy, PullRequestInfo, RepositoryFilesystem,
+        ScaffoldFile,
     };
     use std::collections::HashMap;
     use std::path::PathBuf;
@@ -248,6 +338,8 @@ mod tests {
         let range = RangeContext {
             from_commit: "0123456789abcdef".to_string(),
             to_commit: "fedcba9876543210".to_string(),
+            commits: Vec::new(),
+            authors: Vec::new(),
         };
         let description = build_range_description(&range);
         assert!(description.contains(&BOOTSTRAP_COMMIT_COUNT.to_string()));
@@ -255,6 +347,63 @@ mod tests {
         assert!(description.contains("fedcba9"));
     }
 
+    #[test]
+    fn test_determine_range_includes_commit_subjects() {
+        let git = crate::testing::FakeGit::new();
+        *git.commits.lock().unwrap() = vec![
+            ("parent".to_string(), "Initial commit".to_string()),
+            ("aaaaaaa1".to_string(), "Add cache layer\n\nBody text".to_string()),
+            ("abc123".to_string(), "Fix cache eviction".to_string()),
+        ];
+
+        let range = determine_range(&git, false).unwrap();
+
+        assert_eq!(range.from_commit, "parent");
+        assert_eq!(range.to_commit, "abc123");
+        assert_eq!(
+            range.commits.iter().map(|c| c.subject.as_str()).collect::<Vec<_>>(),
+            vec!["Fix cache eviction", "Add cache layer"]
+        );
+        assert!(range.authors.is_empty());
+        assert_eq!(git.commit_message("aaaaaaa1").unwrap(), "Add cache layer\n\nBody text");
+        assert!(git.commit_message("missing").is_err());
+        let description = build_range_description(&range);
+        assert!(description.contains("- abc123 Fix cache eviction\n- aaaaaaa Add cache layer"));
+    }
+
+    #[test]
+    fn test_most_changed_files_orders_by_churn_and_caps() {
+        let numstat =
+            "3\t1\tsrc/small.rs\n40\t2\tsrc/big.rs\n-\t-\tassets/logo.png\n10\t0\tREADME.md\n";
+
+        assert_eq!(most_changed_files(numstat, 2), vec!["src/big.rs", "README.md"]);
+        assert_eq!(most_changed_files(numstat, 10).last().unwrap(), "assets/logo.png");
+    }
+
+    #[test]
+    fn test_attribution_names_last_authors_from_git() {
+        let git = crate::testing::FakeGit::new();
+        let mut last_authors = git.last_authors.lock().unwrap();
+        last_authors.insert(("abc123".to_string(), "src/big.rs".to_string()), "Ada".to_string());
+        last_authors.insert(("HEAD".to_string(), "src/big.rs".to_string()), "Grace".to_string());
+        drop(last_authors);
+        let files = vec!["src/big.rs".to_string(), "src/deleted.rs".to_string()];
+
+        let authors = attribute_last_authors(&git, "abc123", &files);
+        assert_eq!(authors, vec![("src/big.rs".to_string(), "Ada".to_string())]);
+
+        let range = RangeContext {
+            from_commit: "root000".to_string(),
+            to_commit: "abc123".to_string(),
+            commits: Vec::new(),
+            authors,
+        };
+        let description = build_range_description(&range);
+        assert!(
+            description.contains("Last authors of the most-changed files:\n- src/big.rs (Ada)")
+        );
+    }
+
     // --- Tests from mock/narrator.rs ---
 
     #[allow(dead_code)]
@@ -269,6 +418,14 @@ mod tests {
             panic!("mock narrator no-op must not call get_current_branch");
         }
 
+        fn get_default_branch(&self) -> Result<String, AppError> {
+            panic!("mock narrator no-op must not call get_default_branch");
+        }
+
+        fn blame_last_author(&self, _rev: &str, _path: &str) -> Result<String, AppError> {
+            panic!("mock narrator no-op must not call blame_last_author");
+        }
+
         fn commit_exists(&self, _sha: &str) -> bool {
             panic!("mock narrator no-op must not call commit_exists");
         }
@@ -281,6 +438,22 @@ mod tests {
             panic!("mock narrator no-op must not call get_first_commit");
         }
 
+        fn rev_parse(&self, _rev: &str) -> Result<String, AppError> {
+            panic!("mock narrator no-op must not call rev_parse");
+        }
+
+        fn commit_message(&self, _sha: &str) -> Result<String, AppError> {
+            panic!("mock narrator no-op must not call commit_message");
+        }
+
+        fn log_range(
+            &self,
+            _from: &str,
+            _to: &str,
+        ) -> Result<Vec<crate::ports::CommitInfo>, AppError> {
+            panic!("mock narrator no-op must not call log_range");
+        }
+
         fn has_changes(
             &self,
             _from: &str,
@@ -290,6 +463,10 @@ mod tests {
             panic!("mock narrator no-op must not call has_changes");
         }
 
+        fn status(&self, _pathspec: &[&str]) -> Result<Vec<crate::ports::StatusEntry>, AppError> {
+            panic!("mock narrator no-op must not call status");
+        }
+
         fn run_command(
             &self,
             _args: &[&str],
@@ -327,10 +504,55 @@ mod tests {
             panic!("mock narrator no-op must not call fetch");
         }
 
+        fn fetch_prune(&self, _remote: &str) -> Result<(), AppError> {
+            panic!("mock narrator no-op must not call fetch_prune");
+        }
+
+        fn remote_exists(&self, _name: &str) -> Result<bool, AppError> {
+            panic!("mock narrator no-op must not call remote_exists");
+        }
+
+        fn get_config(&self, _key: &str) -> Result<Option<String>, AppError> {
+            panic!("mock narrator no-op must not call get_config");
+        }
+
+        fn set_config(&self, _key: &str, _value: &str) -> Result<(), AppError> {
+            panic!("mock narrator no-op must not call set_config");
+        }
+
+        fn unset_config(&self, _key: &str) -> Result<(), AppError> {
+            panic!("mock narrator no-op must not call unset_config");
+        }
+
+        fn abort_merge(&self) -> Result<(), AppError> {
+            panic!("mock narrator no-op must not call abort_merge");
+        }
+
+        fn cherry_pick(&self, _rev: &str) -> Result<(), AppError> {
+            panic!("mock narrator no-op must not call cherry_pick");
+        }
+
+        fn cherry_pick_abort(&self) -> Result<(), AppError> {
+            panic!("mock narrator no-op must not call cherry_pick_abort");
+        }
+
+        fn reset_hard_to(&self, _branch: &str, _rev: &str) -> Result<(), AppError> {
+            panic!("mock narrator no-op must not call reset_hard_to");
+        }
+
         fn delete_branch(&self, _branch: &str, _force: bool) -> Result<bool, AppError> {
             panic!("mock narrator no-op must not call delete_branch");
         }
 
+        fn create_tag(
+            &self,
+            _name: &str,
+            _message: Option<&str>,
+            _rev: Option<&str>,
+        ) -> Result<(), AppError> {
+            panic!("mock narrator no-op must not call create_tag");
+        }
+
         fn create_workspace(&self, _branch: &str) -> Result<Box<dyn GitWorkspace>, AppError> {
             panic!("mock narrator no-op must not call create_workspace");
         }
@@ -397,10 +619,20 @@ mod tests {
         fn enable_automerge(&self, _pr_number: u64) -> Result<(), AppError> {
             panic!("mock narrator no-op must not call enable_automerge");
         }
+        fn list_open_pull_requests(
+            &self,
+            _base: &str,
+        ) -> Result<Vec<crate::ports::PullRequestSummary>, AppError> {
+            panic!("mock narrator no-op must not call list_open_pull_requests");
+        }
         fn list_pr_files(&self, _pr_number: u64) -> Result<Vec<String>, AppError> {
             panic!("mock narrator no-op must not call list_pr_files");
         }
-        fn merge_pull_request(&self, _pr_number: u64) -> Result<(), AppError> {
+        fn merge_pull_request(
+            &self,
+            _pr_number: u64,
+            _strategy: MergeStrategy,
+        ) -> Result<(), AppError> {
             panic!("mock narrator no-op must not call merge_pull_request");
         }
     }
@@ -447,6 +679,10 @@ mod tests {
             panic!("mock narrator no-op must not call remove_dir_all");
         }
 
+        fn rename(&self, _from: &str, _to: &str) -> Result<(), AppError> {
+            panic!("mock narrator no-op must not call rename");
+        }
+
         fn list_dir(&self, _path: &str) -> Result<Vec<PathBuf>, AppError> {
             panic!("mock narrator no-op must not call list_dir");
         }
@@ -485,6 +721,14 @@ mod tests {
             panic!("mock narrator no-op must not call jlo_path");
         }
 
+        fn config_path(&self) -> PathBuf {
+            panic!("mock narrator no-op must not call config_path");
+        }
+
+        fn read_config(&self) -> Result<String, AppError> {
+            panic!("mock narrator no-op must not call read_config");
+        }
+
         fn jlo_write_version(&self, _version: &str) -> Result<(), AppError> {
             panic!("mock narrator no-op must not call jlo_write_version");
         }
@@ -501,10 +745,18 @@ mod tests {
             panic!("mock narrator no-op must not call find_role_fuzzy");
         }
 
+        fn role_exists(&self, _layer: Layer, _role: &str) -> bool {
+            panic!("mock narrator no-op must not call role_exists");
+        }
+
         fn role_path(&self, _role: &DiscoveredRole) -> Option<PathBuf> {
             panic!("mock narrator no-op must not call role_path");
         }
 
+        fn file_hash(&self, _path: &str) -> Result<String, AppError> {
+            panic!("mock narrator no-op must not call file_hash");
+        }
+
         fn write_role(
             &self,
             _layer: Layer,
@@ -535,6 +787,15 @@ mod tests {
         fn create_structure(&self, _scaffold_files: &[ScaffoldFile]) -> Result<(), AppError> {
             panic!("mock narrator no-op must not call create_structure");
         }
+
+        fn move_event(
+            &self,
+            _event: &str,
+            _from_state: &str,
+            _to_state: &str,
+        ) -> Result<(), AppError> {
+            panic!("mock narrator no-op must not call move_event");
+        }
     }
 
     #[test]
@@ -547,6 +808,8 @@ mod tests {
             jlo_target_branch: "main".to_string(),
             jules_worker_branch: "jules".to_string(),
             issue_labels: vec!["bugs".to_string()],
+            clock: MockClock::seeded(0),
+            fail_target: None,
         };
 
         let output = execute_mock(&config).expect("mock narrator should succeed as no-op");
@@ -1,10 +1,11 @@
 use std::path::Path;
 
-use chrono::Utc;
-
-use super::super::mock::mock_execution::{MOCK_ASSETS, generate_mock_id};
-use crate::app::commands::run::RunRuntimeOptions;
-use crate::app::commands::run::input::{detect_repository_source, load_mock_config};
+use super::super::mock::mock_execution::MOCK_ASSETS;
+use crate::app::commands::run::input::{
+    detect_repository_source, load_mock_config, load_role_file,
+};
+use crate::app::commands::run::{ReportStream, RunRuntimeOptions};
+use crate::app::validation::validate_pending_event;
 use crate::domain::layers::execute::starting_branch::resolve_starting_branch;
 use crate::domain::prompt_assemble::{PromptAssetLoader, PromptContext, assemble_prompt};
 use crate::domain::{
@@ -12,8 +13,11 @@ use crate::domain::{
 };
 use crate::ports::{Git, GitHub, JloStore, JulesStore, RepositoryFilesystem};
 
-use super::super::role_session::{dispatch_session, print_role_preview, validate_role_exists};
+use super::super::role_session::{
+    dispatch_session, print_role_file_preview, print_role_preview, validate_role_exists,
+};
 use super::super::strategy::{JulesClientFactory, LayerStrategy, RunResult};
+use super::SeedMode;
 
 pub struct ObserversLayer;
 
@@ -44,20 +48,32 @@ where
                 AppError::MissingArgument("Role is required for observers in mock mode".to_string())
             })?;
             let role = RoleId::new(&role_str)?;
-            let mock_config = load_mock_config(jules_path, repository)?;
-            let output = execute_mock(jules_path, &role, &mock_config, git, github, repository)?;
-            // Write mock output
-            if std::env::var("GITHUB_OUTPUT").is_ok() {
-                super::super::mock::mock_execution::write_github_output(&output).map_err(|e| {
-                    AppError::InternalError(format!("Failed to write GITHUB_OUTPUT: {}", e))
-                })?;
-            } else {
-                super::super::mock::mock_execution::print_local(&output);
+            if runtime.print_prompts {
+                let prompt = assemble_observer_prompt(
+                    jules_path,
+                    role.as_str(),
+                    None,
+                    repository,
+                    SeedMode::Report,
+                )?;
+                super::print_prompt_to_stderr(Layer::Observers, Some(role.as_str()), &prompt);
             }
+            let mock_config = load_mock_config(jules_path, repository, runtime.mock_seed)?;
+            let output = execute_mock(
+                jules_path,
+                &role,
+                &mock_config,
+                runtime.mock_events.as_deref(),
+                git,
+                github,
+                repository,
+            )?;
+            super::super::mock::mock_execution::finish_mock_output(&output, runtime.report_stream)?;
             return Ok(RunResult {
                 roles: vec![role.to_string()],
                 prompt_preview: false,
                 sessions: vec![],
+                session_urls: vec![],
                 cleanup_requirement: None,
             });
         }
@@ -65,8 +81,10 @@ where
         execute_real(
             jules_path,
             runtime.prompt_preview,
+            runtime.report_stream,
             runtime.branch.as_deref(),
             target.role.as_deref(),
+            target.role_file.as_deref(),
             config,
             git,
             repository,
@@ -79,8 +97,10 @@ where
 fn execute_real<G, W>(
     jules_path: &Path,
     prompt_preview: bool,
+    report: ReportStream,
     branch: Option<&str>,
     role: Option<&str>,
+    role_file: Option<&Path>,
     config: &ControlPlaneConfig,
     git: &G,
     repository: &W,
@@ -101,28 +121,59 @@ where
         .ok_or_else(|| AppError::MissingArgument("Role is required for observers".to_string()))?;
 
     let role_id = RoleId::new(role)?;
-    validate_role_exists(jules_path, Layer::Observers, role_id.as_str(), repository)?;
+    let role_definition = match role_file {
+        Some(path) => Some(load_role_file(path, Layer::Observers, &role_id, repository)?),
+        None => {
+            validate_role_exists(jules_path, Layer::Observers, role_id.as_str(), repository)?;
+            None
+        }
+    };
 
     let starting_branch = resolve_starting_branch(Layer::Observers, config, branch);
 
     if prompt_preview {
-        print_role_preview(jules_path, Layer::Observers, &role_id, &starting_branch, repository);
-        let assembled = assemble_observer_prompt(jules_path, role_id.as_str(), repository)?;
-        println!("  Assembled prompt: {} chars", assembled.len());
-        println!("\nWould execute 1 session");
+        match role_file {
+            Some(path) => {
+                print_role_file_preview(Layer::Observers, &role_id, &starting_branch, path, report)
+            }
+            None => print_role_preview(
+                jules_path,
+                Layer::Observers,
+                &role_id,
+                &starting_branch,
+                repository,
+                report,
+            ),
+        }
+        let assembled = assemble_observer_prompt(
+            jules_path,
+            role_id.as_str(),
+            role_definition.as_deref(),
+            repository,
+            SeedMode::Report,
+        )?;
+        report.line(format_args!("  Assembled prompt: {} chars", assembled.len()));
+        report.line("\nWould execute 1 session");
         return Ok(RunResult {
             roles: vec![role.to_string()],
             prompt_preview: true,
             sessions: vec![],
+            session_urls: vec![],
             cleanup_requirement: None,
         });
     }
 
     let source = detect_repository_source(git)?;
-    let assembled = assemble_observer_prompt(jules_path, role_id.as_str(), repository)?;
+    let assembled = assemble_observer_prompt(
+        jules_path,
+        role_id.as_str(),
+        role_definition.as_deref(),
+        repository,
+        SeedMode::Apply,
+    )?;
     let client = client_factory.create()?;
 
-    let session_id = dispatch_session(
+    let response = dispatch_session(
         Layer::Observers,
         &role_id,
         assembled,
@@ -134,7 +185,8 @@ where
     Ok(RunResult {
         roles: vec![role.to_string()],
         prompt_preview: false,
-        sessions: vec![session_id],
+        sessions: vec![response.session_id],
+        session_urls: vec![response.url],
         cleanup_requirement: None,
     })
 }
@@ -151,9 +203,14 @@ fn assemble_observer_prompt<
 >(
     jules_path: &Path,
     role: &str,
+    role_definition: Option<&str>,
     repository: &W,
+    seed_mode: SeedMode,
 ) -> Result<String, AppError> {
-    let context = PromptContext::new().with_var("role", role);
+    let mut context = PromptContext::new().with_var("role", role);
+    if let Some(definition) = role_definition {
+        context = context.with_var("role_definition", definition);
+    }
 
     let (prompt, seed_ops) = assemble_prompt(
         jules_path,
@@ -163,7 +220,7 @@ fn assemble_observer_prompt<
         crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset,
     )
     .map_err(|e| AppError::InternalError(e.to_string()))?;
-    super::execute_seed_ops(seed_ops, repository)?;
+    super::handle_seed_ops(seed_ops, repository, seed_mode)?;
     Ok(prompt.content)
 }
 
@@ -174,8 +231,9 @@ const TMPL_TAG: &str = "test-tag";
 
 fn execute_mock<G, H, W>(
     jules_path: &Path,
-    _observer_role: &RoleId,
+    observer_role: &RoleId,
     config: &MockConfig,
+    seeded_events: Option<&[serde_json::Value]>,
     git: &G,
     github: &H,
     repository: &W,
@@ -185,63 +243,43 @@ where
     H: GitHub + ?Sized,
     W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
 {
-    let timestamp = Utc::now().format("%Y%m%d%H%M%S").to_string();
+    config.simulated_failure(Layer::Observers, Some(observer_role.as_str()))?;
+    let seeded = seeded_events.map(seeded_event_files).transpose()?;
+    let timestamp = config.clock.now().format("%Y%m%d%H%M%S").to_string();
     let branch_name = config.branch_name(Layer::Observers, &timestamp)?;
 
-    println!("Mock observers: creating branch {}", branch_name);
+    eprintln!("Mock observers: creating branch {}", branch_name);
 
     // Fetch and checkout from jules branch
     git.fetch("origin")?;
     git.checkout_branch(&format!("origin/{}", config.jules_worker_branch), false)?;
     git.checkout_branch(&branch_name, true)?;
 
-    // Create mock events
     let events_dir = crate::domain::exchange::events::paths::events_pending_dir(jules_path);
-
-    let mock_event_template = MOCK_ASSETS
-        .get_file("observer_event.yml")
-        .ok_or_else(|| {
-            AppError::InternalError("Mock asset missing: observer_event.yml".to_string())
-        })?
-        .contents_utf8()
-        .ok_or_else(|| {
-            AppError::InternalError("Invalid UTF-8 in observer_event.yml".to_string())
-        })?;
-
-    // Create mock event 1 (for planner routing)
-    let event_id_1 = generate_mock_id();
-    let event_file_1 = events_dir.join(format!("{}-{}.yml", config.mock_tag, event_id_1));
-    let event_content_1 = mock_event_template
-        .replace(TMPL_ID, &event_id_1)
-        .replace(TMPL_DATE, &Utc::now().format("%Y-%m-%d").to_string())
-        .replace(TMPL_TAG, &config.mock_tag);
-
-    // Create mock event 2 (for implementer routing)
-    let event_id_2 = generate_mock_id();
-    let event_file_2 = events_dir.join(format!("{}-{}.yml", config.mock_tag, event_id_2));
-    let event_content_2 = mock_event_template
-        .replace(TMPL_ID, &event_id_2)
-        .replace(TMPL_DATE, &Utc::now().format("%Y-%m-%d").to_string())
-        .replace(TMPL_TAG, &config.mock_tag)
-        .replace("workflow validation", "workflow implementation check");
+    let events = match seeded {
+        Some(seeded) => seeded
+            .into_iter()
+            .map(|(id, content)| {
+                (events_dir.join(format!("{}-{}.yml", config.mock_tag, id)), content)
+            })
+            .collect(),
+        None => generated_mock_events(&events_dir, config)?,
+    };
 
     // Ensure directory exists
     repository.create_dir_all(
         events_dir.to_str().ok_or_else(|| AppError::Validation("Invalid path".to_string()))?,
     )?;
 
-    repository.write_file(
-        event_file_1.to_str().ok_or_else(|| AppError::Validation("Invalid path".to_string()))?,
-        &event_content_1,
-    )?;
-
-    repository.write_file(
-        event_file_2.to_str().ok_or_else(|| AppError::Validation("Invalid path".to_string()))?,
-        &event_content_2,
-    )?;
+    for (path, content) in &events {
+        repository.write_file(
+            path.to_str().ok_or_else(|| AppError::Validation("Invalid path".to_string()))?,
+            content,
+        )?;
+    }
 
     // Commit and push
-    let all_files: Vec<&Path> = vec![event_file_1.as_path(), event_file_2.as_path()];
+    let all_files: Vec<&Path> = events.iter().map(|(path, _)| path.as_path()).collect();
     git.commit_files(&format!("[{}] observer: mock event", config.mock_tag), &all_files)?;
     git.push_branch(&branch_name, false)?;
 
@@ -253,7 +291,7 @@ where
         &format!("Mock observer run for workflow validation.\n\nMock tag: `{}`", config.mock_tag),
     )?;
 
-    println!("Mock observers: created PR #{} ({})", pr.number, pr.url);
+    eprintln!("Mock observers: created PR #{} ({})", pr.number, pr.url);
 
     Ok(MockOutput {
         mock_branch: branch_name,
@@ -262,3 +300,69 @@ where
         mock_tag: config.mock_tag.clone(),
     })
 }
+
+/// Validate `--emit-events` bodies and render each as `(id, yaml)`.
+fn seeded_event_files(events: &[serde_json::Value]) -> Result<Vec<(String, String)>, AppError> {
+    if events.is_empty() {
+        return Err(AppError::Validation("--emit-events must list at least one event".into()));
+    }
+    let mut seen = std::collections::HashSet::new();
+    events
+        .iter()
+        .enumerate()
+        .map(|(index, event)| {
+            let label = format!("--emit-events[{}]", index);
+            let serde_yaml::Value::Mapping(data) = serde_yaml::to_value(event)
+                .map_err(|err| AppError::Validation(format!("{}: {}", label, err)))?
+            else {
+                return Err(AppError::Validation(format!("{} must be a JSON object", label)));
+            };
+            validate_pending_event(&data, &label)?;
+            let id = data.get("id").and_then(|id| id.as_str()).unwrap_or_default().to_string();
+            if !seen.insert(id.clone()) {
+                return Err(AppError::Validation(format!(
+                    "{}: duplicate event id '{}'",
+                    label, id
+                )));
+            }
+            let content = serde_yaml::to_string(&data)
+                .map_err(|err| AppError::InternalError(err.to_string()))?;
+            Ok((id, content))
+        })
+        .collect()
+}
+
+/// The two template-based mock events: one routed to the planner, one to the implementer.
+fn generated_mock_events(
+    events_dir: &Path,
+    config: &MockConfig,
+) -> Result<Vec<(std::path::PathBuf, String)>, AppError> {
+    let mock_event_template = MOCK_ASSETS
+        .get_file("observer_event.yml")
+        .ok_or_else(|| {
+            AppError::InternalError("Mock asset missing: observer_event.yml".to_string())
+        })?
+        .contents_utf8()
+        .ok_or_else(|| {
+            AppError::InternalError("Invalid UTF-8 in observer_event.yml".to_string())
+        })?;
+
+    // Create mock event 1 (for planner routing)
+    let event_id_1 = config.clock.next_id();
+    let event_file_1 = events_dir.join(format!("{}-{}.yml", config.mock_tag, event_id_1));
+    let event_content_1 = mock_event_template
+        .replace(TMPL_ID, &event_id_1)
+        .replace(TMPL_DATE, &config.clock.now().format("%Y-%m-%d").to_string())
+        .replace(TMPL_TAG, &config.mock_tag);
+
+    // Create mock event 2 (for implementer routing)
+    let event_id_2 = config.clock.next_id();
+    let event_file_2 = events_dir.join(format!("{}-{}.yml", config.mock_tag, event_id_2));
+    let event_content_2 = mock_event_template
+        .replace(TMPL_ID, &event_id_2)
+        .replace(TMPL_DATE, &config.clock.now().format("%Y-%m-%d").to_string())
+        .replace(TMPL_TAG, &config.mock_tag)
+        .replace("workflow validation", "workflow implementation check");
+
+    Ok(vec![(event_file_1, event_content_1), (event_file_2, event_content_2)])
+}
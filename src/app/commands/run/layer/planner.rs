@@ -1,19 +1,19 @@
 use std::path::Path;
 
-use chrono::Utc;
-
-use crate::app::commands::run::RunRuntimeOptions;
-use crate::app::commands::run::input::{detect_repository_source, load_mock_config};
+use crate::app::commands::run::input::{
+    detect_repository_source, load_mock_config, read_requirement,
+};
+use crate::app::commands::run::{ReportStream, RunRuntimeOptions};
 use crate::domain::layers::execute::starting_branch::resolve_starting_branch;
-use crate::domain::layers::execute::validate_requirement_path;
 use crate::domain::prompt_assemble::{PromptAssetLoader, PromptContext, assemble_prompt};
 use crate::domain::{AppError, ControlPlaneConfig, Layer, MockConfig, MockOutput, RunOptions};
 use crate::ports::{
     AutomationMode, Git, GitHub, JloStore, JulesClient, JulesStore, RepositoryFilesystem,
-    SessionRequest,
+    SessionRequest, SessionResponse,
 };
 
 use super::super::strategy::{JulesClientFactory, LayerStrategy, RunResult};
+use super::SeedMode;
 
 pub struct PlannerLayer;
 
@@ -40,20 +40,27 @@ where
         client_factory: &dyn JulesClientFactory,
     ) -> Result<RunResult, AppError> {
         if runtime.mock {
-            let mock_config = load_mock_config(jules_path, repository)?;
-            let output = execute_mock(jules_path, target, &mock_config, git, github, repository)?;
-            // Write mock output
-            if std::env::var("GITHUB_OUTPUT").is_ok() {
-                super::super::mock::mock_execution::write_github_output(&output).map_err(|e| {
-                    AppError::InternalError(format!("Failed to write GITHUB_OUTPUT: {}", e))
-                })?;
-            } else {
-                super::super::mock::mock_execution::print_local(&output);
+            if runtime.print_prompts
+                && let Some(requirement_path) = target.requirement.as_deref()
+            {
+                let requirement_content = read_requirement(requirement_path, None, repository)?;
+                let prompt = assemble_session_prompt(
+                    jules_path,
+                    &requirement_content,
+                    requirement_path,
+                    repository,
+                    SeedMode::Report,
+                )?;
+                super::print_prompt_to_stderr(Layer::Planner, None, &prompt);
             }
+            let mock_config = load_mock_config(jules_path, repository, runtime.mock_seed)?;
+            let output = execute_mock(jules_path, target, &mock_config, git, github, repository)?;
+            super::super::mock::mock_execution::finish_mock_output(&output, runtime.report_stream)?;
             return Ok(RunResult {
                 roles: vec!["planner".to_string()],
                 prompt_preview: false,
                 sessions: vec![],
+                session_urls: vec![],
                 cleanup_requirement: None,
             });
         }
@@ -61,8 +68,10 @@ where
         execute_real(
             jules_path,
             runtime.prompt_preview,
+            runtime.report_stream,
             runtime.branch.as_deref(),
             target.requirement.as_deref(),
+            runtime.requirement_input.as_deref(),
             config,
             git,
             repository,
@@ -75,8 +84,10 @@ where
 fn execute_real<G, W>(
     jules_path: &Path,
     prompt_preview: bool,
+    report: ReportStream,
     branch: Option<&str>,
     requirement_path: Option<&Path>,
+    requirement_input: Option<&str>,
     config: &ControlPlaneConfig,
     git: &G,
     repository: &W,
@@ -96,8 +107,7 @@ where
     let requirement_path = requirement_path.ok_or_else(|| {
         AppError::MissingArgument("Requirement path is required for planner".to_string())
     })?;
-    let requirement_info = validate_requirement_path(requirement_path, repository)?;
-    let requirement_content = repository.read_file(&requirement_info.requirement_path_str)?;
+    let requirement_content = read_requirement(requirement_path, requirement_input, repository)?;
 
     let starting_branch = resolve_starting_branch(Layer::Planner, config, branch);
 
@@ -108,11 +118,13 @@ where
             &requirement_content,
             requirement_path,
             repository,
+            report,
         )?;
         return Ok(RunResult {
             roles: vec!["planner".to_string()],
             prompt_preview: true,
             sessions: vec![],
+            session_urls: vec![],
             cleanup_requirement: None,
         });
     }
@@ -120,7 +132,7 @@ where
     let source = detect_repository_source(git)?;
     let client = client_factory.create()?;
 
-    let session_id = execute_session(
+    let response = execute_session(
         jules_path,
         &starting_branch,
         &source,
@@ -133,7 +145,8 @@ where
     Ok(RunResult {
         roles: vec!["planner".to_string()],
         prompt_preview: false,
-        sessions: vec![session_id],
+        sessions: vec![response.session_id],
+        session_urls: vec![response.url],
         cleanup_requirement: None,
     })
 }
@@ -156,14 +169,16 @@ fn execute_session<
     requirement_content: &str,
     requirement_path: &Path,
     repository: &W,
-) -> Result<String, AppError> {
-    println!("Executing {}...", Layer::Planner.display_name());
+) -> Result<SessionResponse, AppError> {
+    eprintln!("Executing {}...", Layer::Planner.display_name());
 
-    let mut prompt = assemble_planner_prompt(jules_path, repository)?;
-
-    prompt.push_str("\n---\n# Requirement Content\n");
-    prompt.push_str(&format!("File: {}\n\n", requirement_path.display()));
-    prompt.push_str(requirement_content);
+    let prompt = assemble_session_prompt(
+        jules_path,
+        requirement_content,
+        requirement_path,
+        repository,
+        SeedMode::Apply,
+    )?;
 
     let request = SessionRequest {
         prompt,
@@ -174,9 +189,9 @@ fn execute_session<
     };
 
     let response = client.create_session(request)?;
-    println!("  ✅ Session created: {}", response.session_id);
+    eprintln!("  ✅ Session created: {}", response.session_id);
 
-    Ok(response.session_id)
+    Ok(response)
 }
 
 fn assemble_planner_prompt<
@@ -191,6 +206,7 @@ fn assemble_planner_prompt<
 >(
     jules_path: &Path,
     repository: &W,
+    seed_mode: SeedMode,
 ) -> Result<String, AppError> {
     let (prompt, seed_ops) = assemble_prompt(
         jules_path,
@@ -200,10 +216,34 @@ fn assemble_planner_prompt<
         crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset,
     )
     .map_err(|e| AppError::InternalError(e.to_string()))?;
-    super::execute_seed_ops(seed_ops, repository)?;
+    super::handle_seed_ops(seed_ops, repository, seed_mode)?;
     Ok(prompt.content)
 }
 
+/// Assemble the full session prompt: planner prompt followed by the requirement.
+fn assemble_session_prompt<
+    W: RepositoryFilesystem
+        + JloStore
+        + JulesStore
+        + PromptAssetLoader
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+>(
+    jules_path: &Path,
+    requirement_content: &str,
+    requirement_path: &Path,
+    repository: &W,
+    seed_mode: SeedMode,
+) -> Result<String, AppError> {
+    let mut prompt = assemble_planner_prompt(jules_path, repository, seed_mode)?;
+    prompt.push_str("\n---\n# Requirement Content\n");
+    prompt.push_str(&format!("File: {}\n\n", requirement_path.display()));
+    prompt.push_str(requirement_content);
+    Ok(prompt)
+}
+
 fn execute_prompt_preview<
     W: RepositoryFilesystem
         + JloStore
@@ -219,26 +259,29 @@ fn execute_prompt_preview<
     requirement_content: &str,
     requirement_path: &Path,
     repository: &W,
+    report: ReportStream,
 ) -> Result<(), AppError> {
-    println!("=== Prompt Preview: {} ===", Layer::Planner.display_name());
-    println!("Starting branch: {}\n", starting_branch);
-    println!("Requirement content: {} chars\n", requirement_content.len());
+    report.line(format_args!("=== Prompt Preview: {} ===", Layer::Planner.display_name()));
+    report.line(format_args!("Starting branch: {}\n", starting_branch));
+    report.line(format_args!("Requirement content: {} chars\n", requirement_content.len()));
 
-    println!("Prompt template: planner/planner_prompt.j2 (embedded)");
-    println!("Contracts: planner/contracts.yml (embedded)");
+    report.line("Prompt template: planner/planner_prompt.j2 (embedded)");
+    report.line("Contracts: planner/contracts.yml (embedded)");
 
-    if let Ok(mut prompt) = assemble_planner_prompt(jules_path, repository) {
-        prompt.push_str("\n---\n# Requirement Content\n");
-        prompt.push_str(&format!("File: {}\n\n", requirement_path.display()));
-        prompt.push_str(requirement_content);
-
-        println!(
+    if let Ok(prompt) = assemble_session_prompt(
+        jules_path,
+        requirement_content,
+        requirement_path,
+        repository,
+        SeedMode::Report,
+    ) {
+        report.line(format_args!(
             "Assembled prompt: {} chars (Prompt + Requirement Path + Requirement Content)",
             prompt.len()
-        );
+        ));
     }
 
-    println!("\nWould execute 1 session");
+    report.line("\nWould execute 1 session");
     Ok(())
 }
 
@@ -284,14 +327,15 @@ where
     H: GitHub + ?Sized,
     W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
 {
+    config.simulated_failure(Layer::Planner, None)?;
     let requirement_path = options.requirement.as_ref().ok_or_else(|| {
         AppError::MissingArgument("Requirement path is required for planner".to_string())
     })?;
 
-    let timestamp = Utc::now().format("%Y%m%d%H%M%S").to_string();
+    let timestamp = config.clock.now().format("%Y%m%d%H%M%S").to_string();
     let branch_name = config.branch_name(Layer::Planner, &timestamp)?;
 
-    println!("Mock planner: creating branch {}", branch_name);
+    eprintln!("Mock planner: creating branch {}", branch_name);
 
     // Planner mock always operates on the worker branch contract (`jules_worker_branch`).
     // Runtime branch overrides apply to real-mode dispatch only.
@@ -324,7 +368,7 @@ analysis_details: |
   ## Implementation Notes
   - No actual analysis performed (mock mode)
 "#,
-        Utc::now().to_rfc3339(),
+        config.clock.now().to_rfc3339(),
         config.mock_tag
     ));
 
@@ -347,7 +391,7 @@ analysis_details: |
         ),
     )?;
 
-    println!("Mock planner: created PR #{} ({})", pr.number, pr.url);
+    eprintln!("Mock planner: created PR #{} ({})", pr.number, pr.url);
 
     Ok(MockOutput {
         mock_branch: branch_name,
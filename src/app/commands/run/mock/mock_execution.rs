@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 
 use include_dir::{Dir, include_dir};
 
+use crate::app::commands::run::ReportStream;
 use crate::domain::{AppError, IoErrorKind, MockConfig, MockOutput};
 use crate::ports::{Git, GitHub, RepositoryFilesystem};
 
@@ -35,18 +36,23 @@ fn ensure_single_line_output_value(name: &str, value: &str) -> std::io::Result<(
 }
 
 /// Print outputs in grep-friendly format for local use.
-pub fn print_local(output: &MockOutput) {
-    println!("MOCK_BRANCH={}", output.mock_branch);
-    println!("MOCK_PR_NUMBER={}", output.mock_pr_number);
-    println!("MOCK_PR_URL={}", output.mock_pr_url);
-    println!("MOCK_TAG={}", output.mock_tag);
+pub fn print_local(output: &MockOutput, report: ReportStream) {
+    report.line(format_args!("MOCK_BRANCH={}", output.mock_branch));
+    report.line(format_args!("MOCK_PR_NUMBER={}", output.mock_pr_number));
+    report.line(format_args!("MOCK_PR_URL={}", output.mock_pr_url));
+    report.line(format_args!("MOCK_TAG={}", output.mock_tag));
 }
 
-/// Generate a 6-character mock ID.
-pub fn generate_mock_id() -> String {
-    use std::time::{SystemTime, UNIX_EPOCH};
-    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos();
-    format!("{:06x}", (timestamp % 0xFFFFFF) as u32)
+/// Write mock output to GITHUB_OUTPUT when set, otherwise print it to `report`.
+pub fn finish_mock_output(output: &MockOutput, report: ReportStream) -> Result<(), AppError> {
+    if std::env::var("GITHUB_OUTPUT").is_ok() {
+        write_github_output(output).map_err(|e| {
+            AppError::InternalError(format!("Failed to write GITHUB_OUTPUT: {}", e))
+        })?;
+    } else {
+        print_local(output, report);
+    }
+    Ok(())
 }
 
 /// Parse mock event ID from filename.
@@ -144,18 +150,6 @@ where
     ) -> Result<crate::ports::PullRequestInfo, AppError> {
         self.github.create_pull_request(head, base, title, body)
     }
-
-    /// Write mock output to GITHUB_OUTPUT or stdout.
-    pub fn finish(&self, output: &MockOutput) -> Result<(), AppError> {
-        if std::env::var("GITHUB_OUTPUT").is_ok() {
-            write_github_output(output).map_err(|e| {
-                AppError::InternalError(format!("Failed to write GITHUB_OUTPUT: {}", e))
-            })?;
-        } else {
-            print_local(output);
-        }
-        Ok(())
-    }
 }
 
 #[cfg(test)]
@@ -165,14 +159,6 @@ mod tests {
     use std::fs;
     use tempfile::tempdir;
 
-    #[test]
-    fn test_generate_mock_id() {
-        let id1 = generate_mock_id();
-        let id2 = generate_mock_id();
-        assert_eq!(id1.len(), 6);
-        assert_eq!(id2.len(), 6);
-    }
-
     #[test]
     fn test_mock_event_id_from_path() {
         let mock_tag = "mock-run-123";
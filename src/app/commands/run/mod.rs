@@ -6,22 +6,35 @@ mod mock;
 mod role_session;
 mod strategy;
 
-use std::path::Path;
+pub(crate) use input::{stage_requirement_input, validate_requirement_input};
 
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use serde_json::json;
+
+use crate::adapters::git::RecordingGit;
+use crate::adapters::github::RecordingGitHub;
 use crate::adapters::jules_client::HttpJulesClient;
-use crate::adapters::jules_client::{RetryPolicy, RetryingJulesClient};
-use crate::app::commands::run::input::{load_control_plane_config, validate_mock_prerequisites};
+use crate::adapters::jules_client::{
+    CircuitBreaker, PromptSizeGuardClient, RecordingJulesClient, RetryPolicy, RetryingJulesClient,
+};
+use crate::adapters::transcript::RunTranscript;
+use crate::app::commands::run::input::{
+    load_control_plane_config, read_requirement, validate_mock_prerequisites,
+};
 use crate::app::commands::run::strategy::{JulesClientFactory, get_layer_strategy};
 use crate::app::commands::workflow::exchange::{
     ExchangeCleanRequirementOptions, clean_requirement_apply_with_adapters,
 };
 use crate::app::commands::workflow::push::{
-    PushWorkerBranchOptions, execute as push_worker_branch,
+    MergeStrategy, PushWorkerBranchOptions, execute as push_worker_branch,
 };
-use crate::domain::PromptAssetLoader;
 pub use crate::domain::RunOptions;
+use crate::domain::layers::execute::starting_branch::resolve_starting_branch;
 use crate::domain::validation::validate_identifier;
 use crate::domain::{AppError, JulesApiConfig};
+use crate::domain::{ControlPlaneConfig, Layer, PromptAssetLoader, RoleId};
 use crate::ports::{Git, GitHub, JloStore, JulesClient, JulesStore, RepositoryFilesystem};
 
 pub use strategy::RunResult;
@@ -37,18 +50,226 @@ pub struct RunRuntimeOptions {
     pub mock: bool,
     /// Skip post-execution cleanup (requirement deletion and worker-branch push).
     pub no_cleanup: bool,
+    /// In mock mode, assemble the prompts a real run would send and print them to stderr.
+    pub print_prompts: bool,
+    /// Collapse duplicate pending events before the decider runs.
+    pub dedupe_events: bool,
+    /// Truncate over-limit prompts with a warning instead of failing.
+    pub allow_truncate: bool,
+    /// Seed mock ids and timestamps so mock artifacts are reproducible.
+    pub mock_seed: Option<u64>,
+    /// Commit locally but skip every push and PR creation (implementer).
+    pub no_push: bool,
+    /// In mock mode, event bodies the observer writes instead of generated ones.
+    pub mock_events: Option<Vec<serde_json::Value>>,
+    /// Write a redacted JSON transcript of run inputs and Jules/git/GitHub calls to this path.
+    pub record: Option<PathBuf>,
+    /// Narrator only: name the last author of the most-changed files in the prompt.
+    pub attribute_authors: bool,
+    /// Integrator only: cap on candidate branches; overrides `run.integrator_max_prs`.
+    pub max_prs: Option<usize>,
+    /// Integrator only: tag the integration branch head once the session pushes it.
+    pub tag: Option<String>,
+    /// Planner/implementer only: requirement document read from stdin and already validated.
+    /// Previews render it in memory; `requirement` names where a real run stages it.
+    pub requirement_input: Option<String>,
+    /// Circuit breaker shared with other runs of the same workflow; a fresh one when unset.
+    pub breaker: Option<Arc<CircuitBreaker>>,
+    /// Where prompt previews and local mock outputs are written.
+    pub report_stream: ReportStream,
+    /// Control-plane config read instead of `.jlo/config.toml`.
+    ///
+    /// Applied by [`crate::app::api::run`] when it opens the repository; callers that
+    /// pass their own store configure the path on that store.
+    pub config: Option<PathBuf>,
+}
+
+/// Stream for a run's human-readable report: prompt previews and local mock outputs.
+///
+/// Progress lines always go to stderr; the report goes to stdout unless stdout is
+/// reserved for machine-readable output (`run --format json`, `workflow run`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReportStream {
+    #[default]
+    Stdout,
+    Stderr,
+}
+
+impl ReportStream {
+    /// Write one report line to this stream.
+    pub fn line(self, line: impl std::fmt::Display) {
+        match self {
+            ReportStream::Stdout => println!("{}", line),
+            ReportStream::Stderr => eprintln!("{}", line),
+        }
+    }
 }
 
 struct LazyClientFactory {
     config: JulesApiConfig,
+    allow_truncate: bool,
+    /// Shared by every client of this run so sustained failures fail fast.
+    breaker: Arc<CircuitBreaker>,
+    /// Set by `--record`; every client records its calls here.
+    transcript: Option<Arc<RunTranscript>>,
 }
 
 impl JulesClientFactory for LazyClientFactory {
     fn create(&self) -> Result<Box<dyn JulesClient>, AppError> {
         let transport = HttpJulesClient::from_env_with_config(&self.config)?;
         let retry_policy = RetryPolicy::from_config(&self.config);
-        Ok(Box::new(RetryingJulesClient::new(Box::new(transport), retry_policy)))
+        let retrying = RetryingJulesClient::with_breaker(
+            Box::new(transport),
+            retry_policy,
+            Arc::clone(&self.breaker),
+        );
+        let client: Box<dyn JulesClient> = match &self.transcript {
+            Some(transcript) => {
+                Box::new(RecordingJulesClient::new(Box::new(retrying), Arc::clone(transcript)))
+            }
+            None => Box::new(retrying),
+        };
+        Ok(Box::new(PromptSizeGuardClient::new(
+            client,
+            self.config.max_prompt_chars,
+            self.allow_truncate,
+        )))
+    }
+}
+
+/// Run inputs captured at the top of a `--record` transcript.
+fn transcript_inputs(target: &RunOptions, runtime: &RunRuntimeOptions) -> serde_json::Value {
+    json!({
+        "layer": target.layer.dir_name(),
+        "role": target.role,
+        "requirement": target.requirement,
+        "task": target.task,
+        "role_file": target.role_file,
+        "prompt_preview": runtime.prompt_preview,
+        "branch": runtime.branch,
+        "mock": runtime.mock,
+        "no_cleanup": runtime.no_cleanup,
+        "dedupe_events": runtime.dedupe_events,
+        "allow_truncate": runtime.allow_truncate,
+        "no_push": runtime.no_push,
+        "attribute_authors": runtime.attribute_authors,
+        "max_prs": runtime.max_prs,
+        "tag": runtime.tag,
+    })
+}
+
+/// Environment variables holding credentials the Jules, git, and GitHub calls may echo.
+const TRANSCRIPT_SECRET_ENV_VARS: &[&str] = &["JULES_API_KEY", "GH_TOKEN", "GITHUB_TOKEN"];
+
+/// Secret values that must never appear in a transcript.
+fn transcript_secrets() -> Vec<String> {
+    TRANSCRIPT_SECRET_ENV_VARS.iter().filter_map(|name| std::env::var(name).ok()).collect()
+}
+
+/// What a real run will do, worked out before any session is created.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunPlan {
+    /// Roles the run executes.
+    pub roles: Vec<String>,
+    /// Branch the Jules sessions start from.
+    pub starting_branch: String,
+    /// Jules sessions the run creates.
+    pub sessions: usize,
+}
+
+/// Validate a run's inputs against the repository and describe what it will do.
+///
+/// Runs the same checks as [`execute`] before a client is created, so a run that
+/// would fail is rejected before anything asks for confirmation.
+pub fn plan<G, W>(
+    target: &RunOptions,
+    runtime: &RunRuntimeOptions,
+    git: &G,
+    repository: &W,
+) -> Result<RunPlan, AppError>
+where
+    G: Git,
+    W: RepositoryFilesystem + JloStore + JulesStore,
+{
+    let config = preflight(target, runtime, git, repository)?;
+    let role = match (&target.role, target.layer.is_single_role()) {
+        (Some(role), _) => RoleId::new(role)?.as_str().to_string(),
+        (None, true) => target.layer.dir_name().to_string(),
+        (None, false) => {
+            return Err(AppError::MissingArgument(format!(
+                "Role is required for {}",
+                target.layer.dir_name()
+            )));
+        }
+    };
+    if let Some(path) = &target.requirement {
+        read_requirement(path, runtime.requirement_input.as_deref(), repository)?;
     }
+    // Jules pushes and opens the PR itself, so --no-push skips these sessions.
+    let skips_session =
+        runtime.no_push && matches!(target.layer, Layer::Implementer | Layer::Integrator);
+
+    Ok(RunPlan {
+        roles: vec![role],
+        starting_branch: resolve_starting_branch(target.layer, &config, runtime.branch.as_deref()),
+        sessions: usize::from(!skips_session),
+    })
+}
+
+/// Checks shared by [`plan`] and [`execute`]: the task selector, the control-plane
+/// config, and the layer's branch contract.
+fn preflight<G, W>(
+    target: &RunOptions,
+    runtime: &RunRuntimeOptions,
+    git: &G,
+    repository: &W,
+) -> Result<ControlPlaneConfig, AppError>
+where
+    G: Git,
+    W: RepositoryFilesystem + JloStore,
+{
+    // Validate task selector if provided (prevents path traversal)
+    if let Some(ref task) = target.task
+        && !validate_identifier(task, false)
+    {
+        return Err(AppError::Validation(format!(
+            "Invalid task '{}': must be a safe path component (e.g. 'create_three_proposals')",
+            task,
+        )));
+    }
+
+    if target.task.is_some() && target.layer != crate::domain::Layer::Innovators {
+        return Err(AppError::Validation(format!(
+            "--task is only supported when layer is innovators (requested: {})",
+            target
+        )));
+    }
+
+    let config = load_control_plane_config(repository).map_err(|err| {
+        err.context(format!("while loading control-plane config for run {}", target))
+    })?;
+
+    let expected_branch = if target.layer.uses_worker_branch() {
+        config.run.jules_worker_branch.as_str()
+    } else {
+        config.run.jlo_target_branch.as_str()
+    };
+
+    // Validate current branch matches the layer's branch contract.
+    // --branch override bypasses this check (explicit operator override).
+    if runtime.branch.is_none() {
+        let current = git.get_current_branch()?;
+        if current != expected_branch {
+            return Err(AppError::Validation(format!(
+                "Layer '{}' requires branch '{}', but current branch is '{}'",
+                target.layer.dir_name(),
+                expected_branch,
+                current,
+            )));
+        }
+    }
+
+    Ok(config)
 }
 
 /// Execute the run command.
@@ -105,66 +326,69 @@ where
         + 'static,
     F: Fn() -> Result<(), AppError>,
 {
-    // Validate task selector if provided (prevents path traversal)
-    if let Some(ref task) = target.task
-        && !validate_identifier(task, false)
-    {
-        return Err(AppError::Validation(format!(
-            "Invalid task '{}': must be a safe path component (e.g. 'create_three_proposals')",
-            task,
-        )));
-    }
-
-    if target.task.is_some() && target.layer != crate::domain::Layer::Innovators {
-        return Err(AppError::Validation(
-            "--task is only supported when layer is innovators".to_string(),
-        ));
-    }
-
-    // Load configuration
-    let config = load_control_plane_config(jules_path, repository)?;
-
+    let config = preflight(&target, &runtime, git, repository)?;
     let expected_branch = if target.layer.uses_worker_branch() {
         config.run.jules_worker_branch.as_str()
     } else {
         config.run.jlo_target_branch.as_str()
     };
 
-    // Validate current branch matches the layer's branch contract.
-    // --branch override bypasses this check (explicit operator override).
-    if runtime.branch.is_none() {
-        let current = git.get_current_branch()?;
-        if current != expected_branch {
-            return Err(AppError::Validation(format!(
-                "Layer '{}' requires branch '{}', but current branch is '{}'",
-                target.layer.dir_name(),
-                expected_branch,
-                current,
-            )));
-        }
-    }
-
     if runtime.mock {
         validate_mock()?;
     }
 
     // Create client factory
-    let client_factory = LazyClientFactory { config: config.jules_api.clone() };
+    let client_factory = LazyClientFactory {
+        config: config.jules_api.clone(),
+        allow_truncate: runtime.allow_truncate,
+        breaker: runtime.breaker.clone().unwrap_or_default(),
+        transcript: runtime.record.as_ref().map(|_| Arc::new(RunTranscript::new())),
+    };
 
     // Get layer strategy
     let strategy = get_layer_strategy(target.layer);
 
+    // With --record, git and GitHub calls land in the same transcript as Jules calls.
+    let recording_adapters = client_factory.transcript.as_ref().map(|transcript| {
+        (
+            RecordingGit::new(git as &dyn Git, Arc::clone(transcript)),
+            RecordingGitHub::new(github as &dyn GitHub, Arc::clone(transcript)),
+        )
+    });
+    let (strategy_git, strategy_github): (&dyn Git, &dyn GitHub) = match &recording_adapters {
+        Some((git, github)) => (git, github),
+        None => (git, github),
+    };
+
     // Execute strategy
-    let result = strategy.execute(
+    let outcome = strategy.execute(
         jules_path,
         &target,
         &runtime,
         &config,
-        git,
-        github,
+        strategy_git,
+        strategy_github,
         repository,
         &client_factory,
-    )?;
+    );
+
+    if let (Some(path), Some(transcript)) = (&runtime.record, &client_factory.transcript) {
+        let recorded_outcome = match &outcome {
+            Ok(result) => json!({ "ok": result }),
+            Err(err) => json!({ "error": err.to_string() }),
+        };
+        // The transcript is a side artifact: failing to write it must not change the run result.
+        if let Err(err) = transcript.write(
+            path,
+            transcript_inputs(&target, &runtime),
+            recorded_outcome,
+            &transcript_secrets(),
+        ) {
+            eprintln!("Warning: failed to write run transcript to {}: {}", path.display(), err);
+        }
+    }
+
+    let result = outcome.map_err(|err| err.context(format!("while running {}", target)))?;
 
     // Mock executions can checkout ephemeral branches during simulation.
     // Restore the expected layer branch so subsequent runs keep branch context.
@@ -182,12 +406,14 @@ where
             repository,
             git,
         )?;
-        println!(
+        eprintln!(
             "✅ Cleaned requirement and source events ({} file(s) removed)",
             cleanup_res.deleted_paths.len()
         );
 
-        if !runtime.mock {
+        if runtime.no_push {
+            eprintln!("Skipping worker-branch push for requirement cleanup (--no-push)");
+        } else if !runtime.mock {
             push_worker_branch(PushWorkerBranchOptions {
                 change_token: format!("requirement-cleanup-{}", cleanup_res.requirement_id),
                 commit_message: format!("jules: clean requirement {}", cleanup_res.requirement_id),
@@ -196,6 +422,7 @@ where
                     "Automated cleanup for processed requirement `{}`.\n\n- remove requirement artifact\n- remove source event artifacts",
                     cleanup_res.requirement_id
                 ),
+                merge_strategy: MergeStrategy::Squash,
             })?;
         }
     }
@@ -251,6 +478,14 @@ mod tests {
             Ok(self.current_branch.lock().expect("branch lock poisoned").clone())
         }
 
+        fn get_default_branch(&self) -> Result<String, AppError> {
+            Ok("main".to_string())
+        }
+
+        fn blame_last_author(&self, _rev: &str, _path: &str) -> Result<String, AppError> {
+            Ok("Test Author".to_string())
+        }
+
         fn commit_exists(&self, _sha: &str) -> bool {
             true
         }
@@ -263,6 +498,22 @@ mod tests {
             Ok("mocksha000000".to_string())
         }
 
+        fn rev_parse(&self, _rev: &str) -> Result<String, AppError> {
+            Ok(String::new())
+        }
+
+        fn commit_message(&self, _sha: &str) -> Result<String, AppError> {
+            Ok(String::new())
+        }
+
+        fn log_range(
+            &self,
+            _from: &str,
+            _to: &str,
+        ) -> Result<Vec<crate::ports::CommitInfo>, AppError> {
+            Ok(Vec::new())
+        }
+
         fn has_changes(
             &self,
             _from: &str,
@@ -272,6 +523,10 @@ mod tests {
             Ok(false)
         }
 
+        fn status(&self, _pathspec: &[&str]) -> Result<Vec<crate::ports::StatusEntry>, AppError> {
+            Ok(Vec::new())
+        }
+
         fn run_command(&self, args: &[&str], _cwd: Option<&Path>) -> Result<String, AppError> {
             if args.len() >= 3 && args[0] == "rm" && args[1] == "--" {
                 for rel_path in &args[2..] {
@@ -323,10 +578,55 @@ mod tests {
             Ok(())
         }
 
+        fn fetch_prune(&self, _remote: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn remote_exists(&self, _name: &str) -> Result<bool, AppError> {
+            Ok(true)
+        }
+
+        fn get_config(&self, _key: &str) -> Result<Option<String>, AppError> {
+            Ok(None)
+        }
+
+        fn set_config(&self, _key: &str, _value: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn unset_config(&self, _key: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn abort_merge(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn cherry_pick(&self, _rev: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn cherry_pick_abort(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn reset_hard_to(&self, _branch: &str, _rev: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
         fn delete_branch(&self, _branch: &str, _force: bool) -> Result<bool, AppError> {
             Ok(true)
         }
 
+        fn create_tag(
+            &self,
+            _name: &str,
+            _message: Option<&str>,
+            _rev: Option<&str>,
+        ) -> Result<(), AppError> {
+            Ok(())
+        }
+
         fn create_workspace(&self, _branch: &str) -> Result<Box<dyn GitWorkspace>, AppError> {
             unimplemented!()
         }
@@ -416,11 +716,22 @@ mod tests {
             Ok(())
         }
 
+        fn list_open_pull_requests(
+            &self,
+            _base: &str,
+        ) -> Result<Vec<crate::ports::PullRequestSummary>, AppError> {
+            Ok(Vec::new())
+        }
+
         fn list_pr_files(&self, _pr_number: u64) -> Result<Vec<String>, AppError> {
             Ok(Vec::new())
         }
 
-        fn merge_pull_request(&self, _pr_number: u64) -> Result<(), AppError> {
+        fn merge_pull_request(
+            &self,
+            _pr_number: u64,
+            _strategy: MergeStrategy,
+        ) -> Result<(), AppError> {
             Ok(())
         }
     }
@@ -527,6 +838,215 @@ roles = [
         serde_yaml::from_str::<EventDoc>(&content).expect("parse event")
     }
 
+    fn files_under(dir: &Path) -> Vec<(String, String)> {
+        let mut files = Vec::new();
+        let mut pending = vec![dir.to_path_buf()];
+        while let Some(current) = pending.pop() {
+            for entry in fs::read_dir(&current).expect("read dir") {
+                let path = entry.expect("read dir entry").path();
+                if path.is_dir() {
+                    pending.push(path);
+                } else {
+                    let name = path.strip_prefix(dir).unwrap().display().to_string();
+                    files.push((name, fs::read_to_string(&path).expect("read file")));
+                }
+            }
+        }
+        files.sort();
+        files
+    }
+
+    fn seeded_mock_run(root: &Path, target: RunOptions, seed: u64, branch: &str) -> Vec<String> {
+        let repository = LocalRepositoryAdapter::new(root.to_path_buf());
+        let git = TestGit::new(root.to_path_buf(), branch);
+        let layer = target.layer;
+        execute_with_mock_prerequisite_validator(
+            &repository.jules_path(),
+            target,
+            RunRuntimeOptions { mock: true, mock_seed: Some(seed), ..Default::default() },
+            &git,
+            &TestGitHub::new(),
+            &repository,
+            || Ok(()),
+        )
+        .unwrap_or_else(|err| panic!("{} mock run should succeed: {}", layer.dir_name(), err));
+        git.pushed_branches()
+    }
+
+    /// Run every mock layer that stamps times or ids; returns pushed branches and exchange files.
+    fn seeded_mock_pipeline_run(seed: u64) -> (Vec<String>, Vec<(String, String)>) {
+        use crate::domain::Layer;
+
+        let temp = tempdir().expect("tempdir");
+        let root = temp.path().to_path_buf();
+        write_mock_workspace(&root, "mock-run-seeded");
+        let target = |layer: Layer, role: Option<&str>, requirement: Option<PathBuf>| RunOptions {
+            layer,
+            role: role.map(str::to_string),
+            requirement,
+            task: (layer == Layer::Innovators).then(|| "create_three_proposals".to_string()),
+            role_file: None,
+        };
+
+        let mut pushed = Vec::new();
+        pushed.extend(seeded_mock_run(
+            &root,
+            target(Layer::Observers, Some("taxonomy"), None),
+            seed,
+            "jules",
+        ));
+        pushed.extend(seeded_mock_run(
+            &root,
+            target(Layer::Innovators, Some("screener"), None),
+            seed,
+            "jules",
+        ));
+        pushed.extend(seeded_mock_run(&root, target(Layer::Decider, None, None), seed, "jules"));
+
+        let mut requirements: Vec<PathBuf> =
+            fs::read_dir(root.join(".jules/exchange/requirements"))
+                .expect("read requirements dir")
+                .map(|entry| entry.expect("read dir entry").path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "yml"))
+                .collect();
+        requirements.sort();
+        let (ready, planned): (Vec<PathBuf>, Vec<PathBuf>) = requirements
+            .into_iter()
+            .partition(|path| read_requirement_doc(path).implementation_ready);
+        // Implementer cleanup deletes its requirement, so keep the decider output too.
+        let mut files = files_under(&root.join(".jules/exchange"));
+
+        pushed.extend(seeded_mock_run(
+            &root,
+            target(Layer::Planner, None, planned.first().cloned()),
+            seed,
+            "jules",
+        ));
+        pushed.extend(seeded_mock_run(
+            &root,
+            target(Layer::Implementer, None, ready.first().cloned()),
+            seed,
+            "main",
+        ));
+        files.extend(files_under(&root.join(".jules/exchange")));
+        let marker = fs::read_to_string(root.join(".mock-run-seeded")).expect("implementer marker");
+        files.push((".mock-run-seeded".to_string(), marker));
+        (pushed, files)
+    }
+
+    #[test]
+    #[serial]
+    fn mock_runs_with_same_seed_produce_identical_artifacts() {
+        let _mock_tag_env = EnvVarGuard::set("JULES_MOCK_TAG", "mock-run-seeded");
+
+        let (branches_a, files_a) = seeded_mock_pipeline_run(7);
+        let (branches_b, files_b) = seeded_mock_pipeline_run(7);
+
+        for prefix in [
+            "jules-observer-",
+            "jules-innovator-",
+            "jules-decider-",
+            "jules-planner-",
+            "jules-implementer-",
+        ] {
+            assert!(
+                branches_a.iter().any(|branch| branch.starts_with(prefix)),
+                "expected a {} branch in {:?}",
+                prefix,
+                branches_a
+            );
+        }
+        assert!(files_a.iter().any(|(name, _)| name.starts_with("proposals/")), "{:?}", files_a);
+        assert_eq!(branches_a, branches_b);
+        assert_eq!(files_a, files_b);
+
+        let (_, files_c) = seeded_mock_pipeline_run(8);
+        assert_ne!(files_a, files_c);
+    }
+
+    fn seed_event(id: &str, title: &str) -> serde_json::Value {
+        serde_json::json!({
+            "schema_version": 1,
+            "id": id,
+            "requirement_id": "",
+            "created_at": "2026-02-05",
+            "author_role": "taxonomy",
+            "confidence": "high",
+            "title": title,
+            "statement": "Seeded event for isolated decider testing.",
+            "evidence": [{ "path": "README.md", "loc": [1, 5], "note": "Seeded evidence" }],
+        })
+    }
+
+    fn mock_observer_run(
+        root: &Path,
+        mock_events: Vec<serde_json::Value>,
+    ) -> Result<RunResult, AppError> {
+        let repository = LocalRepositoryAdapter::new(root.to_path_buf());
+        execute_with_mock_prerequisite_validator(
+            &repository.jules_path(),
+            RunOptions {
+                layer: crate::domain::Layer::Observers,
+                role: Some("taxonomy".to_string()),
+                requirement: None,
+                task: None,
+                role_file: None,
+            },
+            RunRuntimeOptions { mock: true, mock_events: Some(mock_events), ..Default::default() },
+            &TestGit::new(root.to_path_buf(), "jules"),
+            &TestGitHub::new(),
+            &repository,
+            || Ok(()),
+        )
+    }
+
+    #[test]
+    #[serial]
+    fn mock_observer_writes_seeded_events_to_pending() {
+        let temp = tempdir().expect("tempdir");
+        let root = temp.path().to_path_buf();
+        let mock_tag = "mock-run-emit";
+        write_mock_workspace(&root, mock_tag);
+        let _mock_tag_env = EnvVarGuard::set("JULES_MOCK_TAG", mock_tag);
+        let pending = root.join(".jules/exchange/events/pending");
+        let before = fs::read_dir(&pending).expect("read pending").count();
+
+        mock_observer_run(
+            &root,
+            vec![seed_event("ee5555", "First seeded"), seed_event("ff6666", "Second seeded")],
+        )
+        .expect("mock observer run should succeed");
+
+        assert_eq!(fs::read_dir(&pending).expect("read pending").count(), before + 2);
+        for (id, title) in [("ee5555", "First seeded"), ("ff6666", "Second seeded")] {
+            let content = fs::read_to_string(pending.join(format!("{}-{}.yml", mock_tag, id)))
+                .expect("seeded event written");
+            let doc: serde_yaml::Value = serde_yaml::from_str(&content).expect("parse event");
+            assert_eq!(doc["id"].as_str(), Some(id));
+            assert_eq!(doc["title"].as_str(), Some(title));
+        }
+    }
+
+    #[test]
+    #[serial]
+    fn mock_observer_rejects_seeded_events_that_fail_the_schema() {
+        let temp = tempdir().expect("tempdir");
+        let root = temp.path().to_path_buf();
+        let mock_tag = "mock-run-emit-invalid";
+        write_mock_workspace(&root, mock_tag);
+        let _mock_tag_env = EnvVarGuard::set("JULES_MOCK_TAG", mock_tag);
+        let pending = root.join(".jules/exchange/events/pending");
+        let before = fs::read_dir(&pending).expect("read pending").count();
+
+        let mut invalid = seed_event("ee5555", "Invalid");
+        invalid["confidence"] = serde_json::json!("certain");
+        let err = mock_observer_run(&root, vec![seed_event("dd5555", "Valid"), invalid])
+            .expect_err("invalid seeded event must be rejected");
+
+        assert!(err.to_string().contains("--emit-events[1]"), "{}", err);
+        assert_eq!(fs::read_dir(&pending).expect("read pending").count(), before);
+    }
+
     #[test]
     #[serial]
     fn mock_decider_and_implementer_cleanup_tracks_source_events_consistently() {
@@ -549,12 +1069,27 @@ roles = [
                 role: None,
                 requirement: None,
                 task: None,
+                role_file: None,
             },
             RunRuntimeOptions {
                 prompt_preview: false,
                 branch: None,
                 mock: true,
                 no_cleanup: false,
+                print_prompts: false,
+                dedupe_events: false,
+                allow_truncate: false,
+                mock_seed: None,
+                no_push: false,
+                mock_events: None,
+                record: None,
+                attribute_authors: false,
+                max_prs: None,
+                tag: None,
+                requirement_input: None,
+                breaker: None,
+                report_stream: Default::default(),
+                config: None,
             },
             &decider_git,
             &github,
@@ -647,12 +1182,27 @@ roles = [
                 role: None,
                 requirement: Some(implementer_requirement.clone()),
                 task: None,
+                role_file: None,
             },
             RunRuntimeOptions {
                 prompt_preview: false,
                 branch: None,
                 mock: true,
                 no_cleanup: false,
+                print_prompts: false,
+                dedupe_events: false,
+                allow_truncate: false,
+                mock_seed: None,
+                no_push: false,
+                mock_events: None,
+                record: None,
+                attribute_authors: false,
+                max_prs: None,
+                tag: None,
+                requirement_input: None,
+                breaker: None,
+                report_stream: Default::default(),
+                config: None,
             },
             &implementer_git,
             &github,
@@ -716,12 +1266,27 @@ roles = [
                 role: None,
                 requirement: None,
                 task: None,
+                role_file: None,
             },
             RunRuntimeOptions {
                 prompt_preview: false,
                 branch: None,
                 mock: false,
                 no_cleanup: false,
+                print_prompts: false,
+                dedupe_events: false,
+                allow_truncate: false,
+                mock_seed: None,
+                no_push: false,
+                mock_events: None,
+                record: None,
+                attribute_authors: false,
+                max_prs: None,
+                tag: None,
+                requirement_input: None,
+                breaker: None,
+                report_stream: Default::default(),
+                config: None,
             },
             &git,
             &github,
@@ -736,6 +1301,94 @@ roles = [
         assert!(msg.contains("main"), "error should name current branch: {}", msg);
     }
 
+    #[test]
+    #[serial]
+    fn transcript_secrets_include_github_tokens() {
+        let _jules = EnvVarGuard::set("JULES_API_KEY", "jules-key");
+        let _gh = EnvVarGuard::set("GH_TOKEN", "gh-token");
+        let _github = EnvVarGuard::set("GITHUB_TOKEN", "github-token");
+
+        assert_eq!(transcript_secrets(), vec!["jules-key", "gh-token", "github-token"]);
+    }
+
+    #[test]
+    #[serial]
+    fn plan_rejects_a_run_that_would_fail_preflight() {
+        let temp = tempdir().expect("tempdir");
+        let root = temp.path().to_path_buf();
+        write_mock_workspace(&root, "test");
+        let repository = LocalRepositoryAdapter::new(root.clone());
+
+        let decider = RunOptions {
+            layer: crate::domain::Layer::Decider,
+            role: None,
+            requirement: None,
+            task: None,
+            role_file: None,
+        };
+        let err = plan(
+            &decider,
+            &RunRuntimeOptions::default(),
+            &TestGit::new(root.clone(), "main"),
+            &repository,
+        )
+        .expect_err("wrong branch must be rejected");
+        assert!(err.to_string().contains("requires branch 'jules'"), "{}", err);
+
+        let implementer = RunOptions {
+            layer: crate::domain::Layer::Implementer,
+            requirement: Some(PathBuf::from(".jules/exchange/requirements/missing.yml")),
+            ..decider
+        };
+        plan(&implementer, &RunRuntimeOptions::default(), &TestGit::new(root, "main"), &repository)
+            .expect_err("missing requirement must be rejected");
+    }
+
+    #[test]
+    #[serial]
+    fn plan_names_the_role_and_counts_sessions() {
+        let temp = tempdir().expect("tempdir");
+        let root = temp.path().to_path_buf();
+        write_mock_workspace(&root, "test");
+        let repository = LocalRepositoryAdapter::new(root.clone());
+
+        let observers = RunOptions {
+            layer: crate::domain::Layer::Observers,
+            role: Some("taxonomy".to_string()),
+            requirement: None,
+            task: None,
+            role_file: None,
+        };
+        let planned = plan(
+            &observers,
+            &RunRuntimeOptions::default(),
+            &TestGit::new(root.clone(), "jules"),
+            &repository,
+        )
+        .expect("observer plan");
+        assert_eq!(
+            planned,
+            RunPlan {
+                roles: vec!["taxonomy".to_string()],
+                starting_branch: "jules".to_string(),
+                sessions: 1,
+            }
+        );
+
+        let integrator =
+            RunOptions { layer: crate::domain::Layer::Integrator, role: None, ..observers };
+        let runtime = RunRuntimeOptions {
+            branch: Some("release".to_string()),
+            no_push: true,
+            ..Default::default()
+        };
+        let planned = plan(&integrator, &runtime, &TestGit::new(root, "jules"), &repository)
+            .expect("integrator plan");
+        assert_eq!(planned.roles, vec!["integrator".to_string()]);
+        assert_eq!(planned.starting_branch, "release");
+        assert_eq!(planned.sessions, 0, "--no-push skips the integrator session");
+    }
+
     #[test]
     #[serial]
     fn wrong_branch_fails_fast_for_target_branch_layer() {
@@ -755,12 +1408,27 @@ roles = [
                 role: None,
                 requirement: Some(root.join(".jules/exchange/requirements/fake.yml")),
                 task: None,
+                role_file: None,
             },
             RunRuntimeOptions {
                 prompt_preview: false,
                 branch: None,
                 mock: false,
                 no_cleanup: false,
+                print_prompts: false,
+                dedupe_events: false,
+                allow_truncate: false,
+                mock_seed: None,
+                no_push: false,
+                mock_events: None,
+                record: None,
+                attribute_authors: false,
+                max_prs: None,
+                tag: None,
+                requirement_input: None,
+                breaker: None,
+                report_stream: Default::default(),
+                config: None,
             },
             &git,
             &github,
@@ -797,12 +1465,27 @@ roles = [
                 role: None,
                 requirement: None,
                 task: None,
+                role_file: None,
             },
             RunRuntimeOptions {
                 prompt_preview: false,
                 branch: Some("custom-branch".to_string()),
                 mock: true,
                 no_cleanup: false,
+                print_prompts: false,
+                dedupe_events: false,
+                allow_truncate: false,
+                mock_seed: None,
+                no_push: false,
+                mock_events: None,
+                record: None,
+                attribute_authors: false,
+                max_prs: None,
+                tag: None,
+                requirement_input: None,
+                breaker: None,
+                report_stream: Default::default(),
+                config: None,
             },
             &git,
             &github,
@@ -835,12 +1518,27 @@ roles = [
                 role: Some("taxonomy".to_string()),
                 requirement: None,
                 task: None,
+                role_file: None,
             },
             RunRuntimeOptions {
                 prompt_preview: false,
                 branch: None,
                 mock: true,
                 no_cleanup: false,
+                print_prompts: false,
+                dedupe_events: false,
+                allow_truncate: false,
+                mock_seed: None,
+                no_push: false,
+                mock_events: None,
+                record: None,
+                attribute_authors: false,
+                max_prs: None,
+                tag: None,
+                requirement_input: None,
+                breaker: None,
+                report_stream: Default::default(),
+                config: None,
             },
             &git,
             &github,
@@ -855,4 +1553,78 @@ roles = [
             "mock observer run should restore worker branch context"
         );
     }
+
+    #[test]
+    #[serial]
+    fn recorded_mock_run_transcript_includes_git_and_github_calls() {
+        let temp = tempdir().expect("tempdir");
+        let root = temp.path().to_path_buf();
+        write_mock_workspace(&root, "mock-record");
+        let _mock_tag_env = EnvVarGuard::set("JULES_MOCK_TAG", "mock-record");
+        let record_path = root.join("records/run.json");
+
+        let repository = LocalRepositoryAdapter::new(root.clone());
+        execute_with_mock_prerequisite_validator(
+            &repository.jules_path(),
+            RunOptions {
+                layer: crate::domain::Layer::Observers,
+                role: Some("taxonomy".to_string()),
+                requirement: None,
+                task: None,
+                role_file: None,
+            },
+            RunRuntimeOptions {
+                mock: true,
+                record: Some(record_path.clone()),
+                ..Default::default()
+            },
+            &TestGit::new(root.clone(), "jules"),
+            &TestGitHub::new(),
+            &repository,
+            || Ok(()),
+        )
+        .expect("recorded mock observer run should succeed");
+
+        let transcript: serde_json::Value =
+            serde_json::from_str(&fs::read_to_string(&record_path).expect("read transcript"))
+                .expect("parse transcript");
+        let calls = transcript["calls"].as_array().expect("calls array");
+        let recorded = |adapter: &str, operation: &str| {
+            calls.iter().any(|call| call["adapter"] == adapter && call["operation"] == operation)
+        };
+        assert!(recorded("git", "checkout_branch"), "calls: {:?}", calls);
+        assert!(recorded("git", "push_branch"), "calls: {:?}", calls);
+        assert!(recorded("github", "create_pull_request"), "calls: {:?}", calls);
+    }
+
+    #[test]
+    #[serial]
+    fn transcript_write_failure_does_not_fail_the_run() {
+        let temp = tempdir().expect("tempdir");
+        let root = temp.path().to_path_buf();
+        write_mock_workspace(&root, "mock-record-fail");
+        let _mock_tag_env = EnvVarGuard::set("JULES_MOCK_TAG", "mock-record-fail");
+        // A directory at the transcript path makes the write fail.
+        let record_path = root.join("records");
+        fs::create_dir_all(&record_path).expect("create directory at record path");
+
+        let repository = LocalRepositoryAdapter::new(root.clone());
+        let result = execute_with_mock_prerequisite_validator(
+            &repository.jules_path(),
+            RunOptions {
+                layer: crate::domain::Layer::Observers,
+                role: Some("taxonomy".to_string()),
+                requirement: None,
+                task: None,
+                role_file: None,
+            },
+            RunRuntimeOptions { mock: true, record: Some(record_path), ..Default::default() },
+            &TestGit::new(root.clone(), "jules"),
+            &TestGitHub::new(),
+            &repository,
+            || Ok(()),
+        );
+
+        assert!(result.is_ok(), "transcript failure must not fail the run: {:?}", result.err());
+    }
 }
@@ -1,7 +1,10 @@
 use std::path::Path;
 
+use super::ReportStream;
 use crate::domain::{AppError, Layer, PromptAssetLoader, RoleError, RoleId};
-use crate::ports::{AutomationMode, JulesClient, RepositoryFilesystem, SessionRequest};
+use crate::ports::{
+    AutomationMode, JulesClient, RepositoryFilesystem, SessionRequest, SessionResponse,
+};
 
 pub fn print_role_preview<W: RepositoryFilesystem + PromptAssetLoader + ?Sized>(
     jules_path: &Path,
@@ -9,16 +12,17 @@ pub fn print_role_preview<W: RepositoryFilesystem + PromptAssetLoader + ?Sized>(
     role: &RoleId,
     starting_branch: &str,
     repository: &W,
+    report: ReportStream,
 ) {
-    println!("=== Prompt Preview: {} ===", layer.display_name());
-    println!("Starting branch: {}", starting_branch);
-    println!("Role: {}\n", role);
+    report.line(format_args!("=== Prompt Preview: {} ===", layer.display_name()));
+    report.line(format_args!("Starting branch: {}", starting_branch));
+    report.line(format_args!("Role: {}\n", role));
 
     let root = jules_path.parent().unwrap_or(Path::new("."));
     let role_yml_path = crate::domain::roles::paths::role_yml(root, layer, role.as_str());
     let custom_role_exists = repository.file_exists(&role_yml_path.to_string_lossy());
     if !custom_role_exists {
-        println!("  ⚠️  role.yml not found at {}\n", role_yml_path.display());
+        report.line(format_args!("  ⚠️  role.yml not found at {}\n", role_yml_path.display()));
         return;
     }
 
@@ -29,10 +33,23 @@ pub fn print_role_preview<W: RepositoryFilesystem + PromptAssetLoader + ?Sized>(
         )
         .is_some();
     if contracts_exists {
-        println!("  Contracts: prompt-assemble://{}", contracts_path);
+        report.line(format_args!("  Contracts: prompt-assemble://{}", contracts_path));
     }
 
-    println!("  Role config: {}", role_yml_path.display());
+    report.line(format_args!("  Role config: {}", role_yml_path.display()));
+}
+
+pub fn print_role_file_preview(
+    layer: Layer,
+    role: &RoleId,
+    starting_branch: &str,
+    role_file: &Path,
+    report: ReportStream,
+) {
+    report.line(format_args!("=== Prompt Preview: {} ===", layer.display_name()));
+    report.line(format_args!("Starting branch: {}", starting_branch));
+    report.line(format_args!("Role: {}\n", role));
+    report.line(format_args!("  Role config (override): {}", role_file.display()));
 }
 
 pub fn validate_role_exists<W: RepositoryFilesystem + PromptAssetLoader + ?Sized>(
@@ -45,7 +62,23 @@ pub fn validate_role_exists<W: RepositoryFilesystem + PromptAssetLoader + ?Sized
     let role_yml_path = crate::domain::roles::paths::role_yml(root, layer, role);
 
     if !repository.file_exists(&role_yml_path.to_string_lossy()) {
-        return Err(RoleError::NotFound(format!("{}/{}", layer.dir_name(), role)).into());
+        // A role defined under another multi-role layer is a common mistake; point at it.
+        let found = Layer::ALL.into_iter().find(|other| {
+            *other != layer
+                && !other.is_single_role()
+                && repository.file_exists(
+                    &crate::domain::roles::paths::role_yml(root, *other, role).to_string_lossy(),
+                )
+        });
+        return Err(match found {
+            Some(found) => RoleError::InOtherLayer {
+                role: role.to_string(),
+                layer: layer.dir_name().to_string(),
+                found: found.dir_name().to_string(),
+            },
+            None => RoleError::NotFound(format!("{}/{}", layer.dir_name(), role)),
+        }
+        .into());
     }
 
     Ok(())
@@ -58,8 +91,8 @@ pub fn dispatch_session<C: JulesClient + ?Sized, S: Into<String>>(
     source: &str,
     starting_branch: S,
     client: &C,
-) -> Result<String, AppError> {
-    println!("Executing {} / {}...", layer.dir_name(), role);
+) -> Result<SessionResponse, AppError> {
+    eprintln!("Executing {} / {}...", layer.dir_name(), role);
 
     let request = SessionRequest {
         prompt,
@@ -70,7 +103,85 @@ pub fn dispatch_session<C: JulesClient + ?Sized, S: Into<String>>(
     };
 
     let response = client.create_session(request)?;
-    println!("  ✅ Session created: {}", response.session_id);
+    eprintln!("  ✅ Session created: {}", response.session_id);
+
+    Ok(response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestStore;
+
+    fn store_with_role(layer: Layer, role: &str) -> TestStore {
+        let path = crate::domain::roles::paths::role_yml(Path::new("repo"), layer, role);
+        TestStore::new().with_exists(true).with_file(&path.to_string_lossy(), "role: x\n")
+    }
+
+    #[test]
+    fn role_from_another_layer_suggests_that_layer() {
+        let repository = store_with_role(Layer::Innovators, "scout");
+
+        let err =
+            validate_role_exists(Path::new("repo/.jules"), Layer::Observers, "scout", &repository)
+                .unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("not defined in layer 'observers'"), "{}", message);
+        assert!(message.contains("jlo run innovators --role scout"), "{}", message);
+    }
 
-    Ok(response.session_id)
+    #[test]
+    fn unknown_role_reports_not_found() {
+        let repository = store_with_role(Layer::Innovators, "scout");
+
+        let err =
+            validate_role_exists(Path::new("repo/.jules"), Layer::Observers, "ghost", &repository)
+                .unwrap_err();
+
+        assert!(err.to_string().contains("Role 'observers/ghost' not found"));
+    }
+
+    #[test]
+    fn role_in_requested_layer_passes() {
+        let repository = store_with_role(Layer::Observers, "scout");
+
+        validate_role_exists(Path::new("repo/.jules"), Layer::Observers, "scout", &repository)
+            .unwrap();
+    }
+
+    struct UrlClient;
+
+    impl JulesClient for UrlClient {
+        fn create_session(&self, request: SessionRequest) -> Result<SessionResponse, AppError> {
+            assert_eq!(request.starting_branch, "jules");
+            Ok(SessionResponse {
+                session_id: "sessions/42".to_string(),
+                status: "created".to_string(),
+                url: Some("https://jules.google.com/session/42".to_string()),
+            })
+        }
+
+        fn health(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn dispatch_session_returns_session_url() {
+        let role = RoleId::new("taxonomy").unwrap();
+
+        let response = dispatch_session(
+            Layer::Observers,
+            &role,
+            "prompt".to_string(),
+            "sources/github/owner/repo",
+            "jules",
+            &UrlClient,
+        )
+        .unwrap();
+
+        assert_eq!(response.session_id, "sessions/42");
+        assert_eq!(response.url.as_deref(), Some("https://jules.google.com/session/42"));
+    }
 }
@@ -1,12 +1,26 @@
 //! Setup gen command - generates install.sh, vars.toml, and secrets.toml.
 
+use std::collections::BTreeSet;
+
 use crate::adapters::catalogs::EmbeddedSetupComponentCatalog;
 use crate::app::config::load_setup_config;
-use crate::domain::AppError;
+use crate::domain::setup::SetupEnvArtifacts;
 use crate::domain::setup::artifact_generator;
 use crate::domain::setup::dependency_graph::DependencyGraph;
 use crate::domain::setup::error::SetupError;
-use crate::ports::RepositoryFilesystem;
+use crate::domain::setup::setup_component::is_valid_env_var_name;
+use crate::domain::setup::tools_config::SetupConfig;
+use crate::domain::{AppError, SetupComponent};
+use crate::ports::{RepositoryFilesystem, ScaffoldFile};
+
+/// Optional behavior for `setup gen` beyond writing the default artifacts.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SetupGenOptions {
+    /// Also write `setup.lock` (resolved order and version pins).
+    pub lock: bool,
+    /// Report every `tools.yml` problem together instead of only the first.
+    pub collect: bool,
+}
 
 /// Execute the setup gen command.
 ///
@@ -15,8 +29,80 @@ use crate::ports::RepositoryFilesystem;
 /// - `.jlo/setup/vars.toml` - Non-secret environment variables
 /// - `.jlo/setup/secrets.toml` - Secret environment variables
 ///
+/// With `lock`, also writes `.jlo/setup/setup.lock`.
+///
 /// Returns the list of resolved component names in installation order.
-pub fn execute(store: &impl RepositoryFilesystem) -> Result<Vec<String>, AppError> {
+pub fn execute(store: &impl RepositoryFilesystem, lock: bool) -> Result<Vec<String>, AppError> {
+    let (config, components, env_artifacts) = resolve(store)?;
+
+    // Generate install script
+    let script_content = artifact_generator::generate_install_script(
+        &components,
+        config.pre_install.as_deref(),
+        config.post_install.as_deref(),
+    );
+    let install_sh = ScaffoldFile::from_asset(".jlo/setup/install.sh".to_string(), script_content);
+    store.write_file(&install_sh.path, &install_sh.content)?;
+    if install_sh.executable {
+        store.set_executable(&install_sh.path)?;
+    }
+
+    store.write_file(VARS_TOML, &env_artifacts.vars_toml)?;
+    store.write_file(SECRETS_TOML, &env_artifacts.secrets_toml)?;
+
+    if lock {
+        let lockfile =
+            artifact_generator::generate_lockfile(&components, &env_artifacts.vars_toml)?;
+        store.write_file(SETUP_LOCK, &lockfile)?;
+    }
+
+    Ok(components.iter().map(|c| c.name.to_string()).collect())
+}
+
+/// Like [`execute`], but first validates all of `tools.yml` and reports every
+/// problem together instead of stopping at the first.
+pub fn execute_collect(
+    store: &impl RepositoryFilesystem,
+    lock: bool,
+) -> Result<Vec<String>, AppError> {
+    let report = validate(store)?;
+    if !report.is_valid() {
+        return Err(SetupError::ValidationFailed(report.errors).into());
+    }
+    execute(store, lock)
+}
+
+/// Verify `.jlo/setup/setup.lock` matches what `setup gen --lock` would write now.
+///
+/// Returns the resolved component names when the lockfile is current.
+pub fn check_lockfile(store: &impl RepositoryFilesystem) -> Result<Vec<String>, AppError> {
+    let (_, components, env_artifacts) = resolve(store)?;
+
+    if !store.file_exists(SETUP_LOCK) {
+        return Err(AppError::Validation(format!(
+            "{} not found; run 'jlo setup gen --lock' to create it",
+            SETUP_LOCK
+        )));
+    }
+    let expected = artifact_generator::generate_lockfile(&components, &env_artifacts.vars_toml)?;
+    if store.read_file(SETUP_LOCK)? != expected {
+        return Err(AppError::Validation(format!(
+            "{} is out of date with tools.yml or vars.toml; run 'jlo setup gen --lock' to refresh it",
+            SETUP_LOCK
+        )));
+    }
+
+    Ok(components.iter().map(|c| c.name.to_string()).collect())
+}
+
+const VARS_TOML: &str = ".jlo/setup/vars.toml";
+const SECRETS_TOML: &str = ".jlo/setup/secrets.toml";
+const SETUP_LOCK: &str = ".jlo/setup/setup.lock";
+
+/// Resolve components from tools.yml and merge env artifacts with existing values.
+fn resolve(
+    store: &impl RepositoryFilesystem,
+) -> Result<(SetupConfig, Vec<SetupComponent>, SetupEnvArtifacts), AppError> {
     let jlo_setup = ".jlo/setup";
     if !store.file_exists(jlo_setup) {
         return Err(SetupError::NotInitialized.into());
@@ -29,30 +115,90 @@ pub fn execute(store: &impl RepositoryFilesystem) -> Result<Vec<String>, AppErro
     let catalog = EmbeddedSetupComponentCatalog::new()?;
     let components = DependencyGraph::resolve(&config.tools, &catalog)?;
 
-    // Generate install script
-    let script_content = artifact_generator::generate_install_script(&components);
-    let install_sh = ".jlo/setup/install.sh";
-    store.write_file(install_sh, &script_content)?;
-    store.set_executable(install_sh)?;
-
     // Generate/merge vars.toml and secrets.toml
-    let vars_toml_path = ".jlo/setup/vars.toml";
-    let secrets_toml_path = ".jlo/setup/secrets.toml";
     let existing_vars =
-        store.file_exists(vars_toml_path).then(|| store.read_file(vars_toml_path)).transpose()?;
-    let existing_secrets = store
-        .file_exists(secrets_toml_path)
-        .then(|| store.read_file(secrets_toml_path))
-        .transpose()?;
+        store.file_exists(VARS_TOML).then(|| store.read_file(VARS_TOML)).transpose()?;
+    let existing_secrets =
+        store.file_exists(SECRETS_TOML).then(|| store.read_file(SECRETS_TOML)).transpose()?;
     let env_artifacts = artifact_generator::merge_env_artifacts(
         &components,
         existing_vars.as_deref(),
         existing_secrets.as_deref(),
     )?;
-    store.write_file(vars_toml_path, &env_artifacts.vars_toml)?;
-    store.write_file(secrets_toml_path, &env_artifacts.secrets_toml)?;
 
-    Ok(components.iter().map(|c| c.name.to_string()).collect())
+    Ok((config, components, env_artifacts))
+}
+
+/// Outcome of validating `tools.yml` without generating artifacts.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SetupValidationReport {
+    /// Resolved component names in installation order (empty when errors exist).
+    pub components: Vec<String>,
+    /// Every validation problem found, in discovery order.
+    pub errors: Vec<String>,
+}
+
+impl SetupValidationReport {
+    pub fn is_valid(&self) -> bool {
+        self.errors.is_empty()
+    }
+}
+
+/// Validate `.jlo/setup/tools.yml` without writing or comparing any artifact.
+///
+/// Each requested tool is resolved independently so that every unknown
+/// component, invalid identifier, cycle, and invalid environment variable name
+/// is reported rather than only the first.
+pub fn validate(store: &impl RepositoryFilesystem) -> Result<SetupValidationReport, AppError> {
+    if !store.file_exists(".jlo/setup") {
+        return Err(SetupError::NotInitialized.into());
+    }
+
+    let mut report = SetupValidationReport::default();
+    let config = match load_setup_config(store) {
+        Ok(config) => config,
+        Err(AppError::Setup(SetupError::ConfigMissing)) => {
+            return Err(SetupError::ConfigMissing.into());
+        }
+        Err(err) => {
+            report.errors.push(err.to_string());
+            return Ok(report);
+        }
+    };
+
+    let catalog = EmbeddedSetupComponentCatalog::new()?;
+    let mut seen = BTreeSet::new();
+    let mut reported_env = BTreeSet::new();
+    for tool in &config.tools {
+        if !seen.insert(tool.as_str()) {
+            report.errors.push(format!("Tool '{}' is listed more than once", tool));
+            continue;
+        }
+        match DependencyGraph::resolve(std::slice::from_ref(tool), &catalog) {
+            Ok(components) => {
+                for component in &components {
+                    for env in &component.env {
+                        if !is_valid_env_var_name(&env.name)
+                            && reported_env.insert((component.name.to_string(), env.name.clone()))
+                        {
+                            report.errors.push(format!(
+                                "Component '{}' declares invalid environment variable name '{}'",
+                                component.name, env.name
+                            ));
+                        }
+                    }
+                }
+            }
+            Err(err) => report.errors.push(err.to_string()),
+        }
+    }
+
+    if report.errors.is_empty() {
+        let components = DependencyGraph::resolve(&config.tools, &catalog)?;
+        report.components = components.iter().map(|c| c.name.to_string()).collect();
+    }
+
+    Ok(report)
 }
 
 #[cfg(test)]
@@ -66,7 +212,7 @@ mod tests {
     fn fails_if_not_initialized() {
         let store = TestStore::new();
 
-        let result = execute(&store);
+        let result = execute(&store, false);
 
         assert!(matches!(result, Err(AppError::Setup(SetupError::NotInitialized))));
     }
@@ -76,7 +222,7 @@ mod tests {
         let store = TestStore::new();
         store.write_file(".jlo/setup/placeholder", "").unwrap();
 
-        let result = execute(&store);
+        let result = execute(&store, false);
 
         assert!(matches!(result, Err(AppError::Setup(SetupError::ConfigMissing))));
     }
@@ -86,17 +232,52 @@ mod tests {
         let store = TestStore::new();
         store.write_file(".jlo/setup/tools.yml", "tools: []").unwrap();
 
-        let result = execute(&store);
+        let result = execute(&store, false);
 
         assert!(result.is_err());
     }
 
+    #[test]
+    fn collect_reports_every_problem_together() {
+        let store = TestStore::new();
+        store
+            .write_file(
+                ".jlo/setup/tools.yml",
+                "tools:\n  - nope-one\n  - just\n  - just\n  - nope-two\n",
+            )
+            .unwrap();
+
+        let fail_fast = execute(&store, false).unwrap_err().to_string();
+        assert!(fail_fast.contains("nope-one") && !fail_fast.contains("nope-two"));
+
+        let err = execute_collect(&store, false).unwrap_err();
+        let AppError::Setup(SetupError::ValidationFailed(errors)) = &err else {
+            panic!("expected collected validation errors, got {:?}", err);
+        };
+        assert_eq!(errors.len(), 3);
+        let message = err.to_string();
+        assert!(message.starts_with("tools.yml has 3 validation error(s):"));
+        assert!(message.contains("'nope-one' not found"));
+        assert!(message.contains("'just' is listed more than once"));
+        assert!(message.contains("'nope-two' not found"));
+        assert!(!store.file_exists(".jlo/setup/install.sh"));
+    }
+
+    #[test]
+    fn collect_generates_when_valid() {
+        let store = TestStore::new();
+        store.write_file(".jlo/setup/tools.yml", "tools:\n  - just").unwrap();
+
+        assert!(execute_collect(&store, false).unwrap().contains(&"just".to_string()));
+        assert!(store.file_exists(".jlo/setup/install.sh"));
+    }
+
     #[test]
     fn generates_install_script_and_env_files_in_control_plane() {
         let store = TestStore::new();
         store.write_file(".jlo/setup/tools.yml", "tools:\n  - just").unwrap();
 
-        let result = execute(&store).unwrap();
+        let result = execute(&store, false).unwrap();
 
         assert!(result.contains(&"just".to_string()));
 
@@ -110,4 +291,85 @@ mod tests {
         assert!(store.file_exists(".jlo/setup/vars.toml"));
         assert!(store.file_exists(".jlo/setup/secrets.toml"));
     }
+
+    #[test]
+    fn validate_reports_every_error_without_writing_artifacts() {
+        let store = TestStore::new();
+        store
+            .write_file(
+                ".jlo/setup/tools.yml",
+                "tools:\n  - just\n  - no-such-tool\n  - bad/id\n  - just\n",
+            )
+            .unwrap();
+
+        let report = validate(&store).unwrap();
+
+        assert!(!report.is_valid());
+        assert_eq!(report.errors.len(), 3, "{:?}", report.errors);
+        assert!(report.errors[0].contains("no-such-tool"));
+        assert!(report.errors[1].contains("bad/id"));
+        assert!(report.errors[2].contains("more than once"));
+        assert!(report.components.is_empty());
+        assert!(!store.file_exists(".jlo/setup/install.sh"));
+        assert!(!store.file_exists(".jlo/setup/vars.toml"));
+    }
+
+    #[test]
+    fn validate_resolves_valid_config() {
+        let store = TestStore::new();
+        store.write_file(".jlo/setup/tools.yml", "tools:\n  - just\n").unwrap();
+
+        let report = validate(&store).unwrap();
+
+        assert!(report.is_valid());
+        assert!(report.components.contains(&"just".to_string()));
+        assert!(!store.file_exists(".jlo/setup/install.sh"));
+    }
+
+    #[test]
+    fn lockfile_lists_components_in_resolved_order() {
+        let store = TestStore::new();
+        store.write_file(".jlo/setup/tools.yml", "tools:\n  - uv\n  - just\n").unwrap();
+
+        let resolved = execute(&store, true).unwrap();
+
+        let lock: serde_json::Value =
+            serde_json::from_str(&store.read_file(".jlo/setup/setup.lock").unwrap()).unwrap();
+        let locked: Vec<&str> = lock["components"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|entry| entry["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(locked, resolved);
+        assert_eq!(locked, vec!["just", "uv"]);
+        assert_eq!(lock["components"][0]["versions"]["JUST_VERSION"], "");
+    }
+
+    #[test]
+    fn check_detects_lockfile_drift() {
+        let store = TestStore::new();
+        store.write_file(".jlo/setup/tools.yml", "tools:\n  - just\n").unwrap();
+
+        assert!(check_lockfile(&store).unwrap_err().to_string().contains("not found"));
+
+        execute(&store, true).unwrap();
+        assert_eq!(check_lockfile(&store).unwrap(), vec!["just"]);
+
+        let vars = store.read_file(".jlo/setup/vars.toml").unwrap();
+        store
+            .write_file(".jlo/setup/vars.toml", &vars.replace("value = \"\"", "value = \"1.40.0\""))
+            .unwrap();
+        assert!(check_lockfile(&store).unwrap_err().to_string().contains("out of date"));
+    }
+
+    #[test]
+    fn gen_without_lock_writes_no_lockfile() {
+        let store = TestStore::new();
+        store.write_file(".jlo/setup/tools.yml", "tools:\n  - just\n").unwrap();
+
+        execute(&store, false).unwrap();
+
+        assert!(!store.file_exists(".jlo/setup/setup.lock"));
+    }
 }
@@ -1,14 +1,17 @@
 //! Setup list command - lists available components.
 
+use std::collections::HashMap;
+
 use crate::adapters::catalogs::EmbeddedSetupComponentCatalog;
 use crate::domain::AppError;
 use crate::ports::SetupComponentCatalog;
 
 /// Summary information for a component.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct SetupComponentSummary {
     pub name: String,
     pub summary: String,
+    pub dependencies: Vec<String>,
 }
 
 /// Detailed information for a component.
@@ -27,8 +30,35 @@ pub struct EnvVarInfo {
     pub name: String,
     pub description: String,
     pub default: Option<String>,
+    /// Non-standard variables referenced from the default value, resolved to their owning component.
+    pub references: Vec<EnvVarReference>,
 }
 
+/// A variable referenced from another variable's default value.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EnvVarReference {
+    pub name: String,
+    /// Component declaring the variable; `None` when no component defines it.
+    pub component: Option<String>,
+}
+
+/// Variables the shell or OS provides, so references to them are never reported as undeclared.
+const STANDARD_ENV_VARS: &[&str] = &[
+    "HOME",
+    "PATH",
+    "USER",
+    "SHELL",
+    "PWD",
+    "TMPDIR",
+    "LANG",
+    "TERM",
+    "HOSTNAME",
+    "XDG_CONFIG_HOME",
+    "XDG_CACHE_HOME",
+    "XDG_DATA_HOME",
+    "XDG_STATE_HOME",
+];
+
 /// Execute the setup list command.
 ///
 /// Returns summaries of all available components.
@@ -38,16 +68,78 @@ pub fn execute() -> Result<Vec<SetupComponentSummary>, AppError> {
 
     Ok(components
         .into_iter()
-        .map(|c| SetupComponentSummary { name: c.name.to_string(), summary: c.summary.clone() })
+        .map(|c| SetupComponentSummary {
+            name: c.name.to_string(),
+            summary: c.summary.clone(),
+            dependencies: c.dependencies.iter().map(|d| d.to_string()).collect(),
+        })
         .collect())
 }
 
+/// Render components as aligned `NAME | SUMMARY | DEPS` columns.
+///
+/// Rows wider than `width` shrink the summary first, then the dependency list; both are
+/// truncated with an ellipsis.
+pub fn render_table(components: &[SetupComponentSummary], width: usize) -> String {
+    const SEPARATOR: &str = " | ";
+    const MIN_SUMMARY_WIDTH: usize = 10;
+    const MIN_DEPS_WIDTH: usize = 4;
+
+    let deps: Vec<String> = components
+        .iter()
+        .map(
+            |c| if c.dependencies.is_empty() { "-".to_string() } else { c.dependencies.join(", ") },
+        )
+        .collect();
+    let name_width =
+        components.iter().map(|c| c.name.chars().count()).chain([4]).max().unwrap_or(4);
+    let longest_deps = deps.iter().map(|d| d.chars().count()).chain([4]).max().unwrap_or(4);
+    let longest_summary =
+        components.iter().map(|c| c.summary.chars().count()).chain([7]).max().unwrap_or(7);
+    let available = width.saturating_sub(name_width + 2 * SEPARATOR.len());
+    let summary_width =
+        longest_summary.min(available.saturating_sub(longest_deps).max(MIN_SUMMARY_WIDTH));
+    let deps_width = available.saturating_sub(summary_width).max(MIN_DEPS_WIDTH);
+
+    let mut out = String::new();
+    let mut push_row = |name: &str, summary: &str, deps: &str| {
+        let line = format!(
+            "{:<name_width$}{SEPARATOR}{:<summary_width$}{SEPARATOR}{}",
+            name,
+            truncate_with_ellipsis(summary, summary_width),
+            truncate_with_ellipsis(deps, deps_width)
+        );
+        out.push_str(line.trim_end());
+        out.push('\n');
+    };
+    push_row("NAME", "SUMMARY", "DEPS");
+    for (component, deps) in components.iter().zip(&deps) {
+        push_row(&component.name, &component.summary, deps);
+    }
+    out
+}
+
+fn truncate_with_ellipsis(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return text.to_string();
+    }
+    let mut truncated: String = text.chars().take(width.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
 /// Execute the setup list --detail command.
 ///
 /// Returns detailed information for a specific component.
 pub fn execute_detail(component_name: &str) -> Result<SetupComponentDetail, AppError> {
     let catalog = EmbeddedSetupComponentCatalog::new()?;
+    detail_from_catalog(&catalog, component_name)
+}
 
+fn detail_from_catalog(
+    catalog: &impl SetupComponentCatalog,
+    component_name: &str,
+) -> Result<SetupComponentDetail, AppError> {
     let component = catalog.get(component_name).ok_or_else(|| -> AppError {
         crate::domain::setup::error::SetupError::ComponentNotFound {
             name: component_name.to_string(),
@@ -56,6 +148,12 @@ pub fn execute_detail(component_name: &str) -> Result<SetupComponentDetail, AppE
         .into()
     })?;
 
+    let owners: HashMap<&str, &str> = catalog
+        .list_all()
+        .into_iter()
+        .flat_map(|c| c.env.iter().map(move |e| (e.name.as_str(), c.name.as_str())))
+        .collect();
+
     Ok(SetupComponentDetail {
         name: component.name.to_string(),
         summary: component.summary.clone(),
@@ -67,6 +165,18 @@ pub fn execute_detail(component_name: &str) -> Result<SetupComponentDetail, AppE
                 name: e.name.clone(),
                 description: e.description.clone(),
                 default: e.default.clone(),
+                references: e
+                    .default_references()
+                    .into_iter()
+                    .filter(|name| {
+                        owners.contains_key(name.as_str())
+                            || !STANDARD_ENV_VARS.contains(&name.as_str())
+                    })
+                    .map(|name| EnvVarReference {
+                        component: owners.get(name.as_str()).map(|c| c.to_string()),
+                        name,
+                    })
+                    .collect(),
             })
             .collect(),
         script_content: component.script_content.clone(),
@@ -76,6 +186,7 @@ pub fn execute_detail(component_name: &str) -> Result<SetupComponentDetail, AppE
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::domain::{EnvSpec, SetupComponent, SetupComponentId};
 
     #[test]
     fn list_returns_components() {
@@ -88,6 +199,52 @@ mod tests {
         assert!(result.iter().any(|c| c.name == "uv"));
     }
 
+    fn summary(name: &str, summary: &str, dependencies: &[&str]) -> SetupComponentSummary {
+        SetupComponentSummary {
+            name: name.to_string(),
+            summary: summary.to_string(),
+            dependencies: dependencies.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn table_aligns_columns() {
+        let table = render_table(
+            &[summary("gh", "GitHub CLI", &[]), summary("swift-tools", "Swift toolchain", &["gh"])],
+            120,
+        );
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "NAME        | SUMMARY         | DEPS",
+                "gh          | GitHub CLI      | -",
+                "swift-tools | Swift toolchain | gh",
+            ]
+        );
+    }
+
+    #[test]
+    fn table_truncates_summary_to_width() {
+        let table = render_table(&[summary("uv", &"x".repeat(80), &[])], 30);
+        let row = table.lines().nth(1).unwrap();
+
+        assert!(row.chars().count() <= 30);
+        assert!(row.contains('…'));
+    }
+
+    #[test]
+    fn table_truncates_deps_to_width() {
+        let deps = ["first-dependency", "second-dependency", "third-dependency"];
+        let table = render_table(&[summary("uv", "Python tools", &deps)], 40);
+        let row = table.lines().nth(1).unwrap();
+
+        assert!(row.chars().count() <= 40, "row exceeds width: {}", row);
+        assert!(row.starts_with("uv   | Python to… | first-dependency"), "{}", row);
+        assert!(row.ends_with('…'), "{}", row);
+    }
+
     #[test]
     fn detail_returns_component_info() {
         let result = execute_detail("just").unwrap();
@@ -97,6 +254,73 @@ mod tests {
         assert!(!result.script_content.is_empty());
     }
 
+    struct TestCatalog(Vec<SetupComponent>);
+
+    impl SetupComponentCatalog for TestCatalog {
+        fn get(&self, name: &str) -> Option<&SetupComponent> {
+            self.0.iter().find(|c| c.name.as_str() == name)
+        }
+
+        fn list_all(&self) -> Vec<&SetupComponent> {
+            self.0.iter().collect()
+        }
+
+        fn names(&self) -> Vec<&str> {
+            self.0.iter().map(|c| c.name.as_str()).collect()
+        }
+    }
+
+    fn component(name: &str, env: &[(&str, Option<&str>)]) -> SetupComponent {
+        SetupComponent {
+            name: SetupComponentId::new(name).unwrap(),
+            summary: format!("{} component", name),
+            dependencies: vec![],
+            env: env
+                .iter()
+                .map(|(var, default)| EnvSpec {
+                    name: var.to_string(),
+                    description: String::new(),
+                    default: default.map(ToString::to_string),
+                    secret: false,
+                })
+                .collect(),
+            script_content: String::new(),
+        }
+    }
+
+    #[test]
+    fn detail_resolves_cross_component_env_references() {
+        let catalog = TestCatalog(vec![
+            component("a", &[("A_BIN", Some("${B_HOME}/bin:${MISSING_HOME}:$HOME/bin:$PATH"))]),
+            component("b", &[("B_HOME", Some("/opt/b"))]),
+        ]);
+
+        let detail = detail_from_catalog(&catalog, "a").unwrap();
+
+        assert_eq!(
+            detail.env_vars[0].references,
+            vec![
+                EnvVarReference { name: "B_HOME".to_string(), component: Some("b".to_string()) },
+                EnvVarReference { name: "MISSING_HOME".to_string(), component: None },
+            ]
+        );
+    }
+
+    #[test]
+    fn detail_keeps_standard_variable_declared_by_a_component() {
+        let catalog = TestCatalog(vec![
+            component("a", &[("A_BIN", Some("$HOME/bin"))]),
+            component("home", &[("HOME", Some("/home/jules"))]),
+        ]);
+
+        let detail = detail_from_catalog(&catalog, "a").unwrap();
+
+        assert_eq!(
+            detail.env_vars[0].references,
+            vec![EnvVarReference { name: "HOME".to_string(), component: Some("home".to_string()) }]
+        );
+    }
+
     #[test]
     fn detail_not_found() {
         let result = execute_detail("nonexistent");
@@ -3,5 +3,8 @@
 mod generate;
 pub mod list;
 
-pub use generate::execute as generate;
+pub use generate::{
+    SetupGenOptions, SetupValidationReport, check_lockfile, execute as generate,
+    execute_collect as generate_collect, validate as validate_config,
+};
 pub use list::{execute as list, execute_detail as list_detail};
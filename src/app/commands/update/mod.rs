@@ -65,7 +65,8 @@ pub fn execute() -> Result<UpdateResult, AppError> {
         "git",
         &["ls-remote", "--tags", "--refs", JLO_GIT_HTTP_URL],
         "git ls-remote",
-    )?;
+    )
+    .map_err(|err| err.context("while checking for the latest jlo release"))?;
 
     let latest_tag = latest_release_tag(&tags_output).ok_or_else(|| {
         AppError::Validation(format!("No semver release tags found in '{}'.", JLO_GIT_HTTP_URL))
@@ -82,7 +83,8 @@ pub fn execute() -> Result<UpdateResult, AppError> {
         "cargo",
         &["install", "--git", JLO_GIT_HTTP_URL, "--tag", &latest_tag, "--force", "jlo"],
         "cargo install",
-    )?;
+    )
+    .map_err(|err| err.context(format!("while installing jlo {}", latest_tag)))?;
 
     Ok(UpdateResult { current_version, latest_tag, updated: true })
 }
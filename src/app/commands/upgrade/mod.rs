@@ -6,19 +6,24 @@
 //! 2. Creates any missing control-plane skeleton files in `.jlo/` from scaffold
 //!    defaults without overwriting existing files.
 //!
-//! Upgrade never reads or writes `.jules/` or runtime exchange artifacts.
-//! Managed framework files (contracts, schemas, prompts) are materialized by
-//! workflow bootstrap from the embedded scaffold for the pinned version.
+//! By default upgrade never reads or writes `.jules/`. Managed framework files
+//! (contracts, schemas, prompts) are materialized by workflow bootstrap from the
+//! embedded scaffold for the pinned version; `--include-runtime` also refreshes
+//! them in place for operators upgrading on the worker branch. Exchange
+//! artifacts are never touched.
 
+use std::path::PathBuf;
+
+use crate::adapters::local_repository::LocalRepositoryAdapter;
 use crate::domain::{AppError, PromptAssetLoader, WorkflowRunnerMode};
-use crate::ports::{JloStore, JulesStore, RepositoryFilesystem, RoleTemplateStore};
+use crate::ports::{JloStore, JulesStore, RepositoryFilesystem, RoleTemplateStore, ScaffoldFile};
 
 /// Result of an upgrade operation.
 #[derive(Debug)]
 pub struct UpgradeResult {
     /// Files that were created (missing skeleton files filled in).
     pub created: Vec<String>,
-    /// Files that were updated (reserved for backward-compatible output shape).
+    /// Managed `.jules/` framework files refreshed by `--include-runtime`.
     pub updated: Vec<String>,
     /// Whether workflow scaffold was refreshed.
     pub workflow_refreshed: bool,
@@ -35,8 +40,18 @@ pub struct UpgradeResult {
 pub struct UpgradeOptions {
     /// Show planned changes without applying.
     pub prompt_preview: bool,
+    /// Plan against another checkout instead of `repository` (preview only).
+    pub baseline: Option<PathBuf>,
+    /// Also refresh managed `.jules/` framework files from the embedded scaffold.
+    pub include_runtime: bool,
+    /// Control-plane config read instead of `.jlo/config.toml`, applied by
+    /// [`crate::app::api::upgrade_with_options_at`] when it opens the repository.
+    pub config: Option<PathBuf>,
 }
 
+/// Runtime directory whose artifacts upgrade must never modify.
+const EXCHANGE_PREFIX: &str = ".jules/exchange/";
+
 /// Execute the upgrade command.
 ///
 /// Operates exclusively on `.jlo/` control-plane files.
@@ -48,6 +63,10 @@ pub fn execute<W>(
 where
     W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
 {
+    if let Some(baseline) = options.baseline {
+        return preview_against_baseline(baseline, options.prompt_preview, templates);
+    }
+
     // Check if control plane exists
     if !repository.jlo_exists() {
         return Err(AppError::Validation(
@@ -87,7 +106,7 @@ where
 
     // Load control-plane skeleton files only.
     let control_plane_files = templates.control_plane_skeleton_files();
-    let mut to_create: Vec<(String, String)> = Vec::new();
+    let mut to_create: Vec<&ScaffoldFile> = Vec::new();
 
     for file in &control_plane_files {
         // Skip the version pin — it is written explicitly below
@@ -96,11 +115,27 @@ where
         }
         // Only create missing files; never overwrite user-owned content
         if !repository.file_exists(&file.path) {
-            to_create.push((file.path.clone(), file.content.clone()));
+            to_create.push(file);
         }
     }
 
-    let to_update: Vec<(String, String)> = Vec::new();
+    let runtime_files = if options.include_runtime {
+        if !repository.jules_exists() {
+            return Err(AppError::Validation(
+                "--include-runtime requires a .jules/ runtime repository (run on the worker branch)."
+                    .to_string(),
+            ));
+        }
+        templates.scaffold_files()
+    } else {
+        Vec::new()
+    };
+    // Only overwrite managed framework files whose content drifted from the scaffold.
+    let to_update: Vec<&ScaffoldFile> = runtime_files
+        .iter()
+        .filter(|file| !file.path.starts_with(EXCHANGE_PREFIX))
+        .filter(|file| repository.read_file(&file.path).ok().as_deref() != Some(&file.content))
+        .collect();
     let warnings = Vec::new();
 
     let workflow_mode = configured_workflow_mode(repository)?;
@@ -116,12 +151,19 @@ where
             println!("No new control-plane files to create.");
         } else {
             println!("Control-plane files to create:");
-            for (path, _) in &to_create {
-                println!("  • {}", path);
+            for file in &to_create {
+                println!("  • {}", file.path);
             }
         }
 
-        println!("No managed defaults to refresh.");
+        if to_update.is_empty() {
+            println!("No managed defaults to refresh.");
+        } else {
+            println!("Managed runtime files to refresh:");
+            for file in &to_update {
+                println!("  • {}", file.path);
+            }
+        }
 
         if workflow_will_refresh {
             println!("Workflow scaffold will be refreshed.");
@@ -134,8 +176,8 @@ where
         }
 
         return Ok(UpgradeResult {
-            created: to_create.into_iter().map(|(p, _)| p).collect(),
-            updated: to_update.into_iter().map(|(p, _)| p).collect(),
+            created: to_create.into_iter().map(|f| f.path.clone()).collect(),
+            updated: to_update.into_iter().map(|f| f.path.clone()).collect(),
             workflow_refreshed: workflow_will_refresh,
             prompt_preview: true,
             previous_version: runtime_version,
@@ -144,8 +186,16 @@ where
     }
 
     // Create missing skeleton files
-    for (rel_path, content) in &to_create {
-        repository.write_file(rel_path, content)?;
+    for file in &to_create {
+        repository.write_file(&file.path, &file.content)?;
+        if file.executable {
+            repository.set_executable(&file.path)?;
+        }
+    }
+
+    // Refresh managed runtime files (--include-runtime)
+    for file in &to_update {
+        repository.write_file(&file.path, &file.content)?;
     }
 
     // Refresh workflow scaffold
@@ -166,10 +216,10 @@ where
         repository.write_file(version_path, &format!("{}\n", binary_version))?;
     }
 
-    let created_paths: Vec<String> = to_create.into_iter().map(|(p, _)| p).collect();
+    let created_paths: Vec<String> = to_create.into_iter().map(|f| f.path.clone()).collect();
     Ok(UpgradeResult {
         created: created_paths,
-        updated: Vec::new(),
+        updated: to_update.into_iter().map(|f| f.path.clone()).collect(),
         workflow_refreshed,
         prompt_preview: false,
         previous_version: runtime_version,
@@ -177,6 +227,31 @@ where
     })
 }
 
+/// Compute the upgrade plan relative to another checkout's `.jlo/`.
+fn preview_against_baseline(
+    baseline: PathBuf,
+    prompt_preview: bool,
+    templates: &impl RoleTemplateStore,
+) -> Result<UpgradeResult, AppError> {
+    if !prompt_preview {
+        return Err(AppError::Validation("--baseline requires --prompt-preview".to_string()));
+    }
+    if !baseline.is_dir() {
+        return Err(AppError::Validation(format!(
+            "Baseline directory not found: {}",
+            baseline.display()
+        )));
+    }
+
+    println!("Baseline: {}\n", baseline.display());
+    let baseline_repository = LocalRepositoryAdapter::new(baseline);
+    execute(
+        &baseline_repository,
+        UpgradeOptions { prompt_preview: true, ..Default::default() },
+        templates,
+    )
+}
+
 fn configured_workflow_mode<W>(repository: &W) -> Result<Option<WorkflowRunnerMode>, AppError>
 where
     W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
@@ -217,7 +292,6 @@ fn compare_versions(a: &[u32], b: &[u32]) -> i32 {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::adapters::local_repository::LocalRepositoryAdapter;
     use std::fs;
 
     #[test]
@@ -247,11 +321,12 @@ wait_minutes_default = 30
 
     struct MockRoleTemplateStore {
         control_files: Vec<ScaffoldFile>,
+        runtime_files: Vec<ScaffoldFile>,
     }
 
     impl RoleTemplateStore for MockRoleTemplateStore {
         fn scaffold_files(&self) -> Vec<ScaffoldFile> {
-            vec![]
+            self.runtime_files.clone()
         }
 
         fn control_plane_files(&self) -> Vec<ScaffoldFile> {
@@ -292,15 +367,18 @@ wait_minutes_default = 30
                 ScaffoldFile {
                     path: ".jlo/config.toml".to_string(),
                     content: sample_config_content(),
+                    executable: false,
                 },
                 ScaffoldFile {
                     path: ".jlo/setup/tools.yml".to_string(),
                     content: "tools: []".to_string(),
+                    executable: false,
                 },
             ],
+            runtime_files: vec![],
         };
 
-        let options = UpgradeOptions { prompt_preview: false };
+        let options = UpgradeOptions { prompt_preview: false, ..Default::default() };
         let repository = LocalRepositoryAdapter::new(temp.path().to_path_buf());
         let result = execute(&repository, options, &mock_store).unwrap();
 
@@ -334,10 +412,12 @@ wait_minutes_default = 30
             control_files: vec![ScaffoldFile {
                 path: ".jlo/config.toml".to_string(),
                 content: sample_config_content(),
+                executable: false,
             }],
+            runtime_files: vec![],
         };
 
-        let options = UpgradeOptions { prompt_preview: false };
+        let options = UpgradeOptions { prompt_preview: false, ..Default::default() };
         let repository = LocalRepositoryAdapter::new(temp.path().to_path_buf());
         let result = execute(&repository, options, &mock_store).unwrap();
 
@@ -376,8 +456,8 @@ wait_minutes_default = 30
         )
         .unwrap();
 
-        let mock_store = MockRoleTemplateStore { control_files: vec![] };
-        let options = UpgradeOptions { prompt_preview: false };
+        let mock_store = MockRoleTemplateStore { control_files: vec![], runtime_files: vec![] };
+        let options = UpgradeOptions { prompt_preview: false, ..Default::default() };
         let repository = LocalRepositoryAdapter::new(temp.path().to_path_buf());
         let err = execute(&repository, options, &mock_store).unwrap_err();
         assert!(err.to_string().contains("workflow.runner_mode"));
@@ -391,9 +471,9 @@ wait_minutes_default = 30
 
         fs::write(jlo_path.join(".jlo-version"), "0.0.0").unwrap();
 
-        let mock_store = MockRoleTemplateStore { control_files: vec![] };
+        let mock_store = MockRoleTemplateStore { control_files: vec![], runtime_files: vec![] };
 
-        let options = UpgradeOptions { prompt_preview: false };
+        let options = UpgradeOptions { prompt_preview: false, ..Default::default() };
         let repository = LocalRepositoryAdapter::new(temp.path().to_path_buf());
         let result = execute(&repository, options, &mock_store).unwrap();
 
@@ -410,9 +490,9 @@ wait_minutes_default = 30
 
         fs::write(jlo_path.join(".jlo-version"), env!("CARGO_PKG_VERSION")).unwrap();
 
-        let mock_store = MockRoleTemplateStore { control_files: vec![] };
+        let mock_store = MockRoleTemplateStore { control_files: vec![], runtime_files: vec![] };
 
-        let options = UpgradeOptions { prompt_preview: false };
+        let options = UpgradeOptions { prompt_preview: false, ..Default::default() };
         let repository = LocalRepositoryAdapter::new(temp.path().to_path_buf());
         let result = execute(&repository, options, &mock_store).unwrap();
 
@@ -435,15 +515,18 @@ wait_minutes_default = 30
                 ScaffoldFile {
                     path: ".jlo/config.toml".to_string(),
                     content: sample_config_content(),
+                    executable: false,
                 },
                 ScaffoldFile {
                     path: ".jlo/roles/observers/default/role.yml".to_string(),
                     content: "role: default".to_string(),
+                    executable: false,
                 },
             ],
+            runtime_files: vec![],
         };
 
-        let options = UpgradeOptions { prompt_preview: false };
+        let options = UpgradeOptions { prompt_preview: false, ..Default::default() };
         let repository = LocalRepositoryAdapter::new(temp.path().to_path_buf());
         let result = execute(&repository, options, &mock_store).unwrap();
 
@@ -457,4 +540,123 @@ wait_minutes_default = 30
         assert!(temp.path().join(".jlo/config.toml").exists());
         assert!(!temp.path().join(".jlo/roles/observers/default/role.yml").exists());
     }
+
+    #[test]
+    fn test_upgrade_preview_diffs_against_baseline_checkout() {
+        let current = TempDir::new().unwrap();
+        let baseline = TempDir::new().unwrap();
+        for root in [current.path(), baseline.path()] {
+            fs::create_dir_all(root.join(".jlo")).unwrap();
+            fs::write(root.join(".jlo/.jlo-version"), "0.0.0").unwrap();
+            fs::write(root.join(".jlo/config.toml"), sample_config_content()).unwrap();
+        }
+        fs::create_dir_all(current.path().join(".jlo/setup")).unwrap();
+        fs::write(current.path().join(".jlo/setup/tools.yml"), "tools: []").unwrap();
+
+        let mock_store = MockRoleTemplateStore {
+            control_files: vec![
+                ScaffoldFile {
+                    path: ".jlo/config.toml".to_string(),
+                    content: sample_config_content(),
+                    executable: false,
+                },
+                ScaffoldFile {
+                    path: ".jlo/setup/tools.yml".to_string(),
+                    content: "tools: []".to_string(),
+                    executable: false,
+                },
+            ],
+            runtime_files: vec![],
+        };
+        let repository = LocalRepositoryAdapter::new(current.path().to_path_buf());
+
+        let own = execute(
+            &repository,
+            UpgradeOptions { prompt_preview: true, ..Default::default() },
+            &mock_store,
+        )
+        .unwrap();
+        assert!(own.created.is_empty());
+
+        let against_baseline = execute(
+            &repository,
+            UpgradeOptions {
+                prompt_preview: true,
+                baseline: Some(baseline.path().to_path_buf()),
+                ..Default::default()
+            },
+            &mock_store,
+        )
+        .unwrap();
+        assert!(against_baseline.prompt_preview);
+        assert_eq!(against_baseline.created, vec![".jlo/setup/tools.yml".to_string()]);
+        assert!(!baseline.path().join(".jlo/setup/tools.yml").exists());
+    }
+
+    #[test]
+    fn test_upgrade_baseline_requires_prompt_preview() {
+        let temp = TempDir::new().unwrap();
+        let repository = LocalRepositoryAdapter::new(temp.path().to_path_buf());
+        let mock_store = MockRoleTemplateStore { control_files: vec![], runtime_files: vec![] };
+
+        let err = execute(
+            &repository,
+            UpgradeOptions {
+                prompt_preview: false,
+                baseline: Some(temp.path().to_path_buf()),
+                ..Default::default()
+            },
+            &mock_store,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("--baseline requires --prompt-preview"));
+    }
+
+    fn runtime_upgrade(include_runtime: bool) -> (TempDir, UpgradeResult) {
+        let temp = TempDir::new().unwrap();
+        fs::create_dir_all(temp.path().join(".jlo")).unwrap();
+        fs::write(temp.path().join(".jlo/.jlo-version"), env!("CARGO_PKG_VERSION")).unwrap();
+        fs::create_dir_all(temp.path().join(".jules/schemas/observers")).unwrap();
+        fs::create_dir_all(temp.path().join(".jules/exchange/events/pending")).unwrap();
+        fs::write(temp.path().join(".jules/schemas/observers/event.yml"), "old: schema\n").unwrap();
+        fs::write(temp.path().join(".jules/exchange/events/pending/e1.yml"), "id: e1\n").unwrap();
+
+        let runtime_file = |path: &str, content: &str| ScaffoldFile {
+            path: path.to_string(),
+            content: content.to_string(),
+            executable: false,
+        };
+        let mock_store = MockRoleTemplateStore {
+            control_files: vec![],
+            runtime_files: vec![
+                runtime_file(".jules/schemas/observers/event.yml", "new: schema\n"),
+                runtime_file(".jules/exchange/events/pending/e1.yml", "scaffold: placeholder\n"),
+            ],
+        };
+
+        let options = UpgradeOptions { include_runtime, ..Default::default() };
+        let repository = LocalRepositoryAdapter::new(temp.path().to_path_buf());
+        let result = execute(&repository, options, &mock_store).unwrap();
+        (temp, result)
+    }
+
+    #[test]
+    fn include_runtime_refreshes_managed_schemas_but_not_exchange() {
+        let (temp, result) = runtime_upgrade(true);
+
+        assert_eq!(result.updated, vec![".jules/schemas/observers/event.yml".to_string()]);
+        let schema = fs::read_to_string(temp.path().join(".jules/schemas/observers/event.yml"));
+        assert_eq!(schema.unwrap(), "new: schema\n");
+        let event = fs::read_to_string(temp.path().join(".jules/exchange/events/pending/e1.yml"));
+        assert_eq!(event.unwrap(), "id: e1\n");
+    }
+
+    #[test]
+    fn runtime_files_are_untouched_without_include_runtime() {
+        let (temp, result) = runtime_upgrade(false);
+
+        assert!(result.updated.is_empty());
+        let schema = fs::read_to_string(temp.path().join(".jules/schemas/observers/event.yml"));
+        assert_eq!(schema.unwrap(), "old: schema\n");
+    }
 }
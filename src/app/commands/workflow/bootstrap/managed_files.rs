@@ -1,19 +1,41 @@
 //! Workflow bootstrap managed-files subcommand.
 //!
-//! Materializes managed runtime files from embedded scaffold assets.
+//! Materializes managed runtime files from embedded scaffold assets, or with
+//! `--verify` reports managed files that drifted from them without writing.
+
+use std::collections::BTreeSet;
+use std::path::Path;
 
 use serde::Serialize;
+use sha2::{Digest, Sha256};
+use similar::TextDiff;
 
 use crate::adapters::catalogs::EmbeddedRoleTemplateStore;
 use crate::adapters::local_repository::LocalRepositoryAdapter;
 use crate::domain::AppError;
-use crate::ports::{JulesStore, RoleTemplateStore};
+use crate::ports::{JloStore, JulesStore, RepositoryFilesystem, RoleTemplateStore, ScaffoldFile};
+
+/// Directories under `.jules/` whose contents are owned entirely by the scaffold.
+const MANAGED_DIRS: &[&str] = &[".jules/schemas"];
 
 /// Options for `workflow bootstrap managed-files`.
 #[derive(Debug)]
 pub struct WorkflowBootstrapManagedFilesOptions {
     /// Root path of the repository.
     pub root: std::path::PathBuf,
+    /// Remove managed files that the current scaffold no longer ships.
+    pub prune: bool,
+    /// Compare managed files against the scaffold without writing anything.
+    pub verify: bool,
+    /// With `verify`, include a unified diff for each drifted file.
+    pub verbose: bool,
+}
+
+/// Unified diff between a managed file on disk and the embedded scaffold.
+#[derive(Debug, Serialize)]
+pub struct ManagedFileDiff {
+    pub path: String,
+    pub diff: String,
 }
 
 /// Output of `workflow bootstrap managed-files`.
@@ -25,6 +47,16 @@ pub struct WorkflowBootstrapManagedFilesOutput {
     pub version: String,
     /// Number of write operations performed.
     pub files_written: usize,
+    /// Stale managed files deleted by `--prune`.
+    pub removed: Vec<String>,
+    /// Stale managed files left in place because `--prune` was not given.
+    pub stale: Vec<String>,
+    /// Managed files missing or differing from the scaffold (`--verify` only).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub drifted: Vec<String>,
+    /// Unified diffs for `drifted` files (`--verify --verbose` only).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub diffs: Vec<ManagedFileDiff>,
 }
 
 /// Execute `workflow bootstrap managed-files`.
@@ -33,17 +65,226 @@ pub fn execute(
 ) -> Result<WorkflowBootstrapManagedFilesOutput, AppError> {
     super::validate_control_plane_preconditions(options.root.as_path())?;
 
-    let repository = LocalRepositoryAdapter::new(options.root);
+    let repository = LocalRepositoryAdapter::new(options.root.clone());
     let templates = EmbeddedRoleTemplateStore::new();
     let scaffold_files = templates.scaffold_files();
-    repository.create_structure(&scaffold_files)?;
-
     let version = env!("CARGO_PKG_VERSION").to_string();
+
+    if options.verify {
+        return verify(&repository, &options, &scaffold_files, version);
+    }
+
+    repository.create_structure(&scaffold_files)?;
     repository.jules_write_version(&version)?;
 
+    let stale = stale_managed_files(&repository, &options.root, &scaffold_files)?;
+    let (removed, stale) = if options.prune {
+        for path in &stale {
+            repository.remove_file(path)?;
+            remove_empty_parents(&repository, path)?;
+        }
+        (stale, Vec::new())
+    } else {
+        (Vec::new(), stale)
+    };
+
     Ok(WorkflowBootstrapManagedFilesOutput {
         applied: true,
         version,
         files_written: scaffold_files.len() + 1,
+        removed,
+        stale,
+        drifted: Vec::new(),
+        diffs: Vec::new(),
     })
 }
+
+fn verify(
+    repository: &LocalRepositoryAdapter,
+    options: &WorkflowBootstrapManagedFilesOptions,
+    scaffold_files: &[ScaffoldFile],
+    version: String,
+) -> Result<WorkflowBootstrapManagedFilesOutput, AppError> {
+    let mut drifted = Vec::new();
+    let mut diffs = Vec::new();
+    for file in scaffold_files {
+        let exists = repository.file_exists(&file.path);
+        if exists && repository.file_hash(&file.path)? == content_hash(&file.content) {
+            continue;
+        }
+        drifted.push(file.path.clone());
+        if options.verbose {
+            let actual = if exists { Some(repository.read_file(&file.path)?) } else { None };
+            let diff = TextDiff::from_lines(actual.as_deref().unwrap_or(""), file.content.as_str())
+                .unified_diff()
+                .header(&file.path, &file.path)
+                .to_string();
+            diffs.push(ManagedFileDiff { path: file.path.clone(), diff });
+        }
+    }
+    drifted.sort();
+    diffs.sort_by(|a, b| a.path.cmp(&b.path));
+
+    Ok(WorkflowBootstrapManagedFilesOutput {
+        applied: false,
+        version,
+        files_written: 0,
+        removed: Vec::new(),
+        stale: stale_managed_files(repository, &options.root, scaffold_files)?,
+        drifted,
+        diffs,
+    })
+}
+
+/// SHA-256 hex digest in the same form as [`JloStore::file_hash`].
+fn content_hash(content: &str) -> String {
+    format!("{:x}", Sha256::digest(content.as_bytes()))
+}
+
+/// Remove directories left empty under a [`MANAGED_DIRS`] root after deleting `path`.
+///
+/// The managed root itself is kept even when it ends up empty.
+fn remove_empty_parents(
+    repository: &impl RepositoryFilesystem,
+    path: &str,
+) -> Result<(), AppError> {
+    let Some(managed_root) = MANAGED_DIRS.iter().find(|dir| Path::new(path).starts_with(dir))
+    else {
+        return Ok(());
+    };
+    let mut current = Path::new(path).parent();
+    while let Some(dir) = current {
+        if dir == Path::new(managed_root) || !dir.starts_with(managed_root) {
+            break;
+        }
+        let dir_str = dir.to_string_lossy();
+        if !repository.list_dir(&dir_str)?.is_empty() {
+            break;
+        }
+        repository.remove_dir_all(&dir_str)?;
+        current = dir.parent();
+    }
+    Ok(())
+}
+
+/// Files inside [`MANAGED_DIRS`] on disk that the embedded scaffold does not ship.
+fn stale_managed_files(
+    repository: &impl RepositoryFilesystem,
+    root: &Path,
+    scaffold_files: &[ScaffoldFile],
+) -> Result<Vec<String>, AppError> {
+    let shipped: BTreeSet<&str> = scaffold_files.iter().map(|file| file.path.as_str()).collect();
+    let mut stale = Vec::new();
+    let mut pending: Vec<String> = MANAGED_DIRS.iter().map(|dir| dir.to_string()).collect();
+    while let Some(dir) = pending.pop() {
+        if !repository.is_dir(&dir) {
+            continue;
+        }
+        for entry in repository.list_dir(&dir)? {
+            let relative = entry.strip_prefix(root).unwrap_or(&entry).to_string_lossy().to_string();
+            if repository.is_dir(&relative) {
+                pending.push(relative);
+            } else if !shipped.contains(relative.as_str()) {
+                stale.push(relative);
+            }
+        }
+    }
+    stale.sort();
+    Ok(stale)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    fn bootstrapped_root() -> tempfile::TempDir {
+        let temp = tempfile::tempdir().unwrap();
+        fs::create_dir_all(temp.path().join(".jlo")).unwrap();
+        fs::write(temp.path().join(".jlo/.jlo-version"), env!("CARGO_PKG_VERSION")).unwrap();
+        fs::create_dir_all(temp.path().join(".jules/schemas/retired")).unwrap();
+        fs::write(temp.path().join(".jules/schemas/retired/old.yml"), "legacy: true\n").unwrap();
+        fs::write(temp.path().join(".jules/notes.md"), "repository-owned\n").unwrap();
+        temp
+    }
+
+    fn run(root: &Path, prune: bool) -> WorkflowBootstrapManagedFilesOutput {
+        execute(WorkflowBootstrapManagedFilesOptions {
+            root: root.to_path_buf(),
+            prune,
+            verify: false,
+            verbose: false,
+        })
+        .unwrap()
+    }
+
+    fn run_verify(root: &Path, verbose: bool) -> WorkflowBootstrapManagedFilesOutput {
+        execute(WorkflowBootstrapManagedFilesOptions {
+            root: root.to_path_buf(),
+            prune: false,
+            verify: true,
+            verbose,
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn dropped_managed_file_is_only_reported_without_prune() {
+        let temp = bootstrapped_root();
+
+        let output = run(temp.path(), false);
+
+        assert_eq!(output.stale, vec![".jules/schemas/retired/old.yml".to_string()]);
+        assert!(output.removed.is_empty());
+        assert!(temp.path().join(".jules/schemas/retired/old.yml").exists());
+    }
+
+    #[test]
+    fn prune_removes_dropped_managed_file_but_not_repository_files() {
+        let temp = bootstrapped_root();
+
+        let output = run(temp.path(), true);
+
+        assert_eq!(output.removed, vec![".jules/schemas/retired/old.yml".to_string()]);
+        assert!(output.stale.is_empty());
+        assert!(!temp.path().join(".jules/schemas/retired").exists());
+        assert!(temp.path().join(".jules/schemas/decider/requirements.yml").exists());
+        assert!(temp.path().join(".jules/notes.md").exists());
+    }
+
+    #[test]
+    fn prune_removes_nested_directories_left_empty_but_keeps_managed_root() {
+        let temp = bootstrapped_root();
+        fs::create_dir_all(temp.path().join(".jules/schemas/retired/nested")).unwrap();
+        fs::write(temp.path().join(".jules/schemas/retired/nested/old.yml"), "legacy: true\n")
+            .unwrap();
+        fs::write(temp.path().join(".jules/schemas/retired/keep.yml"), "legacy: true\n").unwrap();
+
+        let output = run(temp.path(), true);
+
+        assert_eq!(output.removed.len(), 3);
+        assert!(!temp.path().join(".jules/schemas/retired").exists());
+        assert!(temp.path().join(".jules/schemas").is_dir());
+    }
+
+    #[test]
+    fn verify_verbose_includes_diff_for_modified_managed_file() {
+        let temp = bootstrapped_root();
+        run(temp.path(), false);
+        let path = ".jules/schemas/decider/requirements.yml";
+        let original = fs::read_to_string(temp.path().join(path)).unwrap();
+        fs::write(temp.path().join(path), format!("{}locally: edited\n", original)).unwrap();
+
+        let quiet = run_verify(temp.path(), false);
+        let verbose = run_verify(temp.path(), true);
+
+        assert_eq!(quiet.drifted, vec![path.to_string()]);
+        assert!(quiet.diffs.is_empty());
+        assert!(!quiet.applied);
+        assert_eq!(verbose.diffs.len(), 1);
+        assert_eq!(verbose.diffs[0].path, path);
+        assert!(verbose.diffs[0].diff.contains("-locally: edited"), "{}", verbose.diffs[0].diff);
+        assert!(fs::read_to_string(temp.path().join(path)).unwrap().contains("locally: edited"));
+    }
+}
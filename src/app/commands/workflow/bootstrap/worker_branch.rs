@@ -2,9 +2,14 @@
 //!
 //! Ensures the worker branch exists and synchronizes target branch changes into it.
 
+use std::path::Path;
+
 use serde::Serialize;
 
 use crate::adapters::git::GitCommandAdapter;
+use crate::app::commands::workflow::remote::{ORIGIN, ensure_remote};
+
+use super::managed_files::{self, WorkflowBootstrapManagedFilesOptions};
 use crate::domain::AppError;
 use crate::ports::Git;
 
@@ -13,6 +18,8 @@ use crate::ports::Git;
 pub struct WorkflowBootstrapWorkerBranchOptions {
     /// Root path of the repository.
     pub root: std::path::PathBuf,
+    /// Re-materialize jlo-managed `.jules/` files after syncing, overwriting local edits.
+    pub force: bool,
 }
 
 /// Output of `workflow bootstrap worker-branch`.
@@ -24,17 +31,36 @@ pub struct WorkflowBootstrapWorkerBranchOutput {
     pub worker_created: bool,
     pub merged: bool,
     pub conflict_resolved: bool,
+    /// Managed files rewritten from the embedded scaffold by `--force`.
+    pub rematerialized_files: usize,
 }
 
 /// Execute `workflow bootstrap worker-branch`.
 pub fn execute(
     options: WorkflowBootstrapWorkerBranchOptions,
 ) -> Result<WorkflowBootstrapWorkerBranchOutput, AppError> {
-    let git = GitCommandAdapter::new(options.root);
+    let git = GitCommandAdapter::new(options.root.clone());
     let target_branch = read_required_branch_env("JLO_TARGET_BRANCH")?;
     let worker_branch = read_required_branch_env("JULES_WORKER_BRANCH")?;
 
-    execute_with_adapter(&git, target_branch.as_str(), worker_branch.as_str())
+    let mut output = execute_with_adapter(&git, target_branch.as_str(), worker_branch.as_str())?;
+    if options.force {
+        output.rematerialized_files = rematerialize_managed_files(&options.root)?;
+    }
+    Ok(output)
+}
+
+/// Overwrite jlo-managed files from the embedded scaffold, leaving exchange artifacts alone.
+///
+/// The rewritten files are left uncommitted for `workflow push worker-branch` to publish.
+fn rematerialize_managed_files(root: &Path) -> Result<usize, AppError> {
+    let output = managed_files::execute(WorkflowBootstrapManagedFilesOptions {
+        root: root.to_path_buf(),
+        prune: false,
+        verify: false,
+        verbose: false,
+    })?;
+    Ok(output.files_written)
 }
 
 pub(crate) fn execute_with_adapter(
@@ -50,7 +76,8 @@ pub(crate) fn execute_with_adapter(
         ));
     }
 
-    git.run_command(&["fetch", "origin", target_branch], None)?;
+    ensure_remote(git, ORIGIN)?;
+    git.run_command(&["fetch", ORIGIN, target_branch], None)?;
 
     let worker_exists = remote_branch_exists(git, worker_branch)?;
     let worker_ref = format!("origin/{}", worker_branch);
@@ -94,6 +121,7 @@ pub(crate) fn execute_with_adapter(
         worker_created,
         merged: true,
         conflict_resolved,
+        rematerialized_files: 0,
     })
 }
 
@@ -128,6 +156,7 @@ mod tests {
         ls_remote_output: String,
         merge_fails: bool,
         staged_after_conflict: String,
+        remotes: Vec<String>,
         commands: Arc<Mutex<Vec<Vec<String>>>>,
         pushed: Arc<Mutex<Vec<String>>>,
     }
@@ -138,6 +167,7 @@ mod tests {
                 ls_remote_output: ls_remote_output.to_string(),
                 merge_fails,
                 staged_after_conflict: staged_after_conflict.to_string(),
+                remotes: vec!["origin".to_string()],
                 commands: Arc::new(Mutex::new(Vec::new())),
                 pushed: Arc::new(Mutex::new(Vec::new())),
             }
@@ -153,6 +183,14 @@ mod tests {
             Ok("main".to_string())
         }
 
+        fn get_default_branch(&self) -> Result<String, AppError> {
+            Ok("main".to_string())
+        }
+
+        fn blame_last_author(&self, _rev: &str, _path: &str) -> Result<String, AppError> {
+            Ok("Test Author".to_string())
+        }
+
         fn commit_exists(&self, _sha: &str) -> bool {
             true
         }
@@ -165,6 +203,22 @@ mod tests {
             Ok("root".to_string())
         }
 
+        fn rev_parse(&self, _rev: &str) -> Result<String, AppError> {
+            Ok(String::new())
+        }
+
+        fn commit_message(&self, _sha: &str) -> Result<String, AppError> {
+            Ok(String::new())
+        }
+
+        fn log_range(
+            &self,
+            _from: &str,
+            _to: &str,
+        ) -> Result<Vec<crate::ports::CommitInfo>, AppError> {
+            Ok(Vec::new())
+        }
+
         fn has_changes(
             &self,
             _from: &str,
@@ -174,6 +228,10 @@ mod tests {
             Ok(false)
         }
 
+        fn status(&self, _pathspec: &[&str]) -> Result<Vec<crate::ports::StatusEntry>, AppError> {
+            Ok(Vec::new())
+        }
+
         fn run_command(&self, args: &[&str], _cwd: Option<&Path>) -> Result<String, AppError> {
             self.commands
                 .lock()
@@ -223,10 +281,55 @@ mod tests {
             Ok(())
         }
 
+        fn fetch_prune(&self, _remote: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn remote_exists(&self, name: &str) -> Result<bool, AppError> {
+            Ok(self.remotes.iter().any(|remote| remote == name))
+        }
+
+        fn get_config(&self, _key: &str) -> Result<Option<String>, AppError> {
+            Ok(None)
+        }
+
+        fn set_config(&self, _key: &str, _value: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn unset_config(&self, _key: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn abort_merge(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn cherry_pick(&self, _rev: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn cherry_pick_abort(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn reset_hard_to(&self, _branch: &str, _rev: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
         fn delete_branch(&self, _branch: &str, _force: bool) -> Result<bool, AppError> {
             Ok(false)
         }
 
+        fn create_tag(
+            &self,
+            _name: &str,
+            _message: Option<&str>,
+            _rev: Option<&str>,
+        ) -> Result<(), AppError> {
+            Ok(())
+        }
+
         fn create_workspace(&self, _branch: &str) -> Result<Box<dyn GitWorkspace>, AppError> {
             Ok(Box::new(TestGitWorkspace { git: self.clone() }))
         }
@@ -243,6 +346,12 @@ mod tests {
         fn get_current_branch(&self) -> Result<String, AppError> {
             self.git.get_current_branch()
         }
+        fn get_default_branch(&self) -> Result<String, AppError> {
+            self.git.get_default_branch()
+        }
+        fn blame_last_author(&self, rev: &str, path: &str) -> Result<String, AppError> {
+            self.git.blame_last_author(rev, path)
+        }
         fn commit_exists(&self, sha: &str) -> bool {
             self.git.commit_exists(sha)
         }
@@ -252,9 +361,25 @@ mod tests {
         fn get_first_commit(&self, commit: &str) -> Result<String, AppError> {
             self.git.get_first_commit(commit)
         }
+        fn rev_parse(&self, rev: &str) -> Result<String, AppError> {
+            self.git.rev_parse(rev)
+        }
+        fn commit_message(&self, sha: &str) -> Result<String, AppError> {
+            self.git.commit_message(sha)
+        }
+        fn log_range(
+            &self,
+            from: &str,
+            to: &str,
+        ) -> Result<Vec<crate::ports::CommitInfo>, AppError> {
+            self.git.log_range(from, to)
+        }
         fn has_changes(&self, from: &str, to: &str, pathspec: &[&str]) -> Result<bool, AppError> {
             self.git.has_changes(from, to, pathspec)
         }
+        fn status(&self, pathspec: &[&str]) -> Result<Vec<crate::ports::StatusEntry>, AppError> {
+            self.git.status(pathspec)
+        }
         fn run_command(&self, args: &[&str], cwd: Option<&Path>) -> Result<String, AppError> {
             self.git.run_command(args, cwd)
         }
@@ -278,9 +403,45 @@ mod tests {
         fn fetch(&self, remote: &str) -> Result<(), AppError> {
             self.git.fetch(remote)
         }
+        fn fetch_prune(&self, remote: &str) -> Result<(), AppError> {
+            self.git.fetch_prune(remote)
+        }
+        fn remote_exists(&self, name: &str) -> Result<bool, AppError> {
+            self.git.remote_exists(name)
+        }
+        fn get_config(&self, key: &str) -> Result<Option<String>, AppError> {
+            self.git.get_config(key)
+        }
+        fn set_config(&self, key: &str, value: &str) -> Result<(), AppError> {
+            self.git.set_config(key, value)
+        }
+        fn unset_config(&self, key: &str) -> Result<(), AppError> {
+            self.git.unset_config(key)
+        }
+        fn abort_merge(&self) -> Result<(), AppError> {
+            self.git.abort_merge()
+        }
+        fn cherry_pick(&self, rev: &str) -> Result<(), AppError> {
+            self.git.cherry_pick(rev)
+        }
+        fn cherry_pick_abort(&self) -> Result<(), AppError> {
+            self.git.cherry_pick_abort()
+        }
+        fn reset_hard_to(&self, branch: &str, rev: &str) -> Result<(), AppError> {
+            self.git.reset_hard_to(branch, rev)
+        }
         fn delete_branch(&self, branch: &str, force: bool) -> Result<bool, AppError> {
             self.git.delete_branch(branch, force)
         }
+
+        fn create_tag(
+            &self,
+            name: &str,
+            message: Option<&str>,
+            rev: Option<&str>,
+        ) -> Result<(), AppError> {
+            self.git.create_tag(name, message, rev)
+        }
         fn create_workspace(&self, branch: &str) -> Result<Box<dyn GitWorkspace>, AppError> {
             self.git.create_workspace(branch)
         }
@@ -324,6 +485,15 @@ mod tests {
         assert!(git.pushed.lock().expect("pushed lock poisoned").is_empty());
     }
 
+    #[test]
+    fn fails_with_clear_error_when_origin_is_not_configured() {
+        let git = TestGit { remotes: Vec::new(), ..TestGit::new("", false, "") };
+        let err = execute_with_adapter(&git, "main", "jules").expect_err("expected remote failure");
+        assert!(err.to_string().contains("Remote 'origin' not configured"));
+        assert!(git.commands.lock().expect("commands lock poisoned").is_empty());
+        assert!(git.pushed.lock().expect("pushed lock poisoned").is_empty());
+    }
+
     #[test]
     fn fails_when_conflict_resolution_stages_nothing() {
         let git = TestGit::new("sha\trefs/heads/jules", true, "");
@@ -332,4 +502,33 @@ mod tests {
         assert!(err.to_string().contains("no .jules conflict-resolution changes were staged"));
         assert!(git.pushed.lock().expect("pushed lock poisoned").is_empty());
     }
+
+    #[test]
+    fn rematerialize_restores_modified_managed_file_and_keeps_events() {
+        use crate::adapters::catalogs::EmbeddedRoleTemplateStore;
+        use crate::ports::RoleTemplateStore;
+        use std::fs;
+
+        let temp = tempfile::tempdir().unwrap();
+        let root = temp.path();
+        fs::create_dir_all(root.join(".jlo")).unwrap();
+        fs::write(root.join(".jlo/.jlo-version"), env!("CARGO_PKG_VERSION")).unwrap();
+        rematerialize_managed_files(root).unwrap();
+
+        let schema = ".jules/schemas/decider/requirements.yml";
+        let event = ".jules/exchange/events/pending/abc123.yml";
+        fs::write(root.join(schema), "corrupted: true\n").unwrap();
+        fs::write(root.join(event), "id: abc123\n").unwrap();
+
+        let written = rematerialize_managed_files(root).unwrap();
+
+        let shipped = EmbeddedRoleTemplateStore::new()
+            .scaffold_files()
+            .into_iter()
+            .find(|file| file.path == schema)
+            .unwrap();
+        assert!(written > 0);
+        assert_eq!(fs::read_to_string(root.join(schema)).unwrap(), shipped.content);
+        assert_eq!(fs::read_to_string(root.join(event)).unwrap(), "id: abc123\n");
+    }
 }
@@ -2,15 +2,21 @@
 //!
 //! Validates `.jules/` repository structure for workflow automation.
 
+use std::path::PathBuf;
+
 use serde::Serialize;
 
 use crate::adapters::local_repository::LocalRepositoryAdapter;
+use crate::app::commands::doctor::{Category, Diagnostic, Severity};
 use crate::domain::AppError;
 use crate::ports::JulesStore;
 
 /// Options for workflow doctor command.
 #[derive(Debug, Clone, Default)]
-pub struct WorkflowDoctorOptions {}
+pub struct WorkflowDoctorOptions {
+    /// Control-plane config read instead of `.jlo/config.toml`.
+    pub config: Option<PathBuf>,
+}
 
 /// Output of workflow doctor command.
 #[derive(Debug, Clone, Serialize)]
@@ -19,12 +25,38 @@ pub struct WorkflowDoctorOutput {
     pub schema_version: u32,
     /// Whether all checks passed.
     pub ok: bool,
+    /// Errors and warnings that made the gate fail, errors first.
+    pub failed_checks: Vec<WorkflowDoctorCheck>,
+}
+
+/// A single failed doctor check.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowDoctorCheck {
+    /// Stable `JLO-*` diagnostic code, as accepted by `jlo doctor --allow`.
+    pub code: &'static str,
+    /// Check phase that produced the diagnostic.
+    pub category: Category,
+    pub severity: Severity,
+    pub file: String,
+    pub message: String,
+}
+
+impl From<Diagnostic> for WorkflowDoctorCheck {
+    fn from(diagnostic: Diagnostic) -> Self {
+        Self {
+            code: diagnostic.code,
+            category: diagnostic.category,
+            severity: diagnostic.severity,
+            file: diagnostic.file,
+            message: diagnostic.message,
+        }
+    }
 }
 
 /// Execute workflow doctor validation.
 ///
 /// Returns a machine-readable output indicating repository health.
-pub fn execute(_options: WorkflowDoctorOptions) -> Result<WorkflowDoctorOutput, AppError> {
+pub fn execute(options: WorkflowDoctorOptions) -> Result<WorkflowDoctorOutput, AppError> {
     let repository = LocalRepositoryAdapter::current()?;
 
     if !repository.jules_exists() {
@@ -34,9 +66,15 @@ pub fn execute(_options: WorkflowDoctorOptions) -> Result<WorkflowDoctorOutput,
     // Delegate to existing doctor logic but translate to workflow output
     let doctor_options = crate::app::commands::doctor::DoctorOptions {
         strict: true, // Workflow mode is strict by default
+        config: options.config,
+        ..Default::default()
     };
 
     let outcome = crate::app::commands::doctor::execute(&repository.jules_path(), doctor_options)?;
 
-    Ok(WorkflowDoctorOutput { schema_version: 1, ok: outcome.errors == 0 && outcome.warnings == 0 })
+    Ok(WorkflowDoctorOutput {
+        schema_version: 1,
+        ok: outcome.errors == 0 && outcome.warnings == 0,
+        failed_checks: outcome.diagnostics.into_iter().map(WorkflowDoctorCheck::from).collect(),
+    })
 }
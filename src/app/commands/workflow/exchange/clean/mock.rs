@@ -186,8 +186,7 @@ fn delete_mock_files(
         }
     }
 
-    let status = git.run_command(&["status", "--porcelain", "--", ".jules"], None)?;
-    if status.trim().is_empty() {
+    if git.status(&[".jules"])?.is_empty() {
         return Ok(files.len());
     }
 
@@ -5,7 +5,8 @@ pub mod requirement;
 
 pub use mock::{ExchangeCleanMockOptions, ExchangeCleanMockOutput};
 pub use requirement::{
-    ExchangeCleanRequirementApplyOutput, ExchangeCleanRequirementOptions,
+    ExchangeCleanRequirementApplyOutput, ExchangeCleanRequirementBatchOptions,
+    ExchangeCleanRequirementBatchOutput, ExchangeCleanRequirementOptions,
     ExchangeCleanRequirementOutput,
 };
 
@@ -20,6 +21,13 @@ pub fn clean_requirement(
     requirement::execute(options)
 }
 
+/// Execute exchange clean requirement command for several requirements at once.
+pub fn clean_requirement_batch(
+    options: ExchangeCleanRequirementBatchOptions,
+) -> Result<ExchangeCleanRequirementBatchOutput, AppError> {
+    requirement::execute_batch(options)
+}
+
 pub fn clean_requirement_apply_with_adapters<
     G: Git,
     W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
@@ -1,18 +1,21 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::path::{Path, PathBuf};
 
 use serde::Serialize;
 
 use crate::adapters::git::GitCommandAdapter;
+use crate::adapters::github::GitHubCommandAdapter;
 use crate::adapters::local_repository::LocalRepositoryAdapter;
 use crate::app::commands::workflow::push::{
-    PushWorkerBranchOptions, execute as push_worker_branch,
+    MergeStrategy, PushWorkerBranchOptions, execute as push_worker_branch,
+    execute_with_adapters as push_worker_branch_with_adapters,
 };
 use crate::domain::AppError;
 use crate::domain::PromptAssetLoader;
-use crate::ports::{Git, JloStore, JulesStore, RepositoryFilesystem};
+use crate::ports::{Git, GitHub, JloStore, JulesStore, RepositoryFilesystem};
 
 use crate::app::commands::workflow::exchange::inspect::inspect_at;
+use crate::app::commands::workflow::exchange::model::ExchangeInspectOutput;
 
 #[derive(Debug, Clone)]
 pub struct ExchangeCleanRequirementOptions {
@@ -37,6 +40,44 @@ pub struct ExchangeCleanRequirementApplyOutput {
     pub requirement_id: String,
 }
 
+#[derive(Debug, Clone)]
+pub struct ExchangeCleanRequirementBatchOptions {
+    pub requirement_files: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CleanedRequirement {
+    pub requirement_file: String,
+    pub requirement_id: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FailedRequirement {
+    pub requirement_file: String,
+    pub error: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExchangeCleanRequirementBatchApplyOutput {
+    pub schema_version: u32,
+    pub deleted_paths: Vec<String>,
+    pub cleaned: Vec<CleanedRequirement>,
+    pub failed: Vec<FailedRequirement>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ExchangeCleanRequirementBatchOutput {
+    pub schema_version: u32,
+    pub deleted_paths: Vec<String>,
+    pub cleaned: Vec<CleanedRequirement>,
+    pub failed: Vec<FailedRequirement>,
+    pub committed: bool,
+    pub commit_sha: String,
+    pub pushed: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pr_number: Option<u64>,
+}
+
 pub fn execute(
     options: ExchangeCleanRequirementOptions,
 ) -> Result<ExchangeCleanRequirementOutput, AppError> {
@@ -63,6 +104,7 @@ pub fn execute_with_adapters<
             "Automated cleanup for processed requirement `{}`.\n\n- remove requirement artifact\n- remove source event artifacts",
             applied.requirement_id
         ),
+        merge_strategy: MergeStrategy::Squash,
     })?;
 
     let commit_sha = push_output.head_sha.unwrap_or_default();
@@ -79,6 +121,69 @@ pub fn execute_with_adapters<
     })
 }
 
+/// Clean several requirements and publish them with a single worker-branch push.
+pub fn execute_batch(
+    options: ExchangeCleanRequirementBatchOptions,
+) -> Result<ExchangeCleanRequirementBatchOutput, AppError> {
+    let repository = LocalRepositoryAdapter::current()?;
+    let root = repository_root(&repository)?;
+    let git = GitCommandAdapter::new(root);
+    let github = GitHubCommandAdapter::new();
+    execute_batch_with_adapters(options, &repository, &git, &github)
+}
+
+pub fn execute_batch_with_adapters<
+    G: Git,
+    H: GitHub,
+    W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
+>(
+    options: ExchangeCleanRequirementBatchOptions,
+    repository: &W,
+    git: &G,
+    github: &H,
+) -> Result<ExchangeCleanRequirementBatchOutput, AppError> {
+    let applied = apply_batch_with_adapters(options, repository, git)?;
+
+    if applied.cleaned.is_empty() {
+        let failures = applied
+            .failed
+            .iter()
+            .map(|f| format!("{}: {}", f.requirement_file, f.error))
+            .collect::<Vec<_>>()
+            .join("; ");
+        return Err(AppError::Validation(format!("No requirements were cleaned: {}", failures)));
+    }
+
+    let push_output =
+        push_worker_branch_with_adapters(git, github, batch_push_options(&applied.cleaned))?;
+
+    Ok(ExchangeCleanRequirementBatchOutput {
+        schema_version: 1,
+        deleted_paths: applied.deleted_paths,
+        cleaned: applied.cleaned,
+        failed: applied.failed,
+        committed: push_output.applied,
+        commit_sha: push_output.head_sha.unwrap_or_default(),
+        pushed: push_output.applied,
+        pr_number: push_output.pr_number,
+    })
+}
+
+fn batch_push_options(cleaned: &[CleanedRequirement]) -> PushWorkerBranchOptions {
+    let ids: Vec<&str> = cleaned.iter().map(|c| c.requirement_id.as_str()).collect();
+    let bullets = ids.iter().map(|id| format!("- `{}`", id)).collect::<Vec<_>>().join("\n");
+    PushWorkerBranchOptions {
+        change_token: format!("requirement-cleanup-batch-{}", ids.len()),
+        commit_message: format!("jules: clean requirements {}", ids.join(", ")),
+        pr_title: format!("chore: clean {} requirements", ids.len()),
+        pr_body: format!(
+            "Automated cleanup for processed requirements:\n\n{}\n\nRequirement artifacts and their source events are removed.",
+            bullets
+        ),
+        merge_strategy: MergeStrategy::Squash,
+    }
+}
+
 pub fn apply_with_adapters<
     G: Git,
     W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
@@ -87,34 +192,110 @@ pub fn apply_with_adapters<
     repository: &W,
     git: &G,
 ) -> Result<ExchangeCleanRequirementApplyOutput, AppError> {
+    let canonical_jules = canonical_jules_path(repository)?;
+    let inspect_output = inspect_at(repository)?;
+    let (requirement_id, deleted_paths) = resolve_cleanup_paths(
+        &options.requirement_file,
+        &canonical_jules,
+        &inspect_output,
+        repository,
+    )?;
+
+    for path in &deleted_paths {
+        git.run_command(&["rm", "--", path], None)?;
+    }
+
+    Ok(ExchangeCleanRequirementApplyOutput { schema_version: 1, deleted_paths, requirement_id })
+}
+
+/// Resolve and remove every requirement in the batch, collecting per-file failures.
+///
+/// Source events shared between requirements are removed once.
+pub fn apply_batch_with_adapters<
+    G: Git,
+    W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader,
+>(
+    options: ExchangeCleanRequirementBatchOptions,
+    repository: &W,
+    git: &G,
+) -> Result<ExchangeCleanRequirementBatchApplyOutput, AppError> {
+    if options.requirement_files.is_empty() {
+        return Err(AppError::Validation("--batch must list at least one requirement".to_string()));
+    }
+
+    let canonical_jules = canonical_jules_path(repository)?;
+    let inspect_output = inspect_at(repository)?;
+
+    let mut deleted_paths = BTreeSet::new();
+    let mut cleaned = Vec::new();
+    let mut failed = Vec::new();
+    for requirement_file in &options.requirement_files {
+        match resolve_cleanup_paths(requirement_file, &canonical_jules, &inspect_output, repository)
+        {
+            Ok((requirement_id, paths)) => {
+                deleted_paths.extend(paths);
+                cleaned.push(CleanedRequirement {
+                    requirement_file: requirement_file.clone(),
+                    requirement_id,
+                });
+            }
+            Err(err) => failed.push(FailedRequirement {
+                requirement_file: requirement_file.clone(),
+                error: err.to_string(),
+            }),
+        }
+    }
+
+    let deleted_paths: Vec<String> = deleted_paths.into_iter().collect();
+    for path in &deleted_paths {
+        git.run_command(&["rm", "--", path], None)?;
+    }
+
+    Ok(ExchangeCleanRequirementBatchApplyOutput {
+        schema_version: 1,
+        deleted_paths,
+        cleaned,
+        failed,
+    })
+}
+
+fn canonical_jules_path<
+    W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader + ?Sized,
+>(
+    repository: &W,
+) -> Result<PathBuf, AppError> {
     if !repository.jules_exists() {
         return Err(AppError::JulesNotFound);
     }
 
     let jules_path = repository.jules_path();
-    let canonical_jules = repository
+    repository
         .canonicalize(path_to_str(&jules_path, "Invalid .jules path")?)
-        .map_err(|e| AppError::InternalError(format!("Failed to resolve .jules path: {}", e)))?;
+        .map_err(|e| AppError::InternalError(format!("Failed to resolve .jules path: {}", e)))
+}
 
-    let canonical_requirement =
-        repository.canonicalize(&options.requirement_file).map_err(|_| {
-            AppError::Validation(format!(
-                "Requirement file does not exist: {}",
-                options.requirement_file
-            ))
-        })?;
+/// Resolve the requirement id and the sorted repo-relative paths to delete for it.
+fn resolve_cleanup_paths<
+    W: RepositoryFilesystem + JloStore + JulesStore + PromptAssetLoader + ?Sized,
+>(
+    requirement_file: &str,
+    canonical_jules: &Path,
+    inspect_output: &ExchangeInspectOutput,
+    repository: &W,
+) -> Result<(String, Vec<String>), AppError> {
+    let canonical_requirement = repository.canonicalize(requirement_file).map_err(|_| {
+        AppError::Validation(format!("Requirement file does not exist: {}", requirement_file))
+    })?;
 
-    if !canonical_requirement.starts_with(&canonical_jules) {
+    if !canonical_requirement.starts_with(canonical_jules) {
         return Err(AppError::Validation(format!(
             "Requirement file must be within .jules/ directory: {}",
-            options.requirement_file
+            requirement_file
         )));
     }
 
     let requirement_rel =
-        resolve_requirement_path(&canonical_jules, &canonical_requirement, repository)?;
-
-    let inspect_output = inspect_at(repository)?;
+        resolve_requirement_path(canonical_jules, &canonical_requirement, repository)?;
 
     let requirement_item = inspect_output
         .requirements
@@ -155,15 +336,7 @@ pub fn apply_with_adapters<
         ));
     }
 
-    for path in &deleted_paths {
-        git.run_command(&["rm", "--", path], None)?;
-    }
-
-    Ok(ExchangeCleanRequirementApplyOutput {
-        schema_version: 1,
-        deleted_paths,
-        requirement_id: requirement_item.id.clone(),
-    })
+    Ok((requirement_item.id.clone(), deleted_paths))
 }
 
 fn resolve_requirement_path<
@@ -223,16 +396,55 @@ mod tests {
     use super::*;
     use crate::adapters::git::GitCommandAdapter;
     use crate::adapters::local_repository::LocalRepositoryAdapter;
+    use crate::testing::FakeGitHub;
     use serial_test::serial;
     use std::fs;
     use std::process::Command;
     use tempfile::tempdir;
 
-    #[test]
-    #[serial]
-    fn clean_requirement_apply_deletes_files() {
-        let dir = tempdir().unwrap();
-        let root = dir.path();
+    struct EnvVarGuard {
+        key: &'static str,
+        original: Option<std::ffi::OsString>,
+    }
+
+    impl EnvVarGuard {
+        fn set(key: &'static str, value: &str) -> Self {
+            let original = std::env::var_os(key);
+            // SAFETY: Tests touching the environment are marked serial.
+            unsafe {
+                std::env::set_var(key, value);
+            }
+            Self { key, original }
+        }
+    }
+
+    impl Drop for EnvVarGuard {
+        fn drop(&mut self) {
+            // SAFETY: Drop runs in the same serial-test context as `set`.
+            unsafe {
+                match self.original.as_ref() {
+                    Some(original) => std::env::set_var(self.key, original),
+                    None => std::env::remove_var(self.key),
+                }
+            }
+        }
+    }
+
+    const ISSUE_YML: &str = r#"
+id: abc123
+label: bugs
+source_events:
+  - abc123
+  - def456
+implementation_ready: true
+planner_request_reason: ""
+"#;
+
+    fn seed_repository(
+        root: &Path,
+        events: &[(&str, &str)],
+        requirements: &[(&str, &str)],
+    ) -> PathBuf {
         let repo_dir = root.join("repo");
         let remote_dir = root.join("remote.git");
         fs::create_dir_all(&repo_dir).unwrap();
@@ -269,22 +481,15 @@ mod tests {
         let exchange_dir = jules_path.join("exchange");
         fs::create_dir_all(exchange_dir.join("events/pending")).unwrap();
         fs::create_dir_all(exchange_dir.join("requirements")).unwrap();
+        fs::write(jules_path.join("JULES.md"), "# Jules\n").unwrap();
 
-        fs::write(exchange_dir.join("events/pending/event1.yml"), "id: abc123\n").unwrap();
-        fs::write(exchange_dir.join("events/pending/event2.yml"), "id: def456\n").unwrap();
-        fs::write(
-            exchange_dir.join("requirements/issue.yml"),
-            r#"
-id: abc123
-label: bugs
-source_events:
-  - abc123
-  - def456
-implementation_ready: true
-planner_request_reason: ""
-"#,
-        )
-        .unwrap();
+        for (name, id) in events {
+            fs::write(exchange_dir.join("events/pending").join(name), format!("id: {}\n", id))
+                .unwrap();
+        }
+        for (name, content) in requirements {
+            fs::write(exchange_dir.join("requirements").join(name), content).unwrap();
+        }
 
         fs::create_dir_all(&jlo_path).unwrap();
         fs::write(
@@ -306,6 +511,19 @@ roles = [
         Command::new("git").args(["add", ".jlo"]).current_dir(&repo_dir).output().unwrap();
         Command::new("git").args(["commit", "-m", "seed"]).current_dir(&repo_dir).output().unwrap();
 
+        repo_dir
+    }
+
+    #[test]
+    #[serial]
+    fn clean_requirement_apply_deletes_files() {
+        let dir = tempdir().unwrap();
+        let repo_dir = seed_repository(
+            dir.path(),
+            &[("event1.yml", "abc123"), ("event2.yml", "def456")],
+            &[("issue.yml", ISSUE_YML)],
+        );
+
         std::env::set_current_dir(&repo_dir).unwrap();
 
         let repository = LocalRepositoryAdapter::new(repo_dir.clone());
@@ -338,4 +556,68 @@ roles = [
             "cleanup apply should stage/track .jules changes"
         );
     }
+
+    #[test]
+    #[serial]
+    fn clean_requirement_batch_publishes_all_requirements_in_one_commit() {
+        let dir = tempdir().unwrap();
+        let second = ISSUE_YML
+            .replace("id: abc123\nlabel", "id: ghi789\nlabel")
+            .replace("  - abc123\n  - def456", "  - def456\n  - ghi789");
+        let repo_dir = seed_repository(
+            dir.path(),
+            &[("event1.yml", "abc123"), ("event2.yml", "def456"), ("event3.yml", "ghi789")],
+            &[("issue.yml", ISSUE_YML), ("issue2.yml", &second)],
+        );
+
+        Command::new("git")
+            .args(["push", "origin", "jules"])
+            .current_dir(&repo_dir)
+            .output()
+            .unwrap();
+
+        std::env::set_current_dir(&repo_dir).unwrap();
+        let _worker_branch = EnvVarGuard::set("JULES_WORKER_BRANCH", "jules");
+
+        let repository = LocalRepositoryAdapter::new(repo_dir.clone());
+        let git = GitCommandAdapter::new(repo_dir.clone());
+        let github = FakeGitHub::new();
+        let output = execute_batch_with_adapters(
+            ExchangeCleanRequirementBatchOptions {
+                requirement_files: vec![
+                    ".jules/exchange/requirements/issue.yml".to_string(),
+                    ".jules/exchange/requirements/issue2.yml".to_string(),
+                    ".jules/exchange/requirements/missing.yml".to_string(),
+                ],
+            },
+            &repository,
+            &git,
+            &github,
+        )
+        .unwrap();
+
+        let cleaned: Vec<&str> = output.cleaned.iter().map(|c| c.requirement_id.as_str()).collect();
+        assert_eq!(cleaned, vec!["abc123", "ghi789"]);
+        assert_eq!(output.failed.len(), 1);
+        assert!(output.failed[0].requirement_file.ends_with("missing.yml"));
+        assert_eq!(output.deleted_paths.len(), 5, "shared event is removed once");
+        assert!(output.committed && output.pushed);
+        assert_eq!(output.pr_number, Some(101));
+
+        let created = github.created_pull_requests.lock().unwrap().clone();
+        assert_eq!(created.len(), 1, "batch publishes a single pull request");
+        assert_eq!(created[0].1, "jules");
+        let subject = git_stdout(&repo_dir, &["log", "-1", "--format=%s", &output.commit_sha]);
+        assert_eq!(subject.trim(), "jules: clean requirements abc123, ghi789");
+        let committed_files =
+            git_stdout(&repo_dir, &["show", "--name-only", "--format=", &output.commit_sha]);
+        assert_eq!(committed_files.lines().count(), 5);
+        let remote_branches = git_stdout(&repo_dir, &["ls-remote", "--heads", "origin"]);
+        assert!(remote_branches.contains(&format!("refs/heads/{}", created[0].0)));
+    }
+
+    fn git_stdout(repo_dir: &Path, args: &[&str]) -> String {
+        let output = Command::new("git").args(args).current_dir(repo_dir).output().unwrap();
+        String::from_utf8_lossy(&output.stdout).to_string()
+    }
 }
@@ -0,0 +1,206 @@
+//! Compare the current exchange inspect output against a saved snapshot.
+//!
+//! Used to debug why routing differs between two points in time (or two
+//! checkouts): the baseline is a JSON document previously produced by
+//! `jlo workflow inspect-exchange`.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+use serde::Serialize;
+
+use crate::domain::AppError;
+
+use super::model::ExchangeInspectOutput;
+
+#[derive(Debug, Serialize)]
+pub struct ExchangeInspectDiffOutput {
+    pub schema_version: u32,
+    pub baseline: String,
+    pub identical: bool,
+    /// Observer roles whose presence or enabled flag differs.
+    pub roles: Vec<RoleDelta>,
+    /// Event states whose counts differ.
+    pub event_states: Vec<CountDelta>,
+    pub requirements: CountDelta,
+    pub pending_added: Vec<String>,
+    pub pending_removed: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct RoleDelta {
+    pub name: String,
+    pub baseline_enabled: Option<bool>,
+    pub current_enabled: Option<bool>,
+}
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct CountDelta {
+    pub name: String,
+    pub baseline: usize,
+    pub current: usize,
+    pub delta: i64,
+}
+
+impl CountDelta {
+    fn new(name: &str, baseline: usize, current: usize) -> Self {
+        Self { name: name.to_string(), baseline, current, delta: current as i64 - baseline as i64 }
+    }
+}
+
+/// Load a saved inspect document from `path`.
+pub fn load_baseline(path: &Path) -> Result<ExchangeInspectOutput, AppError> {
+    let content = std::fs::read_to_string(path).map_err(|e| {
+        AppError::Validation(format!("Failed to read baseline {}: {}", path.display(), e))
+    })?;
+    serde_json::from_str(&content).map_err(|e| AppError::ParseError {
+        what: format!("inspect baseline {}", path.display()),
+        details: e.to_string(),
+    })
+}
+
+/// Compute per-field deltas between a baseline and the current inspect output.
+pub fn diff_outputs(
+    baseline_label: &str,
+    baseline: &ExchangeInspectOutput,
+    current: &ExchangeInspectOutput,
+) -> ExchangeInspectDiffOutput {
+    let baseline_roles: BTreeMap<&str, bool> =
+        baseline.schedule.observers.roles.iter().map(|r| (r.name.as_str(), r.enabled)).collect();
+    let current_roles: BTreeMap<&str, bool> =
+        current.schedule.observers.roles.iter().map(|r| (r.name.as_str(), r.enabled)).collect();
+    let role_names: BTreeSet<&str> =
+        baseline_roles.keys().chain(current_roles.keys()).copied().collect();
+    let roles: Vec<RoleDelta> = role_names
+        .into_iter()
+        .filter_map(|name| {
+            let baseline_enabled = baseline_roles.get(name).copied();
+            let current_enabled = current_roles.get(name).copied();
+            (baseline_enabled != current_enabled).then(|| RoleDelta {
+                name: name.to_string(),
+                baseline_enabled,
+                current_enabled,
+            })
+        })
+        .collect();
+
+    let baseline_states: BTreeMap<&str, usize> =
+        baseline.events.states.iter().map(|s| (s.name.as_str(), s.count)).collect();
+    let current_states: BTreeMap<&str, usize> =
+        current.events.states.iter().map(|s| (s.name.as_str(), s.count)).collect();
+    let state_names: BTreeSet<&str> =
+        baseline_states.keys().chain(current_states.keys()).copied().collect();
+    let event_states: Vec<CountDelta> = state_names
+        .into_iter()
+        .map(|name| {
+            CountDelta::new(
+                name,
+                baseline_states.get(name).copied().unwrap_or(0),
+                current_states.get(name).copied().unwrap_or(0),
+            )
+        })
+        .filter(|delta| delta.delta != 0)
+        .collect();
+
+    let requirements =
+        CountDelta::new("requirements", baseline.requirements.count, current.requirements.count);
+
+    let baseline_pending: BTreeSet<&str> =
+        baseline.events.pending_files.iter().map(String::as_str).collect();
+    let current_pending: BTreeSet<&str> =
+        current.events.pending_files.iter().map(String::as_str).collect();
+    let pending_added: Vec<String> =
+        current_pending.difference(&baseline_pending).map(|s| s.to_string()).collect();
+    let pending_removed: Vec<String> =
+        baseline_pending.difference(&current_pending).map(|s| s.to_string()).collect();
+
+    let identical = roles.is_empty()
+        && event_states.is_empty()
+        && requirements.delta == 0
+        && pending_added.is_empty()
+        && pending_removed.is_empty();
+
+    ExchangeInspectDiffOutput {
+        schema_version: 1,
+        baseline: baseline_label.to_string(),
+        identical,
+        roles,
+        event_states,
+        requirements,
+        pending_added,
+        pending_removed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inspect_json(roles: &str, pending: usize, requirements: usize) -> ExchangeInspectOutput {
+        let pending_files: Vec<String> = (0..pending)
+            .map(|i| format!("\".jules/exchange/events/pending/e{}.yml\"", i))
+            .collect();
+        serde_json::from_str(&format!(
+            r#"{{
+              "schema_version": 1,
+              "schedule": {{ "observers": {{ "roles": [{roles}] }} }},
+              "events": {{
+                "states": [{{ "name": "pending", "count": {pending} }}],
+                "pending_files": [{files}],
+                "items": []
+              }},
+              "requirements": {{ "count": {requirements}, "items": [] }}
+            }}"#,
+            files = pending_files.join(",")
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn highlights_role_roster_and_pending_count_differences() {
+        let baseline = inspect_json(
+            r#"{"name":"taxonomy","enabled":true},{"name":"qa","enabled":true}"#,
+            1,
+            2,
+        );
+        let current = inspect_json(
+            r#"{"name":"taxonomy","enabled":true},{"name":"qa","enabled":false},{"name":"cov","enabled":true}"#,
+            3,
+            2,
+        );
+
+        let diff = diff_outputs("baseline.json", &baseline, &current);
+
+        assert!(!diff.identical);
+        assert_eq!(
+            diff.roles,
+            vec![
+                RoleDelta {
+                    name: "cov".to_string(),
+                    baseline_enabled: None,
+                    current_enabled: Some(true)
+                },
+                RoleDelta {
+                    name: "qa".to_string(),
+                    baseline_enabled: Some(true),
+                    current_enabled: Some(false)
+                },
+            ]
+        );
+        assert_eq!(diff.event_states, vec![CountDelta::new("pending", 1, 3)]);
+        assert_eq!(diff.event_states[0].delta, 2);
+        assert_eq!(diff.requirements.delta, 0);
+        assert_eq!(diff.pending_added.len(), 2);
+        assert!(diff.pending_removed.is_empty());
+    }
+
+    #[test]
+    fn identical_outputs_report_no_deltas() {
+        let roles = r#"{"name":"taxonomy","enabled":true}"#;
+        let diff = diff_outputs("b", &inspect_json(roles, 1, 0), &inspect_json(roles, 1, 0));
+
+        assert!(diff.identical);
+        assert!(diff.roles.is_empty());
+        assert!(diff.event_states.is_empty());
+    }
+}
@@ -1,38 +1,173 @@
+use std::collections::HashSet;
+use std::io::Write;
 use std::path::{Path, PathBuf};
 
+use serde::Serialize;
 use serde_yaml::{Mapping, Value};
 
 use crate::adapters::local_repository::LocalRepositoryAdapter;
 use crate::app::config::load_schedule;
-use crate::domain::AppError;
+use crate::domain::{AppError, RoleError};
 use crate::ports::{JloStore, JulesStore, RepositoryFilesystem};
 
 use super::model::{
-    EventItem, EventStateSummary, EventSummary, ExchangeInspectOutput, RequirementItem,
-    RequirementSummary, RoleSummary, ScheduleLayerSummary, ScheduleSummary,
+    EventItem, EventStateSummary, EventSummary, ExchangeInspectOutput, INSPECT_SCHEMA_VERSION,
+    RequirementItem, RequirementSummary, RoleSummary, ScheduleLayerSummary, ScheduleSummary,
 };
 
-#[derive(Debug, Clone)]
-pub struct ExchangeInspectOptions {}
+#[derive(Debug, Clone, Default)]
+pub struct ExchangeInspectOptions {
+    /// Report only state counts; leave `items` and `pending_files` empty.
+    pub count_only: bool,
+    /// Scope to events authored by this observer role and the requirements they feed.
+    pub role: Option<String>,
+    /// Control-plane config read instead of `.jlo/config.toml`.
+    pub config: Option<PathBuf>,
+}
+
+/// Events authored by one observer role; requirements are in scope when they cite one.
+struct RoleScope {
+    event_paths: HashSet<PathBuf>,
+    event_ids: HashSet<String>,
+}
+
+impl RoleScope {
+    fn includes_requirement(&self, item: &RequirementItem) -> bool {
+        item.source_events.iter().any(|id| self.event_ids.contains(id))
+    }
+}
 
-pub fn execute(_options: ExchangeInspectOptions) -> Result<ExchangeInspectOutput, AppError> {
-    let repository = LocalRepositoryAdapter::current()?;
+/// One line of streamed inspect output.
+#[derive(Debug, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+enum StreamItem<'a> {
+    Event(&'a EventItem),
+    Requirement(&'a RequirementItem),
+}
+
+pub fn execute(options: ExchangeInspectOptions) -> Result<ExchangeInspectOutput, AppError> {
+    let repository = current_repository(options.config)?;
+    inspect_with(&repository, options.count_only, options.role.as_deref())
+}
+
+/// Write exchange items to `out` as newline-delimited JSON, one item per line.
+///
+/// Items are written as they are read, so the full inspect document is never
+/// held in memory. Returns the number of lines written.
+pub fn stream(options: ExchangeInspectOptions, out: &mut impl Write) -> Result<usize, AppError> {
+    let repository = current_repository(options.config)?;
+    stream_at(&repository, options.role.as_deref(), out)
+}
+
+fn current_repository(config: Option<PathBuf>) -> Result<LocalRepositoryAdapter, AppError> {
+    let repository = LocalRepositoryAdapter::current()?.with_config_path(config);
 
     if !repository.jules_exists() {
         return Err(AppError::JulesNotFound);
     }
 
-    inspect_at(&repository)
+    Ok(repository)
 }
 
-pub(super) fn inspect_at(
+fn resolve_exchange_dir(
     store: &(impl RepositoryFilesystem + JloStore + JulesStore),
-) -> Result<ExchangeInspectOutput, AppError> {
+) -> Result<PathBuf, AppError> {
     let jules_path = store.jules_path();
     let exchange_dir = crate::domain::exchange::paths::exchange_dir(&jules_path);
     if !store.file_exists(exchange_dir.to_str().unwrap()) {
         return Err(AppError::JulesNotFound);
     }
+    Ok(exchange_dir)
+}
+
+/// Resolve the events authored by `role`, which must be a scheduled observer.
+fn resolve_role_scope(
+    store: &(impl RepositoryFilesystem + JloStore + JulesStore),
+    exchange_dir: &Path,
+    role: &str,
+) -> Result<RoleScope, AppError> {
+    let schedule = load_schedule(store)?;
+    if !schedule.observers.roles.iter().any(|entry| entry.name.as_str() == role) {
+        return Err(RoleError::NotInConfig {
+            role: role.to_string(),
+            layer: "observers".to_string(),
+        }
+        .into());
+    }
+
+    let mut scope = RoleScope { event_paths: HashSet::new(), event_ids: HashSet::new() };
+    for state_dir in list_subdirectories(store, &events_dir(store, exchange_dir)?)? {
+        for path in list_yml_files(store, &state_dir)? {
+            let map = read_yaml_mapping(store, &path)?;
+            if map.get("author_role").and_then(Value::as_str) != Some(role) {
+                continue;
+            }
+            if let Some(id) = map.get("id").and_then(Value::as_str) {
+                scope.event_ids.insert(id.to_string());
+            }
+            scope.event_paths.insert(path);
+        }
+    }
+    Ok(scope)
+}
+
+pub(super) fn stream_at(
+    store: &(impl RepositoryFilesystem + JloStore + JulesStore),
+    role: Option<&str>,
+    out: &mut impl Write,
+) -> Result<usize, AppError> {
+    let exchange_dir = resolve_exchange_dir(store)?;
+    let jules_path = store.jules_path();
+    let root = jules_path.parent().unwrap_or(Path::new("."));
+    let scope = role.map(|role| resolve_role_scope(store, &exchange_dir, role)).transpose()?;
+
+    let mut written = 0;
+    walk_events(
+        store,
+        root,
+        &exchange_dir,
+        true,
+        scope.as_ref(),
+        |_, _| {},
+        |item| {
+            write_stream_line(out, &StreamItem::Event(&item))?;
+            written += 1;
+            Ok(())
+        },
+    )?;
+    walk_requirements(store, root, &exchange_dir, scope.as_ref(), |item| {
+        write_stream_line(out, &StreamItem::Requirement(&item))?;
+        written += 1;
+        Ok(())
+    })?;
+
+    out.flush()?;
+    Ok(written)
+}
+
+fn write_stream_line(out: &mut impl Write, item: &StreamItem<'_>) -> Result<(), AppError> {
+    serde_json::to_writer(&mut *out, item)
+        .map_err(|e| AppError::InternalError(format!("Failed to serialize inspect item: {}", e)))?;
+    out.write_all(b"\n")?;
+    Ok(())
+}
+
+pub(super) fn inspect_at(
+    store: &(impl RepositoryFilesystem + JloStore + JulesStore),
+) -> Result<ExchangeInspectOutput, AppError> {
+    inspect_with(store, false, None)
+}
+
+/// Build the inspect document; with `count_only`, item files are counted but never parsed
+/// unless a `role` scope requires reading their authorship.
+fn inspect_with(
+    store: &(impl RepositoryFilesystem + JloStore + JulesStore),
+    count_only: bool,
+    role: Option<&str>,
+) -> Result<ExchangeInspectOutput, AppError> {
+    let exchange_dir = resolve_exchange_dir(store)?;
+    let jules_path = store.jules_path();
+    let scope = role.map(|role| resolve_role_scope(store, &exchange_dir, role)).transpose()?;
 
     let schedule = load_schedule(store)?;
     let schedule_summary = ScheduleSummary {
@@ -47,11 +182,12 @@ pub(super) fn inspect_at(
     };
 
     let root = jules_path.parent().unwrap_or(Path::new("."));
-    let events = summarize_events(store, root, &exchange_dir)?;
-    let requirements = summarize_requirements(store, root, &exchange_dir)?;
+    let events = summarize_events(store, root, &exchange_dir, count_only, scope.as_ref())?;
+    let requirements =
+        summarize_requirements(store, root, &exchange_dir, count_only, scope.as_ref())?;
 
     Ok(ExchangeInspectOutput {
-        schema_version: 1,
+        schema_version: INSPECT_SCHEMA_VERSION,
         schedule: schedule_summary,
         events,
         requirements,
@@ -62,7 +198,40 @@ fn summarize_events(
     store: &(impl RepositoryFilesystem + JloStore + JulesStore),
     root: &Path,
     exchange_dir: &Path,
+    count_only: bool,
+    scope: Option<&RoleScope>,
 ) -> Result<EventSummary, AppError> {
+    let mut states = Vec::new();
+    let mut pending_files = Vec::new();
+    let mut items = Vec::new();
+
+    walk_events(
+        store,
+        root,
+        exchange_dir,
+        !count_only,
+        scope,
+        |state_name, files| {
+            states.push(EventStateSummary { name: state_name.to_string(), count: files.len() });
+            if state_name == "pending" && !count_only {
+                pending_files = files.iter().map(|path| to_repo_relative(root, path)).collect();
+            }
+        },
+        |item| {
+            items.push(item);
+            Ok(())
+        },
+    )?;
+
+    items.sort_by(|left, right| left.path.cmp(&right.path));
+
+    Ok(EventSummary { states, pending_files, items })
+}
+
+fn events_dir(
+    store: &(impl RepositoryFilesystem + JloStore + JulesStore),
+    exchange_dir: &Path,
+) -> Result<PathBuf, AppError> {
     let events_dir = exchange_dir.join("events");
     if !store.file_exists(events_dir.to_str().unwrap()) {
         return Err(AppError::Validation(format!(
@@ -70,12 +239,20 @@ fn summarize_events(
             events_dir.display()
         )));
     }
+    Ok(events_dir)
+}
 
-    let mut states = Vec::new();
-    let mut pending_files = Vec::new();
-    let mut items = Vec::new();
-
-    let state_dirs = list_subdirectories(store, &events_dir)?;
+/// Visit each event state directory and, when `read_items` is set, each event item in sorted order.
+fn walk_events(
+    store: &(impl RepositoryFilesystem + JloStore + JulesStore),
+    root: &Path,
+    exchange_dir: &Path,
+    read_items: bool,
+    scope: Option<&RoleScope>,
+    mut on_state: impl FnMut(&str, &[PathBuf]),
+    mut on_item: impl FnMut(EventItem) -> Result<(), AppError>,
+) -> Result<(), AppError> {
+    let state_dirs = list_subdirectories(store, &events_dir(store, exchange_dir)?)?;
 
     for state_dir in state_dirs {
         let state_name = state_dir
@@ -83,51 +260,83 @@ fn summarize_events(
             .map(|name| name.to_string_lossy().to_string())
             .unwrap_or_else(|| "unknown".to_string());
 
-        let files = list_yml_files(store, &state_dir)?;
-        states.push(EventStateSummary { name: state_name.clone(), count: files.len() });
-
-        if state_name == "pending" {
-            pending_files = files.iter().map(|path| to_repo_relative(root, path)).collect();
+        let mut files = list_yml_files(store, &state_dir)?;
+        if let Some(scope) = scope {
+            files.retain(|path| scope.event_paths.contains(path));
+        }
+        on_state(&state_name, &files);
+        if !read_items {
+            continue;
         }
 
         for path in &files {
-            let item = read_event_item(store, root, path, &state_name)?;
-            items.push(item);
+            on_item(read_event_item(store, root, path, &state_name)?)?;
         }
     }
 
-    items.sort_by(|left, right| left.path.cmp(&right.path));
-
-    Ok(EventSummary { states, pending_files, items })
+    Ok(())
 }
 
 fn summarize_requirements(
     store: &(impl RepositoryFilesystem + JloStore + JulesStore),
     root: &Path,
     exchange_dir: &Path,
+    count_only: bool,
+    scope: Option<&RoleScope>,
 ) -> Result<RequirementSummary, AppError> {
-    let requirements_dir = exchange_dir.join("requirements");
-    if !store.file_exists(requirements_dir.to_str().unwrap()) {
-        return Err(AppError::Validation(format!(
-            "Missing requirements directory: {}",
-            requirements_dir.display()
-        )));
+    if count_only && scope.is_none() {
+        let count = list_requirement_files(store, exchange_dir)?.len();
+        return Ok(RequirementSummary { count, items: Vec::new() });
     }
 
     let mut items = Vec::new();
-    let files = list_yml_files(store, &requirements_dir)?;
-
-    for path in &files {
-        let item = read_requirement_item(store, root, path)?;
+    walk_requirements(store, root, exchange_dir, scope, |item| {
         items.push(item);
-    }
+        Ok(())
+    })?;
 
     items.sort_by(|left, right| left.path.cmp(&right.path));
     let count = items.len();
+    if count_only {
+        items.clear();
+    }
 
     Ok(RequirementSummary { count, items })
 }
 
+/// Visit each requirement item in sorted order, skipping those outside `scope`.
+fn walk_requirements(
+    store: &(impl RepositoryFilesystem + JloStore + JulesStore),
+    root: &Path,
+    exchange_dir: &Path,
+    scope: Option<&RoleScope>,
+    mut on_item: impl FnMut(RequirementItem) -> Result<(), AppError>,
+) -> Result<(), AppError> {
+    for path in &list_requirement_files(store, exchange_dir)? {
+        let item = read_requirement_item(store, root, path)?;
+        if scope.is_none_or(|scope| scope.includes_requirement(&item)) {
+            on_item(item)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn list_requirement_files(
+    store: &(impl RepositoryFilesystem + JloStore + JulesStore),
+    exchange_dir: &Path,
+) -> Result<Vec<PathBuf>, AppError> {
+    let requirements_dir = exchange_dir.join("requirements");
+    if !store.file_exists(requirements_dir.to_str().unwrap()) {
+        return Err(AppError::Validation(format!(
+            "Missing requirements directory: {}",
+            requirements_dir.display()
+        )));
+    }
+
+    list_yml_files(store, &requirements_dir)
+}
+
 fn list_yml_files(
     store: &(impl RepositoryFilesystem + JloStore + JulesStore),
     dir: &Path,
@@ -324,10 +533,7 @@ mod tests {
     use std::fs;
     use tempfile::tempdir;
 
-    #[test]
-    fn inspect_collects_counts_and_files() {
-        let dir = tempdir().unwrap();
-        let root = dir.path();
+    fn seed_exchange(root: &Path) {
         let jules_path = root.join(".jules");
         let jlo_path = root.join(".jlo");
         let exchange_dir = jules_path.join("exchange");
@@ -365,6 +571,13 @@ roles = [
 "#,
         )
         .unwrap();
+    }
+
+    #[test]
+    fn inspect_collects_counts_and_files() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        seed_exchange(root);
 
         let store = LocalRepositoryAdapter::new(root.to_path_buf());
         let output = inspect_at(&store).unwrap();
@@ -386,4 +599,102 @@ roles = [
         assert!(req.implementation_ready);
         assert_eq!(req.source_events, vec!["abc123".to_string()]);
     }
+
+    #[test]
+    fn count_only_reports_counts_with_empty_item_arrays() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        seed_exchange(root);
+        // Count-only mode must not parse item files.
+        fs::write(root.join(".jules/exchange/events/pending/broken.yml"), "not: [valid").unwrap();
+
+        let store = LocalRepositoryAdapter::new(root.to_path_buf());
+        let output = inspect_with(&store, true, None).unwrap();
+
+        assert_eq!(output.schema_version, 1);
+        let pending = output.events.states.iter().find(|state| state.name == "pending").unwrap();
+        assert_eq!(pending.count, 2);
+        assert_eq!(output.requirements.count, 1);
+        assert!(output.events.items.is_empty());
+        assert!(output.events.pending_files.is_empty());
+        assert!(output.requirements.items.is_empty());
+    }
+
+    #[test]
+    fn role_scope_reports_only_that_roles_artifacts() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        seed_exchange(root);
+        let exchange_dir = root.join(".jules/exchange");
+        fs::write(
+            exchange_dir.join("events/pending/one.yml"),
+            "id: abc123\nauthor_role: taxonomy\n",
+        )
+        .unwrap();
+        fs::write(
+            exchange_dir.join("events/decided/two.yml"),
+            "id: def456\nauthor_role: consistency\n",
+        )
+        .unwrap();
+        fs::write(
+            exchange_dir.join("requirements/other.yml"),
+            "id: fed654\nlabel: docs\nsource_events:\n  - def456\nimplementation_ready: false\n",
+        )
+        .unwrap();
+
+        let store = LocalRepositoryAdapter::new(root.to_path_buf());
+        let output = inspect_with(&store, false, Some("taxonomy")).unwrap();
+
+        let ids: Vec<&str> = output.events.items.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, vec!["abc123"]);
+        let decided = output.events.states.iter().find(|state| state.name == "decided").unwrap();
+        assert_eq!(decided.count, 0);
+        assert_eq!(output.requirements.count, 1);
+        assert_eq!(output.requirements.items[0].id, "abc123");
+
+        let counts = inspect_with(&store, true, Some("taxonomy")).unwrap();
+        assert_eq!(counts.requirements.count, 1);
+        assert!(counts.requirements.items.is_empty());
+    }
+
+    #[test]
+    fn role_scope_rejects_unknown_role() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        seed_exchange(root);
+
+        let store = LocalRepositoryAdapter::new(root.to_path_buf());
+        let err = inspect_with(&store, false, Some("ghost")).unwrap_err();
+
+        assert!(err.to_string().contains("Role 'ghost' not found in config for layer 'observers'"));
+    }
+
+    #[test]
+    fn stream_writes_one_json_line_per_item() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        seed_exchange(root);
+
+        let store = LocalRepositoryAdapter::new(root.to_path_buf());
+        let expected = inspect_at(&store).unwrap();
+        let item_count = expected.events.items.len() + expected.requirements.items.len();
+
+        let mut buffer = Vec::new();
+        let written = stream_at(&store, None, &mut buffer).unwrap();
+        let text = String::from_utf8(buffer).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+
+        assert_eq!(written, item_count);
+        assert_eq!(lines.len(), item_count);
+
+        let kinds: Vec<String> = lines
+            .iter()
+            .map(|line| {
+                let value: serde_json::Value = serde_json::from_str(line).unwrap();
+                value["kind"].as_str().unwrap().to_string()
+            })
+            .collect();
+        assert_eq!(kinds.iter().filter(|kind| *kind == "event").count(), 2);
+        assert_eq!(kinds.iter().filter(|kind| *kind == "requirement").count(), 1);
+    }
 }
@@ -4,14 +4,17 @@
 //! `jlo workflow exchange`.
 
 pub mod clean;
+mod diff;
 pub mod inspect;
 mod model;
 pub mod publish_proposals;
 
 pub use clean::{
     ExchangeCleanMockOptions, ExchangeCleanMockOutput, ExchangeCleanRequirementApplyOutput,
+    ExchangeCleanRequirementBatchOptions, ExchangeCleanRequirementBatchOutput,
     ExchangeCleanRequirementOptions, ExchangeCleanRequirementOutput,
 };
+pub use diff::ExchangeInspectDiffOutput;
 pub use inspect::ExchangeInspectOptions;
 pub use model::ExchangeInspectOutput;
 pub use publish_proposals::{ExchangePublishProposalsOptions, ExchangePublishProposalsOutput};
@@ -25,6 +28,24 @@ pub fn inspect(options: ExchangeInspectOptions) -> Result<ExchangeInspectOutput,
     inspect::execute(options)
 }
 
+/// Execute exchange inspect command, streaming items as newline-delimited JSON.
+pub fn inspect_stream(
+    options: ExchangeInspectOptions,
+    out: &mut impl std::io::Write,
+) -> Result<usize, AppError> {
+    inspect::stream(options, out)
+}
+
+/// Execute exchange inspect and diff the result against a saved inspect JSON document.
+pub fn inspect_diff(
+    options: ExchangeInspectOptions,
+    baseline: &std::path::Path,
+) -> Result<ExchangeInspectDiffOutput, AppError> {
+    let baseline_output = diff::load_baseline(baseline)?;
+    let current = inspect::execute(options)?;
+    Ok(diff::diff_outputs(&baseline.display().to_string(), &baseline_output, &current))
+}
+
 /// Execute exchange publish-proposals command.
 pub fn publish_proposals(
     options: ExchangePublishProposalsOptions,
@@ -39,6 +60,13 @@ pub fn clean_requirement(
     clean::clean_requirement(options)
 }
 
+/// Execute exchange clean requirement command for a batch with a single push.
+pub fn clean_requirement_batch(
+    options: ExchangeCleanRequirementBatchOptions,
+) -> Result<ExchangeCleanRequirementBatchOutput, AppError> {
+    clean::clean_requirement_batch(options)
+}
+
 /// Execute exchange clean requirement command with injected adapters.
 pub fn clean_requirement_apply_with_adapters<
     G: Git,
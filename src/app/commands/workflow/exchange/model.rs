@@ -1,6 +1,12 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Serialize)]
+/// Version of the `inspect-exchange` JSON shape.
+///
+/// Adding fields keeps the version; removing, renaming, or retyping existing
+/// fields is a breaking change and must bump it.
+pub const INSPECT_SCHEMA_VERSION: u32 = 1;
+
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ExchangeInspectOutput {
     pub schema_version: u32,
     pub schedule: ScheduleSummary,
@@ -8,49 +14,49 @@ pub struct ExchangeInspectOutput {
     pub requirements: RequirementSummary,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ScheduleSummary {
     pub observers: ScheduleLayerSummary,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct ScheduleLayerSummary {
     pub roles: Vec<RoleSummary>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RoleSummary {
     pub name: String,
     pub enabled: bool,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct EventSummary {
     pub states: Vec<EventStateSummary>,
     pub pending_files: Vec<String>,
     pub items: Vec<EventItem>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct EventStateSummary {
     pub name: String,
     pub count: usize,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct EventItem {
     pub path: String,
     pub state: String,
     pub id: String,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RequirementSummary {
     pub count: usize,
     pub items: Vec<RequirementItem>,
 }
 
-#[derive(Debug, Serialize)]
+#[derive(Debug, Serialize, Deserialize)]
 pub struct RequirementItem {
     pub path: String,
     pub label: String,
@@ -58,3 +64,162 @@ pub struct RequirementItem {
     pub id: String,
     pub source_events: Vec<String>,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::Value;
+
+    /// A v1 document as emitted when the shape was introduced; it must keep parsing.
+    const V1_GOLDEN: &str = r#"{
+  "schema_version": 1,
+  "schedule": {
+    "observers": {
+      "roles": [
+        {
+          "name": "taxonomy",
+          "enabled": true
+        }
+      ]
+    }
+  },
+  "events": {
+    "states": [
+      {
+        "name": "pending",
+        "count": 1
+      }
+    ],
+    "pending_files": [
+      ".jules/exchange/events/pending/e1.yml"
+    ],
+    "items": [
+      {
+        "path": ".jules/exchange/events/pending/e1.yml",
+        "state": "pending",
+        "id": "e1"
+      }
+    ]
+  },
+  "requirements": {
+    "count": 1,
+    "items": [
+      {
+        "path": ".jules/exchange/requirements/r1.yml",
+        "label": "bugs",
+        "implementation_ready": true,
+        "id": "r1",
+        "source_events": [
+          "e1"
+        ]
+      }
+    ]
+  }
+}"#;
+
+    /// JSON type predicate such as `Value::is_string`.
+    type TypeCheck = fn(&Value) -> bool;
+
+    /// Assert that `value` has every `(field, type)` pair, ignoring extra fields.
+    fn assert_fields(value: &Value, fields: &[(&str, TypeCheck)]) {
+        for (field, has_type) in fields {
+            let actual = value.get(field).unwrap_or_else(|| panic!("missing v1 field '{field}'"));
+            assert!(has_type(actual), "v1 field '{field}' changed type: {actual}");
+        }
+    }
+
+    fn sample_output() -> ExchangeInspectOutput {
+        ExchangeInspectOutput {
+            schema_version: INSPECT_SCHEMA_VERSION,
+            schedule: ScheduleSummary {
+                observers: ScheduleLayerSummary {
+                    roles: vec![RoleSummary { name: "taxonomy".to_string(), enabled: true }],
+                },
+            },
+            events: EventSummary {
+                states: vec![EventStateSummary { name: "pending".to_string(), count: 1 }],
+                pending_files: vec![".jules/exchange/events/pending/e1.yml".to_string()],
+                items: vec![EventItem {
+                    path: ".jules/exchange/events/pending/e1.yml".to_string(),
+                    state: "pending".to_string(),
+                    id: "e1".to_string(),
+                }],
+            },
+            requirements: RequirementSummary {
+                count: 1,
+                items: vec![RequirementItem {
+                    path: ".jules/exchange/requirements/r1.yml".to_string(),
+                    label: "bugs".to_string(),
+                    implementation_ready: true,
+                    id: "r1".to_string(),
+                    source_events: vec!["e1".to_string()],
+                }],
+            },
+        }
+    }
+
+    /// Locks the core v1 fields and their types; additive fields pass. If this
+    /// fails, either restore the field or bump `INSPECT_SCHEMA_VERSION`.
+    #[test]
+    fn v1_serialization_keeps_core_fields() {
+        let json = serde_json::to_value(sample_output()).unwrap();
+
+        assert_eq!(INSPECT_SCHEMA_VERSION, 1);
+        assert_eq!(json["schema_version"], 1);
+        assert_fields(
+            &json,
+            &[
+                ("schema_version", Value::is_u64),
+                ("schedule", Value::is_object),
+                ("events", Value::is_object),
+                ("requirements", Value::is_object),
+            ],
+        );
+        assert_fields(&json["schedule"], &[("observers", Value::is_object)]);
+        assert_fields(&json["schedule"]["observers"], &[("roles", Value::is_array)]);
+        assert_fields(
+            &json["schedule"]["observers"]["roles"][0],
+            &[("name", Value::is_string), ("enabled", Value::is_boolean)],
+        );
+        assert_fields(
+            &json["events"],
+            &[
+                ("states", Value::is_array),
+                ("pending_files", Value::is_array),
+                ("items", Value::is_array),
+            ],
+        );
+        assert_fields(
+            &json["events"]["states"][0],
+            &[("name", Value::is_string), ("count", Value::is_u64)],
+        );
+        assert!(json["events"]["pending_files"][0].is_string());
+        assert_fields(
+            &json["events"]["items"][0],
+            &[("path", Value::is_string), ("state", Value::is_string), ("id", Value::is_string)],
+        );
+        assert_fields(
+            &json["requirements"],
+            &[("count", Value::is_u64), ("items", Value::is_array)],
+        );
+        assert_fields(
+            &json["requirements"]["items"][0],
+            &[
+                ("path", Value::is_string),
+                ("label", Value::is_string),
+                ("implementation_ready", Value::is_boolean),
+                ("id", Value::is_string),
+                ("source_events", Value::is_array),
+            ],
+        );
+    }
+
+    #[test]
+    fn v1_golden_still_parses() {
+        let parsed: ExchangeInspectOutput = serde_json::from_str(V1_GOLDEN).unwrap();
+        assert_eq!(parsed.schema_version, 1);
+        assert_eq!(parsed.events.items[0].id, "e1");
+        assert_eq!(parsed.requirements.items[0].source_events, ["e1"]);
+    }
+}
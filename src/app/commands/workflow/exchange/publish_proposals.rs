@@ -12,8 +12,13 @@ use crate::domain::exchange::proposals::Proposal;
 use crate::domain::{AppError, RoleId};
 use crate::ports::{GitHub, IssueInfo, JulesStore, RepositoryFilesystem};
 
-#[derive(Debug, Clone)]
-pub struct ExchangePublishProposalsOptions {}
+#[derive(Debug, Clone, Default)]
+pub struct ExchangePublishProposalsOptions {
+    /// Source attribution (e.g. the workstation or runner that authored the proposals).
+    pub author: Option<String>,
+    /// Also apply an `author/<name>` label to each created issue.
+    pub label_author: bool,
+}
 
 #[derive(Debug, Serialize)]
 pub struct ExchangePublishProposalsOutput {
@@ -29,6 +34,8 @@ pub struct PublishedProposal {
     pub proposal_path: String,
     pub issue_number: u64,
     pub issue_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
 }
 
 pub fn execute(
@@ -47,7 +54,7 @@ pub fn execute(
 /// Core logic, injectable for testing.
 fn execute_with<W, H>(
     repository: &W,
-    _options: &ExchangePublishProposalsOptions,
+    options: &ExchangePublishProposalsOptions,
     github: &H,
 ) -> Result<ExchangePublishProposalsOutput, AppError>
 where
@@ -57,6 +64,13 @@ where
     let jules_path = repository.jules_path();
     let proposals_dir = crate::domain::exchange::proposals::paths::proposals_dir(&jules_path);
 
+    let author = options.author.as_deref().map(str::trim).filter(|a| !a.is_empty());
+    if options.label_author && author.is_none() {
+        return Err(AppError::Validation(
+            "--label-author requires a non-empty --author".to_string(),
+        ));
+    }
+
     let proposals = discover_proposals(&proposals_dir, repository)?;
 
     if proposals.is_empty() {
@@ -122,7 +136,7 @@ where
         let verification_signals = render_list(&data.verification_signals);
 
         let issue_body = format!(
-            "## Problem\n\n{}\n\n## Introduction\n\n{}\n\n## Why It Matters\n\n{}\n\n## Impact Surface\n\n{}\n\n## Implementation Cost\n\n{}\n\n## Consistency Risks\n\n{}\n\n## Verification Signals\n\n{}\n\n---\n\n_Published from proposal `{}` by innovator role `{}`._",
+            "## Problem\n\n{}\n\n## Introduction\n\n{}\n\n## Why It Matters\n\n{}\n\n## Impact Surface\n\n{}\n\n## Implementation Cost\n\n{}\n\n## Consistency Risks\n\n{}\n\n## Verification Signals\n\n{}\n\n---\n\n{}",
             data.problem.trim(),
             data.introduction.trim(),
             data.importance.trim(),
@@ -130,8 +144,7 @@ where
             data.implementation_cost.trim(),
             consistency_risks,
             verification_signals,
-            data.id,
-            role.as_str(),
+            render_attribution(&data.id, role.as_str(), author),
         );
 
         validated.push((role.as_str().to_string(), proposal_path.clone(), issue_title, issue_body));
@@ -143,6 +156,12 @@ where
     for (role, proposal_path, issue_title, issue_body) in &validated {
         let issue: IssueInfo = github.create_issue(issue_title, issue_body, &[])?;
 
+        if let (true, Some(author)) = (options.label_author, author) {
+            let author_label = format!("author/{}", author);
+            github.ensure_label(&author_label, None)?;
+            github.add_label_to_issue(issue.number, &author_label)?;
+        }
+
         // Apply innovator labels to the newly created issue
         crate::app::commands::workflow::process::issue::label_innovator::execute(
             github,
@@ -157,6 +176,7 @@ where
             proposal_path: proposal_path.display().to_string(),
             issue_number: issue.number,
             issue_url: issue.url.clone(),
+            author: author.map(str::to_string),
         });
 
         // Remove proposal artifact
@@ -205,6 +225,19 @@ fn discover_proposals<W: RepositoryFilesystem + JulesStore>(
     Ok(proposals)
 }
 
+/// Footer attributing the issue to its innovator role and, when known, its source.
+fn render_attribution(proposal_id: &str, role: &str, author: Option<&str>) -> String {
+    match author {
+        Some(author) => format!(
+            "_Published from proposal `{}` by innovator role `{}` (source: `{}`)._",
+            proposal_id, role, author
+        ),
+        None => {
+            format!("_Published from proposal `{}` by innovator role `{}`._", proposal_id, role)
+        }
+    }
+}
+
 fn render_list(items: &[String]) -> String {
     items.iter().map(|line| format!("- {}", line.trim())).collect::<Vec<_>>().join("\n")
 }
@@ -288,7 +321,7 @@ verification_signals:
 
         let github = FakeGitHub::new();
 
-        let options = ExchangePublishProposalsOptions {};
+        let options = ExchangePublishProposalsOptions::default();
 
         let output = execute_with(&repository, &options, &github).unwrap();
 
@@ -309,6 +342,37 @@ verification_signals:
         assert!(issues[0].1.contains("## Why It Matters"));
         assert!(issues[0].1.contains("## Implementation Cost"));
         assert!(issues[0].1.contains("## Consistency Risks"));
+        assert!(issues[0].1.contains("by innovator role `alice`._"));
+    }
+
+    #[test]
+    fn attributes_author_in_issue_body_and_label() {
+        let proposal_path = ".jules/exchange/proposals/alice-improve-error-messages.yml";
+        let repository =
+            TestStore::new().with_exists(true).with_file(proposal_path, proposal_yaml());
+        let github = FakeGitHub::new();
+        let options = ExchangePublishProposalsOptions {
+            author: Some("ci-runner-1".to_string()),
+            label_author: true,
+        };
+
+        let output = execute_with(&repository, &options, &github).unwrap();
+
+        assert_eq!(output.published[0].author.as_deref(), Some("ci-runner-1"));
+        let issues = github.created_issues.lock().unwrap();
+        assert!(issues[0].1.contains("innovator role `alice` (source: `ci-runner-1`)"));
+        let labels = github.applied_labels.lock().unwrap();
+        assert!(labels.iter().any(|(_, label)| label == "author/ci-runner-1"));
+    }
+
+    #[test]
+    fn label_author_requires_author() {
+        let repository = TestStore::new().with_exists(true);
+        let github = FakeGitHub::new();
+        let options = ExchangePublishProposalsOptions { author: None, label_author: true };
+
+        let err = execute_with(&repository, &options, &github).unwrap_err();
+        assert!(err.to_string().contains("--label-author"));
     }
 
     #[test]
@@ -316,7 +380,7 @@ verification_signals:
         let repository = TestStore::new().with_exists(true);
         let github = FakeGitHub::new();
 
-        let options = ExchangePublishProposalsOptions {};
+        let options = ExchangePublishProposalsOptions::default();
 
         let output = execute_with(&repository, &options, &github).unwrap();
 
@@ -333,7 +397,7 @@ verification_signals:
             TestStore::new().with_exists(true).with_file(proposal_path, &invalid_role_yaml);
 
         let github = FakeGitHub::new();
-        let options = ExchangePublishProposalsOptions {};
+        let options = ExchangePublishProposalsOptions::default();
 
         let result = execute_with(&repository, &options, &github);
         assert!(result.is_err());
@@ -351,7 +415,7 @@ verification_signals:
             .with_exists(true)
             .with_file(proposal_path, &proposal_with_underscored_role);
         let github = FakeGitHub::new();
-        let options = ExchangePublishProposalsOptions {};
+        let options = ExchangePublishProposalsOptions::default();
 
         let output = execute_with(&repository, &options, &github).unwrap();
         assert_eq!(output.published.len(), 1);
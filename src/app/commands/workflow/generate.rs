@@ -4,6 +4,7 @@
 //! writes directly to the repository `.github/` directory, overwriting
 //! jlo-managed files. Use `-o, --output-dir` to redirect output elsewhere.
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -23,6 +24,10 @@ pub struct WorkflowGenerateOptions {
     pub mode: WorkflowRunnerMode,
     /// Output directory override. When absent, generates to repository root.
     pub output_dir: Option<PathBuf>,
+    /// Extra template variables merged into the scaffold rendering context.
+    pub template_vars: BTreeMap<String, serde_json::Value>,
+    /// Control-plane config read instead of `.jlo/config.toml`.
+    pub config: Option<PathBuf>,
 }
 
 /// Output of workflow generate command.
@@ -41,8 +46,10 @@ pub struct WorkflowGenerateOutput {
 /// Execute workflow generate command.
 pub fn execute(options: WorkflowGenerateOptions) -> Result<WorkflowGenerateOutput, AppError> {
     let repo_root = find_repo_root(&std::env::current_dir()?)?;
-    let repository = LocalRepositoryAdapter::new(repo_root.clone());
-    let generate_config = load_workflow_generate_config(&repository)?;
+    let repository =
+        LocalRepositoryAdapter::new(repo_root.clone()).with_config_path(options.config.clone());
+    let mut generate_config = load_workflow_generate_config(&repository)?;
+    generate_config.template_vars = options.template_vars.clone();
     let output_dir = resolve_output_dir(&options, &repo_root)?;
 
     prepare_output_dir(&output_dir)?;
@@ -92,6 +99,14 @@ fn find_repo_root(start: &Path) -> Result<PathBuf, AppError> {
     Err(AppError::RepositoryDetectionFailed)
 }
 
+/// Parse `--template-vars` JSON into a map; the top level must be an object.
+pub fn parse_template_vars(json: &str) -> Result<BTreeMap<String, serde_json::Value>, AppError> {
+    serde_json::from_str(json).map_err(|e| AppError::ParseError {
+        what: "--template-vars (expected a JSON object)".to_string(),
+        details: e.to_string(),
+    })
+}
+
 /// Prepare output directory. Always overwrites jlo-managed content by default.
 fn prepare_output_dir(output_dir: &Path) -> Result<(), AppError> {
     if output_dir.exists() && output_dir.is_file() {
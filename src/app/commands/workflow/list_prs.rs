@@ -0,0 +1,174 @@
+//! Workflow `list-prs` command implementation.
+//!
+//! Discovers open PRs on a base branch so wait steps can gate on the PRs a run
+//! produced. `--after` drops PRs created before the run started. A listing that
+//! reaches the GitHub page limit is reported as `truncated`.
+
+use chrono::{DateTime, FixedOffset};
+use serde::Serialize;
+
+use crate::domain::AppError;
+use crate::ports::{GitHub, OPEN_PULL_REQUESTS_LIMIT};
+
+/// Options for `workflow list-prs`.
+#[derive(Debug, Clone)]
+pub struct WorkflowListPrsOptions {
+    /// Base branch the PRs target.
+    pub base: String,
+    /// Only include PRs created at or after this RFC 3339 timestamp (e.g. `run_started_at`).
+    pub after: Option<String>,
+}
+
+/// Output of `workflow list-prs`.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowListPrsOutput {
+    pub schema_version: u32,
+    pub base: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    pub pull_requests: Vec<WorkflowListedPr>,
+    /// The listing hit the page limit, so older open PRs may be missing.
+    pub truncated: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowListedPr {
+    pub number: u64,
+    pub head: String,
+    pub created_at: String,
+}
+
+pub fn execute(
+    github: &impl GitHub,
+    options: WorkflowListPrsOptions,
+) -> Result<WorkflowListPrsOutput, AppError> {
+    let after = options
+        .after
+        .as_deref()
+        .map(|value| {
+            parse_timestamp(value).ok_or_else(|| {
+                AppError::Validation(format!(
+                    "Invalid --after '{}': expected an RFC 3339 timestamp (e.g. 2025-01-01T00:00:00Z)",
+                    value
+                ))
+            })
+        })
+        .transpose()?;
+
+    let open = github.list_open_pull_requests(&options.base)?;
+    let truncated = open.len() >= OPEN_PULL_REQUESTS_LIMIT;
+    if truncated {
+        eprintln!(
+            "Warning: listed the first {} open PRs on '{}'; older ones may be missing",
+            OPEN_PULL_REQUESTS_LIMIT, options.base
+        );
+    }
+
+    let mut pull_requests = Vec::new();
+    for pr in open {
+        if let Some(after) = after {
+            let created_at =
+                parse_timestamp(&pr.created_at).ok_or_else(|| AppError::ParseError {
+                    what: "PR createdAt".into(),
+                    details: format!("PR #{} has invalid createdAt '{}'", pr.number, pr.created_at),
+                })?;
+            if created_at < after {
+                continue;
+            }
+        }
+        pull_requests.push(WorkflowListedPr {
+            number: pr.number,
+            head: pr.head,
+            created_at: pr.created_at,
+        });
+    }
+    pull_requests.sort_by_key(|pr| pr.number);
+
+    Ok(WorkflowListPrsOutput {
+        schema_version: 1,
+        base: options.base,
+        after: options.after,
+        pull_requests,
+        truncated,
+    })
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(value).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ports::PullRequestSummary;
+    use crate::testing::FakeGitHub;
+
+    fn pr(number: u64, base: &str, created_at: &str) -> PullRequestSummary {
+        PullRequestSummary {
+            number,
+            head: format!("jules-{}", number),
+            base: base.to_string(),
+            created_at: created_at.to_string(),
+        }
+    }
+
+    fn github() -> FakeGitHub {
+        FakeGitHub::new().with_open_pull_requests(vec![
+            pr(7, "jules", "2025-01-01T09:59:59Z"),
+            pr(8, "jules", "2025-01-01T10:00:00Z"),
+            pr(9, "jules", "2025-01-01T10:05:00Z"),
+            pr(10, "main", "2025-01-01T10:05:00Z"),
+        ])
+    }
+
+    #[test]
+    fn after_excludes_prs_created_before_the_run() {
+        let output = execute(
+            &github(),
+            WorkflowListPrsOptions {
+                base: "jules".to_string(),
+                after: Some("2025-01-01T10:00:00Z".to_string()),
+            },
+        )
+        .unwrap();
+
+        let numbers: Vec<u64> = output.pull_requests.iter().map(|pr| pr.number).collect();
+        assert_eq!(numbers, vec![8, 9]);
+    }
+
+    #[test]
+    fn without_after_lists_every_open_pr_on_base() {
+        let output =
+            execute(&github(), WorkflowListPrsOptions { base: "jules".to_string(), after: None })
+                .unwrap();
+
+        assert_eq!(output.pull_requests.len(), 3);
+        assert!(!output.truncated);
+    }
+
+    #[test]
+    fn a_full_page_is_reported_as_truncated() {
+        let prs = (1..=OPEN_PULL_REQUESTS_LIMIT as u64)
+            .map(|number| pr(number, "jules", "2025-01-01T10:00:00Z"))
+            .collect();
+        let github = FakeGitHub::new().with_open_pull_requests(prs);
+
+        let output =
+            execute(&github, WorkflowListPrsOptions { base: "jules".to_string(), after: None })
+                .unwrap();
+
+        assert!(output.truncated);
+        assert_eq!(output.pull_requests.len(), OPEN_PULL_REQUESTS_LIMIT);
+    }
+
+    #[test]
+    fn invalid_after_is_rejected() {
+        let err = execute(
+            &github(),
+            WorkflowListPrsOptions { base: "jules".to_string(), after: Some("yesterday".into()) },
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("Invalid --after 'yesterday'"));
+    }
+}
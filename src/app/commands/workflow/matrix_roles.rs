@@ -0,0 +1,129 @@
+//! Workflow `matrix-roles` command implementation.
+//!
+//! Emits the enabled roles of a multi-role layer, read from `.jlo/config.toml`,
+//! as a GitHub Actions matrix so each role can run as its own job.
+
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use crate::app::config::load_schedule;
+use crate::domain::{AppError, Layer};
+use crate::ports::{JloStore, RepositoryFilesystem};
+
+/// Options for `workflow matrix-roles`.
+#[derive(Debug, Clone)]
+pub struct WorkflowMatrixRolesOptions {
+    /// Multi-role layer whose enabled roles form the matrix.
+    pub layer: Layer,
+    /// Control-plane config read instead of `.jlo/config.toml`.
+    pub config: Option<PathBuf>,
+}
+
+/// Output of `workflow matrix-roles`.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowMatrixRolesOutput {
+    pub schema_version: u32,
+    pub layer: Layer,
+    /// Value for `strategy.matrix` (`fromJSON`), one `include` entry per role.
+    pub matrix: RoleMatrix,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RoleMatrix {
+    pub include: Vec<RoleMatrixEntry>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RoleMatrixEntry {
+    pub role: String,
+}
+
+pub fn execute(
+    store: &(impl RepositoryFilesystem + JloStore),
+    options: WorkflowMatrixRolesOptions,
+) -> Result<WorkflowMatrixRolesOutput, AppError> {
+    if options.layer.is_single_role() {
+        return Err(AppError::Validation(format!(
+            "matrix-roles requires a multi-role layer (observers, innovators), not '{}'",
+            options.layer.dir_name()
+        )));
+    }
+
+    let schedule = load_schedule(store)?;
+    let roles = match options.layer {
+        Layer::Observers => schedule.observers.enabled_roles(),
+        _ => schedule.innovators.as_ref().map(|l| l.enabled_roles()).unwrap_or_default(),
+    };
+
+    Ok(WorkflowMatrixRolesOutput {
+        schema_version: 1,
+        layer: options.layer,
+        matrix: RoleMatrix {
+            include: roles
+                .into_iter()
+                .map(|role| RoleMatrixEntry { role: role.as_str().to_string() })
+                .collect(),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestStore;
+
+    fn store() -> TestStore {
+        TestStore::new().with_exists(true).with_file(
+            ".jlo/config.toml",
+            r#"
+[run]
+jlo_target_branch = "main"
+jules_worker_branch = "jules"
+
+[observers]
+roles = [
+  { name = "taxonomy", enabled = true },
+  { name = "qa", enabled = false },
+  { name = "cov", enabled = true },
+]
+
+[innovators]
+roles = [
+  { name = "recruiter", enabled = true },
+]
+"#,
+        )
+    }
+
+    fn matrix_roles(output: &WorkflowMatrixRolesOutput) -> Vec<&str> {
+        output.matrix.include.iter().map(|entry| entry.role.as_str()).collect()
+    }
+
+    #[test]
+    fn matrix_reflects_enabled_config_roles() {
+        let output =
+            execute(&store(), WorkflowMatrixRolesOptions { layer: Layer::Observers, config: None })
+                .unwrap();
+        assert_eq!(matrix_roles(&output), vec!["taxonomy", "cov"]);
+
+        let json = serde_json::to_value(&output).unwrap();
+        assert_eq!(json["layer"], "observers");
+        assert_eq!(json["matrix"]["include"][0]["role"], "taxonomy");
+
+        let output = execute(
+            &store(),
+            WorkflowMatrixRolesOptions { layer: Layer::Innovators, config: None },
+        )
+        .unwrap();
+        assert_eq!(matrix_roles(&output), vec!["recruiter"]);
+    }
+
+    #[test]
+    fn single_role_layer_is_rejected() {
+        let err =
+            execute(&store(), WorkflowMatrixRolesOptions { layer: Layer::Decider, config: None })
+                .unwrap_err();
+        assert!(err.to_string().contains("multi-role layer"));
+    }
+}
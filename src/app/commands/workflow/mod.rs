@@ -7,9 +7,12 @@ pub mod bootstrap;
 mod doctor;
 pub mod exchange;
 pub mod generate;
+mod list_prs;
+mod matrix_roles;
 mod output;
 pub mod process;
 pub mod push;
+mod remote;
 mod run;
 
 pub use bootstrap::{
@@ -18,9 +21,13 @@ pub use bootstrap::{
     WorkflowBootstrapWorkerBranchOptions, WorkflowBootstrapWorkerBranchOutput,
 };
 pub use doctor::{WorkflowDoctorOptions, WorkflowDoctorOutput};
-pub use generate::{WorkflowGenerateOptions, WorkflowGenerateOutput};
+pub use generate::{WorkflowGenerateOptions, WorkflowGenerateOutput, parse_template_vars};
+pub use list_prs::{WorkflowListPrsOptions, WorkflowListPrsOutput};
+pub use matrix_roles::{WorkflowMatrixRolesOptions, WorkflowMatrixRolesOutput};
 pub use output::write_workflow_output;
-pub use run::{WorkflowRunOptions, WorkflowRunOutput};
+pub use run::{
+    WorkflowRunOptions, WorkflowRunOutput, WorkflowRunPipelineOptions, WorkflowRunPipelineOutput,
+};
 
 use crate::domain::AppError;
 use crate::ports::JulesStore;
@@ -53,7 +60,8 @@ pub fn doctor(options: WorkflowDoctorOptions) -> Result<WorkflowDoctorOutput, Ap
 
 /// Execute workflow run command.
 pub fn run(options: WorkflowRunOptions) -> Result<WorkflowRunOutput, AppError> {
-    let store = crate::adapters::local_repository::LocalRepositoryAdapter::current()?;
+    let store = crate::adapters::local_repository::LocalRepositoryAdapter::current()?
+        .with_config_path(options.config.clone());
 
     let jules_path = store.jules_path();
     let git_root = jules_path.parent().unwrap_or(&jules_path).to_path_buf();
@@ -63,6 +71,34 @@ pub fn run(options: WorkflowRunOptions) -> Result<WorkflowRunOutput, AppError> {
     run::execute(&store, options, &git, &github)
 }
 
+/// Execute all pipeline layers sequentially (`workflow run all`).
+pub fn run_all(options: WorkflowRunPipelineOptions) -> Result<WorkflowRunPipelineOutput, AppError> {
+    let store = crate::adapters::local_repository::LocalRepositoryAdapter::current()?
+        .with_config_path(options.config.clone());
+
+    let jules_path = store.jules_path();
+    let git_root = jules_path.parent().unwrap_or(&jules_path).to_path_buf();
+    let git = crate::adapters::git::GitCommandAdapter::new(git_root);
+    let github = crate::adapters::github::GitHubCommandAdapter::new();
+
+    run::execute_all(&store, options, &git, &github)
+}
+
+/// Execute workflow list-prs discovery.
+pub fn list_prs(options: WorkflowListPrsOptions) -> Result<WorkflowListPrsOutput, AppError> {
+    let github = crate::adapters::github::GitHubCommandAdapter::new();
+    list_prs::execute(&github, options)
+}
+
+/// Execute workflow matrix-roles from the control-plane config.
+pub fn matrix_roles(
+    options: WorkflowMatrixRolesOptions,
+) -> Result<WorkflowMatrixRolesOutput, AppError> {
+    let store = crate::adapters::local_repository::LocalRepositoryAdapter::current()?
+        .with_config_path(options.config.clone());
+    matrix_roles::execute(&store, options)
+}
+
 /// Execute workflow generate command.
 pub fn generate(options: WorkflowGenerateOptions) -> Result<WorkflowGenerateOutput, AppError> {
     generate::execute(options)
@@ -4,10 +4,14 @@ use serde::Serialize;
 use crate::adapters::git::GitCommandAdapter;
 use crate::adapters::github::GitHubCommandAdapter;
 use crate::adapters::local_repository::LocalRepositoryAdapter;
+use crate::app::commands::workflow::remote::{ORIGIN, ensure_remote};
 use crate::domain::AppError;
+pub use crate::ports::MergeStrategy;
 use crate::ports::{Git, GitHub, JulesStore};
 
 const WORKER_PUSH_BRANCH_PREFIX: &str = "jules-worker-sync-";
+const BOT_IDENTITY: [(&str, &str); 2] =
+    [("user.name", "jlo[bot]"), ("user.email", "jlo[bot]@users.noreply.github.com")];
 
 #[derive(Debug, Clone)]
 pub struct PushWorkerBranchOptions {
@@ -15,6 +19,7 @@ pub struct PushWorkerBranchOptions {
     pub commit_message: String,
     pub pr_title: String,
     pub pr_body: String,
+    pub merge_strategy: MergeStrategy,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -65,10 +70,10 @@ pub(crate) fn execute_with_adapters(
         )));
     }
 
-    git.fetch("origin")?;
+    ensure_remote(git, ORIGIN)?;
+    git.fetch(ORIGIN)?;
     let has_local_commits = has_local_commits_ahead(git, &worker_branch)?;
-    let status = git.run_command(&["status", "--porcelain", "--", ".jules"], None)?;
-    let has_jules_changes = !status.trim().is_empty();
+    let has_jules_changes = !git.status(&[".jules"])?.is_empty();
     if !has_local_commits && !has_jules_changes {
         return Ok(PushWorkerBranchOutput {
             schema_version: 1,
@@ -101,12 +106,23 @@ pub(crate) fn execute_with_adapters(
             });
         }
         if !staged.trim().is_empty() {
-            git.run_command(&["commit", "-m", &options.commit_message], None)?;
+            let defaulted = ensure_commit_identity(git)?;
+            let committed = git.run_command(&["commit", "-m", &options.commit_message], None);
+            restore_commit_identity(git, &defaulted)?;
+            committed?;
         }
     }
 
     let head_sha = git.get_head_sha()?;
-    git.push_branch(&push_branch, false)?;
+    if let Err(err) = git.push_branch(&push_branch, false) {
+        return Err(recover_worker_branch(
+            git,
+            &worker_branch,
+            &push_branch,
+            has_local_commits,
+            err,
+        ));
+    }
 
     let pr = match github.create_pull_request(
         &push_branch,
@@ -117,18 +133,32 @@ pub(crate) fn execute_with_adapters(
         Ok(pr) => pr,
         Err(err) => {
             let cleanup_error = github.delete_branch(&push_branch).err();
-            return Err(with_cleanup_context(err, cleanup_error, None, &push_branch));
+            let err = with_cleanup_context(err, cleanup_error, None, &push_branch);
+            return Err(recover_worker_branch(
+                git,
+                &worker_branch,
+                &push_branch,
+                has_local_commits,
+                err,
+            ));
         }
     };
 
     // checks wait logic removed as requested checks
 
-    if let Err(err) = github.merge_pull_request(pr.number) {
+    if let Err(err) = github.merge_pull_request(pr.number, options.merge_strategy) {
         let cleanup_error = cleanup_pr_and_branch(github, pr.number, &push_branch).err();
-        return Err(with_cleanup_context(err, cleanup_error, Some(pr.number), &push_branch));
+        let err = with_cleanup_context(err, cleanup_error, Some(pr.number), &push_branch);
+        return Err(recover_worker_branch(
+            git,
+            &worker_branch,
+            &push_branch,
+            has_local_commits,
+            err,
+        ));
     }
 
-    sync_worker_branch_to_origin(git, &worker_branch)?;
+    sync_worker_branch_to_origin(git, &worker_branch, options.merge_strategy)?;
 
     Ok(PushWorkerBranchOutput {
         schema_version: 1,
@@ -141,6 +171,29 @@ pub(crate) fn execute_with_adapters(
     })
 }
 
+/// Set a bot commit identity for any of `user.name`/`user.email` unset in the local config.
+///
+/// Fresh CI runners often have no identity configured, which makes `git commit`
+/// fail with "empty ident". Returns the keys that were defaulted so the caller
+/// can remove them again once the commit is made.
+fn ensure_commit_identity(git: &impl Git) -> Result<Vec<&'static str>, AppError> {
+    let mut defaulted = Vec::new();
+    for (key, value) in BOT_IDENTITY {
+        if git.get_config(key)?.is_none() {
+            git.set_config(key, value)?;
+            defaulted.push(key);
+        }
+    }
+    Ok(defaulted)
+}
+
+fn restore_commit_identity(git: &impl Git, defaulted: &[&str]) -> Result<(), AppError> {
+    for key in defaulted {
+        git.unset_config(key)?;
+    }
+    Ok(())
+}
+
 fn has_local_commits_ahead(git: &impl Git, worker_branch: &str) -> Result<bool, AppError> {
     let remote_ref = format!("origin/{}", worker_branch);
     let range = format!("{}..HEAD", remote_ref);
@@ -182,12 +235,70 @@ fn with_cleanup_context(
     }
 }
 
-fn sync_worker_branch_to_origin(git: &impl Git, worker_branch: &str) -> Result<(), AppError> {
-    // Worker-branch PRs are squash-merged, so local history can legitimately diverge.
-    // Re-anchor the local worker branch to origin/<worker> explicitly.
+/// Restore the worker branch to `origin/<worker>` after a failure between commit and merge.
+///
+/// Only state created by this command is discarded: the reset is skipped when the
+/// worker branch carried commits ahead of origin before the push started, or when
+/// the current branch is not a jlo push branch. The `.jules` commit stays reachable
+/// from the local push branch for inspection. The reset is refused when the working
+/// tree has uncommitted changes, since only `.jules` was committed by this command.
+fn recover_worker_branch(
+    git: &impl Git,
+    worker_branch: &str,
+    push_branch: &str,
+    had_local_commits: bool,
+    cause: AppError,
+) -> AppError {
+    if had_local_commits || !push_branch.starts_with(WORKER_PUSH_BRANCH_PREFIX) {
+        return cause;
+    }
+    let remote_ref = format!("origin/{}", worker_branch);
+    let dirty = match git.status(&[]) {
+        Ok(entries) => entries.into_iter().map(|entry| entry.path).collect::<Vec<_>>(),
+        Err(status_error) => {
+            return AppError::InternalError(format!(
+                "worker-branch push failed: {} (recovery reset of '{}' skipped: could not read status: {})",
+                cause, worker_branch, status_error
+            ));
+        }
+    };
+    if !dirty.is_empty() {
+        return AppError::InternalError(format!(
+            "worker-branch push failed: {} (recovery reset of '{}' to '{}' skipped: uncommitted changes would be lost: {})",
+            cause,
+            worker_branch,
+            remote_ref,
+            dirty.join(", ")
+        ));
+    }
+    match git.reset_hard_to(worker_branch, &remote_ref) {
+        Ok(()) => cause,
+        Err(reset_error) => AppError::InternalError(format!(
+            "worker-branch push failed: {} (recovery reset of '{}' to '{}' failed: {})",
+            cause, worker_branch, remote_ref, reset_error
+        )),
+    }
+}
+
+fn sync_worker_branch_to_origin(
+    git: &impl Git,
+    worker_branch: &str,
+    strategy: MergeStrategy,
+) -> Result<(), AppError> {
     let remote_ref = format!("origin/{}", worker_branch);
     git.fetch("origin")?;
-    git.run_command(&["checkout", "-B", worker_branch, remote_ref.as_str()], None)?;
+    match strategy {
+        // A merge commit keeps the local worker history as an ancestor of origin.
+        MergeStrategy::Merge => {
+            git.run_command(&["checkout", worker_branch], None)?;
+            git.run_command(&["merge", "--ff-only", remote_ref.as_str()], None)?;
+        }
+        // Squash and rebase rewrite the pushed commits, so local history can
+        // legitimately diverge. Re-anchor the local worker branch explicitly.
+        MergeStrategy::Squash | MergeStrategy::Rebase => {
+            git.run_command(&["checkout", "-B", worker_branch, remote_ref.as_str()], None)?;
+        }
+    }
     Ok(())
 }
 
@@ -251,6 +362,7 @@ mod tests {
     use crate::ports::GitWorkspace;
     use crate::ports::{IssueInfo, PrComment, PullRequestDetail, PullRequestInfo};
     use serial_test::serial;
+    use std::collections::HashMap;
     use std::path::Path;
     use std::sync::{Arc, Mutex};
 
@@ -295,6 +407,12 @@ mod tests {
         ahead_count: String,
         commands: Arc<Mutex<Vec<Vec<String>>>>,
         deleted_branches: Arc<Mutex<Vec<String>>>,
+        hard_resets: Arc<Mutex<Vec<(String, String)>>>,
+        config: Arc<Mutex<HashMap<String, String>>>,
+        /// Config snapshots taken whenever `git commit` runs.
+        config_at_commit: Arc<Mutex<Vec<HashMap<String, String>>>>,
+        /// Set once `git commit` runs; committed `.jules` paths drop out of status.
+        jules_committed: Arc<Mutex<bool>>,
     }
 
     impl TestGit {
@@ -311,6 +429,10 @@ mod tests {
                 ahead_count: ahead_count.to_string(),
                 commands: Arc::new(Mutex::new(Vec::new())),
                 deleted_branches: Arc::new(Mutex::new(Vec::new())),
+                hard_resets: Arc::new(Mutex::new(Vec::new())),
+                config: Arc::new(Mutex::new(HashMap::new())),
+                config_at_commit: Arc::new(Mutex::new(Vec::new())),
+                jules_committed: Arc::new(Mutex::new(false)),
             }
         }
     }
@@ -324,6 +446,14 @@ mod tests {
             Ok(self.current_branch.lock().expect("branch lock poisoned").clone())
         }
 
+        fn get_default_branch(&self) -> Result<String, AppError> {
+            Ok("main".to_string())
+        }
+
+        fn blame_last_author(&self, _rev: &str, _path: &str) -> Result<String, AppError> {
+            Ok("Test Author".to_string())
+        }
+
         fn commit_exists(&self, _sha: &str) -> bool {
             true
         }
@@ -336,6 +466,22 @@ mod tests {
             Ok("root".to_string())
         }
 
+        fn rev_parse(&self, _rev: &str) -> Result<String, AppError> {
+            Ok(String::new())
+        }
+
+        fn commit_message(&self, _sha: &str) -> Result<String, AppError> {
+            Ok(String::new())
+        }
+
+        fn log_range(
+            &self,
+            _from: &str,
+            _to: &str,
+        ) -> Result<Vec<crate::ports::CommitInfo>, AppError> {
+            Ok(Vec::new())
+        }
+
         fn has_changes(
             &self,
             _from: &str,
@@ -345,14 +491,40 @@ mod tests {
             Ok(false)
         }
 
+        fn status(&self, pathspec: &[&str]) -> Result<Vec<crate::ports::StatusEntry>, AppError> {
+            self.commands
+                .lock()
+                .expect("commands lock poisoned")
+                .push(["status"].iter().chain(pathspec).map(|arg| arg.to_string()).collect());
+            let jules_committed = *self.jules_committed.lock().expect("commit lock poisoned");
+            Ok(self
+                .status_output
+                .lines()
+                .filter_map(|line| {
+                    let mut chars = line.chars();
+                    Some(crate::ports::StatusEntry {
+                        index_state: chars.next()?,
+                        worktree_state: chars.next()?,
+                        path: line.get(3..)?.to_string(),
+                    })
+                })
+                .filter(|entry| {
+                    pathspec.is_empty() || pathspec.iter().any(|spec| entry.path.starts_with(spec))
+                })
+                .filter(|entry| !(jules_committed && entry.path.starts_with(".jules")))
+                .collect())
+        }
+
         fn run_command(&self, args: &[&str], _cwd: Option<&Path>) -> Result<String, AppError> {
             self.commands
                 .lock()
                 .expect("commands lock poisoned")
                 .push(args.iter().map(|arg| arg.to_string()).collect());
 
-            if args == ["status", "--porcelain", "--", ".jules"] {
-                return Ok(self.status_output.clone());
+            if args.first().copied() == Some("commit") {
+                let config = self.config.lock().expect("config lock poisoned").clone();
+                self.config_at_commit.lock().expect("config lock poisoned").push(config);
+                *self.jules_committed.lock().expect("commit lock poisoned") = true;
             }
             if args == ["diff", "--cached", "--name-only"] {
                 return Ok(self.staged_output.clone());
@@ -390,6 +562,52 @@ mod tests {
             Ok(())
         }
 
+        fn fetch_prune(&self, _remote: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn remote_exists(&self, _name: &str) -> Result<bool, AppError> {
+            Ok(true)
+        }
+
+        fn get_config(&self, key: &str) -> Result<Option<String>, AppError> {
+            Ok(self.config.lock().expect("config lock poisoned").get(key).cloned())
+        }
+
+        fn set_config(&self, key: &str, value: &str) -> Result<(), AppError> {
+            self.config
+                .lock()
+                .expect("config lock poisoned")
+                .insert(key.to_string(), value.to_string());
+            Ok(())
+        }
+
+        fn unset_config(&self, key: &str) -> Result<(), AppError> {
+            self.config.lock().expect("config lock poisoned").remove(key);
+            Ok(())
+        }
+
+        fn abort_merge(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn cherry_pick(&self, _rev: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn cherry_pick_abort(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn reset_hard_to(&self, branch: &str, rev: &str) -> Result<(), AppError> {
+            self.hard_resets
+                .lock()
+                .expect("hard resets lock poisoned")
+                .push((branch.to_string(), rev.to_string()));
+            *self.current_branch.lock().expect("branch lock poisoned") = branch.to_string();
+            Ok(())
+        }
+
         fn delete_branch(&self, branch: &str, _force: bool) -> Result<bool, AppError> {
             self.deleted_branches
                 .lock()
@@ -398,6 +616,15 @@ mod tests {
             Ok(true)
         }
 
+        fn create_tag(
+            &self,
+            _name: &str,
+            _message: Option<&str>,
+            _rev: Option<&str>,
+        ) -> Result<(), AppError> {
+            Ok(())
+        }
+
         fn create_workspace(&self, _branch: &str) -> Result<Box<dyn GitWorkspace>, AppError> {
             unimplemented!()
         }
@@ -409,7 +636,7 @@ mod tests {
         should_fail_merge: bool,
         created_head: Arc<Mutex<Option<String>>>,
         closed_prs: Arc<Mutex<Vec<u64>>>,
-        merged_prs: Arc<Mutex<Vec<u64>>>,
+        merged_prs: Arc<Mutex<Vec<(u64, MergeStrategy)>>>,
         deleted_remote_branches: Arc<Mutex<Vec<String>>>,
     }
 
@@ -510,18 +737,29 @@ mod tests {
             Ok(())
         }
 
+        fn list_open_pull_requests(
+            &self,
+            _base: &str,
+        ) -> Result<Vec<crate::ports::PullRequestSummary>, AppError> {
+            Ok(Vec::new())
+        }
+
         fn list_pr_files(&self, _pr_number: u64) -> Result<Vec<String>, AppError> {
             Ok(Vec::new())
         }
 
-        fn merge_pull_request(&self, pr_number: u64) -> Result<(), AppError> {
+        fn merge_pull_request(
+            &self,
+            pr_number: u64,
+            strategy: MergeStrategy,
+        ) -> Result<(), AppError> {
             if self.should_fail_merge {
                 return Err(AppError::ExternalToolError {
                     tool: "github".to_string(),
                     error: "merge failed".to_string(),
                 });
             }
-            self.merged_prs.lock().expect("merged prs lock poisoned").push(pr_number);
+            self.merged_prs.lock().expect("merged prs lock poisoned").push((pr_number, strategy));
             Ok(())
         }
     }
@@ -532,6 +770,7 @@ mod tests {
             commit_message: "jules: cleanup".to_string(),
             pr_title: "chore: cleanup".to_string(),
             pr_body: "cleanup details".to_string(),
+            merge_strategy: MergeStrategy::Squash,
         }
     }
 
@@ -570,6 +809,31 @@ mod tests {
             commands.iter().any(|cmd| cmd == &vec!["checkout", "-B", "jules", "origin/jules"]),
             "worker branch should be reset to origin after merge"
         );
+        let merged = github.merged_prs.lock().expect("merged prs lock poisoned");
+        assert_eq!(*merged, vec![(77, MergeStrategy::Squash)]);
+    }
+
+    #[test]
+    #[serial]
+    fn execute_with_adapters_fast_forwards_worker_branch_after_true_merge() {
+        let _worker_branch = EnvVarGuard::set("JULES_WORKER_BRANCH", "jules");
+        let git = TestGit::new(
+            "jules",
+            " M .jules/schemas/observers/event.yml",
+            ".jules/schemas/observers/event.yml\n",
+            "0",
+        );
+        let github = TestGitHub::new(false, false);
+        let options = PushWorkerBranchOptions { merge_strategy: MergeStrategy::Merge, ..options() };
+
+        execute_with_adapters(&git, &github, options).expect("push should succeed");
+
+        let merged = github.merged_prs.lock().expect("merged prs lock poisoned");
+        assert_eq!(*merged, vec![(77, MergeStrategy::Merge)]);
+        let commands = git.commands.lock().expect("commands lock poisoned");
+        assert!(commands.iter().any(|cmd| cmd == &vec!["merge", "--ff-only", "origin/jules"]));
+        assert!(!commands.iter().any(|cmd| cmd.first().map(String::as_str) == Some("checkout")
+            && cmd.get(1).map(String::as_str) == Some("-B")));
     }
 
     #[test]
@@ -615,6 +879,62 @@ mod tests {
         assert!(deleted_remote[0].starts_with(WORKER_PUSH_BRANCH_PREFIX));
     }
 
+    #[test]
+    #[serial]
+    fn execute_with_adapters_resets_worker_branch_to_origin_when_merge_fails() {
+        let _worker_branch = EnvVarGuard::set("JULES_WORKER_BRANCH", "jules");
+        let git = TestGit::new(
+            "jules",
+            " M .jules/schemas/observers/event.yml",
+            ".jules/schemas/observers/event.yml\n",
+            "0",
+        );
+        let github = TestGitHub::new(false, true); // fail merge
+
+        execute_with_adapters(&git, &github, options())
+            .expect_err("merge failure should return error");
+
+        let resets = git.hard_resets.lock().expect("hard resets lock poisoned");
+        assert_eq!(*resets, vec![("jules".to_string(), "origin/jules".to_string())]);
+        assert_eq!(*git.current_branch.lock().expect("branch lock poisoned"), "jules");
+    }
+
+    #[test]
+    #[serial]
+    fn execute_with_adapters_refuses_reset_when_files_outside_jules_are_dirty() {
+        let _worker_branch = EnvVarGuard::set("JULES_WORKER_BRANCH", "jules");
+        let git = TestGit::new(
+            "jules",
+            " M .jules/schemas/observers/event.yml\n M src/main.rs",
+            ".jules/schemas/observers/event.yml\n",
+            "0",
+        );
+        let github = TestGitHub::new(false, true); // fail merge
+
+        let err = execute_with_adapters(&git, &github, options())
+            .expect_err("merge failure should return error");
+
+        let resets = git.hard_resets.lock().expect("hard resets lock poisoned");
+        assert!(resets.is_empty(), "dirty worktree must not be reset away");
+        let message = err.to_string();
+        assert!(message.contains("uncommitted changes would be lost"), "{}", message);
+        assert!(message.contains("src/main.rs"), "{}", message);
+    }
+
+    #[test]
+    #[serial]
+    fn execute_with_adapters_keeps_preexisting_local_commits_when_pr_creation_fails() {
+        let _worker_branch = EnvVarGuard::set("JULES_WORKER_BRANCH", "jules");
+        let git = TestGit::new("jules", "", "", "2");
+        let github = TestGitHub::new(true, false); // fail PR creation
+
+        execute_with_adapters(&git, &github, options())
+            .expect_err("PR creation failure should return error");
+
+        let resets = git.hard_resets.lock().expect("hard resets lock poisoned");
+        assert!(resets.is_empty(), "non-jlo local commits must not be reset away");
+    }
+
     #[test]
     #[serial]
     fn execute_with_adapters_pushes_existing_local_commits_without_new_jules_commit() {
@@ -651,4 +971,60 @@ mod tests {
             Some("No local commits or .jules changes to push")
         );
     }
+
+    #[test]
+    #[serial]
+    fn execute_with_adapters_defaults_bot_identity_for_commit_and_reverts_it() {
+        let _worker_branch = EnvVarGuard::set("JULES_WORKER_BRANCH", "jules");
+        let git = TestGit::new(
+            "jules",
+            " M .jules/schemas/observers/event.yml",
+            ".jules/schemas/observers/event.yml\n",
+            "0",
+        );
+        let github = TestGitHub::new(false, false);
+
+        execute_with_adapters(&git, &github, options()).expect("push should succeed");
+
+        let at_commit = git.config_at_commit.lock().expect("config lock poisoned");
+        assert_eq!(at_commit.len(), 1);
+        assert_eq!(at_commit[0].get("user.name").map(String::as_str), Some("jlo[bot]"));
+        assert_eq!(
+            at_commit[0].get("user.email").map(String::as_str),
+            Some("jlo[bot]@users.noreply.github.com")
+        );
+        assert!(
+            git.config.lock().expect("config lock poisoned").is_empty(),
+            "defaulted identity should be removed"
+        );
+    }
+
+    #[test]
+    #[serial]
+    fn execute_with_adapters_keeps_configured_identity() {
+        let _worker_branch = EnvVarGuard::set("JULES_WORKER_BRANCH", "jules");
+        let git = TestGit::new(
+            "jules",
+            " M .jules/schemas/observers/event.yml",
+            ".jules/schemas/observers/event.yml\n",
+            "0",
+        );
+        git.config
+            .lock()
+            .expect("config lock poisoned")
+            .insert("user.name".to_string(), "Maintainer".to_string());
+        let github = TestGitHub::new(false, false);
+
+        execute_with_adapters(&git, &github, options()).expect("push should succeed");
+
+        let at_commit = git.config_at_commit.lock().expect("config lock poisoned");
+        assert_eq!(at_commit[0].get("user.name").map(String::as_str), Some("Maintainer"));
+        assert_eq!(
+            at_commit[0].get("user.email").map(String::as_str),
+            Some("jlo[bot]@users.noreply.github.com")
+        );
+        let config = git.config.lock().expect("config lock poisoned");
+        assert_eq!(config.get("user.name").map(String::as_str), Some("Maintainer"));
+        assert!(!config.contains_key("user.email"));
+    }
 }
@@ -0,0 +1,18 @@
+//! Remote preflight checks for workflow commands that touch the network.
+
+use crate::domain::AppError;
+use crate::ports::Git;
+
+/// Remote used by workflow fetch/push operations.
+pub(crate) const ORIGIN: &str = "origin";
+
+/// Fail early with an actionable message when `name` is not a configured remote.
+pub(crate) fn ensure_remote(git: &(impl Git + ?Sized), name: &str) -> Result<(), AppError> {
+    if git.remote_exists(name)? {
+        return Ok(());
+    }
+    Err(AppError::Validation(format!(
+        "Remote '{}' not configured. Add it with 'git remote add {} <url>' before running this command.",
+        name, name
+    )))
+}
@@ -1,9 +1,9 @@
-use crate::app::commands::run::{RunOptions, RunRuntimeOptions};
+use crate::app::commands::run::{ReportStream, RunOptions, RunRuntimeOptions};
 use crate::app::commands::workflow::exchange::{
     ExchangeCleanRequirementOptions, clean_requirement_apply_with_adapters,
 };
 use crate::app::commands::workflow::push::{
-    PushWorkerBranchOptions, execute as push_worker_branch,
+    MergeStrategy, PushWorkerBranchOptions, execute as push_worker_branch,
 };
 use crate::app::commands::workflow::run::options::{RunResults, WorkflowRunOptions};
 use crate::app::commands::workflow::run::requirements_routing::find_requirements;
@@ -51,12 +51,27 @@ where
             role: None,
             requirement: Some(requirement_path.clone()),
             task: options.task.clone(),
+            role_file: None,
         };
         let runtime = RunRuntimeOptions {
             prompt_preview: false,
             branch: options.branch.clone(),
             mock: options.mock,
             no_cleanup: true,
+            print_prompts: options.print_prompts,
+            dedupe_events: false,
+            allow_truncate: false,
+            mock_seed: options.mock_seed,
+            no_push: false,
+            mock_events: None,
+            record: None,
+            attribute_authors: false,
+            max_prs: None,
+            tag: None,
+            requirement_input: None,
+            breaker: None,
+            report_stream: ReportStream::Stderr,
+            config: None,
         };
 
         eprintln!("Executing: implementer {}{}", requirement_path.display(), mock_suffix);
@@ -104,6 +119,7 @@ where
             pr_title: "chore: clean implementer requirements".to_string(),
             pr_body: "Automated cleanup for processed implementer requirements and source events."
                 .to_string(),
+            merge_strategy: MergeStrategy::Squash,
         })?;
 
         if out.applied {
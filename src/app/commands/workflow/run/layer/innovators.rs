@@ -1,4 +1,4 @@
-use crate::app::commands::run::{RunOptions, RunRuntimeOptions};
+use crate::app::commands::run::{ReportStream, RunOptions, RunRuntimeOptions};
 use crate::app::commands::workflow::run::input::load_schedule;
 use crate::domain::PromptAssetLoader;
 use crate::domain::{AppError, Layer};
@@ -31,7 +31,11 @@ where
     let mock_suffix = if options.mock { " (mock)" } else { "" };
     let schedule = load_schedule(store)?;
 
-    let roles = schedule.innovators.as_ref().map(|l| l.enabled_roles()).unwrap_or_default();
+    let roles = schedule
+        .innovators
+        .as_ref()
+        .map(|l| l.enabled_roles_excluding(&options.exclude))
+        .unwrap_or_default();
     if roles.is_empty() {
         eprintln!("No enabled innovators roles");
         return Ok(RunResults::skipped("No enabled innovators roles"));
@@ -44,12 +48,27 @@ where
             role: Some(role.as_str().to_string()),
             requirement: None,
             task: options.task.clone(),
+            role_file: None,
         };
         let runtime = RunRuntimeOptions {
             prompt_preview: false,
             branch: options.branch.clone(),
             mock: options.mock,
             no_cleanup: false,
+            print_prompts: options.print_prompts,
+            dedupe_events: false,
+            allow_truncate: false,
+            mock_seed: options.mock_seed,
+            no_push: false,
+            mock_events: None,
+            record: None,
+            attribute_authors: false,
+            max_prs: None,
+            tag: None,
+            requirement_input: None,
+            breaker: None,
+            report_stream: ReportStream::Stderr,
+            config: None,
         };
 
         eprintln!("Executing: innovators --role {}{}", role, mock_suffix);
@@ -1,10 +1,14 @@
+use crate::adapters::jules_client::CircuitBreaker;
 use crate::app::commands::run::{self, RunOptions, RunRuntimeOptions};
 use crate::domain::PromptAssetLoader;
 use crate::domain::{AppError, Layer};
 use crate::ports::{Git, GitHub, JloStore, JulesStore, RepositoryFilesystem};
 use std::path::Path;
+use std::sync::Arc;
 
-use super::options::{RunResults, WorkflowRunOptions};
+use super::options::{
+    PipelineStep, PipelineStepStatus, RunResults, WorkflowRunOptions, WorkflowRunPipelineOptions,
+};
 
 mod decider;
 mod implementer;
@@ -33,18 +37,135 @@ where
     G: Git,
     H: GitHub,
 {
-    let mut run_layer = |path: &Path,
-                         run_options: RunOptions,
-                         runtime: RunRuntimeOptions,
-                         git_ref: &G,
-                         github_ref: &H,
-                         store_ref: &W| {
-        run::execute(path, run_options, runtime, git_ref, github_ref, store_ref).map(|_| ())
-    };
+    let mut run_layer = with_shared_breaker(
+        |path: &Path,
+         run_options: RunOptions,
+         runtime: RunRuntimeOptions,
+         git_ref: &G,
+         github_ref: &H,
+         store_ref: &W| {
+            run::execute(path, run_options, runtime, git_ref, github_ref, store_ref).map(|_| ())
+        },
+    );
 
     execute_layer_with_runner(store, options, git, github, &mut run_layer)
 }
 
+/// Execute every pipeline layer in order.
+pub(crate) fn execute_pipeline<W, G, H>(
+    store: &W,
+    options: &WorkflowRunPipelineOptions,
+    git: &G,
+    github: &H,
+) -> Vec<PipelineStep>
+where
+    W: RepositoryFilesystem
+        + JloStore
+        + JulesStore
+        + PromptAssetLoader
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+    G: Git,
+    H: GitHub,
+{
+    let mut run_layer = with_shared_breaker(
+        |path: &Path,
+         run_options: RunOptions,
+         runtime: RunRuntimeOptions,
+         git_ref: &G,
+         github_ref: &H,
+         store_ref: &W| {
+            run::execute(path, run_options, runtime, git_ref, github_ref, store_ref).map(|_| ())
+        },
+    );
+
+    execute_pipeline_with_runner(store, options, git, github, &mut run_layer)
+}
+
+/// Hand every run of one workflow invocation the same circuit breaker, so sustained
+/// Jules failures trip it across roles and layers rather than per session.
+fn with_shared_breaker<W, G, H, F>(
+    mut run_layer: F,
+) -> impl FnMut(&Path, RunOptions, RunRuntimeOptions, &G, &H, &W) -> Result<(), AppError>
+where
+    F: FnMut(&Path, RunOptions, RunRuntimeOptions, &G, &H, &W) -> Result<(), AppError>,
+{
+    let breaker = Arc::new(CircuitBreaker::default());
+    move |path, run_options, mut runtime, git, github, store| {
+        runtime.breaker = Some(Arc::clone(&breaker));
+        run_layer(path, run_options, runtime, git, github, store)
+    }
+}
+
+fn execute_pipeline_with_runner<W, G, H, F>(
+    store: &W,
+    options: &WorkflowRunPipelineOptions,
+    git: &G,
+    github: &H,
+    run_layer: &mut F,
+) -> Vec<PipelineStep>
+where
+    W: RepositoryFilesystem
+        + JloStore
+        + JulesStore
+        + PromptAssetLoader
+        + Clone
+        + Send
+        + Sync
+        + 'static,
+    G: Git,
+    H: GitHub,
+    F: FnMut(&Path, RunOptions, RunRuntimeOptions, &G, &H, &W) -> Result<(), AppError>,
+{
+    let mut steps = Vec::with_capacity(Layer::PIPELINE.len());
+
+    for layer in Layer::PIPELINE {
+        if options.mock && layer == Layer::Integrator {
+            eprintln!("Skipping integrator: mock mode is not supported");
+            steps.push(PipelineStep {
+                layer,
+                status: PipelineStepStatus::Skipped,
+                number_of_api_requests_succeeded: 0,
+                skip_reason: Some("Integrator does not support mock mode".to_string()),
+                error: None,
+            });
+            continue;
+        }
+
+        let layer_options = options.for_layer(layer);
+        match execute_layer_with_runner(store, &layer_options, git, github, run_layer) {
+            Ok(results) => steps.push(PipelineStep {
+                layer,
+                status: if results.skip_reason.is_some() {
+                    PipelineStepStatus::Skipped
+                } else {
+                    PipelineStepStatus::Succeeded
+                },
+                number_of_api_requests_succeeded: results.number_of_api_requests_succeeded,
+                skip_reason: results.skip_reason,
+                error: None,
+            }),
+            Err(err) => {
+                eprintln!("Layer {} failed: {}", layer.dir_name(), err);
+                steps.push(PipelineStep {
+                    layer,
+                    status: PipelineStepStatus::Failed,
+                    number_of_api_requests_succeeded: 0,
+                    skip_reason: None,
+                    error: Some(err.to_string()),
+                });
+                if !options.continue_on_error {
+                    break;
+                }
+            }
+        }
+    }
+
+    steps
+}
+
 fn execute_layer_with_runner<W, G, H, F>(
     store: &W,
     options: &WorkflowRunOptions,
@@ -88,7 +209,9 @@ where
 mod tests {
     use super::*;
     use crate::ports::GitWorkspace;
-    use crate::ports::{GitHub, IssueInfo, PrComment, PullRequestDetail, PullRequestInfo};
+    use crate::ports::{
+        GitHub, IssueInfo, MergeStrategy, PrComment, PullRequestDetail, PullRequestInfo,
+    };
     use crate::testing::TestStore;
 
     struct NoopGit;
@@ -102,6 +225,14 @@ mod tests {
             Ok("jules".to_string())
         }
 
+        fn get_default_branch(&self) -> Result<String, AppError> {
+            Ok("main".to_string())
+        }
+
+        fn blame_last_author(&self, _rev: &str, _path: &str) -> Result<String, AppError> {
+            Ok("Test Author".to_string())
+        }
+
         fn commit_exists(&self, _sha: &str) -> bool {
             true
         }
@@ -114,6 +245,22 @@ mod tests {
             Ok("deadbeef".to_string())
         }
 
+        fn rev_parse(&self, _rev: &str) -> Result<String, AppError> {
+            Ok(String::new())
+        }
+
+        fn commit_message(&self, _sha: &str) -> Result<String, AppError> {
+            Ok(String::new())
+        }
+
+        fn log_range(
+            &self,
+            _from: &str,
+            _to: &str,
+        ) -> Result<Vec<crate::ports::CommitInfo>, AppError> {
+            Ok(Vec::new())
+        }
+
         fn has_changes(
             &self,
             _from: &str,
@@ -123,6 +270,10 @@ mod tests {
             Ok(false)
         }
 
+        fn status(&self, _pathspec: &[&str]) -> Result<Vec<crate::ports::StatusEntry>, AppError> {
+            Ok(Vec::new())
+        }
+
         fn run_command(&self, _args: &[&str], _cwd: Option<&Path>) -> Result<String, AppError> {
             Ok(String::new())
         }
@@ -152,10 +303,55 @@ mod tests {
             Ok(())
         }
 
+        fn fetch_prune(&self, _remote: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn remote_exists(&self, _name: &str) -> Result<bool, AppError> {
+            Ok(true)
+        }
+
+        fn get_config(&self, _key: &str) -> Result<Option<String>, AppError> {
+            Ok(None)
+        }
+
+        fn set_config(&self, _key: &str, _value: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn unset_config(&self, _key: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn abort_merge(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn cherry_pick(&self, _rev: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn cherry_pick_abort(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+
+        fn reset_hard_to(&self, _branch: &str, _rev: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+
         fn delete_branch(&self, _branch: &str, _force: bool) -> Result<bool, AppError> {
             Ok(true)
         }
 
+        fn create_tag(
+            &self,
+            _name: &str,
+            _message: Option<&str>,
+            _rev: Option<&str>,
+        ) -> Result<(), AppError> {
+            Ok(())
+        }
+
         fn create_workspace(&self, _branch: &str) -> Result<Box<dyn GitWorkspace>, AppError> {
             unimplemented!()
         }
@@ -234,11 +430,22 @@ mod tests {
             Ok(())
         }
 
+        fn list_open_pull_requests(
+            &self,
+            _base: &str,
+        ) -> Result<Vec<crate::ports::PullRequestSummary>, AppError> {
+            Ok(Vec::new())
+        }
+
         fn list_pr_files(&self, _pr_number: u64) -> Result<Vec<String>, AppError> {
             Ok(vec![])
         }
 
-        fn merge_pull_request(&self, _pr_number: u64) -> Result<(), AppError> {
+        fn merge_pull_request(
+            &self,
+            _pr_number: u64,
+            _strategy: MergeStrategy,
+        ) -> Result<(), AppError> {
             Ok(())
         }
     }
@@ -267,6 +474,16 @@ roles = [
             branch: None,
             mock_tag: Some("mock-test-001".to_string()),
             task: None,
+            print_prompts: false,
+            mock_seed: None,
+            label: None,
+            exclude: Vec::new(),
+            emit_events: None,
+            allow_tag_reuse: false,
+            explain_routing: false,
+            dedupe_events: false,
+            continue_on_error: false,
+            config: None,
         };
         let git = NoopGit;
         let github = NoopGitHub;
@@ -293,4 +510,425 @@ roles = [
             vec!["taxonomy".to_string(), "consistency".to_string(), "cov".to_string()]
         );
     }
+
+    #[test]
+    fn execute_layer_observers_skips_excluded_roles() {
+        let store = TestStore::new().with_exists(true).with_file(
+            ".jlo/config.toml",
+            r#"
+[run]
+jlo_target_branch = "main"
+jules_worker_branch = "jules"
+
+[observers]
+roles = [
+  { name = "taxonomy", enabled = true },
+  { name = "archived-foo", enabled = true },
+  { name = "cov", enabled = true },
+]
+"#,
+        );
+        let options = WorkflowRunOptions {
+            layer: Layer::Observers,
+            mock: true,
+            branch: None,
+            mock_tag: Some("mock-test-001".to_string()),
+            task: None,
+            print_prompts: false,
+            mock_seed: None,
+            label: None,
+            exclude: vec!["archived-*".to_string()],
+            emit_events: None,
+            allow_tag_reuse: false,
+            explain_routing: false,
+            dedupe_events: false,
+            continue_on_error: false,
+            config: None,
+        };
+
+        let mut executed_roles: Vec<String> = Vec::new();
+        let mut run_layer = |_path: &Path,
+                             run_options: RunOptions,
+                             _runtime: RunRuntimeOptions,
+                             _git: &NoopGit,
+                             _gh: &NoopGitHub,
+                             _store: &TestStore| {
+            executed_roles.push(run_options.role.expect("role should be present"));
+            Ok(())
+        };
+
+        let out =
+            execute_layer_with_runner(&store, &options, &NoopGit, &NoopGitHub, &mut run_layer)
+                .unwrap();
+
+        assert_eq!(out.number_of_api_requests_succeeded, 2);
+        assert_eq!(executed_roles, vec!["taxonomy".to_string(), "cov".to_string()]);
+    }
+
+    #[test]
+    fn execute_layer_observers_mock_run_stops_at_first_failure_unless_continuing() {
+        let store = TestStore::new().with_exists(true).with_file(
+            ".jlo/config.toml",
+            r#"
+[run]
+jlo_target_branch = "main"
+jules_worker_branch = "jules"
+
+[observers]
+roles = [
+  { name = "taxonomy", enabled = true },
+  { name = "qa", enabled = true },
+  { name = "cov", enabled = true },
+]
+"#,
+        );
+        let mut options = WorkflowRunOptions {
+            layer: Layer::Observers,
+            mock: true,
+            branch: None,
+            mock_tag: Some("mock-test-001".to_string()),
+            task: None,
+            print_prompts: false,
+            mock_seed: None,
+            label: None,
+            exclude: Vec::new(),
+            emit_events: None,
+            allow_tag_reuse: false,
+            explain_routing: false,
+            dedupe_events: false,
+            continue_on_error: false,
+            config: None,
+        };
+
+        let mut completed_roles: Vec<String> = Vec::new();
+        let mut run_layer = |_path: &Path,
+                             run_options: RunOptions,
+                             _runtime: RunRuntimeOptions,
+                             _git: &NoopGit,
+                             _gh: &NoopGitHub,
+                             _store: &TestStore| {
+            let role = run_options.role.expect("role should be present");
+            if role == "qa" {
+                return Err(AppError::JulesApiError {
+                    message: "simulated mock failure for 'qa'".to_string(),
+                    status: Some(503),
+                });
+            }
+            completed_roles.push(role);
+            Ok(())
+        };
+
+        let err =
+            execute_layer_with_runner(&store, &options, &NoopGit, &NoopGitHub, &mut run_layer)
+                .unwrap_err();
+        assert!(err.to_string().contains("simulated mock failure for 'qa'"), "{}", err);
+        assert!(!err.to_string().contains("observer role(s) failed"), "{}", err);
+
+        options.continue_on_error = true;
+        let err =
+            execute_layer_with_runner(&store, &options, &NoopGit, &NoopGitHub, &mut run_layer)
+                .unwrap_err();
+        assert!(err.to_string().contains("1 of 3 observer role(s) failed"), "{}", err);
+        assert!(err.to_string().contains("simulated mock failure for 'qa'"), "{}", err);
+
+        assert_eq!(completed_roles, vec!["taxonomy", "taxonomy", "cov"]);
+    }
+
+    #[test]
+    fn execute_layer_observers_real_run_stops_at_first_failure_unless_continuing() {
+        let store = TestStore::new().with_exists(true).with_file(
+            ".jlo/config.toml",
+            r#"
+[run]
+jlo_target_branch = "main"
+jules_worker_branch = "jules"
+
+[observers]
+roles = [
+  { name = "taxonomy", enabled = true },
+  { name = "qa", enabled = true },
+  { name = "cov", enabled = true },
+]
+"#,
+        );
+        let mut options = WorkflowRunOptions {
+            layer: Layer::Observers,
+            mock: false,
+            branch: None,
+            mock_tag: None,
+            task: None,
+            print_prompts: false,
+            mock_seed: None,
+            label: None,
+            exclude: Vec::new(),
+            emit_events: None,
+            allow_tag_reuse: false,
+            explain_routing: false,
+            dedupe_events: false,
+            continue_on_error: false,
+            config: None,
+        };
+
+        let mut completed_roles: Vec<String> = Vec::new();
+        let mut run_layer = |_path: &Path,
+                             run_options: RunOptions,
+                             _runtime: RunRuntimeOptions,
+                             _git: &NoopGit,
+                             _gh: &NoopGitHub,
+                             _store: &TestStore| {
+            let role = run_options.role.expect("role should be present");
+            if role == "qa" {
+                return Err(AppError::Validation("qa broke".to_string()));
+            }
+            completed_roles.push(role);
+            Ok(())
+        };
+
+        let err =
+            execute_layer_with_runner(&store, &options, &NoopGit, &NoopGitHub, &mut run_layer)
+                .unwrap_err();
+        assert_eq!(err.to_string(), "Validation failed: qa broke");
+
+        options.continue_on_error = true;
+        let err =
+            execute_layer_with_runner(&store, &options, &NoopGit, &NoopGitHub, &mut run_layer)
+                .unwrap_err();
+        assert!(err.to_string().contains("1 of 3 observer role(s) failed"), "{}", err);
+
+        assert_eq!(completed_roles, vec!["taxonomy", "taxonomy", "cov"]);
+    }
+
+    #[test]
+    fn execute_layer_decider_forwards_dedupe_events() {
+        let store = TestStore::new().with_exists(true).with_file(
+            ".jlo/config.toml",
+            "[run]\njlo_target_branch = \"main\"\njules_worker_branch = \"jules\"\n",
+        );
+        let options = WorkflowRunOptions {
+            layer: Layer::Decider,
+            mock: true,
+            branch: None,
+            mock_tag: Some("mock-test-001".to_string()),
+            task: None,
+            print_prompts: false,
+            mock_seed: None,
+            label: None,
+            exclude: Vec::new(),
+            emit_events: None,
+            allow_tag_reuse: false,
+            explain_routing: false,
+            dedupe_events: true,
+            continue_on_error: false,
+            config: None,
+        };
+
+        let mut dedupe_events = None;
+        let mut run_layer = |_path: &Path,
+                             _run_options: RunOptions,
+                             runtime: RunRuntimeOptions,
+                             _git: &NoopGit,
+                             _gh: &NoopGitHub,
+                             _store: &TestStore| {
+            dedupe_events = Some(runtime.dedupe_events);
+            Ok(())
+        };
+
+        execute_layer_with_runner(&store, &options, &NoopGit, &NoopGitHub, &mut run_layer).unwrap();
+
+        assert_eq!(dedupe_events, Some(true));
+    }
+
+    fn pipeline_store() -> TestStore {
+        let store = TestStore::new().with_exists(true).with_file(
+            ".jlo/config.toml",
+            r#"
+[run]
+jlo_target_branch = "main"
+jules_worker_branch = "jules"
+
+[observers]
+roles = [
+  { name = "taxonomy", enabled = true },
+]
+"#,
+        );
+        store
+            .write_file(
+                ".jules/exchange/requirements/plan-me.yml",
+                "id: plan01\nlabel: bugs\nimplementation_ready: false\n",
+            )
+            .unwrap();
+        store
+            .write_file(
+                ".jules/exchange/requirements/build-me.yml",
+                "id: impl01\nlabel: bugs\nimplementation_ready: true\n",
+            )
+            .unwrap();
+        store
+    }
+
+    fn pipeline_options(continue_on_error: bool) -> WorkflowRunPipelineOptions {
+        WorkflowRunPipelineOptions {
+            mock: true,
+            branch: None,
+            mock_tag: Some("mock-test-001".to_string()),
+            print_prompts: false,
+            mock_seed: None,
+            continue_on_error,
+            config: None,
+            allow_tag_reuse: false,
+        }
+    }
+
+    #[test]
+    fn execute_pipeline_runs_layers_in_pipeline_order() {
+        let store = pipeline_store();
+        let mut calls: Vec<String> = Vec::new();
+        let mut run_layer = |_path: &Path,
+                             run_options: RunOptions,
+                             _runtime: RunRuntimeOptions,
+                             _git: &NoopGit,
+                             _gh: &NoopGitHub,
+                             _store: &TestStore| {
+            calls.push(match run_options.role {
+                Some(role) => format!("{}:{}", run_options.layer.dir_name(), role),
+                None => run_options.layer.dir_name().to_string(),
+            });
+            Ok(())
+        };
+
+        let steps = execute_pipeline_with_runner(
+            &store,
+            &pipeline_options(false),
+            &NoopGit,
+            &NoopGitHub,
+            &mut run_layer,
+        );
+
+        assert_eq!(
+            calls,
+            vec!["narrator", "observers:taxonomy", "decider", "planner", "implementer"]
+        );
+        let statuses: Vec<_> = steps.iter().map(|step| (step.layer, step.status)).collect();
+        assert_eq!(
+            statuses,
+            vec![
+                (Layer::Narrator, PipelineStepStatus::Succeeded),
+                (Layer::Observers, PipelineStepStatus::Succeeded),
+                (Layer::Decider, PipelineStepStatus::Succeeded),
+                (Layer::Planner, PipelineStepStatus::Succeeded),
+                (Layer::Implementer, PipelineStepStatus::Succeeded),
+                (Layer::Integrator, PipelineStepStatus::Skipped),
+            ]
+        );
+    }
+
+    #[test]
+    fn execute_pipeline_stops_on_first_failure_unless_continuing() {
+        let store = pipeline_store();
+        let mut fail_decider = |_path: &Path,
+                                run_options: RunOptions,
+                                _runtime: RunRuntimeOptions,
+                                _git: &NoopGit,
+                                _gh: &NoopGitHub,
+                                _store: &TestStore| {
+            if run_options.layer == Layer::Decider {
+                return Err(AppError::Validation("decider broke".to_string()));
+            }
+            Ok(())
+        };
+
+        let steps = execute_pipeline_with_runner(
+            &store,
+            &pipeline_options(false),
+            &NoopGit,
+            &NoopGitHub,
+            &mut fail_decider,
+        );
+        assert_eq!(steps.len(), 3);
+        assert_eq!(steps[2].status, PipelineStepStatus::Failed);
+        assert!(steps[2].error.as_deref().unwrap().contains("decider broke"));
+
+        let steps = execute_pipeline_with_runner(
+            &store,
+            &pipeline_options(true),
+            &NoopGit,
+            &NoopGitHub,
+            &mut fail_decider,
+        );
+        assert_eq!(steps.len(), Layer::PIPELINE.len());
+        assert_eq!(steps[3].status, PipelineStepStatus::Succeeded);
+    }
+
+    struct UnavailableJules;
+
+    impl crate::ports::JulesClient for UnavailableJules {
+        fn create_session(
+            &self,
+            _request: crate::ports::SessionRequest,
+        ) -> Result<crate::ports::SessionResponse, AppError> {
+            Err(AppError::JulesApiError { message: "server error".to_string(), status: Some(503) })
+        }
+
+        fn health(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn circuit_breaker_trips_across_layers_of_one_workflow_run() {
+        use crate::adapters::jules_client::{
+            DEFAULT_CIRCUIT_FAILURE_THRESHOLD, RetryPolicy, RetryingJulesClient,
+        };
+        use crate::ports::{AutomationMode, JulesClient, SessionRequest};
+
+        let store = pipeline_store();
+        let policy = RetryPolicy::from_config(&crate::domain::JulesApiConfig {
+            max_retries: 1,
+            retry_delay_ms: 1,
+            ..Default::default()
+        });
+        // Each run builds its own client, as run::execute does, but takes the runtime breaker.
+        let mut run_layer = with_shared_breaker(
+            |_path: &Path,
+             _run_options: RunOptions,
+             runtime: RunRuntimeOptions,
+             _git: &NoopGit,
+             _gh: &NoopGitHub,
+             _store: &TestStore| {
+                let client = RetryingJulesClient::with_breaker(
+                    Box::new(UnavailableJules),
+                    policy,
+                    runtime.breaker.expect("workflow runs share a breaker"),
+                );
+                client
+                    .create_session(SessionRequest {
+                        prompt: "prompt".to_string(),
+                        source: "sources/github/owner/repo".to_string(),
+                        starting_branch: "jules".to_string(),
+                        require_plan_approval: false,
+                        automation_mode: AutomationMode::AutoCreatePr,
+                    })
+                    .map(|_| ())
+            },
+        );
+
+        let steps = execute_pipeline_with_runner(
+            &store,
+            &pipeline_options(true),
+            &NoopGit,
+            &NoopGitHub,
+            &mut run_layer,
+        );
+
+        let threshold = DEFAULT_CIRCUIT_FAILURE_THRESHOLD as usize;
+        let errors: Vec<&str> = steps.iter().filter_map(|step| step.error.as_deref()).collect();
+        assert_eq!(errors.len(), Layer::PIPELINE.len() - 1);
+        assert!(errors[..threshold].iter().all(|error| error.contains("server error")));
+        assert!(
+            errors[threshold..].iter().all(|error| error.contains("circuit is open")),
+            "{:?}",
+            errors
+        );
+    }
 }
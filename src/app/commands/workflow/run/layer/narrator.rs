@@ -1,4 +1,4 @@
-use crate::app::commands::run::{RunOptions, RunRuntimeOptions};
+use crate::app::commands::run::{ReportStream, RunOptions, RunRuntimeOptions};
 use crate::domain::PromptAssetLoader;
 use crate::domain::{AppError, Layer};
 use crate::ports::{Git, GitHub, JloStore, JulesStore, RepositoryFilesystem};
@@ -32,12 +32,27 @@ where
         role: None,
         requirement: None,
         task: options.task.clone(),
+        role_file: None,
     };
     let runtime = RunRuntimeOptions {
         prompt_preview: false,
         branch: options.branch.clone(),
         mock: options.mock,
         no_cleanup: false,
+        print_prompts: options.print_prompts,
+        dedupe_events: false,
+        allow_truncate: false,
+        mock_seed: options.mock_seed,
+        no_push: false,
+        mock_events: None,
+        record: None,
+        attribute_authors: false,
+        max_prs: None,
+        tag: None,
+        requirement_input: None,
+        breaker: None,
+        report_stream: ReportStream::Stderr,
+        config: None,
     };
 
     eprintln!("Executing: narrator{}", if options.mock { " (mock)" } else { "" });
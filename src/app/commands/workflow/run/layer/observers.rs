@@ -1,4 +1,4 @@
-use crate::app::commands::run::{RunOptions, RunRuntimeOptions};
+use crate::app::commands::run::{ReportStream, RunOptions, RunRuntimeOptions};
 use crate::app::commands::workflow::run::input::load_schedule;
 use crate::domain::PromptAssetLoader;
 use crate::domain::{AppError, Layer};
@@ -31,30 +31,63 @@ where
     let mock_suffix = if options.mock { " (mock)" } else { "" };
     let schedule = load_schedule(store)?;
 
-    let roles = schedule.observers.enabled_roles();
+    let roles = schedule.observers.enabled_roles_excluding(&options.exclude);
     if roles.is_empty() {
         eprintln!("No enabled observers roles");
         return Ok(RunResults::skipped("No enabled observers roles"));
     }
 
     let mut success_count: u32 = 0;
+    let mut failures: Vec<String> = Vec::new();
     for role in roles {
         let run_options = RunOptions {
             layer: Layer::Observers,
             role: Some(role.as_str().to_string()),
             requirement: None,
             task: options.task.clone(),
+            role_file: None,
         };
         let runtime = RunRuntimeOptions {
             prompt_preview: false,
             branch: options.branch.clone(),
             mock: options.mock,
             no_cleanup: false,
+            print_prompts: options.print_prompts,
+            dedupe_events: false,
+            allow_truncate: false,
+            mock_seed: options.mock_seed,
+            no_push: false,
+            mock_events: options.emit_events.clone(),
+            record: None,
+            attribute_authors: false,
+            max_prs: None,
+            tag: None,
+            requirement_input: None,
+            breaker: None,
+            report_stream: ReportStream::Stderr,
+            config: None,
         };
 
         eprintln!("Executing: observers --role {}{}", role, mock_suffix);
-        run_layer(jules_path, run_options, runtime, git, github, store)?;
-        success_count += 1;
+        match run_layer(jules_path, run_options, runtime, git, github, store) {
+            Ok(()) => success_count += 1,
+            // --continue-on-error keeps going so the remaining roles still run; otherwise mock
+            // and real runs alike stop at the first failure.
+            Err(err) if options.continue_on_error => {
+                eprintln!("Observer role '{}' failed: {}", role, err);
+                failures.push(format!("{}: {}", role, err));
+            }
+            Err(err) => return Err(err),
+        }
+    }
+
+    if !failures.is_empty() {
+        return Err(AppError::Validation(format!(
+            "{} of {} observer role(s) failed: {}",
+            failures.len(),
+            failures.len() as u32 + success_count,
+            failures.join("; ")
+        )));
     }
 
     Ok(RunResults::with_count(success_count))
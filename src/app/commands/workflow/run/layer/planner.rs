@@ -1,6 +1,8 @@
-use crate::app::commands::run::{RunOptions, RunRuntimeOptions};
+use crate::app::commands::run::{ReportStream, RunOptions, RunRuntimeOptions};
 use crate::app::commands::workflow::run::options::{RunResults, WorkflowRunOptions};
-use crate::app::commands::workflow::run::requirements_routing::find_requirements;
+use crate::app::commands::workflow::run::requirements_routing::{
+    find_requirements, route_requirements,
+};
 use crate::domain::PromptAssetLoader;
 use crate::domain::{AppError, Layer};
 use crate::ports::{Git, GitHub, JloStore, JulesStore, RepositoryFilesystem};
@@ -28,6 +30,11 @@ where
     F: FnMut(&Path, RunOptions, RunRuntimeOptions, &G, &H, &W) -> Result<(), AppError>,
 {
     let mock_suffix = if options.mock { " (mock)" } else { "" };
+    if options.explain_routing {
+        for route in route_requirements(store)? {
+            eprintln!("Routing: {}", route.explain(Layer::Planner));
+        }
+    }
     let requirements = find_requirements(store, Layer::Planner)?;
 
     if requirements.is_empty() {
@@ -42,12 +49,27 @@ where
             role: None,
             requirement: Some(requirement_path.clone()),
             task: options.task.clone(),
+            role_file: None,
         };
         let runtime = RunRuntimeOptions {
             prompt_preview: false,
             branch: options.branch.clone(),
             mock: options.mock,
             no_cleanup: false,
+            print_prompts: options.print_prompts,
+            dedupe_events: false,
+            allow_truncate: false,
+            mock_seed: options.mock_seed,
+            no_push: false,
+            mock_events: None,
+            record: None,
+            attribute_authors: false,
+            max_prs: None,
+            tag: None,
+            requirement_input: None,
+            breaker: None,
+            report_stream: ReportStream::Stderr,
+            config: None,
         };
 
         eprintln!("Executing: planner {}{}", requirement_path.display(), mock_suffix);
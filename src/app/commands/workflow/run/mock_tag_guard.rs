@@ -0,0 +1,131 @@
+//! Guard against reusing a mock tag whose artifacts already exist on the remote.
+//!
+//! `workflow clean-mock` deletes everything carrying a tag, so two runs sharing
+//! one tag can delete each other's artifacts.
+
+use crate::app::config::load_branch_prefix_for_layer;
+use crate::domain::{AppError, Layer};
+use crate::ports::Git;
+
+/// Fail when `origin` already has mock branches for `layers` carrying `mock_tag`.
+///
+/// Only branches under the given layers' mock prefixes count, so later layers of
+/// the same workflow run (which share one tag) do not trip the guard.
+pub(super) fn ensure_mock_tag_unused<G>(
+    git: &G,
+    mock_tag: &str,
+    layers: &[Layer],
+) -> Result<(), AppError>
+where
+    G: Git + ?Sized,
+{
+    if !git.remote_exists("origin")? {
+        return Ok(());
+    }
+
+    let prefixes = layers
+        .iter()
+        .map(|layer| load_branch_prefix_for_layer(*layer))
+        .collect::<Result<Vec<_>, _>>()?;
+    let heads = git.run_command(&["ls-remote", "--heads", "origin"], None)?;
+    let reused = tagged_branches(&heads, mock_tag, &prefixes);
+    if reused.is_empty() {
+        return Ok(());
+    }
+
+    Err(AppError::Validation(format!(
+        "Mock tag '{}' is already used by existing branch(es): {}. Set a unique JULES_MOCK_TAG \
+         (e.g. include the run id and attempt), clean up with 'jlo workflow clean-mock', \
+         or pass --allow-tag-reuse.",
+        mock_tag,
+        reused.join(", ")
+    )))
+}
+
+/// Branch names from `git ls-remote --heads` output under `prefixes` that carry `mock_tag`.
+fn tagged_branches(ls_remote_output: &str, mock_tag: &str, prefixes: &[String]) -> Vec<String> {
+    let mut branches: Vec<String> = ls_remote_output
+        .lines()
+        .filter_map(|line| line.split('\t').nth(1))
+        .filter_map(|reference| reference.strip_prefix("refs/heads/"))
+        .filter(|branch| {
+            prefixes.iter().any(|prefix| {
+                branch
+                    .strip_prefix(prefix.as_str())
+                    .is_some_and(|rest| has_tag_segment(rest, mock_tag))
+            })
+        })
+        .map(str::to_string)
+        .collect();
+    branches.sort();
+    branches
+}
+
+/// Whether `mock_tag` appears in `name` as whole `-`-delimited segments.
+///
+/// Mock branches are `<prefix><tag>-<timestamp>` or `<prefix><label>-<tag>`, so the
+/// tag starts the name or follows a `-`, and ends the name or precedes a `-`. This
+/// keeps `mock-run-4` from matching `mock-run-42` branches.
+fn has_tag_segment(name: &str, mock_tag: &str) -> bool {
+    name.match_indices(mock_tag).any(|(start, _)| {
+        let end = start + mock_tag.len();
+        let starts_segment = start == 0 || name[..start].ends_with('-');
+        let ends_segment = end == name.len() || name[end..].starts_with('-');
+        starts_segment && ends_segment
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::FakeGit;
+
+    fn observer_branch(tag: &str) -> String {
+        let prefix = load_branch_prefix_for_layer(Layer::Observers).unwrap();
+        format!("{}{}-20260101000000", prefix, tag)
+    }
+
+    #[test]
+    fn existing_tagged_branch_fires_guard() {
+        let git = FakeGit::new();
+        git.remote_heads.lock().unwrap().push(observer_branch("mock-run-42"));
+
+        let err = ensure_mock_tag_unused(&git, "mock-run-42", &[Layer::Observers]).unwrap_err();
+
+        let message = err.to_string();
+        assert!(message.contains("Mock tag 'mock-run-42' is already used"), "{}", message);
+        assert!(message.contains(&observer_branch("mock-run-42")), "{}", message);
+        assert!(message.contains("--allow-tag-reuse"), "{}", message);
+    }
+
+    #[test]
+    fn other_tags_and_other_layers_pass() {
+        let git = FakeGit::new();
+        git.remote_heads.lock().unwrap().push(observer_branch("mock-run-41"));
+        git.remote_heads.lock().unwrap().push(observer_branch("mock-run-42"));
+
+        ensure_mock_tag_unused(&git, "mock-run-43", &[Layer::Observers]).unwrap();
+        ensure_mock_tag_unused(&git, "mock-run-42", &[Layer::Decider]).unwrap();
+    }
+
+    #[test]
+    fn tag_that_prefixes_another_tag_does_not_match_it() {
+        let git = FakeGit::new();
+        git.remote_heads.lock().unwrap().push(observer_branch("mock-run-42"));
+        let prefix = load_branch_prefix_for_layer(Layer::Implementer).unwrap();
+        git.remote_heads.lock().unwrap().push(format!("{}bugs-mock-run-42", prefix));
+
+        ensure_mock_tag_unused(&git, "mock-run-4", &[Layer::Observers, Layer::Implementer])
+            .unwrap();
+    }
+
+    #[test]
+    fn trailing_tag_segment_fires_guard() {
+        let git = FakeGit::new();
+        let prefix = load_branch_prefix_for_layer(Layer::Implementer).unwrap();
+        git.remote_heads.lock().unwrap().push(format!("{}bugs-mock-run-4", prefix));
+
+        let err = ensure_mock_tag_unused(&git, "mock-run-4", &[Layer::Implementer]).unwrap_err();
+        assert!(err.to_string().contains("bugs-mock-run-4"), "{}", err);
+    }
+}
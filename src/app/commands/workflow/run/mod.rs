@@ -6,16 +6,19 @@
 
 mod input;
 pub mod layer;
+mod mock_tag_guard;
 pub mod options;
 pub mod requirements_routing;
 
 use chrono::Utc;
 
-use crate::domain::AppError;
+use crate::domain::{AppError, Layer, MockClock};
 use crate::ports::{Git, GitHub, JloStore, JulesStore, RepositoryFilesystem};
 
-use self::layer::execute_layer;
-pub use self::options::{WorkflowRunOptions, WorkflowRunOutput};
+use self::layer::{execute_layer, execute_pipeline};
+pub use self::options::{
+    WorkflowRunOptions, WorkflowRunOutput, WorkflowRunPipelineOptions, WorkflowRunPipelineOutput,
+};
 
 /// Execute workflow run command.
 pub fn execute<G, H>(
@@ -32,21 +35,13 @@ where
         return Err(AppError::JulesNotFound);
     }
 
-    let run_started_at = Utc::now().to_rfc3339();
-
-    // Mock mode configuration
-    let mock_tag = if options.mock {
-        let tag = options.mock_tag.clone().ok_or_else(|| {
-            AppError::Validation("Mock mode requires mock_tag in options".to_string())
-        })?;
-
-        if !tag.contains("mock") {
-            return Err(AppError::Validation("mock_tag must contain 'mock' substring".to_string()));
-        }
-        Some(tag)
-    } else {
-        None
-    };
+    let run_started_at = run_started_at(options.mock_seed);
+    let mock_tag = resolve_mock_tag(options.mock, options.mock_tag.as_deref())?;
+    if let Some(tag) = mock_tag.as_deref()
+        && !options.allow_tag_reuse
+    {
+        mock_tag_guard::ensure_mock_tag_unused(git, tag, &[options.layer])?;
+    }
 
     // Execute layer runs for all active roles
     let run_results = execute_layer(store, &options, git, github)?;
@@ -60,5 +55,57 @@ where
         mock_tag,
         mock_pr_numbers: run_results.mock_pr_numbers,
         mock_branches: run_results.mock_branches,
+        label: options.label,
     })
 }
+
+/// Execute every pipeline layer sequentially (`workflow run all`).
+pub fn execute_all<G, H>(
+    store: &(impl RepositoryFilesystem + JloStore + JulesStore + Clone + Send + Sync + 'static),
+    options: WorkflowRunPipelineOptions,
+    git: &G,
+    github: &H,
+) -> Result<WorkflowRunPipelineOutput, AppError>
+where
+    G: Git,
+    H: GitHub,
+{
+    if !store.jules_exists() {
+        return Err(AppError::JulesNotFound);
+    }
+
+    let run_started_at = run_started_at(options.mock_seed);
+    let mock_tag = resolve_mock_tag(options.mock, options.mock_tag.as_deref())?;
+    if let Some(tag) = mock_tag.as_deref()
+        && !options.allow_tag_reuse
+    {
+        mock_tag_guard::ensure_mock_tag_unused(git, tag, &Layer::PIPELINE)?;
+    }
+    let steps = execute_pipeline(store, &options, git, github);
+
+    Ok(WorkflowRunPipelineOutput { schema_version: 1, run_started_at, mock_tag, steps })
+}
+
+/// Start timestamp; fixed when a mock seed makes the run reproducible.
+fn run_started_at(mock_seed: Option<u64>) -> String {
+    match mock_seed {
+        Some(seed) => MockClock::seeded(seed).now().to_rfc3339(),
+        None => Utc::now().to_rfc3339(),
+    }
+}
+
+/// Validate mock configuration and return the mock tag when mock mode is on.
+fn resolve_mock_tag(mock: bool, mock_tag: Option<&str>) -> Result<Option<String>, AppError> {
+    if !mock {
+        return Ok(None);
+    }
+
+    let tag = mock_tag.ok_or_else(|| {
+        AppError::Validation("Mock mode requires mock_tag in options".to_string())
+    })?;
+
+    if !tag.contains("mock") {
+        return Err(AppError::Validation("mock_tag must contain 'mock' substring".to_string()));
+    }
+    Ok(Some(tag.to_string()))
+}
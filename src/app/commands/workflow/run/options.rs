@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use crate::domain::Layer;
 use serde::Serialize;
 
@@ -14,6 +16,26 @@ pub struct WorkflowRunOptions {
     pub mock_tag: Option<String>,
     /// Task selector for innovators (expected: create_three_proposals).
     pub task: Option<String>,
+    /// Print the prompts a real run would send to stderr (mock mode only).
+    pub print_prompts: bool,
+    /// Seed for deterministic mock ids and timestamps (mock mode only).
+    pub mock_seed: Option<u64>,
+    /// Caller-supplied label echoed into the output (e.g. a CI matrix entry).
+    pub label: Option<String>,
+    /// Role-name globs skipped when running multi-role layers.
+    pub exclude: Vec<String>,
+    /// Event bodies the mock observer seeds into pending (mock mode only).
+    pub emit_events: Option<Vec<serde_json::Value>>,
+    /// Run even when remote branches from an earlier run already carry the mock tag.
+    pub allow_tag_reuse: bool,
+    /// Print each requirement's routing decision to stderr before running (planner only).
+    pub explain_routing: bool,
+    /// Merge duplicate pending events before the decider runs (decider only).
+    pub dedupe_events: bool,
+    /// Keep running the remaining roles after one fails (observers).
+    pub continue_on_error: bool,
+    /// Control-plane config read instead of `.jlo/config.toml`.
+    pub config: Option<PathBuf>,
 }
 
 /// Output of workflow run command.
@@ -39,9 +61,104 @@ pub struct WorkflowRunOutput {
     /// Mock branches (only in mock mode).
     #[serde(skip_serializing_if = "Option::is_none")]
     pub mock_branches: Option<Vec<String>>,
+    /// Label passed via `--label`, for correlating outputs with matrix entries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+}
+
+/// Options for `workflow run all` (sequential full-pipeline execution).
+#[derive(Debug, Clone)]
+pub struct WorkflowRunPipelineOptions {
+    /// Run in mock mode.
+    pub mock: bool,
+    /// Optional starting branch override passed to `run`.
+    pub branch: Option<String>,
+    /// Mock tag (required if mock is true).
+    pub mock_tag: Option<String>,
+    /// Print the prompts a real run would send to stderr (mock mode only).
+    pub print_prompts: bool,
+    /// Seed for deterministic mock ids and timestamps (mock mode only).
+    pub mock_seed: Option<u64>,
+    /// Keep running later layers after a layer fails.
+    pub continue_on_error: bool,
+    /// Run even when remote branches from an earlier run already carry the mock tag.
+    pub allow_tag_reuse: bool,
+    /// Control-plane config read instead of `.jlo/config.toml`.
+    pub config: Option<PathBuf>,
+}
+
+impl WorkflowRunPipelineOptions {
+    /// Per-layer options for one pipeline step.
+    pub(crate) fn for_layer(&self, layer: Layer) -> WorkflowRunOptions {
+        WorkflowRunOptions {
+            layer,
+            mock: self.mock,
+            branch: self.branch.clone(),
+            mock_tag: self.mock_tag.clone(),
+            task: None,
+            print_prompts: self.print_prompts,
+            mock_seed: self.mock_seed,
+            label: None,
+            exclude: Vec::new(),
+            emit_events: None,
+            allow_tag_reuse: self.allow_tag_reuse,
+            explain_routing: false,
+            dedupe_events: false,
+            continue_on_error: self.continue_on_error,
+            config: self.config.clone(),
+        }
+    }
+}
+
+/// Outcome of one layer within a pipeline run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PipelineStepStatus {
+    Succeeded,
+    Skipped,
+    Failed,
+}
+
+/// Result of one layer within a pipeline run.
+#[derive(Debug, Clone, Serialize)]
+pub struct PipelineStep {
+    /// Layer that was executed.
+    pub layer: Layer,
+    /// Step outcome.
+    pub status: PipelineStepStatus,
+    /// Number of API requests that succeeded during this step.
+    pub number_of_api_requests_succeeded: u32,
+    /// Reason the layer was skipped.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_reason: Option<String>,
+    /// Error message when the layer failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+/// Output of `workflow run all`.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkflowRunPipelineOutput {
+    /// Schema version for output format stability.
+    pub schema_version: u32,
+    /// Timestamp when run started (RFC3339 UTC).
+    pub run_started_at: String,
+    /// Mock tag (only in mock mode).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mock_tag: Option<String>,
+    /// Executed steps, in pipeline order.
+    pub steps: Vec<PipelineStep>,
+}
+
+impl WorkflowRunPipelineOutput {
+    /// First failed step, if any.
+    pub fn first_failure(&self) -> Option<&PipelineStep> {
+        self.steps.iter().find(|step| step.status == PipelineStepStatus::Failed)
+    }
 }
 
 /// Results from running a layer.
+#[derive(Debug)]
 pub(crate) struct RunResults {
     /// Number of API requests that succeeded.
     pub(crate) number_of_api_requests_succeeded: u32,
@@ -85,6 +202,16 @@ mod tests {
             branch: None,
             mock_tag: None,
             task: None,
+            print_prompts: false,
+            mock_seed: None,
+            label: None,
+            exclude: Vec::new(),
+            emit_events: None,
+            allow_tag_reuse: false,
+            explain_routing: false,
+            dedupe_events: false,
+            continue_on_error: false,
+            config: None,
         };
         assert_eq!(options.layer, Layer::Observers);
         assert!(!options.mock);
@@ -101,6 +228,7 @@ mod tests {
             mock_tag: None,
             mock_pr_numbers: None,
             mock_branches: None,
+            label: None,
         };
         let json = serde_json::to_string(&output).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
@@ -109,6 +237,7 @@ mod tests {
         assert_eq!(parsed["number_of_api_requests_succeeded"], 3);
         assert!(parsed.get("skip_reason").is_none());
         assert!(parsed.get("mock_tag").is_none());
+        assert!(parsed.get("label").is_none());
     }
 
     #[test]
@@ -122,6 +251,7 @@ mod tests {
             mock_tag: None,
             mock_pr_numbers: None,
             mock_branches: None,
+            label: None,
         };
         let json = serde_json::to_string(&output).unwrap();
         let parsed: serde_json::Value = serde_json::from_str(&json).unwrap();
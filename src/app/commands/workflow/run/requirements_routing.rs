@@ -2,6 +2,34 @@ use crate::domain::{AppError, Layer, RequirementHeader};
 use crate::ports::{JulesStore, RepositoryFilesystem};
 use std::path::PathBuf;
 
+/// Routing decision for one requirement file in the exchange directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RequirementRoute {
+    pub path: PathBuf,
+    pub label: String,
+    pub implementation_ready: bool,
+}
+
+impl RequirementRoute {
+    /// Layer that picks this requirement up.
+    pub fn layer(&self) -> Layer {
+        if self.implementation_ready { Layer::Implementer } else { Layer::Planner }
+    }
+
+    /// One line stating whether `layer` selects this requirement, and why.
+    pub fn explain(&self, layer: Layer) -> String {
+        let status = if self.layer() == layer { "selected" } else { "skipped" };
+        format!(
+            "{}: {} (label '{}', implementation_ready: {}; routed to {})",
+            status,
+            self.path.display(),
+            self.label,
+            self.implementation_ready,
+            self.layer().dir_name()
+        )
+    }
+}
+
 /// Find requirements for a layer in the flat exchange directory.
 pub(crate) fn find_requirements(
     store: &(impl RepositoryFilesystem + JulesStore),
@@ -11,6 +39,17 @@ pub(crate) fn find_requirements(
         return Err(AppError::Validation("Invalid layer for requirement discovery".to_string()));
     }
 
+    Ok(route_requirements(store)?
+        .into_iter()
+        .filter(|route| route.layer() == layer)
+        .map(|route| route.path)
+        .collect())
+}
+
+/// Route every requirement in the flat exchange directory, sorted by path.
+pub(crate) fn route_requirements(
+    store: &(impl RepositoryFilesystem + JulesStore),
+) -> Result<Vec<RequirementRoute>, AppError> {
     let jules_path = store.jules_path();
     let requirements_dir =
         crate::domain::exchange::requirements::paths::requirements_dir(&jules_path);
@@ -24,7 +63,7 @@ pub(crate) fn find_requirements(
         return Ok(Vec::new());
     }
 
-    let mut issues = Vec::new();
+    let mut routes = Vec::new();
     let entries = store.list_dir(requirements_dir_str)?;
 
     for path in entries {
@@ -37,26 +76,21 @@ pub(crate) fn find_requirements(
             .to_str()
             .ok_or_else(|| AppError::Validation(format!("Invalid path: {}", path.display())))?;
         let content = store.read_file(path_str)?;
-        let implementation_ready = RequirementHeader::parse(&content)
-            .map_err(|err| match err {
-                AppError::ParseError { details, .. } => {
-                    AppError::ParseError { what: path_str.to_string(), details }
-                }
-                other => other,
-            })?
-            .implementation_ready;
-        let belongs_to_layer = match layer {
-            Layer::Planner => !implementation_ready,
-            Layer::Implementer => implementation_ready,
-            _ => false,
-        };
-        if belongs_to_layer {
-            issues.push(path);
-        }
+        let header = RequirementHeader::parse(&content).map_err(|err| match err {
+            AppError::ParseError { details, .. } => {
+                AppError::ParseError { what: path_str.to_string(), details }
+            }
+            other => other,
+        })?;
+        routes.push(RequirementRoute {
+            path,
+            label: header.label,
+            implementation_ready: header.implementation_ready,
+        });
     }
 
-    issues.sort();
-    Ok(issues)
+    routes.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(routes)
 }
 
 #[cfg(test)]
@@ -110,4 +144,27 @@ mod tests {
         assert_eq!(issues.len(), 1);
         assert!(issues[0].to_string_lossy().contains("ready-to-implement.yml"));
     }
+
+    #[test]
+    #[serial]
+    fn planner_routing_explanation_distinguishes_selected_and_skipped() {
+        let store = TestStore::new();
+        setup_workspace(&store);
+
+        write_requirement(&store, "needs-plan", "bugs", false);
+        write_requirement(&store, "ready", "feats", true);
+
+        let explanations: Vec<String> = route_requirements(&store)
+            .unwrap()
+            .iter()
+            .map(|route| route.explain(Layer::Planner))
+            .collect();
+
+        assert_eq!(explanations.len(), 2);
+        assert!(explanations[0].starts_with("selected: "), "{}", explanations[0]);
+        assert!(explanations[0].contains("needs-plan.yml"), "{}", explanations[0]);
+        assert!(explanations[0].contains("label 'bugs', implementation_ready: false"));
+        assert!(explanations[1].starts_with("skipped: "), "{}", explanations[1]);
+        assert!(explanations[1].contains("routed to implementer"), "{}", explanations[1]);
+    }
 }
@@ -40,6 +40,13 @@ mod tests {
         fn get_current_branch(&self) -> Result<String, AppError> {
             Ok(String::new())
         }
+        fn get_default_branch(&self) -> Result<String, AppError> {
+            Ok("main".to_string())
+        }
+        fn blame_last_author(&self, _rev: &str, _path: &str) -> Result<String, AppError> {
+            Ok("Test Author".to_string())
+        }
+
         fn commit_exists(&self, _sha: &str) -> bool {
             true
         }
@@ -49,6 +56,19 @@ mod tests {
         fn get_first_commit(&self, _commit: &str) -> Result<String, AppError> {
             Ok(String::new())
         }
+        fn rev_parse(&self, _rev: &str) -> Result<String, AppError> {
+            Ok(String::new())
+        }
+        fn commit_message(&self, _sha: &str) -> Result<String, AppError> {
+            Ok(String::new())
+        }
+        fn log_range(
+            &self,
+            _from: &str,
+            _to: &str,
+        ) -> Result<Vec<crate::ports::CommitInfo>, AppError> {
+            Ok(Vec::new())
+        }
         fn has_changes(
             &self,
             _from: &str,
@@ -57,6 +77,10 @@ mod tests {
         ) -> Result<bool, AppError> {
             Ok(false)
         }
+
+        fn status(&self, _pathspec: &[&str]) -> Result<Vec<crate::ports::StatusEntry>, AppError> {
+            Ok(Vec::new())
+        }
         fn run_command(&self, args: &[&str], _cwd: Option<&Path>) -> Result<String, AppError> {
             if self.fail {
                 return Err(AppError::InternalError("Mock git failure".into()));
@@ -83,10 +107,46 @@ mod tests {
         fn fetch(&self, _remote: &str) -> Result<(), AppError> {
             Ok(())
         }
+        fn fetch_prune(&self, _remote: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+        fn remote_exists(&self, _name: &str) -> Result<bool, AppError> {
+            Ok(true)
+        }
+        fn get_config(&self, _key: &str) -> Result<Option<String>, AppError> {
+            Ok(None)
+        }
+        fn set_config(&self, _key: &str, _value: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+        fn unset_config(&self, _key: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+        fn abort_merge(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+        fn cherry_pick(&self, _rev: &str) -> Result<(), AppError> {
+            Ok(())
+        }
+        fn cherry_pick_abort(&self) -> Result<(), AppError> {
+            Ok(())
+        }
+        fn reset_hard_to(&self, _branch: &str, _rev: &str) -> Result<(), AppError> {
+            Ok(())
+        }
         fn delete_branch(&self, _branch: &str, _force: bool) -> Result<bool, AppError> {
             Ok(true)
         }
 
+        fn create_tag(
+            &self,
+            _name: &str,
+            _message: Option<&str>,
+            _rev: Option<&str>,
+        ) -> Result<(), AppError> {
+            Ok(())
+        }
+
         fn push_branch_from_rev(
             &self,
             _rev: &str,
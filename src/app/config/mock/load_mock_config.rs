@@ -3,15 +3,18 @@
 use std::collections::HashMap;
 use std::path::Path;
 
+use chrono::Utc;
+
 use crate::app::config::load_config;
 use crate::domain::config::mock_parse::{extract_branch_prefix, extract_issue_labels};
 use crate::domain::jules_paths;
-use crate::domain::{AppError, ConfigError, Layer, MockConfig};
-use crate::ports::RepositoryFilesystem;
+use crate::domain::{AppError, ConfigError, Layer, MockClock, MockConfig};
+use crate::ports::{JloStore, RepositoryFilesystem};
 
 use super::mock_tag::resolve_mock_tag;
 
-fn load_branch_prefix_for_layer(layer: Layer) -> Result<String, AppError> {
+/// Branch prefix the embedded contracts assign to `layer`'s mock branches.
+pub fn load_branch_prefix_for_layer(layer: Layer) -> Result<String, AppError> {
     let catalog_path = format!("{}/contracts.yml", layer.dir_name());
     let content = crate::adapters::catalogs::prompt_assemble_assets::read_prompt_assemble_asset(
         &catalog_path,
@@ -35,11 +38,15 @@ fn load_branch_prefix_for_layer(layer: Layer) -> Result<String, AppError> {
 }
 
 /// Load mock configuration from repository files.
-pub fn load_mock_config<W: RepositoryFilesystem>(
+///
+/// `seed` fixes the mock clock for reproducible runs; without it the clock starts at
+/// the current time with an id sequence seeded from it.
+pub fn load_mock_config<W: RepositoryFilesystem + JloStore>(
     jules_path: &Path,
     repository: &W,
+    seed: Option<u64>,
 ) -> Result<MockConfig, AppError> {
-    let run_config = load_config(jules_path, repository)?;
+    let run_config = load_config(repository)?;
 
     let mut branch_prefixes = HashMap::new();
     for layer in Layer::ALL {
@@ -67,6 +74,10 @@ pub fn load_mock_config<W: RepositoryFilesystem>(
     }
 
     let mock_tag = resolve_mock_tag()?;
+    let fail_target = std::env::var("JULES_MOCK_FAIL")
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
 
     Ok(MockConfig {
         mock_tag,
@@ -74,9 +85,21 @@ pub fn load_mock_config<W: RepositoryFilesystem>(
         jlo_target_branch: run_config.run.jlo_target_branch,
         jules_worker_branch: run_config.run.jules_worker_branch,
         issue_labels,
+        clock: mock_clock(seed),
+        fail_target,
     })
 }
 
+fn mock_clock(seed: Option<u64>) -> MockClock {
+    match seed {
+        Some(seed) => MockClock::seeded(seed),
+        None => {
+            let now = Utc::now();
+            MockClock::new(now, now.timestamp_nanos_opt().unwrap_or_default() as u64)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
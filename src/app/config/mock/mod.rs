@@ -4,5 +4,5 @@ mod load_mock_config;
 mod mock_tag;
 mod prerequisites;
 
-pub use load_mock_config::load_mock_config;
+pub use load_mock_config::{load_branch_prefix_for_layer, load_mock_config};
 pub use prerequisites::validate_mock_prerequisites;
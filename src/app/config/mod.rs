@@ -17,4 +17,4 @@ pub use detect_repository_source::detect_repository_source;
 pub use load_config::load_config;
 pub use load_schedule::load_schedule;
 pub use load_setup_config::load_setup_config;
-pub use mock::{load_mock_config, validate_mock_prerequisites};
+pub use mock::{load_branch_prefix_for_layer, load_mock_config, validate_mock_prerequisites};
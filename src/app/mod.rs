@@ -3,5 +3,7 @@ pub mod cli;
 pub mod commands;
 pub mod config;
 mod context;
+pub mod style;
+pub mod validation;
 
 pub use context::AppContext;
@@ -0,0 +1,80 @@
+//! Terminal styling for human-facing output.
+//!
+//! Machine-readable output (JSON, workflow output) must never pass through here.
+
+use std::io::IsTerminal;
+
+const RED: &str = "\x1b[31m";
+const YELLOW: &str = "\x1b[33m";
+const RESET: &str = "\x1b[0m";
+
+/// When to emit ANSI color codes.
+///
+/// The CLI defaults to `auto`; library callers default to `never`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    /// Color when the stream is a terminal and `NO_COLOR` is unset.
+    Auto,
+    Always,
+    #[default]
+    Never,
+}
+
+impl ColorChoice {
+    /// Resolve the choice for stderr, where diagnostics are written.
+    pub fn enabled_for_stderr(self) -> bool {
+        self.enabled(std::io::stderr().is_terminal())
+    }
+
+    fn enabled(self, is_terminal: bool) -> bool {
+        match self {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                is_terminal && std::env::var_os("NO_COLOR").is_none_or(|value| value.is_empty())
+            }
+        }
+    }
+}
+
+/// Applies colors only when enabled.
+#[derive(Debug, Clone, Copy)]
+pub struct Styler {
+    enabled: bool,
+}
+
+impl Styler {
+    pub fn new(enabled: bool) -> Self {
+        Self { enabled }
+    }
+
+    pub fn red(&self, text: &str) -> String {
+        self.paint(RED, text)
+    }
+
+    pub fn yellow(&self, text: &str) -> String {
+        self.paint(YELLOW, text)
+    }
+
+    fn paint(&self, color: &str, text: &str) -> String {
+        if self.enabled { format!("{}{}{}", color, text, RESET) } else { text.to_string() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn never_and_non_terminal_auto_disable_color() {
+        assert!(!ColorChoice::Never.enabled(true));
+        assert!(!ColorChoice::Auto.enabled(false));
+        assert!(ColorChoice::Always.enabled(false));
+    }
+
+    #[test]
+    fn disabled_styler_leaves_text_plain() {
+        assert_eq!(Styler::new(false).red("[ERROR]"), "[ERROR]");
+        assert_eq!(Styler::new(true).yellow("[WARN]"), "\x1b[33m[WARN]\x1b[0m");
+    }
+}
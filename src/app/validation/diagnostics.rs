@@ -0,0 +1,484 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+
+use crate::app::style::Styler;
+use crate::domain::AppError;
+
+/// Stable diagnostic codes (`JLO-<FAMILY>-NNN`), grouped by the module that emits them.
+///
+/// Codes never change meaning once released; new checks get new numbers.
+pub mod codes {
+    // structure.rs (plus mod.rs for --check-api and workflows.rs for --check-workflows)
+    /// `.jlo/config.toml` is missing.
+    pub const STRUCT_CONFIG_MISSING: &str = "JLO-STRUCT-001";
+    /// `.jlo/config.toml` cannot be read or parsed.
+    pub const STRUCT_CONFIG_INVALID: &str = "JLO-STRUCT-002";
+    /// A required directory is missing.
+    pub const STRUCT_MISSING_DIR: &str = "JLO-STRUCT-003";
+    /// A required file is missing.
+    pub const STRUCT_MISSING_FILE: &str = "JLO-STRUCT-004";
+    /// `.jules/.jlo-version` is unreadable or malformed.
+    pub const STRUCT_VERSION_INVALID: &str = "JLO-STRUCT-005";
+    /// `.jules/` was written by a newer jlo than this binary.
+    pub const STRUCT_VERSION_NEWER: &str = "JLO-STRUCT-006";
+    /// A temporary workspace was left behind.
+    pub const STRUCT_STALE_WORKSPACE: &str = "JLO-STRUCT-007";
+    /// A directory could not be listed during structural checks.
+    pub const STRUCT_UNREADABLE_DIR: &str = "JLO-STRUCT-008";
+    /// The Jules API probe failed (`--check-api`).
+    pub const STRUCT_API_HEALTH: &str = "JLO-STRUCT-009";
+    /// A workflow file drifted from the scaffold (`--check-workflows`).
+    pub const STRUCT_WORKFLOW_DRIFT: &str = "JLO-STRUCT-010";
+    /// Runtime exchange artifacts exist while `jlo_target_branch` is checked out.
+    pub const STRUCT_CONTROL_BRANCH_ARTIFACTS: &str = "JLO-STRUCT-011";
+    /// An embedded layer contracts.yml is missing, unparseable, or inconsistent.
+    pub const STRUCT_LAYER_CONTRACT: &str = "JLO-STRUCT-012";
+
+    // yaml.rs and schemas/
+    /// A YAML document does not parse or its root is not a mapping.
+    pub const SCHEMA_YAML_INVALID: &str = "JLO-SCHEMA-001";
+    /// A required field or section is missing or empty.
+    pub const SCHEMA_REQUIRED_FIELD: &str = "JLO-SCHEMA-002";
+    /// A field has the wrong type or format.
+    pub const SCHEMA_INVALID_VALUE: &str = "JLO-SCHEMA-003";
+    /// A field value is outside its allowed set.
+    pub const SCHEMA_INVALID_ENUM: &str = "JLO-SCHEMA-004";
+    /// A date field is not `YYYY-MM-DD`.
+    pub const SCHEMA_DATE_FORMAT: &str = "JLO-SCHEMA-005";
+    /// A template placeholder was left in place.
+    pub const SCHEMA_PLACEHOLDER: &str = "JLO-SCHEMA-006";
+    /// A declared layer or role does not match its location.
+    pub const SCHEMA_MISMATCH: &str = "JLO-SCHEMA-007";
+    /// An event's `requirement_id` contradicts its state directory.
+    pub const SCHEMA_EVENT_STATE: &str = "JLO-SCHEMA-008";
+    /// A file or directory could not be read during schema checks.
+    pub const SCHEMA_UNREADABLE: &str = "JLO-SCHEMA-009";
+
+    // naming.rs
+    /// An exchange file is not `.yml`.
+    pub const NAMING_EXTENSION: &str = "JLO-NAMING-001";
+    /// A proposal filename is not `<role>-<slug>.yml`.
+    pub const NAMING_PROPOSAL_FORMAT: &str = "JLO-NAMING-002";
+    /// A filename is not kebab-case.
+    pub const NAMING_KEBAB_CASE: &str = "JLO-NAMING-003";
+    /// A directory could not be listed during naming checks.
+    pub const NAMING_UNREADABLE_DIR: &str = "JLO-NAMING-004";
+
+    // semantic.rs
+    /// An event references a requirement that does not exist.
+    pub const SEMANTIC_MISSING_REQUIREMENT: &str = "JLO-SEMANTIC-001";
+    /// A requirement references an event that does not exist.
+    pub const SEMANTIC_MISSING_EVENT: &str = "JLO-SEMANTIC-002";
+    /// Event and requirement ownership links disagree.
+    pub const SEMANTIC_EVENT_OWNERSHIP: &str = "JLO-SEMANTIC-003";
+    /// A path could not be resolved or read during semantic checks.
+    pub const SEMANTIC_UNREADABLE: &str = "JLO-SEMANTIC-004";
+    /// The schedule is invalid or lists a missing role.
+    pub const SEMANTIC_SCHEDULE: &str = "JLO-SEMANTIC-005";
+    /// A requirement awaiting planning has no `planner_request_reason`.
+    pub const SEMANTIC_PLANNER_REASON: &str = "JLO-SEMANTIC-006";
+    /// A requirement has awaited planning for too long.
+    pub const SEMANTIC_STALE_PLANNER_REQUEST: &str = "JLO-SEMANTIC-007";
+    /// No observer role is enabled to feed downstream layers.
+    pub const SEMANTIC_NO_OBSERVERS: &str = "JLO-SEMANTIC-008";
+
+    // quality.rs
+    /// A free-text field is suspiciously short.
+    pub const QUALITY_SHORT_CONTENT: &str = "JLO-QUALITY-001";
+    /// A verification criterion looks incomplete.
+    pub const QUALITY_VERIFICATION_CRITERIA: &str = "JLO-QUALITY-002";
+    /// role.yml indentation contains tabs.
+    pub const QUALITY_TAB_INDENTATION: &str = "JLO-QUALITY-003";
+    /// `.jlo/config.toml` sections are out of canonical order.
+    pub const QUALITY_SECTION_ORDER: &str = "JLO-QUALITY-004";
+
+    /// Every code, for validating `--allow` entries.
+    pub const ALL: &[&str] = &[
+        STRUCT_CONFIG_MISSING,
+        STRUCT_CONFIG_INVALID,
+        STRUCT_MISSING_DIR,
+        STRUCT_MISSING_FILE,
+        STRUCT_VERSION_INVALID,
+        STRUCT_VERSION_NEWER,
+        STRUCT_STALE_WORKSPACE,
+        STRUCT_UNREADABLE_DIR,
+        STRUCT_API_HEALTH,
+        STRUCT_WORKFLOW_DRIFT,
+        STRUCT_CONTROL_BRANCH_ARTIFACTS,
+        STRUCT_LAYER_CONTRACT,
+        SCHEMA_YAML_INVALID,
+        SCHEMA_REQUIRED_FIELD,
+        SCHEMA_INVALID_VALUE,
+        SCHEMA_INVALID_ENUM,
+        SCHEMA_DATE_FORMAT,
+        SCHEMA_PLACEHOLDER,
+        SCHEMA_MISMATCH,
+        SCHEMA_EVENT_STATE,
+        SCHEMA_UNREADABLE,
+        NAMING_EXTENSION,
+        NAMING_PROPOSAL_FORMAT,
+        NAMING_KEBAB_CASE,
+        NAMING_UNREADABLE_DIR,
+        SEMANTIC_MISSING_REQUIREMENT,
+        SEMANTIC_MISSING_EVENT,
+        SEMANTIC_EVENT_OWNERSHIP,
+        SEMANTIC_UNREADABLE,
+        SEMANTIC_SCHEDULE,
+        SEMANTIC_PLANNER_REASON,
+        SEMANTIC_STALE_PLANNER_REQUEST,
+        SEMANTIC_NO_OBSERVERS,
+        QUALITY_SHORT_CONTENT,
+        QUALITY_VERIFICATION_CRITERIA,
+        QUALITY_TAB_INDENTATION,
+        QUALITY_SECTION_ORDER,
+    ];
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+impl Severity {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        }
+    }
+}
+
+/// Check phase that produced a diagnostic.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Category {
+    #[default]
+    Structure,
+    Schema,
+    Naming,
+    Semantic,
+    Quality,
+}
+
+impl Category {
+    /// Every category in execution order.
+    pub const ALL: [Category; 5] = [
+        Category::Structure,
+        Category::Schema,
+        Category::Naming,
+        Category::Semantic,
+        Category::Quality,
+    ];
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|category| category.as_str() == name)
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Category::Structure => "structure",
+            Category::Schema => "schema",
+            Category::Naming => "naming",
+            Category::Semantic => "semantic",
+            Category::Quality => "quality",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[allow(dead_code)]
+pub struct Diagnostic {
+    /// Stable code from [`codes`]; its family names the emitting module
+    /// (`STRUCT` structure, `SCHEMA` yaml/schemas, `NAMING` naming,
+    /// `SEMANTIC` semantic, `QUALITY` quality).
+    pub code: &'static str,
+    pub file: String,
+    pub message: String,
+    pub severity: Severity,
+    pub category: Category,
+}
+
+#[derive(Debug, Default)]
+pub struct Diagnostics {
+    errors: Vec<Diagnostic>,
+    warnings: Vec<Diagnostic>,
+    category: Category,
+}
+
+impl Diagnostics {
+    /// Set the category attached to subsequently pushed diagnostics.
+    pub fn set_category(&mut self, category: Category) {
+        self.category = category;
+    }
+
+    /// Empty collector sharing this one's category, for an independent worker.
+    pub fn fork(&self) -> Self {
+        Self { category: self.category, ..Self::default() }
+    }
+
+    /// Append worker results ordered by file then message, independent of scheduling.
+    pub fn merge_sorted(&mut self, parts: Vec<Diagnostics>) {
+        let mut errors = Vec::new();
+        let mut warnings = Vec::new();
+        for part in parts {
+            errors.extend(part.errors);
+            warnings.extend(part.warnings);
+        }
+        for merged in [&mut errors, &mut warnings] {
+            merged.sort_by(|a, b| (&a.file, &a.message).cmp(&(&b.file, &b.message)));
+        }
+        self.errors.extend(errors);
+        self.warnings.extend(warnings);
+    }
+
+    pub fn push_error(
+        &mut self,
+        code: &'static str,
+        file: impl Into<String>,
+        message: impl Into<String>,
+    ) {
+        let diagnostic = Diagnostic {
+            code,
+            file: file.into(),
+            message: message.into(),
+            severity: Severity::Error,
+            category: self.category,
+        };
+        self.errors.push(diagnostic);
+    }
+
+    pub fn push_warning(
+        &mut self,
+        code: &'static str,
+        file: impl Into<String>,
+        message: impl Into<String>,
+    ) {
+        let diagnostic = Diagnostic {
+            code,
+            file: file.into(),
+            message: message.into(),
+            severity: Severity::Warning,
+            category: self.category,
+        };
+        self.warnings.push(diagnostic);
+    }
+
+    pub fn error_count(&self) -> usize {
+        self.errors.len()
+    }
+
+    pub fn warning_count(&self) -> usize {
+        self.warnings.len()
+    }
+
+    /// Count errors and warnings per diagnostic code (`JLO-…`).
+    pub fn counts_by_code(&self) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for diagnostic in self.errors.iter().chain(&self.warnings) {
+            *counts.entry(diagnostic.code.to_string()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    /// Count errors and warnings per category.
+    pub fn counts_by_category(&self) -> BTreeMap<String, usize> {
+        let mut counts = BTreeMap::new();
+        for diagnostic in self.errors.iter().chain(&self.warnings) {
+            *counts.entry(diagnostic.category.as_str().to_string()).or_insert(0) += 1;
+        }
+        counts
+    }
+
+    pub fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    #[allow(dead_code)]
+    pub fn has_warnings(&self) -> bool {
+        !self.warnings.is_empty()
+    }
+
+    pub fn errors(&self) -> &[Diagnostic] {
+        &self.errors
+    }
+
+    pub fn warnings(&self) -> &[Diagnostic] {
+        &self.warnings
+    }
+
+    /// Apply `--allow` rules: silenced codes are dropped, downgraded errors become warnings.
+    ///
+    /// Returns how many diagnostics were silenced.
+    pub fn apply_allow(&mut self, rules: &[AllowRule]) -> usize {
+        let allowance =
+            |code: &str| rules.iter().find(|rule| rule.code == code).map(|rule| rule.allowance);
+        let before = self.errors.len() + self.warnings.len();
+
+        let mut downgraded = Vec::new();
+        self.errors.retain(|diagnostic| match allowance(diagnostic.code) {
+            None => true,
+            Some(Allowance::Silence) => false,
+            Some(Allowance::Warn) => {
+                downgraded.push(Diagnostic { severity: Severity::Warning, ..diagnostic.clone() });
+                false
+            }
+        });
+        self.warnings.retain(|diagnostic| allowance(diagnostic.code) != Some(Allowance::Silence));
+        self.warnings.extend(downgraded);
+
+        before - (self.errors.len() + self.warnings.len())
+    }
+
+    /// Print diagnostics to stderr, coloring severity prefixes when `color` is set.
+    pub fn emit(&self, color: bool) {
+        let styler = Styler::new(color);
+        for diagnostic in &self.errors {
+            eprintln!(
+                "{} {}: {} [{}]",
+                styler.red("[ERROR]"),
+                diagnostic.file,
+                diagnostic.message,
+                diagnostic.code
+            );
+        }
+        for diagnostic in &self.warnings {
+            eprintln!(
+                "{} {}: {} [{}]",
+                styler.yellow("[WARN]"),
+                diagnostic.file,
+                diagnostic.message,
+                diagnostic.code
+            );
+        }
+    }
+}
+
+/// Treatment of diagnostics whose code is listed in `--allow`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Allowance {
+    /// Drop the diagnostic entirely.
+    Silence,
+    /// Report errors as warnings; warnings are unchanged.
+    Warn,
+}
+
+/// Parsed `--allow` entry: `CODE` silences the code, `CODE=warning` downgrades it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AllowRule {
+    pub code: &'static str,
+    pub allowance: Allowance,
+}
+
+impl AllowRule {
+    pub fn parse(entry: &str) -> Result<Self, AppError> {
+        let (code, allowance) = match entry.split_once('=') {
+            None => (entry, Allowance::Silence),
+            Some((code, "warning")) => (code, Allowance::Warn),
+            Some((_, other)) => {
+                return Err(AppError::Validation(format!(
+                    "Invalid --allow entry '{}': expected CODE or CODE=warning, not '={}'",
+                    entry, other
+                )));
+            }
+        };
+        let code = codes::ALL.iter().find(|known| **known == code.trim()).ok_or_else(|| {
+            AppError::Validation(format!(
+                "Unknown diagnostic code '{}' in --allow (see `jlo doctor --list-rules`)",
+                code
+            ))
+        })?;
+        Ok(Self { code, allowance })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diagnostics() -> Diagnostics {
+        let mut diagnostics = Diagnostics::default();
+        diagnostics.push_error(codes::SCHEMA_DATE_FORMAT, "a.yml", "created_at must be YYYY-MM-DD");
+        diagnostics.push_error(codes::SCHEMA_REQUIRED_FIELD, "a.yml", "title is required");
+        diagnostics.push_warning(
+            codes::QUALITY_SHORT_CONTENT,
+            "b.yml",
+            "statement appears too short",
+        );
+        diagnostics
+    }
+
+    #[test]
+    fn allow_silences_and_downgrades_codes() {
+        let mut diagnostics = diagnostics();
+        let rules = [
+            AllowRule::parse("JLO-QUALITY-001").unwrap(),
+            AllowRule::parse("JLO-SCHEMA-005=warning").unwrap(),
+        ];
+
+        let silenced = diagnostics.apply_allow(&rules);
+
+        assert_eq!(silenced, 1);
+        assert_eq!(diagnostics.error_count(), 1);
+        assert_eq!(diagnostics.errors()[0].code, codes::SCHEMA_REQUIRED_FIELD);
+        assert_eq!(diagnostics.warning_count(), 1);
+        assert_eq!(diagnostics.warnings()[0].code, codes::SCHEMA_DATE_FORMAT);
+        assert!(matches!(diagnostics.warnings()[0].severity, Severity::Warning));
+    }
+
+    #[test]
+    fn allow_rejects_unknown_codes_and_modes() {
+        assert!(AllowRule::parse("JLO-NOPE-999").unwrap_err().to_string().contains("Unknown"));
+        assert!(AllowRule::parse("JLO-SCHEMA-005=error").is_err());
+    }
+
+    #[test]
+    fn merge_sorted_orders_by_file_then_message() {
+        let mut merged = Diagnostics::default();
+        merged.set_category(Category::Schema);
+        let mut late = merged.fork();
+        late.push_error(codes::SCHEMA_REQUIRED_FIELD, "b.yml", "title is required");
+        let mut early = merged.fork();
+        early.push_error(codes::SCHEMA_REQUIRED_FIELD, "a.yml", "title is required");
+        early.push_error(codes::SCHEMA_DATE_FORMAT, "a.yml", "created_at must be YYYY-MM-DD");
+
+        merged.merge_sorted(vec![late, early]);
+
+        let order: Vec<(&str, &str)> =
+            merged.errors().iter().map(|d| (d.file.as_str(), d.message.as_str())).collect();
+        assert_eq!(
+            order,
+            [
+                ("a.yml", "created_at must be YYYY-MM-DD"),
+                ("a.yml", "title is required"),
+                ("b.yml", "title is required"),
+            ]
+        );
+        assert!(merged.errors().iter().all(|d| d.category == Category::Schema));
+    }
+
+    #[test]
+    fn counts_by_code_tallies_errors_and_warnings() {
+        let mut diagnostics = diagnostics();
+        diagnostics.push_warning(
+            codes::QUALITY_SHORT_CONTENT,
+            "c.yml",
+            "statement appears too short",
+        );
+
+        let counts = diagnostics.counts_by_code();
+
+        assert_eq!(counts.get(codes::QUALITY_SHORT_CONTENT), Some(&2));
+        assert_eq!(counts.get(codes::SCHEMA_DATE_FORMAT), Some(&1));
+        assert_eq!(counts.get(codes::SCHEMA_REQUIRED_FIELD), Some(&1));
+        assert_eq!(counts.len(), 3);
+    }
+
+    #[test]
+    fn codes_are_unique() {
+        let unique: std::collections::HashSet<&str> = codes::ALL.iter().copied().collect();
+        assert_eq!(unique.len(), codes::ALL.len());
+    }
+}
@@ -1,27 +1,14 @@
 use serde_yaml::Mapping;
 use std::path::Path;
 
-use crate::app::commands::doctor::diagnostics::Diagnostics;
-use crate::app::commands::doctor::yaml::{
+use super::yaml::{
     ensure_enum, ensure_id, ensure_int, ensure_non_empty_string, get_sequence, get_string,
-    load_yaml_mapping,
+    is_valid_id,
 };
+use crate::app::validation::diagnostics::{Diagnostics, codes};
 
 use super::dates::ensure_date;
 
-pub fn validate_event_file(
-    path: &Path,
-    state: &str,
-    event_confidence: &[String],
-    diagnostics: &mut Diagnostics,
-) {
-    let data = match load_yaml_mapping(path, diagnostics) {
-        Some(data) => data,
-        None => return,
-    };
-    validate_event(&data, path, state, event_confidence, diagnostics);
-}
-
 pub fn validate_event(
     data: &Mapping,
     path: &Path,
@@ -35,16 +22,27 @@ pub fn validate_event(
     let requirement_id = match get_string(data, "requirement_id") {
         Some(value) => value,
         None => {
-            diagnostics.push_error(path.display().to_string(), "requirement_id is required");
+            diagnostics.push_error(
+                codes::SCHEMA_REQUIRED_FIELD,
+                path.display().to_string(),
+                "requirement_id is required",
+            );
             return;
         }
     };
     if state == "pending" && !requirement_id.is_empty() {
-        diagnostics
-            .push_error(path.display().to_string(), "requirement_id must be empty in pending");
+        diagnostics.push_error(
+            codes::SCHEMA_EVENT_STATE,
+            path.display().to_string(),
+            "requirement_id must be empty in pending",
+        );
     }
     if state == "decided" && requirement_id.is_empty() {
-        diagnostics.push_error(path.display().to_string(), "requirement_id must be set in decided");
+        diagnostics.push_error(
+            codes::SCHEMA_EVENT_STATE,
+            path.display().to_string(),
+            "requirement_id must be set in decided",
+        );
     }
 
     ensure_date(data, path, "created_at", diagnostics);
@@ -54,32 +52,69 @@ pub fn validate_event(
     ensure_non_empty_string(data, path, "title", diagnostics);
     ensure_non_empty_string(data, path, "statement", diagnostics);
 
+    let dedupe_key = data.get(serde_yaml::Value::String("dedupe_key".to_string()));
+    if dedupe_key.is_some_and(|value| !value.is_string()) {
+        diagnostics.push_error(
+            codes::SCHEMA_INVALID_VALUE,
+            path.display().to_string(),
+            "dedupe_key must be a string",
+        );
+    }
+    if data.contains_key(serde_yaml::Value::String("merged_events".to_string())) {
+        match get_sequence(data, "merged_events") {
+            Some(merged) => {
+                for (idx, entry) in merged.iter().enumerate() {
+                    if !entry.as_str().is_some_and(is_valid_id) {
+                        diagnostics.push_error(
+                            codes::SCHEMA_INVALID_VALUE,
+                            path.display().to_string(),
+                            format!("merged_events[{}] must be an event id", idx),
+                        );
+                    }
+                }
+            }
+            None => diagnostics.push_error(
+                codes::SCHEMA_INVALID_VALUE,
+                path.display().to_string(),
+                "merged_events must be a list of event ids",
+            ),
+        }
+    }
+
     if let Some(evidence) = get_sequence(data, "evidence") {
         if evidence.is_empty() {
-            diagnostics.push_error(path.display().to_string(), "evidence must have entries");
+            diagnostics.push_error(
+                codes::SCHEMA_REQUIRED_FIELD,
+                path.display().to_string(),
+                "evidence must have entries",
+            );
         } else {
             for (idx, entry) in evidence.iter().enumerate() {
                 if let serde_yaml::Value::Mapping(map) = entry {
                     if get_string(map, "path").unwrap_or_default().is_empty() {
                         diagnostics.push_error(
+                            codes::SCHEMA_REQUIRED_FIELD,
                             path.display().to_string(),
                             format!("evidence[{}].path is required", idx),
                         );
                     }
                     if get_sequence(map, "loc").map(|seq| seq.is_empty()).unwrap_or(true) {
                         diagnostics.push_error(
+                            codes::SCHEMA_REQUIRED_FIELD,
                             path.display().to_string(),
                             format!("evidence[{}].loc is required", idx),
                         );
                     }
                     if get_string(map, "note").unwrap_or_default().is_empty() {
                         diagnostics.push_error(
+                            codes::SCHEMA_REQUIRED_FIELD,
                             path.display().to_string(),
                             format!("evidence[{}].note is required", idx),
                         );
                     }
                 } else {
                     diagnostics.push_error(
+                        codes::SCHEMA_INVALID_VALUE,
                         path.display().to_string(),
                         format!("evidence[{}] must be a map", idx),
                     );
@@ -87,7 +122,11 @@ pub fn validate_event(
             }
         }
     } else {
-        diagnostics.push_error(path.display().to_string(), "Missing evidence list");
+        diagnostics.push_error(
+            codes::SCHEMA_REQUIRED_FIELD,
+            path.display().to_string(),
+            "Missing evidence list",
+        );
     }
 }
 
@@ -143,4 +182,38 @@ evidence: []
         assert!(diagnostics.error_count() > 0);
         // Should have errors: requirement_id must be empty in pending, evidence must have entries
     }
+
+    #[test]
+    fn validate_event_accepts_dedupe_fields_and_rejects_bad_merged_ids() {
+        let yaml = r#"
+schema_version: 1
+id: "abc123"
+requirement_id: ""
+created_at: "2023-10-27"
+author_role: "observer"
+confidence: "high"
+merged_events: ["def456", "Not-An-Id"]
+title: "Something happened"
+dedupe_key: "missing-error-context"
+statement: "Evidence suggests..."
+evidence:
+  - path: "src/main.rs"
+    loc: ["10-20"]
+    note: "See this"
+"#;
+        let data: Mapping = serde_yaml::from_str(yaml).unwrap();
+        let mut diagnostics = Diagnostics::default();
+
+        validate_event(
+            &data,
+            &PathBuf::from("test.yml"),
+            "pending",
+            &["high".to_string()],
+            &mut diagnostics,
+        );
+
+        let messages: Vec<&str> =
+            diagnostics.errors().iter().map(|diagnostic| diagnostic.message.as_str()).collect();
+        assert_eq!(messages, vec!["merged_events[1] must be an event id"]);
+    }
 }
@@ -0,0 +1,72 @@
+//! Schema validation shared by `doctor` and `run`.
+//!
+//! Validators work on parsed YAML mappings and report into [`Diagnostics`]; reading
+//! files is left to the caller. The `validate_*` functions below collapse the
+//! diagnostics into a single [`AppError`] for command paths that must fail fast.
+
+pub mod dates;
+pub mod diagnostics;
+pub mod events;
+pub mod requirements;
+pub mod roles;
+pub mod yaml;
+
+use std::path::Path;
+
+use crate::adapters::catalogs::scaffold_assets::{list_issue_labels, read_enum_values};
+use crate::domain::AppError;
+
+use self::diagnostics::Diagnostics;
+
+/// Validate a standalone observer role definition against the role schema.
+///
+/// `role` is the role name the definition is expected to declare; `label` names the
+/// definition in error messages.
+pub fn validate_observer_role(content: &str, label: &Path, role: &str) -> Result<(), AppError> {
+    let mut diagnostics = Diagnostics::default();
+    if let Some(data) = yaml::parse_yaml_mapping(content, label, &mut diagnostics) {
+        roles::validate_role(&data, label, Path::new(role), &mut diagnostics);
+    }
+    schema_result(&diagnostics, &format!("Role file {}", label.display()))
+}
+
+/// Validate an event body destined for `exchange/events/pending` against the event schema.
+///
+/// `label` names the event in error messages.
+pub fn validate_pending_event(data: &serde_yaml::Mapping, label: &str) -> Result<(), AppError> {
+    let event_confidence = read_enum_values(".jules/schemas/observers/event.yml", "confidence")?;
+    let mut diagnostics = Diagnostics::default();
+    events::validate_event(data, Path::new(label), "pending", &event_confidence, &mut diagnostics);
+    schema_result(&diagnostics, &format!("Event {}", label))
+}
+
+/// Validate a requirement document against the requirement schema.
+///
+/// `label` names the requirement in error messages.
+pub fn validate_requirement_document(
+    data: &serde_yaml::Mapping,
+    label: &str,
+) -> Result<(), AppError> {
+    let issue_labels = list_issue_labels()?;
+    let issue_priorities = read_enum_values(".jules/schemas/decider/requirements.yml", "priority")?;
+    let mut diagnostics = Diagnostics::default();
+    requirements::validate_requirement(
+        data,
+        Path::new(label),
+        &issue_labels,
+        &issue_priorities,
+        &mut diagnostics,
+    );
+    schema_result(&diagnostics, &format!("Requirement {}", label))
+}
+
+/// Collapse schema diagnostics into a single validation error naming `subject`.
+fn schema_result(diagnostics: &Diagnostics, subject: &str) -> Result<(), AppError> {
+    if !diagnostics.has_errors() {
+        return Ok(());
+    }
+
+    let messages: Vec<&str> =
+        diagnostics.errors().iter().map(|diagnostic| diagnostic.message.as_str()).collect();
+    Err(AppError::Validation(format!("{} is invalid: {}", subject, messages.join("; "))))
+}
@@ -0,0 +1,340 @@
+use serde_yaml::Mapping;
+use std::path::Path;
+
+use super::yaml::{
+    ensure_enum, ensure_id, ensure_int, ensure_non_empty_string, get_bool, get_sequence, get_string,
+};
+use crate::app::validation::diagnostics::{Diagnostics, codes};
+
+/// Fields an implementer needs. Always required to have entries; a ready requirement
+/// must also have no blank entries in them.
+const IMPLEMENTATION_READY_FIELDS: &[&str] =
+    &["affected_areas", "acceptance_criteria", "verification_criteria"];
+
+pub fn validate_requirement(
+    data: &Mapping,
+    path: &Path,
+    issue_labels: &[String],
+    issue_priorities: &[String],
+    diagnostics: &mut Diagnostics,
+) {
+    ensure_int(data, path, "schema_version", diagnostics, Some(2));
+    ensure_id(data, path, "id", diagnostics);
+    let source_events = get_sequence(data, "source_events");
+    if source_events.as_ref().map(|seq| seq.is_empty()).unwrap_or(true) {
+        diagnostics.push_error(
+            codes::SCHEMA_REQUIRED_FIELD,
+            path.display().to_string(),
+            "source_events must have entries",
+        );
+    } else if let Some(seq) = source_events {
+        for event_id in seq {
+            if let serde_yaml::Value::String(value) = event_id
+                && !super::yaml::is_valid_id(&value)
+            {
+                diagnostics.push_error(
+                    codes::SCHEMA_INVALID_VALUE,
+                    path.display().to_string(),
+                    format!("Invalid source_events id: {}", value),
+                );
+            }
+        }
+    }
+
+    ensure_non_empty_string(data, path, "title", diagnostics);
+    ensure_non_empty_string(data, path, "label", diagnostics);
+
+    let label_value = get_string(data, "label").unwrap_or_default();
+    if !label_value.is_empty() && !issue_labels.contains(&label_value) {
+        diagnostics.push_error(
+            codes::SCHEMA_INVALID_ENUM,
+            path.display().to_string(),
+            format!("label '{}' is not defined in github-labels.json", label_value),
+        );
+    }
+
+    let allowed: Vec<&str> = issue_priorities.iter().map(|value| value.as_str()).collect();
+    ensure_enum(data, path, "priority", &allowed, diagnostics);
+
+    ensure_non_empty_string(data, path, "summary", diagnostics);
+    ensure_non_empty_string(data, path, "goal", diagnostics);
+    ensure_non_empty_string(data, path, "problem", diagnostics);
+    ensure_non_empty_string(data, path, "impact", diagnostics);
+    ensure_non_empty_string(data, path, "desired_outcome", diagnostics);
+
+    for key in IMPLEMENTATION_READY_FIELDS {
+        if get_sequence(data, key).map(|seq| seq.is_empty()).unwrap_or(true) {
+            diagnostics.push_error(
+                codes::SCHEMA_REQUIRED_FIELD,
+                path.display().to_string(),
+                format!("{} must have entries", key),
+            );
+        }
+    }
+
+    let implementation_ready = match get_bool(data, "implementation_ready") {
+        Some(val) => val,
+        None => {
+            diagnostics.push_error(
+                codes::SCHEMA_REQUIRED_FIELD,
+                path.display().to_string(),
+                "implementation_ready is required (true/false)",
+            );
+            return;
+        }
+    };
+
+    // Empty lists are already reported above; a ready requirement must also not hand the
+    // implementer blank or non-text entries.
+    let blank: Vec<&str> = IMPLEMENTATION_READY_FIELDS
+        .iter()
+        .copied()
+        .filter(|key| {
+            get_sequence(data, key).is_some_and(|seq| {
+                seq.iter().any(|entry| entry.as_str().is_none_or(|text| text.trim().is_empty()))
+            })
+        })
+        .collect();
+    if implementation_ready && !blank.is_empty() {
+        diagnostics.push_error(
+            codes::SCHEMA_REQUIRED_FIELD,
+            path.display().to_string(),
+            format!(
+                "implementation_ready is true but these fields have blank entries: {}",
+                blank.join(", ")
+            ),
+        );
+    }
+
+    let planner_reason = get_string(data, "planner_request_reason").unwrap_or_default();
+    if !implementation_ready && planner_reason.trim().is_empty() {
+        diagnostics.push_error(
+            codes::SCHEMA_REQUIRED_FIELD,
+            path.display().to_string(),
+            "planner_request_reason required when implementation_ready is false",
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_validate_requirement_data_valid() {
+        let yaml = r#"
+schema_version: 2
+implementation_ready: true
+planner_request_reason: ""
+id: "abc123"
+source_events: ["ev1234"]
+title: "Bug fix"
+label: "bugs"
+priority: "high"
+summary: "Summary"
+goal: "Goal"
+problem: "Problem"
+impact: "Impact"
+desired_outcome: "Outcome"
+affected_areas: ["src/"]
+acceptance_criteria: ["Done"]
+verification_criteria: ["test commands"]
+"#;
+        let data: Mapping = serde_yaml::from_str(yaml).unwrap();
+        let path = PathBuf::from("test.yml");
+        let mut diagnostics = Diagnostics::default();
+        let labels = vec!["bugs".to_string()];
+        let priorities = vec!["high".to_string()];
+
+        validate_requirement(&data, &path, &labels, &priorities, &mut diagnostics);
+        assert_eq!(diagnostics.error_count(), 0);
+    }
+
+    #[test]
+    fn test_validate_requirement_missing_implementation_ready() {
+        let yaml = r#"
+schema_version: 2
+id: "abc123"
+source_events: ["ev1234"]
+title: "Bug fix"
+label: "bugs"
+priority: "high"
+summary: "Summary"
+goal: "Goal"
+problem: "Problem"
+impact: "Impact"
+desired_outcome: "Outcome"
+affected_areas: ["src/"]
+acceptance_criteria: ["Done"]
+verification_criteria: ["test commands"]
+"#;
+        let data: Mapping = serde_yaml::from_str(yaml).unwrap();
+        let path = PathBuf::from("test.yml");
+        let mut diagnostics = Diagnostics::default();
+        let labels = vec!["bugs".to_string()];
+        let priorities = vec!["high".to_string()];
+
+        validate_requirement(&data, &path, &labels, &priorities, &mut diagnostics);
+        assert!(diagnostics.error_count() > 0);
+        assert!(diagnostics.errors()[0].message.contains("implementation_ready is required"));
+    }
+
+    #[test]
+    fn test_validate_requirement_allows_reason_when_implementation_ready_true() {
+        let yaml = r#"
+schema_version: 2
+implementation_ready: true
+planner_request_reason: "History retained for context"
+id: "abc123"
+source_events: ["ev1234"]
+title: "Bug fix"
+label: "bugs"
+priority: "high"
+summary: "Summary"
+goal: "Goal"
+problem: "Problem"
+impact: "Impact"
+desired_outcome: "Outcome"
+affected_areas: ["src/"]
+acceptance_criteria: ["Done"]
+verification_criteria: ["test commands"]
+"#;
+        let data: Mapping = serde_yaml::from_str(yaml).unwrap();
+        let path = PathBuf::from("test.yml");
+        let mut diagnostics = Diagnostics::default();
+        let labels = vec!["bugs".to_string()];
+        let priorities = vec!["high".to_string()];
+
+        validate_requirement(&data, &path, &labels, &priorities, &mut diagnostics);
+        assert_eq!(diagnostics.error_count(), 0);
+    }
+
+    #[test]
+    fn test_validate_requirement_rejects_priority_outside_enum() {
+        let yaml = r#"
+schema_version: 2
+implementation_ready: true
+planner_request_reason: ""
+id: "abc123"
+source_events: ["ev1234"]
+title: "Bug fix"
+label: "bugs"
+priority: "urgent"
+summary: "Summary"
+goal: "Goal"
+problem: "Problem"
+impact: "Impact"
+desired_outcome: "Outcome"
+affected_areas: ["src/"]
+acceptance_criteria: ["Done"]
+verification_criteria: ["test commands"]
+"#;
+        let data: Mapping = serde_yaml::from_str(yaml).unwrap();
+        let path = PathBuf::from("test.yml");
+        let mut diagnostics = Diagnostics::default();
+        let labels = vec!["bugs".to_string()];
+        let priorities = vec!["low".to_string(), "medium".to_string(), "high".to_string()];
+
+        validate_requirement(&data, &path, &labels, &priorities, &mut diagnostics);
+        assert_eq!(diagnostics.error_count(), 1);
+        assert_eq!(
+            diagnostics.errors()[0].message,
+            "priority 'urgent' is invalid (allowed: low, medium, high)"
+        );
+    }
+
+    const PLANNER_PENDING: &str = r#"
+schema_version: 2
+implementation_ready: false
+planner_request_reason: "Needs dependency analysis"
+id: "abc123"
+source_events: ["ev1234"]
+title: "Bug fix"
+label: "bugs"
+priority: "high"
+summary: "Summary"
+goal: "Goal"
+problem: "Problem"
+impact: "Impact"
+desired_outcome: "Outcome"
+affected_areas: []
+"#;
+
+    fn validate(yaml: &str) -> Diagnostics {
+        let data: Mapping = serde_yaml::from_str(yaml).unwrap();
+        let mut diagnostics = Diagnostics::default();
+        validate_requirement(
+            &data,
+            &PathBuf::from("test.yml"),
+            &["bugs".to_string()],
+            &["high".to_string()],
+            &mut diagnostics,
+        );
+        diagnostics
+    }
+
+    #[test]
+    fn test_validate_requirement_not_ready_still_requires_planner_fields() {
+        let diagnostics = validate(PLANNER_PENDING);
+
+        let messages: Vec<&str> =
+            diagnostics.errors().iter().map(|error| error.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "affected_areas must have entries",
+                "acceptance_criteria must have entries",
+                "verification_criteria must have entries",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_requirement_ready_reports_each_missing_field_once() {
+        let yaml = PLANNER_PENDING
+            .replace("implementation_ready: false", "implementation_ready: true")
+            + "verification_criteria: [\"cargo test\"]\n";
+
+        let diagnostics = validate(&yaml);
+
+        let messages: Vec<&str> =
+            diagnostics.errors().iter().map(|error| error.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec!["affected_areas must have entries", "acceptance_criteria must have entries"]
+        );
+    }
+
+    const BLANK_ENTRIES: &str = r#"
+affected_areas: ["src/", ""]
+acceptance_criteria: ["Done"]
+verification_criteria: ["  "]
+"#;
+
+    #[test]
+    fn test_validate_requirement_ready_rejects_blank_entries() {
+        let yaml = PLANNER_PENDING
+            .replace("implementation_ready: false", "implementation_ready: true")
+            .replace("affected_areas: []\n", BLANK_ENTRIES);
+
+        let diagnostics = validate(&yaml);
+
+        let messages: Vec<&str> =
+            diagnostics.errors().iter().map(|error| error.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "implementation_ready is true but these fields have blank entries: \
+                 affected_areas, verification_criteria"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_requirement_not_ready_allows_blank_entries() {
+        let yaml = PLANNER_PENDING.replace("affected_areas: []\n", BLANK_ENTRIES);
+
+        assert_eq!(validate(&yaml).error_count(), 0);
+    }
+}
@@ -1,27 +1,21 @@
 use serde_yaml::Mapping;
 use std::path::Path;
 
-use crate::app::commands::doctor::diagnostics::Diagnostics;
-use crate::app::commands::doctor::yaml::{
-    ensure_non_empty_sequence, ensure_non_empty_string, get_string, load_yaml_mapping,
-};
+use super::yaml::{ensure_non_empty_sequence, ensure_non_empty_string, get_string};
+use crate::app::validation::diagnostics::{Diagnostics, codes};
 use crate::domain::Layer;
 
-pub fn validate_role_file(path: &Path, role_dir: &Path, diagnostics: &mut Diagnostics) {
-    let data = match load_yaml_mapping(path, diagnostics) {
-        Some(data) => data,
-        None => return,
-    };
-    validate_role(&data, path, role_dir, diagnostics);
-}
-
 pub fn validate_role(data: &Mapping, path: &Path, role_dir: &Path, diagnostics: &mut Diagnostics) {
     ensure_non_empty_string(data, path, "role", diagnostics);
 
     // Check layer field
     let layer_value = get_string(data, "layer").unwrap_or_default();
     if layer_value != Layer::Observers.dir_name() {
-        diagnostics.push_error(path.display().to_string(), "layer must be 'observers'");
+        diagnostics.push_error(
+            codes::SCHEMA_MISMATCH,
+            path.display().to_string(),
+            "layer must be 'observers'",
+        );
     }
     validate_constraint(data, path, diagnostics);
 
@@ -32,10 +26,18 @@ pub fn validate_role(data: &Mapping, path: &Path, role_dir: &Path, diagnostics:
             ensure_non_empty_sequence(profile_map, path, "analysis_points", diagnostics);
         }
         Some(_) => {
-            diagnostics.push_error(path.display().to_string(), "'profile' must be a mapping");
+            diagnostics.push_error(
+                codes::SCHEMA_INVALID_VALUE,
+                path.display().to_string(),
+                "'profile' must be a mapping",
+            );
         }
         None => {
-            diagnostics.push_error(path.display().to_string(), "Missing profile section");
+            diagnostics.push_error(
+                codes::SCHEMA_REQUIRED_FIELD,
+                path.display().to_string(),
+                "Missing profile section",
+            );
         }
     }
 
@@ -43,25 +45,30 @@ pub fn validate_role(data: &Mapping, path: &Path, role_dir: &Path, diagnostics:
     let role_value = get_string(data, "role").unwrap_or_default();
     if !role_value.is_empty() && role_value != role_name {
         diagnostics.push_error(
+            codes::SCHEMA_MISMATCH,
             path.display().to_string(),
             format!("role '{}' does not match directory '{}'", role_value, role_name),
         );
     }
 }
 
-pub fn validate_innovator_role_file(path: &Path, role_dir: &Path, diagnostics: &mut Diagnostics) {
-    let data = match load_yaml_mapping(path, diagnostics) {
-        Some(data) => data,
-        None => return,
-    };
-
-    ensure_non_empty_string(&data, path, "role", diagnostics);
+pub fn validate_innovator_role(
+    data: &Mapping,
+    path: &Path,
+    role_dir: &Path,
+    diagnostics: &mut Diagnostics,
+) {
+    ensure_non_empty_string(data, path, "role", diagnostics);
 
-    let layer_value = get_string(&data, "layer").unwrap_or_default();
+    let layer_value = get_string(data, "layer").unwrap_or_default();
     if layer_value != Layer::Innovators.dir_name() {
-        diagnostics.push_error(path.display().to_string(), "layer must be 'innovators'");
+        diagnostics.push_error(
+            codes::SCHEMA_MISMATCH,
+            path.display().to_string(),
+            "layer must be 'innovators'",
+        );
     }
-    validate_constraint(&data, path, diagnostics);
+    validate_constraint(data, path, diagnostics);
 
     match data.get("profile") {
         Some(serde_yaml::Value::Mapping(profile_map)) => {
@@ -74,17 +81,26 @@ pub fn validate_innovator_role_file(path: &Path, role_dir: &Path, diagnostics: &
             ensure_non_empty_sequence(profile_map, path, "proposal_quality_bar", diagnostics);
         }
         Some(_) => {
-            diagnostics.push_error(path.display().to_string(), "'profile' must be a mapping");
+            diagnostics.push_error(
+                codes::SCHEMA_INVALID_VALUE,
+                path.display().to_string(),
+                "'profile' must be a mapping",
+            );
         }
         None => {
-            diagnostics.push_error(path.display().to_string(), "Missing profile section");
+            diagnostics.push_error(
+                codes::SCHEMA_REQUIRED_FIELD,
+                path.display().to_string(),
+                "Missing profile section",
+            );
         }
     }
 
     let role_name = role_dir.file_name().and_then(|n| n.to_str()).unwrap_or("");
-    let role_value = get_string(&data, "role").unwrap_or_default();
+    let role_value = get_string(data, "role").unwrap_or_default();
     if !role_value.is_empty() && role_value != role_name {
         diagnostics.push_error(
+            codes::SCHEMA_MISMATCH,
             path.display().to_string(),
             format!("role '{}' does not match directory '{}'", role_value, role_name),
         );
@@ -97,6 +113,7 @@ fn validate_constraint(data: &Mapping, path: &Path, diagnostics: &mut Diagnostic
             for (index, item) in items.iter().enumerate() {
                 if !item.is_string() {
                     diagnostics.push_error(
+                        codes::SCHEMA_INVALID_VALUE,
                         path.display().to_string(),
                         format!("constraint[{}] must be a string", index),
                     );
@@ -104,10 +121,18 @@ fn validate_constraint(data: &Mapping, path: &Path, diagnostics: &mut Diagnostic
             }
         }
         Some(_) => {
-            diagnostics.push_error(path.display().to_string(), "'constraint' must be a sequence");
+            diagnostics.push_error(
+                codes::SCHEMA_INVALID_VALUE,
+                path.display().to_string(),
+                "'constraint' must be a sequence",
+            );
         }
         None => {
-            diagnostics.push_error(path.display().to_string(), "Missing constraint section");
+            diagnostics.push_error(
+                codes::SCHEMA_REQUIRED_FIELD,
+                path.display().to_string(),
+                "Missing constraint section",
+            );
         }
     }
 }
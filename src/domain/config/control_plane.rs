@@ -5,10 +5,11 @@ use url::Url;
 
 use crate::domain::AppError;
 use crate::domain::config::error::ConfigError;
-use crate::domain::config::schedule::Schedule;
+use crate::domain::config::schedule::{Schedule, ScheduleLayer};
 
 /// Configuration for agent execution loaded from `.jlo/config.toml`.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(from = "ControlPlaneConfigFile")]
 pub struct ControlPlaneConfig {
     /// Execution configuration.
     #[serde(default)]
@@ -22,16 +23,53 @@ pub struct ControlPlaneConfig {
     #[serde(default)]
     #[allow(dead_code)]
     pub workflow: WorkflowTimingConfig,
+    /// Implementer layer configuration.
+    #[serde(default)]
+    pub implementer: ImplementerConfig,
     /// Schedule configuration flattened at top-level (`[observers]`, `[innovators]`).
     #[serde(default, flatten)]
     pub schedule: Schedule,
 }
 
+/// On-disk layout of `.jlo/config.toml`.
+///
+/// Schedule sections are named fields rather than a flattened `Schedule` so
+/// deserialization errors keep the source span of the offending entry.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ControlPlaneConfigFile {
+    #[serde(default)]
+    run: ExecutionConfig,
+    #[serde(default)]
+    jules_api: JulesApiConfig,
+    #[serde(default)]
+    workflow: WorkflowTimingConfig,
+    #[serde(default)]
+    implementer: ImplementerConfig,
+    #[serde(default)]
+    observers: ScheduleLayer,
+    #[serde(default)]
+    innovators: Option<ScheduleLayer>,
+}
+
+impl From<ControlPlaneConfigFile> for ControlPlaneConfig {
+    fn from(file: ControlPlaneConfigFile) -> Self {
+        Self {
+            run: file.run,
+            jules_api: file.jules_api,
+            workflow: file.workflow,
+            implementer: file.implementer,
+            schedule: Schedule { observers: file.observers, innovators: file.innovators },
+        }
+    }
+}
+
 impl ControlPlaneConfig {
     pub fn validate(&self) -> Result<(), AppError> {
         self.run.validate()?;
         self.jules_api.validate()?;
         self.workflow.validate()?;
+        self.implementer.validate()?;
         self.schedule.validate()?;
         Ok(())
     }
@@ -57,6 +95,9 @@ pub struct JulesApiConfig {
     /// Delay between retries in milliseconds.
     #[serde(default = "default_retry_delay_ms")]
     pub retry_delay_ms: u64,
+    /// Maximum assembled prompt length (in characters) sent to the API.
+    #[serde(default = "default_max_prompt_chars")]
+    pub max_prompt_chars: usize,
 }
 
 impl Default for JulesApiConfig {
@@ -66,20 +107,45 @@ impl Default for JulesApiConfig {
             timeout_secs: default_timeout(),
             max_retries: default_max_retries(),
             retry_delay_ms: default_retry_delay_ms(),
+            max_prompt_chars: default_max_prompt_chars(),
         }
     }
 }
 
+/// Upper bound for `jules_api.max_retries`; larger values stall runs for minutes.
+pub const MAX_JULES_API_RETRIES: u32 = 10;
+
 impl JulesApiConfig {
     pub fn validate(&self) -> Result<(), ConfigError> {
         if self.timeout_secs == 0 {
-            return Err(ConfigError::Invalid("timeout_secs must be greater than 0".to_string()));
+            return Err(ConfigError::Invalid(format!(
+                "jules_api.timeout_secs must be greater than 0 (default: {}). Set a positive number of seconds in [jules_api] of .jlo/config.toml.",
+                default_timeout()
+            )));
         }
         if self.max_retries == 0 {
-            return Err(ConfigError::Invalid("max_retries must be greater than 0".to_string()));
+            return Err(ConfigError::Invalid(format!(
+                "jules_api.max_retries must be greater than 0 (default: {}).",
+                default_max_retries()
+            )));
+        }
+        if self.max_retries > MAX_JULES_API_RETRIES {
+            return Err(ConfigError::Invalid(format!(
+                "jules_api.max_retries = {} is too high (maximum: {}). Lower it in [jules_api] of .jlo/config.toml.",
+                self.max_retries, MAX_JULES_API_RETRIES
+            )));
+        }
+        if self.max_prompt_chars == 0 {
+            return Err(ConfigError::Invalid(format!(
+                "jules_api.max_prompt_chars must be greater than 0 (default: {}).",
+                default_max_prompt_chars()
+            )));
         }
         if self.retry_delay_ms == 0 {
-            return Err(ConfigError::Invalid("retry_delay_ms must be greater than 0".to_string()));
+            return Err(ConfigError::Invalid(format!(
+                "jules_api.retry_delay_ms must be greater than 0 (default: {}).",
+                default_retry_delay_ms()
+            )));
         }
         Ok(())
     }
@@ -102,6 +168,10 @@ fn default_retry_delay_ms() -> u64 {
     1000
 }
 
+fn default_max_prompt_chars() -> usize {
+    100_000
+}
+
 /// Execution configuration for agent runs.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
@@ -112,6 +182,13 @@ pub struct ExecutionConfig {
     /// Branch where .jules/ runtime repository resides (worker).
     #[serde(default = "default_jules_worker_branch")]
     pub jules_worker_branch: String,
+    /// Worker-pool size for parallel execution paths; 1 runs sequentially. Unset leaves
+    /// each path its own default.
+    #[serde(default)]
+    pub max_concurrency: Option<usize>,
+    /// Abort an integrator run with more candidate branches than this; unset means no cap.
+    #[serde(default)]
+    pub integrator_max_prs: Option<usize>,
 }
 
 impl Default for ExecutionConfig {
@@ -119,10 +196,15 @@ impl Default for ExecutionConfig {
         Self {
             jlo_target_branch: default_jlo_target_branch(),
             jules_worker_branch: default_jules_worker_branch(),
+            max_concurrency: None,
+            integrator_max_prs: None,
         }
     }
 }
 
+/// Environment variable overriding `run.max_concurrency`.
+pub const MAX_CONCURRENCY_ENV: &str = "JLO_MAX_CONCURRENCY";
+
 impl ExecutionConfig {
     pub fn validate(&self) -> Result<(), ConfigError> {
         if self.jlo_target_branch.trim().is_empty() {
@@ -131,8 +213,45 @@ impl ExecutionConfig {
         if self.jules_worker_branch.trim().is_empty() {
             return Err(ConfigError::Invalid("jules_worker_branch must not be empty".to_string()));
         }
+        if self.max_concurrency == Some(0) {
+            return Err(ConfigError::Invalid(
+                "run.max_concurrency must be greater than 0 (1 runs sequentially).".to_string(),
+            ));
+        }
+        if self.integrator_max_prs == Some(0) {
+            return Err(ConfigError::Invalid(
+                "run.integrator_max_prs must be greater than 0.".to_string(),
+            ));
+        }
         Ok(())
     }
+
+    /// Worker-pool size for a parallel path: a per-command `flag` wins, then the
+    /// `JLO_MAX_CONCURRENCY` value in `env`, then `run.max_concurrency`, then the
+    /// path's own `fallback`.
+    pub fn effective_max_concurrency(
+        &self,
+        flag: Option<usize>,
+        env: Option<&str>,
+        fallback: usize,
+    ) -> Result<usize, ConfigError> {
+        let value = match (flag, env) {
+            (Some(flag), _) => flag,
+            (None, Some(raw)) => raw.trim().parse().map_err(|_| {
+                ConfigError::Invalid(format!(
+                    "{} must be a positive integer, got '{}'.",
+                    MAX_CONCURRENCY_ENV, raw
+                ))
+            })?,
+            (None, None) => self.max_concurrency.unwrap_or(fallback),
+        };
+        if value == 0 {
+            return Err(ConfigError::Invalid(
+                "max concurrency must be greater than 0 (1 runs sequentially).".to_string(),
+            ));
+        }
+        Ok(value)
+    }
 }
 
 fn default_jlo_target_branch() -> String {
@@ -166,6 +285,12 @@ impl WorkflowTimingConfig {
                         "workflow.cron entries must be non-empty strings.".to_string(),
                     ));
                 }
+                if let Err(reason) = super::cron::validate_cron(cron) {
+                    return Err(ConfigError::Invalid(format!(
+                        "workflow.cron entry '{}' is invalid: {}.",
+                        cron, reason
+                    )));
+                }
             }
         }
         if let Some(wait) = self.wait_minutes_default
@@ -179,6 +304,28 @@ impl WorkflowTimingConfig {
     }
 }
 
+/// Implementer layer configuration.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ImplementerConfig {
+    /// Minijinja template for implementer PR bodies; unset keeps the built-in body.
+    pub pr_body_template: Option<String>,
+}
+
+impl ImplementerConfig {
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if let Some(ref template) = self.pr_body_template
+            && let Err(reason) = super::pr_body_template::validate_pr_body_template(template)
+        {
+            return Err(ConfigError::Invalid(format!(
+                "implementer.pr_body_template is invalid: {}.",
+                reason
+            )));
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,6 +347,23 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn max_concurrency_resolves_flag_then_env_then_config() {
+        let config = ExecutionConfig { max_concurrency: Some(4), ..Default::default() };
+
+        assert_eq!(config.effective_max_concurrency(None, None, 8).unwrap(), 4);
+        assert_eq!(config.effective_max_concurrency(None, Some("2"), 8).unwrap(), 2);
+        assert_eq!(config.effective_max_concurrency(Some(1), Some("2"), 8).unwrap(), 1);
+        assert!(config.effective_max_concurrency(None, Some("many"), 8).is_err());
+        assert!(config.effective_max_concurrency(Some(0), None, 8).is_err());
+    }
+
+    #[test]
+    fn validate_execution_config_rejects_zero_max_concurrency() {
+        let config = ExecutionConfig { max_concurrency: Some(0), ..Default::default() };
+        assert!(config.validate().unwrap_err().to_string().contains("run.max_concurrency"));
+    }
+
     #[test]
     fn validate_jules_config_invalid_timeout() {
         let config = JulesApiConfig { timeout_secs: 0, ..Default::default() };
@@ -212,6 +376,58 @@ mod tests {
         assert!(config.validate().is_err());
     }
 
+    #[test]
+    fn validate_jules_config_rejects_excessive_max_retries() {
+        let config = JulesApiConfig { max_retries: 1000, ..Default::default() };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("jules_api.max_retries = 1000"), "{}", err);
+        assert!(err.contains(&format!("maximum: {}", MAX_JULES_API_RETRIES)), "{}", err);
+
+        let config = JulesApiConfig { max_retries: MAX_JULES_API_RETRIES, ..Default::default() };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_workflow_cron_entries() {
+        let config = WorkflowTimingConfig {
+            cron: Some(vec!["0 20 * * *".to_string()]),
+            ..Default::default()
+        };
+        assert!(config.validate().is_ok());
+
+        let config = WorkflowTimingConfig {
+            cron: Some(vec!["0 20 * * *".to_string(), "0 99 * * *".to_string()]),
+            ..Default::default()
+        };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("workflow.cron entry '0 99 * * *' is invalid"), "{}", err);
+        assert!(err.contains("hour value 99 is out of range 0-23"), "{}", err);
+    }
+
+    #[test]
+    fn validate_rejects_zero_integrator_max_prs() {
+        let config = ExecutionConfig { integrator_max_prs: Some(0), ..Default::default() };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("run.integrator_max_prs must be greater than 0"), "{}", err);
+
+        let config = ExecutionConfig { integrator_max_prs: Some(3), ..Default::default() };
+        assert!(config.validate().is_ok());
+    }
+
+    #[test]
+    fn validate_implementer_pr_body_template() {
+        let config = ImplementerConfig {
+            pr_body_template: Some("Requirement {{ requirement_id }}".to_string()),
+        };
+        assert!(config.validate().is_ok());
+
+        let config = ImplementerConfig {
+            pr_body_template: Some("{% for e in source_events %}".to_string()),
+        };
+        let err = config.validate().unwrap_err().to_string();
+        assert!(err.contains("implementer.pr_body_template is invalid"), "{}", err);
+    }
+
     #[test]
     fn validate_jules_config_invalid_retry_delay() {
         let config = JulesApiConfig { retry_delay_ms: 0, ..Default::default() };
@@ -230,7 +446,7 @@ mod tests {
         config.jules_api.timeout_secs = 0;
         let err = config.validate().unwrap_err();
         assert!(
-            matches!(err, AppError::Config(ConfigError::Invalid(ref msg)) if msg.contains("timeout_secs"))
+            matches!(err, AppError::Config(ConfigError::Invalid(ref msg)) if msg.contains("jules_api.timeout_secs must be greater than 0"))
         );
     }
 }
@@ -0,0 +1,108 @@
+//! Five-field cron expression validation for `[workflow] cron`.
+//!
+//! Accepts the syntax GitHub Actions schedules support: `*`, values, ranges,
+//! steps, comma lists, and three-letter month/weekday names.
+
+const MONTH_NAMES: [&str; 12] =
+    ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+const WEEKDAY_NAMES: [&str; 7] = ["sun", "mon", "tue", "wed", "thu", "fri", "sat"];
+
+struct Field {
+    name: &'static str,
+    min: u32,
+    max: u32,
+    /// Accepted names, mapped to values starting at `min`.
+    names: &'static [&'static str],
+}
+
+const FIELDS: [Field; 5] = [
+    Field { name: "minute", min: 0, max: 59, names: &[] },
+    Field { name: "hour", min: 0, max: 23, names: &[] },
+    Field { name: "day-of-month", min: 1, max: 31, names: &[] },
+    Field { name: "month", min: 1, max: 12, names: &MONTH_NAMES },
+    Field { name: "day-of-week", min: 0, max: 7, names: &WEEKDAY_NAMES },
+];
+
+/// Validate a five-field cron expression, describing the first problem found.
+pub fn validate_cron(expression: &str) -> Result<(), String> {
+    let parts: Vec<&str> = expression.split_whitespace().collect();
+    if parts.len() != FIELDS.len() {
+        return Err(format!("expected 5 fields, found {}", parts.len()));
+    }
+    for (part, field) in parts.iter().zip(FIELDS.iter()) {
+        for item in part.split(',') {
+            validate_item(item, field)?;
+        }
+    }
+    Ok(())
+}
+
+fn validate_item(item: &str, field: &Field) -> Result<(), String> {
+    let (range, step) = match item.split_once('/') {
+        Some((range, step)) => (range, Some(step)),
+        None => (item, None),
+    };
+    if let Some(step) = step {
+        match step.parse::<u32>() {
+            Ok(value) if value > 0 => {}
+            _ => return Err(format!("{} step '{}' must be a positive integer", field.name, step)),
+        }
+    }
+    if range == "*" {
+        return Ok(());
+    }
+    match range.split_once('-') {
+        Some((start, end)) => {
+            let start = parse_value(start, field)?;
+            let end = parse_value(end, field)?;
+            if start > end {
+                return Err(format!("{} range '{}' is reversed", field.name, range));
+            }
+        }
+        None => {
+            parse_value(range, field)?;
+        }
+    }
+    Ok(())
+}
+
+fn parse_value(value: &str, field: &Field) -> Result<u32, String> {
+    let lowered = value.to_ascii_lowercase();
+    if let Some(index) = field.names.iter().position(|name| *name == lowered) {
+        return Ok(field.min + index as u32);
+    }
+    let parsed = value
+        .parse::<u32>()
+        .map_err(|_| format!("{} value '{}' is not a number", field.name, value))?;
+    if parsed < field.min || parsed > field.max {
+        return Err(format!(
+            "{} value {} is out of range {}-{}",
+            field.name, parsed, field.min, field.max
+        ));
+    }
+    Ok(parsed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_common_expressions() {
+        for expression in
+            ["0 20 * * *", "*/15 * * * *", "0 12 * * 1-5", "30 6 1,15 * *", "0 0 * jan-mar sun"]
+        {
+            assert!(validate_cron(expression).is_ok(), "{}", expression);
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_expressions() {
+        assert_eq!(validate_cron("0 99 * * *").unwrap_err(), "hour value 99 is out of range 0-23");
+        assert_eq!(validate_cron("0 20 * *").unwrap_err(), "expected 5 fields, found 4");
+        assert!(validate_cron("*/0 * * * *").unwrap_err().contains("step"));
+        assert!(validate_cron("0 5-1 * * *").unwrap_err().contains("reversed"));
+        assert!(validate_cron("0 0 0 * *").unwrap_err().contains("day-of-month"));
+        assert!(validate_cron("0 0 * * funday").unwrap_err().contains("not a number"));
+    }
+}
@@ -3,4 +3,11 @@
 pub enum ConfigError {
     #[error("Invalid configuration: {0}")]
     Invalid(String),
+
+    /// A config entry failed to parse, located by file and dotted key path.
+    #[error(
+        "Invalid configuration in {file}{}: {detail}",
+        .key.as_deref().map(|key| format!(" at '{}'", key)).unwrap_or_default()
+    )]
+    Entry { file: String, key: Option<String>, detail: String },
 }
@@ -0,0 +1,202 @@
+//! JSON Schema for `.jlo/config.toml`, mirroring `ControlPlaneConfig`.
+//!
+//! Every key has a parser default unless listed under `required`; the test
+//! module keeps the property set aligned with the serialized config.
+
+use serde_json::{Value, json};
+
+/// JSON Schema (draft 2020-12) describing `.jlo/config.toml`.
+pub fn control_plane_config_schema() -> Value {
+    json!({
+        "$schema": "https://json-schema.org/draft/2020-12/schema",
+        "title": "jlo control-plane configuration (.jlo/config.toml)",
+        "type": "object",
+        "additionalProperties": false,
+        "properties": {
+            "run": {
+                "description": "Execution configuration.",
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "jlo_target_branch": {
+                        "description": "Target branch for agent operations (base for PRs).",
+                        "type": "string",
+                        "minLength": 1,
+                        "default": "main"
+                    },
+                    "jules_worker_branch": {
+                        "description": "Branch where the .jules/ runtime repository resides.",
+                        "type": "string",
+                        "minLength": 1,
+                        "default": "jules"
+                    },
+                    "max_concurrency": {
+                        "description": "Worker-pool size for parallel execution paths; 1 runs sequentially. Unset leaves each path its own default.",
+                        "type": "integer",
+                        "minimum": 1
+                    },
+                    "integrator_max_prs": {
+                        "description": "Abort an integrator run when more implementer branches than this are candidates.",
+                        "type": "integer",
+                        "minimum": 1
+                    }
+                }
+            },
+            "jules_api": {
+                "description": "Jules API configuration.",
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "api_url": {
+                        "description": "Jules API endpoint URL.",
+                        "type": "string",
+                        "format": "uri",
+                        "default": "https://jules.googleapis.com/v1alpha/sessions"
+                    },
+                    "timeout_secs": {
+                        "description": "Request timeout in seconds.",
+                        "type": "integer",
+                        "minimum": 1,
+                        "default": 30
+                    },
+                    "max_retries": {
+                        "description": "Maximum retry attempts.",
+                        "type": "integer",
+                        "minimum": 1,
+                        "maximum": super::control_plane::MAX_JULES_API_RETRIES,
+                        "default": 3
+                    },
+                    "retry_delay_ms": {
+                        "description": "Delay between retries in milliseconds.",
+                        "type": "integer",
+                        "minimum": 1,
+                        "default": 1000
+                    },
+                    "max_prompt_chars": {
+                        "description": "Maximum assembled prompt length (in characters) sent to the API.",
+                        "type": "integer",
+                        "minimum": 1,
+                        "default": 100000
+                    }
+                }
+            },
+            "workflow": {
+                "description": "Workflow timing configuration.",
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "runner_mode": {
+                        "description": "GitHub Actions runner: `remote` (ubuntu-latest), `self-hosted`, or a runner label.",
+                        "type": "string"
+                    },
+                    "cron": {
+                        "description": "Five-field cron expressions for the scheduled workflow.",
+                        "type": "array",
+                        "minItems": 1,
+                        "items": { "type": "string", "minLength": 1 }
+                    },
+                    "wait_minutes_default": {
+                        "description": "Default minutes the scheduled workflow waits between layers.",
+                        "type": "integer",
+                        "minimum": 1
+                    }
+                }
+            },
+            "implementer": {
+                "description": "Implementer layer configuration.",
+                "type": "object",
+                "additionalProperties": false,
+                "properties": {
+                    "pr_body_template": {
+                        "description": "Minijinja template for implementer PR bodies (`requirement_id`, `source_events`, `branch`). Real sessions are instructed to use the rendered body.",
+                        "type": "string"
+                    }
+                }
+            },
+            "observers": schedule_layer_schema("Observer roles scheduled by the workflow."),
+            "innovators": schedule_layer_schema("Innovator roles scheduled by the workflow.")
+        }
+    })
+}
+
+fn schedule_layer_schema(description: &str) -> Value {
+    json!({
+        "description": description,
+        "type": "object",
+        "additionalProperties": false,
+        "properties": {
+            "roles": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "additionalProperties": false,
+                    "required": ["name", "enabled"],
+                    "properties": {
+                        "name": {
+                            "type": "string",
+                            "pattern": "^[a-z0-9_-]+$"
+                        },
+                        "enabled": { "type": "boolean" }
+                    }
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeSet;
+
+    use super::*;
+    use crate::domain::config::control_plane::ExecutionConfig;
+    use crate::domain::config::parse::parse_config_content;
+
+    fn keys(value: &Value) -> BTreeSet<String> {
+        value.as_object().unwrap().keys().cloned().collect()
+    }
+
+    /// A config with every optional section present, so each serializes its full key set.
+    fn populated_config() -> Value {
+        let config = parse_config_content(
+            r#"[implementer]
+pr_body_template = "{{ requirement_id }}"
+
+[observers]
+roles = [{ name = "taxonomy", enabled = true }]
+
+[innovators]
+roles = [{ name = "recruiter", enabled = false }]
+"#,
+        )
+        .unwrap();
+        serde_json::to_value(config).unwrap()
+    }
+
+    #[test]
+    fn schema_describes_run_branch_fields() {
+        let schema = control_plane_config_schema();
+        let run = &schema["properties"]["run"]["properties"];
+        let defaults = ExecutionConfig::default();
+
+        assert_eq!(run["jlo_target_branch"]["default"], defaults.jlo_target_branch);
+        assert_eq!(run["jules_worker_branch"]["default"], defaults.jules_worker_branch);
+    }
+
+    #[test]
+    fn schema_properties_match_config_definition() {
+        let schema = control_plane_config_schema();
+        let config = populated_config();
+
+        assert_eq!(keys(&schema["properties"]), keys(&config));
+        for (section, fields) in config.as_object().unwrap() {
+            let section_schema = &schema["properties"][section];
+            assert_eq!(keys(&section_schema["properties"]), keys(fields), "{}", section);
+            if let Some(role) = fields.get("roles").and_then(|roles| roles.get(0)) {
+                let item = &section_schema["properties"]["roles"]["items"];
+                assert_eq!(keys(&item["properties"]), keys(role), "{}.roles", section);
+                assert_eq!(item["required"], json!(["name", "enabled"]), "{}.roles", section);
+            }
+        }
+    }
+}
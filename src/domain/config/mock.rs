@@ -1,9 +1,57 @@
 //! Mock execution configuration and output types.
 
 use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use chrono::{DateTime, TimeZone, Utc};
 
 use crate::domain::{AppError, Layer};
 
+/// Fixed instant reported by seeded mock clocks (2026-01-01T00:00:00Z).
+const SEEDED_EPOCH_SECS: i64 = 1_767_225_600;
+
+/// Source of timestamps and ids for mock artifacts.
+///
+/// The clock never reads system time: the caller supplies the instant and the id
+/// seed, so two runs built from the same inputs produce identical branches and files.
+#[derive(Debug, Clone)]
+pub struct MockClock {
+    now: DateTime<Utc>,
+    seed: u64,
+    counter: Arc<AtomicU64>,
+}
+
+impl MockClock {
+    /// Clock reporting `now` and an id sequence derived from `seed`.
+    pub fn new(now: DateTime<Utc>, seed: u64) -> Self {
+        Self { now, seed, counter: Arc::new(AtomicU64::new(0)) }
+    }
+
+    /// Clock fixed at the seeded epoch, for `--mock-seed` runs.
+    pub fn seeded(seed: u64) -> Self {
+        Self::new(Utc.timestamp_opt(SEEDED_EPOCH_SECS, 0).single().unwrap_or_default(), seed)
+    }
+
+    /// Instant stamped on mock artifacts.
+    pub fn now(&self) -> DateTime<Utc> {
+        self.now
+    }
+
+    /// Generate the next 6-character mock ID.
+    pub fn next_id(&self) -> String {
+        let index = self.counter.fetch_add(1, Ordering::Relaxed);
+        format!("{:06x}", splitmix64(self.seed.wrapping_add(index)) % 0xFFFFFF)
+    }
+}
+
+fn splitmix64(mut x: u64) -> u64 {
+    x = x.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    x ^ (x >> 31)
+}
+
 /// Configuration for mock execution, loaded from repository files.
 #[derive(Debug, Clone)]
 pub struct MockConfig {
@@ -17,9 +65,27 @@ pub struct MockConfig {
     pub jules_worker_branch: String,
     /// Allowed issue labels, from github-labels.json.
     pub issue_labels: Vec<String>,
+    /// Timestamp and id source for generated artifacts.
+    pub clock: MockClock,
+    /// Role or layer name whose mock run fails with a simulated API error.
+    pub fail_target: Option<String>,
 }
 
 impl MockConfig {
+    /// Return a simulated Jules API error when `fail_target` names this layer or role.
+    pub fn simulated_failure(&self, layer: Layer, role: Option<&str>) -> Result<(), AppError> {
+        let Some(target) = self.fail_target.as_deref() else {
+            return Ok(());
+        };
+        if target != layer.dir_name() && Some(target) != role {
+            return Ok(());
+        }
+        Err(AppError::JulesApiError {
+            message: format!("simulated mock failure for '{}'", target),
+            status: Some(503),
+        })
+    }
+
     /// Generate branch name for a layer with mock tag embedded.
     pub fn branch_prefix(&self, layer: Layer) -> Result<&str, AppError> {
         self.branch_prefixes.get(&layer).map(|s| s.as_str()).ok_or_else(|| {
@@ -73,6 +139,8 @@ mod tests {
             jlo_target_branch: "main".to_string(),
             jules_worker_branch: "jules".to_string(),
             issue_labels: vec!["bugs".to_string()],
+            clock: MockClock::seeded(0),
+            fail_target: None,
         };
 
         assert_eq!(
@@ -93,6 +161,8 @@ mod tests {
             jlo_target_branch: "main".to_string(),
             jules_worker_branch: "jules".to_string(),
             issue_labels: vec![],
+            clock: MockClock::seeded(0),
+            fail_target: None,
         };
 
         assert_eq!(config.base_branch(Layer::Observers), "jules");
@@ -100,4 +170,49 @@ mod tests {
         assert_eq!(config.base_branch(Layer::Planner), "jules");
         assert_eq!(config.base_branch(Layer::Implementer), "main");
     }
+
+    #[test]
+    fn simulated_failure_matches_layer_or_role() {
+        let config = MockConfig {
+            mock_tag: "test".to_string(),
+            branch_prefixes: HashMap::new(),
+            jlo_target_branch: "main".to_string(),
+            jules_worker_branch: "jules".to_string(),
+            issue_labels: vec![],
+            clock: MockClock::seeded(0),
+            fail_target: Some("qa".to_string()),
+        };
+
+        let err = config.simulated_failure(Layer::Observers, Some("qa")).unwrap_err();
+        assert!(matches!(err, AppError::JulesApiError { status: Some(503), .. }));
+        assert!(config.simulated_failure(Layer::Observers, Some("taxonomy")).is_ok());
+
+        let layer_target = MockConfig { fail_target: Some("decider".to_string()), ..config };
+        assert!(layer_target.simulated_failure(Layer::Decider, None).is_err());
+        assert!(layer_target.simulated_failure(Layer::Planner, None).is_ok());
+    }
+
+    #[test]
+    fn clock_reports_the_injected_instant() {
+        let now = Utc.timestamp_opt(1_800_000_000, 0).single().unwrap();
+        let clock = MockClock::new(now, 9);
+        assert_eq!(clock.now(), now);
+        assert_eq!(clock.now(), now);
+        assert_eq!(clock.next_id().len(), 6);
+        assert_ne!(clock.next_id(), MockClock::new(now, 9).next_id());
+    }
+
+    #[test]
+    fn seeded_clock_is_reproducible() {
+        let a = MockClock::seeded(42);
+        let b = MockClock::seeded(42);
+
+        assert_eq!(a.now(), b.now());
+        let ids_a: Vec<String> = (0..3).map(|_| a.next_id()).collect();
+        let ids_b: Vec<String> = (0..3).map(|_| b.next_id()).collect();
+        assert_eq!(ids_a, ids_b);
+        assert_ne!(ids_a[0], ids_a[1]);
+        assert!(ids_a.iter().all(|id| id.len() == 6));
+        assert_ne!(ids_a[0], MockClock::seeded(43).next_id());
+    }
 }
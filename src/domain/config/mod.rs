@@ -1,21 +1,25 @@
 pub mod control_plane;
+pub mod cron;
 pub mod error;
+pub mod json_schema;
 pub mod mock;
 pub mod mock_parse;
 pub mod parse;
 pub mod paths;
+pub mod pr_body_template;
 pub mod run_options;
 pub mod schedule;
 pub mod workflow_generate;
 pub mod workflow_runner_mode;
 
 pub use control_plane::{
-    ControlPlaneConfig, ExecutionConfig, JulesApiConfig, WorkflowTimingConfig,
+    ControlPlaneConfig, ExecutionConfig, ImplementerConfig, JulesApiConfig, MAX_CONCURRENCY_ENV,
+    WorkflowTimingConfig,
 };
 pub use error::ConfigError;
-pub use mock::{MockConfig, MockOutput};
+pub use mock::{MockClock, MockConfig, MockOutput};
 #[allow(unused_imports)]
-pub use parse::parse_config_content;
+pub use parse::{parse_config_content, parse_config_file};
 pub use run_options::RunOptions;
 pub use workflow_generate::WorkflowGenerateConfig;
 pub use workflow_runner_mode::WorkflowRunnerMode;
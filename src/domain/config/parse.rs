@@ -1,14 +1,88 @@
 //! Pure parse/validate for run configuration (`config.toml`).
 
-use crate::domain::{AppError, ControlPlaneConfig};
+use toml_edit::{Item, TableLike, Value};
+
+use crate::domain::{AppError, ConfigError, ControlPlaneConfig};
+
+/// Config file name reported when the caller does not supply a path.
+const DEFAULT_CONFIG_FILE: &str = ".jlo/config.toml";
 
 /// Parse and validate run configuration from TOML content.
 pub fn parse_config_content(content: &str) -> Result<ControlPlaneConfig, AppError> {
-    let config: ControlPlaneConfig = toml::from_str(content)?;
+    parse_config_file(DEFAULT_CONFIG_FILE, content)
+}
+
+/// Parse and validate run configuration, attributing parse errors to `file`.
+///
+/// Deserialization failures surface as [`ConfigError::Entry`] carrying the
+/// dotted key path of the offending entry when it can be located.
+pub fn parse_config_file(file: &str, content: &str) -> Result<ControlPlaneConfig, AppError> {
+    let config: ControlPlaneConfig = toml::from_str(content).map_err(|err| ConfigError::Entry {
+        file: file.to_string(),
+        key: err.span().and_then(|span| key_path_at(content, span.start, err.message())),
+        detail: err.message().to_string(),
+    })?;
     config.validate()?;
     Ok(config)
 }
 
+/// Dotted key path of the deepest entry whose source span contains byte `offset`,
+/// completed with the field a `missing field` error names.
+fn key_path_at(content: &str, offset: usize, message: &str) -> Option<String> {
+    let document = toml_edit::Document::parse(content).ok()?;
+    let mut path = Vec::new();
+    table_path(document.as_table(), offset, &mut path);
+    if let Some(field) =
+        message.strip_prefix("missing field `").and_then(|rest| rest.split('`').next())
+    {
+        path.push(field.to_string());
+    }
+    (!path.is_empty()).then(|| path.join("."))
+}
+
+fn table_path(table: &dyn TableLike, offset: usize, path: &mut Vec<String>) -> bool {
+    for (name, item) in table.iter() {
+        let key_span = table.get_key_value(name).and_then(|(key, _)| key.span());
+        path.push(name.to_string());
+        if spans(key_span, offset) || item_path(item, offset, path) {
+            return true;
+        }
+        path.pop();
+    }
+    false
+}
+
+fn item_path(item: &Item, offset: usize, path: &mut Vec<String>) -> bool {
+    match item {
+        Item::Table(table) => table_path(table, offset, path) || spans(table.span(), offset),
+        Item::ArrayOfTables(tables) => tables
+            .iter()
+            .any(|table| table_path(table, offset, path) || spans(table.span(), offset)),
+        Item::Value(value) => value_path(value, offset, path),
+        Item::None => false,
+    }
+}
+
+fn value_path(value: &Value, offset: usize, path: &mut Vec<String>) -> bool {
+    if !spans(value.span(), offset) {
+        return false;
+    }
+    match value {
+        Value::InlineTable(table) => {
+            table_path(table, offset, path);
+        }
+        Value::Array(values) => {
+            values.iter().any(|value| value_path(value, offset, path));
+        }
+        _ => {}
+    }
+    true
+}
+
+fn spans(span: Option<std::ops::Range<usize>>, offset: usize) -> bool {
+    span.is_some_and(|span| span.contains(&offset))
+}
+
 /// Parse a GitHub remote URL to extract `owner/repo`.
 ///
 /// Supports SSH (`git@github.com:owner/repo.git`) and
@@ -88,6 +162,30 @@ jlo_target_branch = ""
         assert!(matches!(result, Err(AppError::Config(_))));
     }
 
+    #[test]
+    fn run_config_rejects_unreasonable_jules_api_values_at_load() {
+        let result = parse_config_content("[jules_api]\ntimeout_secs = 0\n");
+        assert!(
+            matches!(result, Err(AppError::Config(ref e)) if e.to_string().contains("jules_api.timeout_secs"))
+        );
+
+        let result = parse_config_content("[jules_api]\nmax_retries = 500\n");
+        assert!(
+            matches!(result, Err(AppError::Config(ref e)) if e.to_string().contains("jules_api.max_retries = 500"))
+        );
+    }
+
+    #[test]
+    fn run_config_reads_max_concurrency_default() {
+        let config = parse_config_content("[run]\nmax_concurrency = 3\n").unwrap();
+        assert_eq!(config.run.max_concurrency, Some(3));
+        assert_eq!(config.run.effective_max_concurrency(None, None, 8).unwrap(), 3);
+
+        let config = parse_config_content("[run]\n").unwrap();
+        assert_eq!(config.run.max_concurrency, None);
+        assert_eq!(config.run.effective_max_concurrency(None, None, 8).unwrap(), 8);
+    }
+
     #[test]
     fn run_config_rejects_removed_parallel_fields() {
         let toml = r#"
@@ -96,7 +194,65 @@ parallel = true
 max_parallel = 3
 "#;
         let result = parse_config_content(toml);
-        assert!(result.is_err());
-        assert!(matches!(result, Err(AppError::TomlParseError(_))));
+        assert!(matches!(
+            result,
+            Err(AppError::Config(ConfigError::Entry { ref key, .. })) if key.as_deref() == Some("run.parallel")
+        ));
+    }
+
+    #[test]
+    fn missing_required_field_reports_file_and_key() {
+        let toml = r#"
+[run]
+jlo_target_branch = "main"
+
+[observers]
+roles = [
+  { name = "taxonomy", enabled = true },
+  { name = "qa" },
+]
+"#;
+        let err = parse_config_file("custom/config.toml", toml).unwrap_err();
+
+        match err {
+            AppError::Config(ConfigError::Entry { ref file, ref key, ref detail }) => {
+                assert_eq!(file, "custom/config.toml");
+                assert_eq!(key.as_deref(), Some("observers.roles.enabled"));
+                assert!(detail.contains("missing field `enabled`"), "{}", detail);
+            }
+            other => panic!("unexpected error: {}", other),
+        }
+        assert!(err.to_string().starts_with(
+            "Invalid configuration in custom/config.toml at 'observers.roles.enabled':"
+        ));
+    }
+
+    #[test]
+    fn invalid_value_reports_its_key() {
+        let err = parse_config_content("[jules_api]\ntimeout_secs = \"ten\"\n").unwrap_err();
+        assert!(matches!(
+            err,
+            AppError::Config(ConfigError::Entry { ref key, .. }) if key.as_deref() == Some("jules_api.timeout_secs")
+        ));
+    }
+
+    #[test]
+    fn invalid_value_inside_a_multi_line_array_reports_its_key() {
+        let toml = r#"
+[observers]
+roles = [
+  { name = "taxonomy", enabled = "yes" },
+]
+"#;
+        let err = parse_config_content(toml).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                AppError::Config(ConfigError::Entry { ref key, .. })
+                    if key.as_deref() == Some("observers.roles.enabled")
+            ),
+            "{}",
+            err
+        );
     }
 }
@@ -0,0 +1,63 @@
+//! Rendering of `[implementer] pr_body_template`.
+//!
+//! Templates use minijinja syntax with `requirement_id`, `source_events`, and
+//! `branch` in scope; referencing anything else is an error.
+
+use minijinja::{Environment, UndefinedBehavior, context};
+
+/// Values available to the implementer PR body template.
+pub struct PrBodyContext<'a> {
+    pub requirement_id: &'a str,
+    pub source_events: &'a [String],
+    pub branch: &'a str,
+}
+
+/// Render `template` for one implementer PR, describing the first problem found.
+pub fn render_pr_body(template: &str, ctx: &PrBodyContext<'_>) -> Result<String, String> {
+    let mut env = Environment::new();
+    env.set_undefined_behavior(UndefinedBehavior::Strict);
+    env.add_template("implementer_pr_body", template).map_err(|err| err.to_string())?;
+    env.get_template("implementer_pr_body")
+        .and_then(|tmpl| {
+            tmpl.render(context! {
+                requirement_id => ctx.requirement_id,
+                source_events => ctx.source_events,
+                branch => ctx.branch,
+            })
+        })
+        .map_err(|err| err.to_string())
+}
+
+/// Check that `template` parses and renders against a sample context.
+pub fn validate_pr_body_template(template: &str) -> Result<(), String> {
+    let source_events = ["abc123".to_string()];
+    let sample = PrBodyContext {
+        requirement_id: "abc123",
+        source_events: &source_events,
+        branch: "jules-implementer-bugs-abc123",
+    };
+    render_pr_body(template, &sample).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_context_variables() {
+        let source_events = ["ev0001".to_string(), "ev0002".to_string()];
+        let body = render_pr_body(
+            "Closes {{ requirement_id }} on {{ branch }}\n{% for e in source_events %}- {{ e }}\n{% endfor %}",
+            &PrBodyContext { requirement_id: "req001", source_events: &source_events, branch: "b" },
+        )
+        .unwrap();
+        assert_eq!(body, "Closes req001 on b\n- ev0001\n- ev0002\n");
+    }
+
+    #[test]
+    fn rejects_syntax_errors_and_unknown_variables() {
+        assert!(validate_pr_body_template("{% if %}").is_err());
+        assert!(validate_pr_body_template("{{ issue_url }}").is_err());
+        assert!(validate_pr_body_template("Requirement {{ requirement_id }}").is_ok());
+    }
+}
@@ -1,3 +1,4 @@
+use std::fmt;
 use std::path::PathBuf;
 
 use crate::domain::Layer;
@@ -15,4 +16,72 @@ pub struct RunOptions {
     pub requirement: Option<PathBuf>,
     /// Task file selector for innovators (expected: create_three_proposals).
     pub task: Option<String>,
+    /// Role definition loaded in place of `.jlo/roles/<layer>/<role>/role.yml` (observers only).
+    pub role_file: Option<PathBuf>,
+}
+
+/// One-line summary of the run target, e.g. `observers (role: taxonomy)`.
+impl fmt::Display for RunOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.layer.dir_name())?;
+
+        let mut parts = Vec::new();
+        if let Some(role) = &self.role {
+            parts.push(format!("role: {}", role));
+        }
+        if let Some(requirement) = &self.requirement {
+            parts.push(format!("requirement: {}", requirement.display()));
+        }
+        if let Some(task) = &self.task {
+            parts.push(format!("task: {}", task));
+        }
+        if let Some(role_file) = &self.role_file {
+            parts.push(format!("role file: {}", role_file.display()));
+        }
+
+        if !parts.is_empty() {
+            write!(f, " ({})", parts.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(layer: Layer) -> RunOptions {
+        RunOptions { layer, role: None, requirement: None, task: None, role_file: None }
+    }
+
+    #[test]
+    fn display_includes_layer_and_role() {
+        let target = RunOptions { role: Some("taxonomy".to_string()), ..options(Layer::Observers) };
+
+        assert_eq!(target.to_string(), "observers (role: taxonomy)");
+    }
+
+    #[test]
+    fn display_lists_every_selector() {
+        let target = RunOptions {
+            role: Some("scout".to_string()),
+            task: Some("create_three_proposals".to_string()),
+            ..options(Layer::Innovators)
+        };
+        assert_eq!(target.to_string(), "innovators (role: scout, task: create_three_proposals)");
+
+        let target = RunOptions {
+            requirement: Some(PathBuf::from(".jules/exchange/requirements/fix.yml")),
+            ..options(Layer::Implementer)
+        };
+        assert_eq!(
+            target.to_string(),
+            "implementer (requirement: .jules/exchange/requirements/fix.yml)"
+        );
+    }
+
+    #[test]
+    fn display_without_selectors_is_layer_only() {
+        assert_eq!(options(Layer::Narrator).to_string(), "narrator");
+    }
 }
@@ -29,6 +29,45 @@ impl ScheduleLayer {
     pub fn enabled_roles(&self) -> Vec<RoleId> {
         self.roles.iter().filter(|r| r.enabled).map(|r| r.name.clone()).collect()
     }
+
+    /// Enabled roles whose names match none of the `exclude` globs (`*` and `?` wildcards).
+    pub fn enabled_roles_excluding(&self, exclude: &[String]) -> Vec<RoleId> {
+        self.enabled_roles()
+            .into_iter()
+            .filter(|role| !exclude.iter().any(|pattern| glob_matches(pattern, role.as_str())))
+            .collect()
+    }
+}
+
+fn glob_matches(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    // Position of the last `*` in the pattern and the name index it has consumed up to.
+    let (mut p, mut n) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while n < name.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, n));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == name[n] => {
+                p += 1;
+                n += 1;
+            }
+            _ => match backtrack {
+                Some((star, consumed)) => {
+                    p = star + 1;
+                    n = consumed + 1;
+                    backtrack = Some((star, consumed + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, Default)]
@@ -98,6 +137,38 @@ roles = [
         assert_eq!(obs_roles, vec!["taxonomy"]);
     }
 
+    #[test]
+    fn enabled_roles_excluding_drops_glob_matches() {
+        let content = r#"
+[observers]
+roles = [
+  { name = "taxonomy", enabled = true },
+  { name = "archived-foo", enabled = true },
+  { name = "archived-bar", enabled = false },
+  { name = "qa", enabled = true },
+]
+"#;
+        let schedule = Schedule::parse_toml(content).unwrap();
+
+        let roles: Vec<String> = schedule
+            .observers
+            .enabled_roles_excluding(&["archived-*".to_string()])
+            .into_iter()
+            .map(|r| r.into())
+            .collect();
+        assert_eq!(roles, vec!["taxonomy", "qa"]);
+    }
+
+    #[test]
+    fn glob_matches_wildcards() {
+        assert!(glob_matches("archived-*", "archived-foo"));
+        assert!(glob_matches("*-foo", "archived-foo"));
+        assert!(glob_matches("q?", "qa"));
+        assert!(glob_matches("*a*o*", "archived-foo"));
+        assert!(!glob_matches("archived-*", "taxonomy"));
+        assert!(!glob_matches("q?", "qaa"));
+    }
+
     #[test]
     fn missing_required_fields_use_defaults() {
         let content = r#"
@@ -1,3 +1,5 @@
+use std::collections::BTreeMap;
+
 /// Workflow generate configuration for template expansion.
 ///
 /// Values are sourced from `.jlo/config.toml` and rendered
@@ -12,6 +14,9 @@ pub struct WorkflowGenerateConfig {
     pub schedule_crons: Vec<String>,
     /// Default wait minutes for orchestration pacing.
     pub wait_minutes_default: u32,
+    /// Extra template variables supplied by the caller (`--template-vars`).
+    /// Must not shadow built-in context keys.
+    pub template_vars: BTreeMap<String, serde_json::Value>,
 }
 
 impl Default for WorkflowGenerateConfig {
@@ -21,6 +26,7 @@ impl Default for WorkflowGenerateConfig {
             worker_branch: "jules".to_string(),
             schedule_crons: vec!["0 20 * * *".to_string()],
             wait_minutes_default: 30,
+            template_vars: BTreeMap::new(),
         }
     }
 }
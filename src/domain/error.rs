@@ -43,6 +43,10 @@ pub enum AppError {
     #[error("Jules API error: {message} (Status: {status:?})")]
     JulesApiError { message: String, status: Option<u16> },
 
+    /// Jules API circuit opened after sustained failures within this run.
+    #[error("Jules API circuit is open after {failures} consecutive failed requests; skipping")]
+    JulesCircuitOpen { failures: u32 },
+
     /// General validation error.
     #[error("Validation failed: {0}")]
     Validation(String),
@@ -125,6 +129,14 @@ pub enum AppError {
     #[error("Git error running '{command}': {details}")]
     GitError { command: String, details: String },
 
+    /// A cherry-picked commit conflicted with the current branch.
+    #[error("Cherry-pick of '{rev}' conflicted: {details}")]
+    CherryPickConflict { rev: String, details: String },
+
+    /// A tag with this name already exists.
+    #[error("Tag '{0}' already exists")]
+    TagExists(String),
+
     /// Parse error.
     #[error("Failed to parse {what}: {details}")]
     ParseError { what: String, details: String },
@@ -132,6 +144,32 @@ pub enum AppError {
     /// TOML parsing error.
     #[error("TOML parse error: {0}")]
     TomlParseError(String),
+
+    /// Error annotated with the operation that was in progress.
+    #[error("{context}: {source}")]
+    Contextual {
+        context: String,
+        #[source]
+        source: Box<AppError>,
+    },
+}
+
+impl AppError {
+    /// Attach context describing the operation that failed.
+    ///
+    /// Contexts chain outermost-first in `Display`, e.g.
+    /// `while running observers: while loading control-plane config: <cause>`.
+    pub fn context(self, context: impl Into<String>) -> Self {
+        AppError::Contextual { context: context.into(), source: Box::new(self) }
+    }
+
+    /// Innermost error with all context layers removed.
+    pub fn root_cause(&self) -> &AppError {
+        match self {
+            AppError::Contextual { source, .. } => source.root_cause(),
+            other => other,
+        }
+    }
 }
 
 impl From<io::Error> for AppError {
@@ -145,3 +183,22 @@ impl From<toml::de::Error> for AppError {
         AppError::TomlParseError(err.to_string())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn context_chains_into_display() {
+        let err = AppError::ControlPlaneConfigMissing
+            .context("while loading control-plane config")
+            .context("while running observers");
+
+        assert_eq!(
+            err.to_string(),
+            "while running observers: while loading control-plane config: \
+             Control plane config not found. Create .jlo/config.toml first."
+        );
+        assert!(matches!(err.root_cause(), AppError::ControlPlaneConfigMissing));
+    }
+}
@@ -0,0 +1,84 @@
+//! Duplicate detection for pending events.
+//!
+//! Two events are duplicates when their dedupe keys match after normalization.
+//! The key is the event's explicit `dedupe_key` when present, otherwise its `title`.
+
+/// Events sharing one dedupe key: the first is kept, the rest are merged into it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateGroup<T> {
+    pub kept: T,
+    pub merged: Vec<T>,
+}
+
+/// Normalize a summary for comparison.
+///
+/// Lowercases, collapses internal whitespace, and strips trailing punctuation so
+/// `"Missing docs."` and `"missing  docs"` compare equal.
+pub fn normalize_summary(summary: &str) -> String {
+    summary
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches(['.', '!', '?', ':', ';', ','])
+        .to_lowercase()
+}
+
+/// Resolve the dedupe key for an event, preferring an explicit `dedupe_key`.
+///
+/// Returns `None` when neither field yields a non-empty key; such events are
+/// never merged.
+pub fn dedupe_key(explicit_key: Option<&str>, title: Option<&str>) -> Option<String> {
+    [explicit_key, title].into_iter().flatten().map(normalize_summary).find(|key| !key.is_empty())
+}
+
+/// Group items by key, preserving input order.
+///
+/// Only groups with at least one duplicate are returned. Items without a key are
+/// left alone.
+pub fn find_duplicates<T: Clone>(items: &[(T, Option<String>)]) -> Vec<DuplicateGroup<T>> {
+    let mut groups: Vec<(String, DuplicateGroup<T>)> = Vec::new();
+
+    for (item, key) in items {
+        let Some(key) = key else { continue };
+        match groups.iter_mut().find(|(existing, _)| existing == key) {
+            Some((_, group)) => group.merged.push(item.clone()),
+            None => groups
+                .push((key.clone(), DuplicateGroup { kept: item.clone(), merged: Vec::new() })),
+        }
+    }
+
+    groups.into_iter().map(|(_, group)| group).filter(|group| !group.merged.is_empty()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_summary_ignores_case_whitespace_and_trailing_punctuation() {
+        assert_eq!(normalize_summary("  Missing   Docs for CLI. "), "missing docs for cli");
+        assert_eq!(normalize_summary("missing docs for cli"), "missing docs for cli");
+    }
+
+    #[test]
+    fn dedupe_key_prefers_explicit_key() {
+        assert_eq!(dedupe_key(Some("Key-A"), Some("Title")), Some("key-a".to_string()));
+        assert_eq!(dedupe_key(Some("  "), Some("Title")), Some("title".to_string()));
+        assert_eq!(dedupe_key(None, None), None);
+    }
+
+    #[test]
+    fn find_duplicates_keeps_first_and_merges_rest() {
+        let items = vec![
+            ("a", Some("x".to_string())),
+            ("b", Some("y".to_string())),
+            ("c", Some("x".to_string())),
+            ("d", None),
+            ("e", None),
+        ];
+
+        let groups = find_duplicates(&items);
+
+        assert_eq!(groups, vec![DuplicateGroup { kept: "a", merged: vec!["c"] }]);
+    }
+}
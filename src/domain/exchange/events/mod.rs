@@ -1 +1,2 @@
+pub mod dedupe;
 pub mod paths;
@@ -1,6 +1,7 @@
 use std::path::{Path, PathBuf};
 
-use crate::domain::exchange;
+use crate::domain::validation::validate_identifier;
+use crate::domain::{AppError, exchange};
 
 /// `.jules/exchange/events/`
 pub fn events_dir(jules_path: &Path) -> PathBuf {
@@ -21,3 +22,39 @@ pub fn events_pending_dir(jules_path: &Path) -> PathBuf {
 pub fn events_decided_dir(jules_path: &Path) -> PathBuf {
     events_state_dir(jules_path, "decided")
 }
+
+/// Source and destination of `<event>.yml` moving from `from_state` to `to_state`.
+///
+/// Fails when either state is not in `known_states` or `event` is not a safe file stem.
+pub fn event_transition_paths(
+    jules_path: &Path,
+    event: &str,
+    from_state: &str,
+    to_state: &str,
+    known_states: &[String],
+) -> Result<(PathBuf, PathBuf), AppError> {
+    for state in [from_state, to_state] {
+        if !known_states.iter().any(|known| known == state) {
+            return Err(AppError::Validation(format!(
+                "Unknown event state '{}': expected one of {}",
+                state,
+                known_states.join(", ")
+            )));
+        }
+    }
+    if from_state == to_state {
+        return Err(AppError::Validation(format!(
+            "Event '{}' is already in state '{}'",
+            event, from_state
+        )));
+    }
+    if !validate_identifier(event, false) {
+        return Err(AppError::InvalidPath(format!("Invalid event name '{}'", event)));
+    }
+
+    let file_name = format!("{}.yml", event);
+    Ok((
+        events_state_dir(jules_path, from_state).join(&file_name),
+        events_state_dir(jules_path, to_state).join(&file_name),
+    ))
+}
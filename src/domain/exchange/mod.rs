@@ -9,3 +9,19 @@ pub mod events;
 pub mod paths;
 pub mod proposals;
 pub mod requirements;
+
+use serde::Serialize;
+
+/// Kinds of artifacts layers exchange under `.jules/exchange/`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ArtifactKind {
+    /// Narrator changes feed (`changes.yml`).
+    Changes,
+    /// Observation events.
+    Events,
+    /// Actionable requirements.
+    Requirements,
+    /// Innovator proposals.
+    Proposals,
+}
@@ -3,11 +3,13 @@ pub mod starting_branch;
 
 use std::path::{Path, PathBuf};
 
+use serde::Serialize;
+
 use crate::domain::AppError;
 use crate::ports::{JulesClient, JulesStore, RepositoryFilesystem};
 
 /// Result of a run execution.
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 pub struct RunResult {
     /// Role that was processed.
     pub roles: Vec<String>,
@@ -15,7 +17,12 @@ pub struct RunResult {
     pub prompt_preview: bool,
     /// Session IDs from Jules (empty if prompt_preview or mock).
     pub sessions: Vec<String>,
+    /// Web link for each entry of `sessions`, in the same order; `None` where Jules
+    /// reported no link.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub session_urls: Vec<Option<String>>,
     /// Requirement file to clean up (delete) after successful execution.
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub cleanup_requirement: Option<PathBuf>,
 }
 
@@ -62,3 +69,50 @@ pub fn validate_requirement_path<W: RepositoryFilesystem + JulesStore + ?Sized>(
 
     Ok(RequirementPathInfo { requirement_path_str: path_str.to_string() })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn run_result_serializes_sessions_and_roles() {
+        let result = RunResult {
+            roles: vec!["implementer".to_string()],
+            prompt_preview: false,
+            sessions: vec!["sessions/123".to_string()],
+            session_urls: vec![Some("https://jules.google.com/session/123".to_string())],
+            cleanup_requirement: Some(PathBuf::from(".jules/exchange/requirements/fix.yml")),
+        };
+
+        let json = serde_json::to_value(&result).unwrap();
+
+        assert_eq!(json["roles"], serde_json::json!(["implementer"]));
+        assert_eq!(json["sessions"], serde_json::json!(["sessions/123"]));
+        assert_eq!(
+            json["session_urls"],
+            serde_json::json!(["https://jules.google.com/session/123"])
+        );
+        assert_eq!(json["cleanup_requirement"], ".jules/exchange/requirements/fix.yml");
+
+        let result = RunResult { cleanup_requirement: None, session_urls: vec![], ..result };
+        let json = serde_json::to_value(&result).unwrap();
+        assert!(json.get("cleanup_requirement").is_none());
+        assert!(json.get("session_urls").is_none());
+    }
+
+    #[test]
+    fn run_result_keeps_empty_sessions_array() {
+        let result = RunResult {
+            roles: vec!["narrator".to_string()],
+            prompt_preview: false,
+            sessions: vec![],
+            session_urls: vec![],
+            cleanup_requirement: None,
+        };
+
+        let json = serde_json::to_value(&result).unwrap();
+
+        assert_eq!(json["sessions"], serde_json::json!([]));
+        assert_eq!(json["prompt_preview"], false);
+    }
+}
@@ -4,6 +4,8 @@ pub mod paths;
 use serde::Serialize;
 use std::fmt;
 
+use crate::domain::exchange::ArtifactKind;
+
 /// The architectural layers for execution roles.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Layer {
@@ -35,6 +37,23 @@ impl Layer {
         Layer::Integrator,
     ];
 
+    /// Layers executed by the pipeline (`workflow run all`), in order.
+    ///
+    /// Innovators are excluded because they need an explicit task selector.
+    pub const PIPELINE: [Layer; 6] = [
+        Layer::Narrator,
+        Layer::Observers,
+        Layer::Decider,
+        Layer::Planner,
+        Layer::Implementer,
+        Layer::Integrator,
+    ];
+
+    /// Position of this layer in [`Layer::PIPELINE`] (`None` for innovators).
+    pub fn pipeline_index(&self) -> Option<usize> {
+        Self::PIPELINE.iter().position(|layer| layer == self)
+    }
+
     /// Directory name for this layer.
     pub fn dir_name(&self) -> &'static str {
         match self {
@@ -104,6 +123,31 @@ impl Layer {
         }
     }
 
+    /// Exchange artifact kinds this layer consumes.
+    pub fn reads_from(&self) -> &'static [ArtifactKind] {
+        match self {
+            Layer::Narrator | Layer::Integrator => &[],
+            Layer::Observers => &[ArtifactKind::Changes],
+            Layer::Decider => &[ArtifactKind::Events],
+            Layer::Planner | Layer::Implementer | Layer::Innovators => {
+                &[ArtifactKind::Requirements]
+            }
+        }
+    }
+
+    /// Exchange artifact kinds this layer may produce or modify.
+    ///
+    /// The decider also deletes the events it processes; deletion is not listed here.
+    pub fn writes_to(&self) -> &'static [ArtifactKind] {
+        match self {
+            Layer::Narrator => &[ArtifactKind::Changes],
+            Layer::Observers => &[ArtifactKind::Events],
+            Layer::Decider | Layer::Planner => &[ArtifactKind::Requirements],
+            Layer::Innovators => &[ArtifactKind::Proposals],
+            Layer::Implementer | Layer::Integrator => &[],
+        }
+    }
+
     /// Whether this layer has a single, fixed role (no subdirectories).
     ///
     /// Single-role layers (Narrator, Decider, Planner, Implementer) have contracts.yml
@@ -240,6 +284,38 @@ mod tests {
         assert!(!Layer::Integrator.uses_worker_branch());
     }
 
+    #[test]
+    fn pipeline_index_follows_documented_pipeline_order() {
+        assert_eq!(Layer::Narrator.pipeline_index(), Some(0));
+        assert_eq!(Layer::Observers.pipeline_index(), Some(1));
+        assert_eq!(Layer::Decider.pipeline_index(), Some(2));
+        assert_eq!(Layer::Planner.pipeline_index(), Some(3));
+        assert_eq!(Layer::Implementer.pipeline_index(), Some(4));
+        assert_eq!(Layer::Integrator.pipeline_index(), Some(5));
+        assert_eq!(Layer::Innovators.pipeline_index(), None);
+
+        for (index, layer) in Layer::PIPELINE.iter().enumerate() {
+            assert_eq!(layer.pipeline_index(), Some(index));
+        }
+    }
+
+    #[test]
+    fn exchange_contract_matches_layer_responsibilities() {
+        assert_eq!(Layer::Observers.reads_from(), &[ArtifactKind::Changes]);
+        assert_eq!(Layer::Observers.writes_to(), &[ArtifactKind::Events]);
+        assert!(!Layer::Observers.writes_to().contains(&ArtifactKind::Requirements));
+
+        assert!(Layer::Decider.reads_from().contains(&ArtifactKind::Events));
+        assert!(Layer::Decider.writes_to().contains(&ArtifactKind::Requirements));
+        assert!(Layer::Innovators.writes_to().contains(&ArtifactKind::Proposals));
+
+        for layer in Layer::ALL {
+            if layer != Layer::Narrator {
+                assert!(!layer.writes_to().contains(&ArtifactKind::Changes), "{}", layer);
+            }
+        }
+    }
+
     #[test]
     fn layer_aliases_match_cli() {
         assert_eq!(Layer::from_dir_name("n"), Some(Layer::Narrator));
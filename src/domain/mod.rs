@@ -15,7 +15,7 @@ pub mod setup;
 pub use config::WorkflowGenerateConfig;
 pub use config::schedule::Schedule;
 pub use config::{
-    ConfigError, ControlPlaneConfig, JulesApiConfig, MockConfig, MockOutput, RunOptions,
+    ConfigError, ControlPlaneConfig, JulesApiConfig, MockClock, MockConfig, MockOutput, RunOptions,
     WorkflowRunnerMode,
 };
 #[allow(unused_imports)]
@@ -1,9 +1,41 @@
 use std::path::Path;
 
+use sha2::{Digest, Sha256};
+
 /// Abstraction for prompt asset loading.
 pub trait PromptAssetLoader {
     fn read_asset(&self, path: &Path) -> std::io::Result<String>;
     fn asset_exists(&self, path: &Path) -> bool;
     fn ensure_asset_dir(&self, path: &Path) -> std::io::Result<()>;
     fn copy_asset(&self, from: &Path, to: &Path) -> std::io::Result<u64>;
+
+    /// SHA-256 hex digest of an asset's content, or `None` when it cannot be read.
+    ///
+    /// Stable across runs and versions, so it can key caches or flag changed templates.
+    fn asset_hash(&self, path: &Path) -> Option<String> {
+        let content = self.read_asset(path).ok()?;
+        Some(format!("{:x}", Sha256::digest(content.as_bytes())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::TestStore;
+
+    #[test]
+    fn asset_hash_is_stable_and_content_sensitive() {
+        let store = TestStore::new()
+            .with_file(".jules/prompts/a.md", "observe the codebase")
+            .with_file(".jules/prompts/b.md", "decide on events");
+
+        let first = store.asset_hash(Path::new(".jules/prompts/a.md")).unwrap();
+        let again = store.asset_hash(Path::new(".jules/prompts/a.md")).unwrap();
+        let other = store.asset_hash(Path::new(".jules/prompts/b.md")).unwrap();
+
+        assert_eq!(first, again);
+        assert_eq!(first.len(), 64);
+        assert_ne!(first, other);
+        assert_eq!(store.asset_hash(Path::new(".jules/prompts/missing.md")), None);
+    }
 }
@@ -4,6 +4,11 @@ pub enum RoleError {
     #[error("Invalid role identifier '{0}': must be alphanumeric with hyphens or underscores")]
     InvalidId(String),
 
+    #[error(
+        "Invalid role identifier '{id}': role ids must be lowercase with no surrounding whitespace; use '{normalized}'"
+    )]
+    NotNormalized { id: String, normalized: String },
+
     #[error(
         "Invalid layer '{name}': must be one of Narrator, Observers, Decider, Planner, Implementer, Innovators, Integrator"
     )]
@@ -12,6 +17,11 @@ pub enum RoleError {
     #[error("Role '{0}' not found")]
     NotFound(String),
 
+    #[error(
+        "Role '{role}' is not defined in layer '{layer}', but exists in '{found}'; run it with `jlo run {found} --role {role}`"
+    )]
+    InOtherLayer { role: String, layer: String, found: String },
+
     #[error("Role '{role}' already exists in layer '{layer}'")]
     AlreadyExists { role: String, layer: String },
 
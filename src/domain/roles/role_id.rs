@@ -8,10 +8,20 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 /// - Non-empty
 /// - Contains only alphanumeric characters, `-`, or `_`
 /// - No path traversal components (/, \, ., ..)
+/// - Lowercase with no surrounding whitespace, so the id always matches its directory
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct RoleId(String);
 
-impl_validated_id!(RoleId, false, RoleError, RoleError::InvalidId);
+impl_validated_id!(RoleId, false, RoleError, RoleError::InvalidId, reject_unnormalized);
+
+/// Reject ids that differ from their trimmed, lowercased form instead of silently normalizing.
+fn reject_unnormalized(id: &str) -> Result<(), RoleError> {
+    let normalized = id.trim().to_lowercase();
+    if normalized != id && crate::domain::validation::validate_identifier(&normalized, false) {
+        return Err(RoleError::NotNormalized { id: id.to_string(), normalized });
+    }
+    Ok(())
+}
 
 impl From<RoleId> for String {
     fn from(val: RoleId) -> Self {
@@ -77,6 +87,29 @@ mod tests {
         assert!(RoleId::new("has space").is_err());
     }
 
+    #[test]
+    fn uppercase_id_is_rejected_with_normalized_suggestion() {
+        let err = RoleId::new("Taxonomy").unwrap_err();
+        assert!(matches!(
+            &err,
+            RoleError::NotNormalized { id, normalized } if id == "Taxonomy" && normalized == "taxonomy"
+        ));
+        assert!(err.to_string().contains("use 'taxonomy'"));
+    }
+
+    #[test]
+    fn surrounding_whitespace_is_rejected_with_normalized_suggestion() {
+        let err = RoleId::new("  data_arch\n").unwrap_err();
+        assert!(
+            matches!(&err, RoleError::NotNormalized { normalized, .. } if normalized == "data_arch")
+        );
+    }
+
+    #[test]
+    fn unfixable_id_keeps_invalid_id_error() {
+        assert!(matches!(RoleId::new(" Has Space "), Err(RoleError::InvalidId(_))));
+    }
+
     #[test]
     fn display_impl() {
         let role = RoleId::new("test-role").unwrap();
@@ -5,10 +5,10 @@ use std::collections::BTreeMap;
 use crate::domain::setup::error::SetupError;
 use crate::domain::{AppError, SetupComponent};
 
-const SCRIPT_HEADER: &str = r#"#!/usr/bin/env bash
-set -euo pipefail
+/// Preamble used when `tools.yml` sets no `pre_install`.
+pub const DEFAULT_PRE_INSTALL: &str = "set -euo pipefail";
 
-# Generated by jlo setup
+const SCRIPT_NOTICE: &str = r#"# Generated by jlo setup
 # Do not edit manually - regenerate with 'jlo setup gen'
 
 "#;
@@ -24,11 +24,17 @@ pub struct SetupEnvArtifacts {
 ///
 /// Each component's `script_content` is a fragment (no shebang, no `set -e`).
 /// Identifying comments belong in the fragment itself, not injected here.
-pub fn generate_install_script(components: &[SetupComponent]) -> String {
-    let mut parts = vec![SCRIPT_HEADER.to_string()];
-
-    for component in components {
-        let content = component.script_content.trim();
+/// `pre_install` replaces [`DEFAULT_PRE_INSTALL`]; `post_install` follows the last component.
+pub fn generate_install_script(
+    components: &[SetupComponent],
+    pre_install: Option<&str>,
+    post_install: Option<&str>,
+) -> String {
+    let preamble = pre_install.map(str::trim).unwrap_or(DEFAULT_PRE_INSTALL);
+    let mut parts = vec![format!("#!/usr/bin/env bash\n{}\n\n{}", preamble, SCRIPT_NOTICE)];
+
+    let fragments = components.iter().map(|component| component.script_content.trim());
+    for content in fragments.chain(post_install.map(str::trim)) {
         if !content.is_empty() {
             parts.push(content.to_string());
             parts.push(String::new());
@@ -137,6 +143,44 @@ fn build_env_toml(
     Ok(lines.join("\n"))
 }
 
+/// Generate `setup.lock` content: resolved component order plus version pins.
+///
+/// A pin is any non-secret `*_VERSION` variable a component declares, with its
+/// value taken from the merged `vars.toml` (empty means "latest"). The output is
+/// pretty-printed JSON with sorted keys so unchanged inputs produce identical bytes.
+pub fn generate_lockfile(
+    components: &[SetupComponent],
+    vars_toml: &str,
+) -> Result<String, AppError> {
+    let vars = parse_env_toml(vars_toml)?;
+
+    let entries: Vec<serde_json::Value> = components
+        .iter()
+        .map(|component| {
+            let versions: BTreeMap<&str, &str> = component
+                .env
+                .iter()
+                .filter(|env| !env.secret && env.name.ends_with("_VERSION"))
+                .map(|env| {
+                    let value = vars
+                        .get(&env.name)
+                        .and_then(|table| table.get("value"))
+                        .map(String::as_str)
+                        .unwrap_or_default();
+                    (env.name.as_str(), value)
+                })
+                .collect();
+            serde_json::json!({ "name": component.name.as_str(), "versions": versions })
+        })
+        .collect();
+
+    let lock = serde_json::json!({ "schema_version": 1, "components": entries });
+    let mut content = serde_json::to_string_pretty(&lock)
+        .map_err(|e| SetupError::LockfileSerialization(e.to_string()))?;
+    content.push('\n');
+    Ok(content)
+}
+
 /// Parse vars.toml/secrets.toml content into table name -> key/value pairs.
 fn parse_env_toml(content: &str) -> Result<BTreeMap<String, BTreeMap<String, String>>, AppError> {
     let data: toml::Value =
@@ -180,7 +224,7 @@ mod tests {
     fn generate_script_with_header() {
         let components = vec![make_component("test", vec![])];
 
-        let script = generate_install_script(&components);
+        let script = generate_install_script(&components, None, None);
 
         assert!(script.starts_with("#!/usr/bin/env bash"));
         assert!(script.contains("set -euo pipefail"));
@@ -188,11 +232,29 @@ mod tests {
         assert!(script.contains("echo test"));
     }
 
+    #[test]
+    fn generate_script_places_install_hooks() {
+        let components = vec![make_component("alpha", vec![])];
+
+        let script = generate_install_script(
+            &components,
+            Some("set -eu\nexport CI=1\n"),
+            Some("rm -rf /tmp/jlo-setup\n"),
+        );
+
+        assert!(script.starts_with("#!/usr/bin/env bash\nset -eu\nexport CI=1\n"));
+        assert!(!script.contains(DEFAULT_PRE_INSTALL));
+        let body = script.find("echo alpha").unwrap();
+        let post = script.find("rm -rf /tmp/jlo-setup").unwrap();
+        assert!(post > body);
+        assert!(script.trim_end().ends_with("rm -rf /tmp/jlo-setup"));
+    }
+
     #[test]
     fn generate_script_concatenates_fragments() {
         let components = vec![make_component("alpha", vec![]), make_component("beta", vec![])];
 
-        let script = generate_install_script(&components);
+        let script = generate_install_script(&components, None, None);
 
         assert!(script.contains("echo alpha"));
         assert!(script.contains("echo beta"));
@@ -309,4 +371,28 @@ note = "legacy location"
         assert!(result.secrets_toml.contains("value = \"from-vars\""));
         assert!(result.secrets_toml.contains("note = \"legacy location\""));
     }
+
+    #[test]
+    fn lockfile_records_order_and_version_pins() {
+        let version = |name: &str| EnvSpec {
+            name: name.to_string(),
+            description: String::new(),
+            default: None,
+            secret: false,
+        };
+        let components = vec![
+            make_component("zeta", vec![version("ZETA_VERSION")]),
+            make_component("alpha", vec![version("ALPHA_VERSION"), version("ALPHA_HOME")]),
+        ];
+        let vars_toml = "[ZETA_VERSION]\nvalue = \"1.2.3\"\n\n[ALPHA_VERSION]\nvalue = \"\"\n";
+
+        let lock: serde_json::Value =
+            serde_json::from_str(&generate_lockfile(&components, vars_toml).unwrap()).unwrap();
+
+        assert_eq!(lock["schema_version"], 1);
+        assert_eq!(lock["components"][0]["name"], "zeta");
+        assert_eq!(lock["components"][0]["versions"]["ZETA_VERSION"], "1.2.3");
+        assert_eq!(lock["components"][1]["name"], "alpha");
+        assert_eq!(lock["components"][1]["versions"], serde_json::json!({ "ALPHA_VERSION": "" }));
+    }
 }
@@ -23,4 +23,14 @@ pub enum SetupError {
 
     #[error("Malformed setup environment TOML: {0}")]
     MalformedEnvToml(String),
+
+    #[error("Failed to serialize setup.lock: {0}")]
+    LockfileSerialization(String),
+
+    #[error("tools.yml has {} validation error(s):{}", .0.len(), format_errors(.0))]
+    ValidationFailed(Vec<String>),
+}
+
+fn format_errors(errors: &[String]) -> String {
+    errors.iter().map(|error| format!("\n  - {}", error)).collect()
 }
@@ -32,6 +32,42 @@ pub struct EnvSpec {
     pub secret: bool,
 }
 
+/// Environment variable names must be shell-safe: `[A-Za-z_][A-Za-z0-9_]*`.
+pub fn is_valid_env_var_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl EnvSpec {
+    /// Names of variables referenced from the default value (`${NAME}` or `$NAME`).
+    pub fn default_references(&self) -> Vec<String> {
+        let Some(default) = self.default.as_deref() else {
+            return Vec::new();
+        };
+
+        let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_';
+        let mut references: Vec<String> = Vec::new();
+        let mut rest = default;
+        while let Some(pos) = rest.find('$') {
+            rest = &rest[pos + 1..];
+            let name = if let Some(braced) = rest.strip_prefix('{') {
+                match braced.find('}') {
+                    Some(end) => &braced[..end],
+                    None => break,
+                }
+            } else {
+                let end = rest.find(|c: char| !is_name_char(c)).unwrap_or(rest.len());
+                &rest[..end]
+            };
+            if is_valid_env_var_name(name) && !references.iter().any(|existing| existing == name) {
+                references.push(name.to_string());
+            }
+        }
+        references
+    }
+}
+
 /// A setup component that can be installed.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SetupComponent {
@@ -51,6 +87,34 @@ pub struct SetupComponent {
 mod tests {
     use super::*;
 
+    fn env_with_default(default: &str) -> EnvSpec {
+        EnvSpec {
+            name: "X".to_string(),
+            description: String::new(),
+            default: Some(default.to_string()),
+            secret: false,
+        }
+    }
+
+    #[test]
+    fn env_var_names_must_be_shell_safe() {
+        assert!(is_valid_env_var_name("GH_TOKEN"));
+        assert!(is_valid_env_var_name("_private1"));
+        assert!(!is_valid_env_var_name("1TOKEN"));
+        assert!(!is_valid_env_var_name("MY-VAR"));
+        assert!(!is_valid_env_var_name(""));
+    }
+
+    #[test]
+    fn default_references_parses_braced_and_bare_forms() {
+        assert_eq!(
+            env_with_default("${B_HOME}/bin:$PATH:${B_HOME}").default_references(),
+            vec!["B_HOME".to_string(), "PATH".to_string()]
+        );
+        assert!(env_with_default("/usr/local/bin").default_references().is_empty());
+        assert!(env_with_default("cost: $5").default_references().is_empty());
+    }
+
     #[test]
     fn valid_alphanumeric_id() {
         assert!(SetupComponentId::new("rust").is_ok());
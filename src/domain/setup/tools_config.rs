@@ -2,7 +2,10 @@
 
 use serde::Deserialize;
 
-use crate::domain::AppError;
+use crate::domain::{AppError, ConfigError};
+
+/// Repository path reported in tools config errors.
+const TOOLS_CONFIG_FILE: &str = ".jlo/setup/tools.yml";
 
 /// Configuration for setup artifact generation.
 #[derive(Debug, Clone, Default, Deserialize)]
@@ -10,17 +13,31 @@ pub struct SetupConfig {
     /// List of tool names to install.
     #[serde(default)]
     pub tools: Vec<String>,
+    /// Shell snippet emitted at the top of install.sh, replacing the default strict-mode preamble.
+    #[serde(default)]
+    pub pre_install: Option<String>,
+    /// Shell snippet emitted at the end of install.sh, after every component.
+    #[serde(default)]
+    pub post_install: Option<String>,
 }
 
 /// Parse and validate setup tools configuration content.
+///
+/// Failures surface as [`ConfigError::Entry`] for `.jlo/setup/tools.yml`.
 pub fn parse_tools_config_content(content: &str) -> Result<SetupConfig, AppError> {
-    let config: SetupConfig = serde_yaml::from_str(content)
-        .map_err(|e| AppError::ParseError { what: "tools.yml".into(), details: e.to_string() })?;
+    let config: SetupConfig = serde_yaml::from_str(content).map_err(|e| ConfigError::Entry {
+        file: TOOLS_CONFIG_FILE.to_string(),
+        key: None,
+        detail: e.to_string(),
+    })?;
 
     if config.tools.is_empty() {
-        return Err(AppError::Validation(
-            "No tools specified in tools.yml. Add tools to the 'tools' list.".into(),
-        ));
+        return Err(ConfigError::Entry {
+            file: TOOLS_CONFIG_FILE.to_string(),
+            key: Some("tools".to_string()),
+            detail: "No tools specified. Add tools to the 'tools' list.".to_string(),
+        }
+        .into());
     }
 
     Ok(config)
@@ -33,12 +50,39 @@ mod tests {
     #[test]
     fn parse_rejects_empty_tools() {
         let err = parse_tools_config_content("tools: []").unwrap_err();
-        assert!(matches!(err, AppError::Validation(msg) if msg.contains("No tools specified")));
+        assert!(matches!(
+            err,
+            AppError::Config(ConfigError::Entry { ref file, ref key, ref detail })
+                if file == ".jlo/setup/tools.yml"
+                    && key.as_deref() == Some("tools")
+                    && detail.contains("No tools specified")
+        ));
+    }
+
+    #[test]
+    fn parse_reports_malformed_yaml_against_the_file() {
+        let err = parse_tools_config_content("tools: [just\n").unwrap_err();
+        assert!(matches!(
+            err,
+            AppError::Config(ConfigError::Entry { ref file, key: None, .. })
+                if file == ".jlo/setup/tools.yml"
+        ));
     }
 
     #[test]
     fn parse_accepts_tools() {
         let cfg = parse_tools_config_content("tools:\n  - just\n").unwrap();
         assert_eq!(cfg.tools, vec!["just".to_string()]);
+        assert!(cfg.pre_install.is_none() && cfg.post_install.is_none());
+    }
+
+    #[test]
+    fn parse_accepts_install_hooks() {
+        let cfg = parse_tools_config_content(
+            "tools:\n  - just\npre_install: |\n  set -eu\npost_install: echo done\n",
+        )
+        .unwrap();
+        assert_eq!(cfg.pre_install.as_deref(), Some("set -eu\n"));
+        assert_eq!(cfg.post_install.as_deref(), Some("echo done"));
     }
 }
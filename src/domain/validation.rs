@@ -21,9 +21,14 @@ pub fn validate_identifier(id: &str, allow_dots: bool) -> bool {
 #[macro_export]
 macro_rules! impl_validated_id {
     ($name:ident, $allow_dots:expr, $err_type:ty, $err_variant:expr) => {
+        $crate::impl_validated_id!($name, $allow_dots, $err_type, $err_variant, |_id: &str| Ok(()));
+    };
+    ($name:ident, $allow_dots:expr, $err_type:ty, $err_variant:expr, $precheck:expr) => {
         impl $name {
             /// Validate and create a new instance.
             pub fn new(id: &str) -> Result<Self, $err_type> {
+                let precheck: fn(&str) -> Result<(), $err_type> = $precheck;
+                precheck(id)?;
                 if $crate::domain::validation::validate_identifier(id, $allow_dots) {
                     Ok(Self(id.to_string()))
                 } else {
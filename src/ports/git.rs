@@ -1,6 +1,38 @@
 use crate::domain::AppError;
 use std::path::Path;
 
+/// A commit in a history range.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitInfo {
+    /// Full commit SHA.
+    pub sha: String,
+    /// First line of the commit message.
+    pub subject: String,
+}
+
+/// One path reported by `git status --porcelain`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StatusEntry {
+    /// Repository-relative path (the destination for renames and copies).
+    pub path: String,
+    /// Staged state (`X` column): `' '` unmodified, `'M'`, `'A'`, `'D'`, `'R'`, `'?'`, ...
+    pub index_state: char,
+    /// Working-tree state (`Y` column).
+    pub worktree_state: char,
+}
+
+impl StatusEntry {
+    /// Whether the path is untracked (`??`).
+    pub fn is_untracked(&self) -> bool {
+        self.index_state == '?' && self.worktree_state == '?'
+    }
+
+    /// Whether the path has changes staged in the index.
+    pub fn is_staged(&self) -> bool {
+        !matches!(self.index_state, ' ' | '?' | '!')
+    }
+}
+
 pub trait Git {
     /// Get the current HEAD SHA.
     fn get_head_sha(&self) -> Result<String, AppError>;
@@ -8,6 +40,14 @@ pub trait Git {
     /// Get the current branch name.
     fn get_current_branch(&self) -> Result<String, AppError>;
 
+    /// Default branch of `origin` as recorded by `refs/remotes/origin/HEAD`.
+    /// Fails when the remote or its HEAD ref is not known locally.
+    fn get_default_branch(&self) -> Result<String, AppError>;
+
+    /// Author name of the most recent commit touching `path` in the history of `rev`
+    /// (local history only).
+    fn blame_last_author(&self, rev: &str, path: &str) -> Result<String, AppError>;
+
     /// Check if a commit exists.
     fn commit_exists(&self, sha: &str) -> bool;
 
@@ -17,9 +57,21 @@ pub trait Git {
     /// Get the first commit (root) in the ancestry of the given commit.
     fn get_first_commit(&self, commit: &str) -> Result<String, AppError>;
 
+    /// Resolve a revision (branch, tag, or SHA) to its full commit SHA.
+    fn rev_parse(&self, rev: &str) -> Result<String, AppError>;
+
+    /// Get the full commit message of a commit.
+    fn commit_message(&self, sha: &str) -> Result<String, AppError>;
+
+    /// List commits reachable from `to` but not `from`, newest first.
+    fn log_range(&self, from: &str, to: &str) -> Result<Vec<CommitInfo>, AppError>;
+
     /// Check if there are changes in the range matching the pathspec.
     fn has_changes(&self, from: &str, to: &str, pathspec: &[&str]) -> Result<bool, AppError>;
 
+    /// Working-tree status for paths matching `pathspec` (all paths when empty).
+    fn status(&self, pathspec: &[&str]) -> Result<Vec<StatusEntry>, AppError>;
+
     /// Execute an arbitrary git command (fallback).
     #[allow(dead_code)]
     fn run_command(&self, args: &[&str], cwd: Option<&Path>) -> Result<String, AppError>;
@@ -41,9 +93,51 @@ pub trait Git {
     /// Fetch from remote.
     fn fetch(&self, remote: &str) -> Result<(), AppError>;
 
+    /// Fetch from remote, pruning remote-tracking refs deleted on the remote.
+    fn fetch_prune(&self, remote: &str) -> Result<(), AppError>;
+
+    /// Check whether a remote with the given name is configured.
+    fn remote_exists(&self, name: &str) -> Result<bool, AppError>;
+
+    /// Read a value from the repository's local config.
+    /// Returns `None` when the key is unset there; an empty value is `Some("")`.
+    fn get_config(&self, key: &str) -> Result<Option<String>, AppError>;
+
+    /// Set a config value in the repository's local config.
+    fn set_config(&self, key: &str, value: &str) -> Result<(), AppError>;
+
+    /// Remove a value from the repository's local config.
+    fn unset_config(&self, key: &str) -> Result<(), AppError>;
+
+    /// Abort an in-progress merge, restoring the pre-merge working tree.
+    /// No-op when no merge is in progress.
+    fn abort_merge(&self) -> Result<(), AppError>;
+
+    /// Apply the commit `rev` onto the current branch.
+    /// Fails with [`AppError::CherryPickConflict`] when it does not apply cleanly,
+    /// leaving the cherry-pick in progress for [`Git::cherry_pick_abort`].
+    fn cherry_pick(&self, rev: &str) -> Result<(), AppError>;
+
+    /// Abort an in-progress cherry-pick, restoring the pre-pick working tree.
+    /// No-op when no cherry-pick is in progress.
+    fn cherry_pick_abort(&self) -> Result<(), AppError>;
+
+    /// Check out `branch` and hard-reset it to `rev`, discarding local commits
+    /// and working-tree changes on that branch.
+    fn reset_hard_to(&self, branch: &str, rev: &str) -> Result<(), AppError>;
+
     /// Delete a local branch. Returns true if the branch was deleted.
     fn delete_branch(&self, branch: &str, force: bool) -> Result<bool, AppError>;
 
+    /// Create a tag at `rev` (HEAD when `None`), annotated when `message` is given.
+    /// Fails with [`AppError::TagExists`] instead of moving an existing tag.
+    fn create_tag(
+        &self,
+        name: &str,
+        message: Option<&str>,
+        rev: Option<&str>,
+    ) -> Result<(), AppError>;
+
     /// Create a transactional workspace for the given branch.
     fn create_workspace(&self, branch: &str) -> Result<Box<dyn GitWorkspace>, AppError>;
 }
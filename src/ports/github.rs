@@ -30,6 +30,19 @@ pub struct PullRequestDetail {
     pub auto_merge_enabled: bool,
 }
 
+/// Summary of an open pull request returned by PR discovery.
+#[derive(Debug, Clone)]
+pub struct PullRequestSummary {
+    /// PR number.
+    pub number: u64,
+    /// Head branch name.
+    pub head: String,
+    /// Base branch name.
+    pub base: String,
+    /// Creation time as reported by GitHub (RFC 3339).
+    pub created_at: String,
+}
+
 /// A single comment on a PR or issue.
 #[derive(Debug, Clone)]
 #[allow(dead_code)]
@@ -49,6 +62,29 @@ pub struct IssueInfo {
     pub url: String,
 }
 
+/// How `gh pr merge` lands a pull request.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MergeStrategy {
+    #[default]
+    Squash,
+    Merge,
+    Rebase,
+}
+
+impl MergeStrategy {
+    /// The matching `gh pr merge` flag.
+    pub fn gh_flag(self) -> &'static str {
+        match self {
+            MergeStrategy::Squash => "--squash",
+            MergeStrategy::Merge => "--merge",
+            MergeStrategy::Rebase => "--rebase",
+        }
+    }
+}
+
+/// Most pull requests [`GitHub::list_open_pull_requests`] returns; a full page may be truncated.
+pub const OPEN_PULL_REQUESTS_LIMIT: usize = 200;
+
 pub trait GitHub {
     // === Mock mode operations ===
 
@@ -81,6 +117,9 @@ pub trait GitHub {
     #[allow(dead_code)]
     fn get_pr_detail(&self, pr_number: u64) -> Result<PullRequestDetail, AppError>;
 
+    /// List open pull requests targeting `base`, at most [`OPEN_PULL_REQUESTS_LIMIT`].
+    fn list_open_pull_requests(&self, base: &str) -> Result<Vec<PullRequestSummary>, AppError>;
+
     /// List comments on a pull request.
     #[allow(dead_code)]
     fn list_pr_comments(&self, pr_number: u64) -> Result<Vec<PrComment>, AppError>;
@@ -110,9 +149,9 @@ pub trait GitHub {
     #[allow(dead_code)]
     fn enable_automerge(&self, pr_number: u64) -> Result<(), AppError>;
 
-    /// Merge a pull request immediately (squash).
+    /// Merge a pull request immediately with the given strategy.
     /// Does NOT use auto-merge side-effects.
-    fn merge_pull_request(&self, pr_number: u64) -> Result<(), AppError>;
+    fn merge_pull_request(&self, pr_number: u64, strategy: MergeStrategy) -> Result<(), AppError>;
 
     /// List files changed by a pull request (relative paths).
     #[allow(dead_code)]
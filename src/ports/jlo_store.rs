@@ -26,6 +26,15 @@ pub trait JloStore {
     /// Absolute path to the `.jlo/` directory.
     fn jlo_path(&self) -> PathBuf;
 
+    /// Path of the control-plane config this store reads: the `--config` file when one
+    /// was given, otherwise `.jlo/config.toml`.
+    fn config_path(&self) -> PathBuf;
+
+    /// Read the control-plane config at [`JloStore::config_path`].
+    ///
+    /// Fails with [`AppError::ControlPlaneConfigMissing`] when the file does not exist.
+    fn read_config(&self) -> Result<String, AppError>;
+
     /// Write the `.jlo/.jlo-version` version pin.
     fn jlo_write_version(&self, version: &str) -> Result<(), AppError>;
 
@@ -38,9 +47,17 @@ pub trait JloStore {
     /// Find a role by exact match, `layer/role` format, or unique prefix.
     fn find_role_fuzzy(&self, query: &str) -> Result<Option<DiscoveredRole>, AppError>;
 
+    /// Whether `.jlo/roles/<layer>/<role>/role.yml` exists.
+    ///
+    /// Names that are not valid role IDs report `false` instead of erroring.
+    fn role_exists(&self, layer: Layer, role: &str) -> bool;
+
     /// Absolute path to a discovered role's directory, if it exists.
     fn role_path(&self, role: &DiscoveredRole) -> Option<PathBuf>;
 
     /// Write a role definition file at `.jlo/roles/<layer>/<role>/role.yml`.
     fn write_role(&self, layer: Layer, role_id: &str, content: &str) -> Result<(), AppError>;
+
+    /// SHA-256 hex digest of a repository-relative file's bytes.
+    fn file_hash(&self, path: &str) -> Result<String, AppError>;
 }
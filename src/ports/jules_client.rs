@@ -50,10 +50,15 @@ pub struct SessionResponse {
     /// Status of the session.
     #[allow(dead_code)]
     pub status: String,
+    /// Link to the session in the Jules web UI, when the API returns one.
+    pub url: Option<String>,
 }
 
 /// Port for Jules API operations.
 pub trait JulesClient {
     /// Create a new Jules session.
     fn create_session(&self, request: SessionRequest) -> Result<SessionResponse, AppError>;
+
+    /// Verify the API is reachable and accepts the configured credentials.
+    fn health(&self) -> Result<(), AppError>;
 }
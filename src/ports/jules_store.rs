@@ -32,4 +32,10 @@ pub trait JulesStore: PromptAssetLoader {
 
     /// Read the `.jules/.jlo-version` version marker, if present.
     fn jules_read_version(&self) -> Result<Option<String>, AppError>;
+
+    /// Move `exchange/events/<from_state>/<event>.yml` to `<to_state>/` in one rename.
+    ///
+    /// Both states must be known event states, the event must exist in
+    /// `from_state`, and `to_state` must not already hold it.
+    fn move_event(&self, event: &str, from_state: &str, to_state: &str) -> Result<(), AppError>;
 }
@@ -7,8 +7,11 @@ mod repository_filesystem;
 mod role_template_store;
 mod setup_component_catalog;
 
-pub use git::{Git, GitWorkspace};
-pub use github::{GitHub, IssueInfo, PrComment, PullRequestDetail, PullRequestInfo};
+pub use git::{CommitInfo, Git, GitWorkspace, StatusEntry};
+pub use github::{
+    GitHub, IssueInfo, MergeStrategy, OPEN_PULL_REQUESTS_LIMIT, PrComment, PullRequestDetail,
+    PullRequestInfo, PullRequestSummary,
+};
 pub use jlo_store::{DiscoveredRole, JloStore};
 pub use jules_client::{AutomationMode, JulesClient, SessionRequest, SessionResponse};
 pub use jules_store::JulesStore;
@@ -25,9 +25,34 @@ pub trait RepositoryFilesystem {
     /// Remove a directory and all its contents. No-op if absent.
     fn remove_dir_all(&self, path: &str) -> Result<(), AppError>;
 
+    /// Rename a file or directory, creating the destination's parent as needed.
+    ///
+    /// Fails if `to` already exists; never overwrites.
+    fn rename(&self, from: &str, to: &str) -> Result<(), AppError>;
+
     /// List entries in a directory (returns paths relative to root).
     fn list_dir(&self, path: &str) -> Result<Vec<PathBuf>, AppError>;
 
+    /// List subdirectory names of `path`, sorted, excluding dot-directories.
+    fn list_dirs(&self, path: &str) -> Result<Vec<String>, AppError> {
+        let mut names = self.list_dirs_including_hidden(path)?;
+        names.retain(|name| !name.starts_with('.'));
+        Ok(names)
+    }
+
+    /// List subdirectory names of `path`, sorted, including dot-directories.
+    fn list_dirs_including_hidden(&self, path: &str) -> Result<Vec<String>, AppError> {
+        let mut names: Vec<String> = self
+            .list_dir(path)?
+            .iter()
+            .filter_map(|entry| entry.file_name().map(|name| name.to_string_lossy().to_string()))
+            .filter(|name| self.is_dir(&format!("{}/{}", path.trim_end_matches('/'), name)))
+            .collect();
+        names.sort();
+        names.dedup();
+        Ok(names)
+    }
+
     /// Set the executable bit on a file (Unix-only).
     fn set_executable(&self, path: &str) -> Result<(), AppError>;
 
@@ -7,6 +7,19 @@ pub struct ScaffoldFile {
     pub path: String,
     /// File content as UTF-8 text.
     pub content: String,
+    /// Write the file with the executable bit set (Unix).
+    pub executable: bool,
+}
+
+impl ScaffoldFile {
+    /// Build a scaffold file from embedded or generated content.
+    ///
+    /// Such content carries no filesystem mode, so scripts are marked
+    /// executable by their shebang line.
+    pub fn from_asset(path: String, content: String) -> Self {
+        let executable = content.starts_with("#!");
+        Self { path, content, executable }
+    }
 }
 
 /// Port for accessing role templates and scaffold content.
@@ -39,3 +52,17 @@ pub trait RoleTemplateStore {
     /// Load embedded builtin role.yml content for a specific layer/role.
     fn builtin_role_content(&self, layer: Layer, role_id: &str) -> Result<String, AppError>;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_asset_marks_shebang_scripts_executable() {
+        let script = ScaffoldFile::from_asset("a/run".to_string(), "#!/bin/sh\n".to_string());
+        let doc = ScaffoldFile::from_asset("a/run.sh".to_string(), "# notes\n".to_string());
+
+        assert!(script.executable);
+        assert!(!doc.executable);
+    }
+}
@@ -11,6 +11,7 @@ pub struct RunOptionsBuilder {
     role: Option<String>,
     requirement: Option<PathBuf>,
     task: Option<String>,
+    role_file: Option<PathBuf>,
     runtime: RunRuntimeOptions,
 }
 
@@ -22,6 +23,7 @@ impl RunOptionsBuilder {
             role: None,
             requirement: None,
             task: None,
+            role_file: None,
             runtime: RunRuntimeOptions::default(),
         }
     }
@@ -31,6 +33,11 @@ impl RunOptionsBuilder {
         self
     }
 
+    pub fn role_file(mut self, role_file: impl Into<PathBuf>) -> Self {
+        self.role_file = Some(role_file.into());
+        self
+    }
+
     pub fn prompt_preview(mut self, enabled: bool) -> Self {
         self.runtime.prompt_preview = enabled;
         self
@@ -67,6 +74,7 @@ impl RunOptionsBuilder {
             role: self.role,
             requirement: self.requirement,
             task: self.task,
+            role_file: self.role_file,
         }
     }
 
@@ -1,14 +1,40 @@
+use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Mutex;
 
 use crate::domain::AppError;
-use crate::ports::{Git, GitWorkspace};
+use crate::ports::{CommitInfo, Git, GitWorkspace};
 
 pub struct FakeGit {
     pub committed_files: Mutex<Vec<PathBuf>>,
     pub branches_created: Mutex<Vec<String>>,
     pub head_sha: Mutex<String>,
     pub current_branch: Mutex<String>,
+    /// Branch reported by `get_default_branch`; `None` when undetectable.
+    pub default_branch: Mutex<Option<String>>,
+    pub refs: Mutex<HashMap<String, String>>,
+    /// Merge sources for which `git merge` fails with a conflict.
+    pub conflicting_merges: Mutex<Vec<String>>,
+    /// Commit history as `(sha, message)`, oldest first.
+    pub commits: Mutex<Vec<(String, String)>>,
+    pub merge_aborts: Mutex<usize>,
+    /// Revisions passed to `cherry_pick`, including conflicting ones.
+    pub cherry_picks: Mutex<Vec<String>>,
+    /// Revisions for which `cherry_pick` fails with a conflict.
+    pub conflicting_cherry_picks: Mutex<Vec<String>>,
+    pub cherry_pick_aborts: Mutex<usize>,
+    /// `(branch, rev)` pairs passed to `reset_hard_to`.
+    pub hard_resets: Mutex<Vec<(String, String)>>,
+    /// Branch names passed to `push_branch`.
+    pub pushed_branches: Mutex<Vec<String>>,
+    /// Repository-local config values.
+    pub config: Mutex<HashMap<String, String>>,
+    /// Author returned by `blame_last_author`, keyed by `(rev, path)`.
+    pub last_authors: Mutex<HashMap<(String, String), String>>,
+    /// Branch names reported by `git ls-remote --heads origin`.
+    pub remote_heads: Mutex<Vec<String>>,
+    /// `run_command` and `fetch_prune` invocations as git argument strings, in call order.
+    pub commands: Mutex<Vec<String>>,
 }
 
 impl Default for FakeGit {
@@ -24,6 +50,20 @@ impl FakeGit {
             branches_created: Mutex::new(Vec::new()),
             head_sha: Mutex::new("abc123".to_string()),
             current_branch: Mutex::new("jules".to_string()),
+            default_branch: Mutex::new(None),
+            refs: Mutex::new(HashMap::new()),
+            conflicting_merges: Mutex::new(Vec::new()),
+            commits: Mutex::new(Vec::new()),
+            merge_aborts: Mutex::new(0),
+            cherry_picks: Mutex::new(Vec::new()),
+            conflicting_cherry_picks: Mutex::new(Vec::new()),
+            cherry_pick_aborts: Mutex::new(0),
+            hard_resets: Mutex::new(Vec::new()),
+            pushed_branches: Mutex::new(Vec::new()),
+            config: Mutex::new(HashMap::new()),
+            last_authors: Mutex::new(HashMap::new()),
+            remote_heads: Mutex::new(Vec::new()),
+            commands: Mutex::new(Vec::new()),
         }
     }
 }
@@ -37,6 +77,21 @@ impl Git for FakeGit {
         Ok(self.current_branch.lock().unwrap().clone())
     }
 
+    fn get_default_branch(&self) -> Result<String, AppError> {
+        self.default_branch.lock().unwrap().clone().ok_or_else(|| AppError::GitError {
+            command: "git symbolic-ref refs/remotes/origin/HEAD".to_string(),
+            details: "ref refs/remotes/origin/HEAD is not a symbolic ref".to_string(),
+        })
+    }
+
+    fn blame_last_author(&self, rev: &str, path: &str) -> Result<String, AppError> {
+        let key = (rev.to_string(), path.to_string());
+        self.last_authors.lock().unwrap().get(&key).cloned().ok_or_else(|| AppError::GitError {
+            command: format!("git log -1 --format=%an {} -- {}", rev, path),
+            details: "no commit touches this path".to_string(),
+        })
+    }
+
     fn commit_exists(&self, _sha: &str) -> bool {
         true
     }
@@ -49,11 +104,81 @@ impl Git for FakeGit {
         Ok("root".into())
     }
 
+    fn rev_parse(&self, rev: &str) -> Result<String, AppError> {
+        if rev == "HEAD" {
+            return self.get_head_sha();
+        }
+        self.refs.lock().unwrap().get(rev).cloned().ok_or_else(|| AppError::GitError {
+            command: format!("git rev-parse {}", rev),
+            details: format!("Cannot resolve '{}' to a commit", rev),
+        })
+    }
+
+    fn commit_message(&self, sha: &str) -> Result<String, AppError> {
+        self.commits
+            .lock()
+            .unwrap()
+            .iter()
+            .find(|(commit, _)| commit == sha)
+            .map(|(_, message)| message.clone())
+            .ok_or_else(|| AppError::GitError {
+                command: format!("git log -1 --format=%B {}", sha),
+                details: format!("Commit {} does not exist", sha),
+            })
+    }
+
+    fn log_range(&self, from: &str, to: &str) -> Result<Vec<CommitInfo>, AppError> {
+        let commits = self.commits.lock().unwrap();
+        let start = commits.iter().position(|(sha, _)| sha == from).map_or(0, |i| i + 1);
+        let end = commits.iter().position(|(sha, _)| sha == to).map_or(commits.len(), |i| i + 1);
+        Ok(commits[start..end.max(start)]
+            .iter()
+            .rev()
+            .map(|(sha, message)| CommitInfo {
+                sha: sha.clone(),
+                subject: message.lines().next().unwrap_or_default().to_string(),
+            })
+            .collect())
+    }
+
     fn has_changes(&self, _from: &str, _to: &str, _pathspec: &[&str]) -> Result<bool, AppError> {
         Ok(false)
     }
 
-    fn run_command(&self, _args: &[&str], _cwd: Option<&Path>) -> Result<String, AppError> {
+    fn status(&self, _pathspec: &[&str]) -> Result<Vec<crate::ports::StatusEntry>, AppError> {
+        Ok(Vec::new())
+    }
+
+    fn run_command(&self, args: &[&str], _cwd: Option<&Path>) -> Result<String, AppError> {
+        self.commands.lock().unwrap().push(args.join(" "));
+        if args.first() == Some(&"merge")
+            && let Some(source) = args.last()
+            && self.conflicting_merges.lock().unwrap().iter().any(|c| c == source)
+        {
+            return Err(AppError::GitError {
+                command: format!("git {}", args.join(" ")),
+                details: format!("CONFLICT: automatic merge of '{}' failed", source),
+            });
+        }
+        if args.starts_with(&["branch", "-r", "--list"])
+            && let Some(prefix) = args.get(3).and_then(|pattern| pattern.strip_prefix("origin/"))
+        {
+            let prefix = prefix.trim_end_matches('*');
+            let heads = self.remote_heads.lock().unwrap();
+            return Ok(heads
+                .iter()
+                .filter(|branch| branch.starts_with(prefix))
+                .map(|branch| format!("origin/{}\n", branch))
+                .collect());
+        }
+        if args.first() == Some(&"ls-remote") {
+            let heads = self.remote_heads.lock().unwrap();
+            return Ok(heads
+                .iter()
+                .map(|branch| format!("0000000\trefs/heads/{}", branch))
+                .collect::<Vec<_>>()
+                .join("\n"));
+        }
         Ok(String::new())
     }
 
@@ -61,6 +186,56 @@ impl Git for FakeGit {
         Ok(())
     }
 
+    fn fetch_prune(&self, remote: &str) -> Result<(), AppError> {
+        self.commands.lock().unwrap().push(format!("fetch --prune {}", remote));
+        Ok(())
+    }
+
+    fn remote_exists(&self, _name: &str) -> Result<bool, AppError> {
+        Ok(true)
+    }
+
+    fn get_config(&self, key: &str) -> Result<Option<String>, AppError> {
+        Ok(self.config.lock().unwrap().get(key).cloned())
+    }
+
+    fn set_config(&self, key: &str, value: &str) -> Result<(), AppError> {
+        self.config.lock().unwrap().insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    fn unset_config(&self, key: &str) -> Result<(), AppError> {
+        self.config.lock().unwrap().remove(key);
+        Ok(())
+    }
+
+    fn abort_merge(&self) -> Result<(), AppError> {
+        *self.merge_aborts.lock().unwrap() += 1;
+        Ok(())
+    }
+
+    fn cherry_pick(&self, rev: &str) -> Result<(), AppError> {
+        self.cherry_picks.lock().unwrap().push(rev.to_string());
+        if self.conflicting_cherry_picks.lock().unwrap().iter().any(|c| c == rev) {
+            return Err(AppError::CherryPickConflict {
+                rev: rev.to_string(),
+                details: format!("CONFLICT: could not apply {}", rev),
+            });
+        }
+        Ok(())
+    }
+
+    fn cherry_pick_abort(&self) -> Result<(), AppError> {
+        *self.cherry_pick_aborts.lock().unwrap() += 1;
+        Ok(())
+    }
+
+    fn reset_hard_to(&self, branch: &str, rev: &str) -> Result<(), AppError> {
+        self.hard_resets.lock().unwrap().push((branch.to_string(), rev.to_string()));
+        *self.current_branch.lock().unwrap() = branch.to_string();
+        Ok(())
+    }
+
     fn checkout_branch(&self, name: &str, create: bool) -> Result<(), AppError> {
         if create {
             self.branches_created.lock().unwrap().push(name.to_string());
@@ -69,7 +244,8 @@ impl Git for FakeGit {
         Ok(())
     }
 
-    fn push_branch(&self, _name: &str, _force: bool) -> Result<(), AppError> {
+    fn push_branch(&self, name: &str, _force: bool) -> Result<(), AppError> {
+        self.pushed_branches.lock().unwrap().push(name.to_string());
         Ok(())
     }
 
@@ -86,6 +262,25 @@ impl Git for FakeGit {
         Ok(true)
     }
 
+    fn create_tag(
+        &self,
+        name: &str,
+        _message: Option<&str>,
+        rev: Option<&str>,
+    ) -> Result<(), AppError> {
+        let key = format!("refs/tags/{}", name);
+        if self.refs.lock().unwrap().contains_key(&key) {
+            return Err(AppError::TagExists(name.to_string()));
+        }
+        // Revisions that are not known refs are taken to be commit SHAs.
+        let sha = match rev {
+            None => self.get_head_sha()?,
+            Some(rev) => self.rev_parse(rev).unwrap_or_else(|_| rev.to_string()),
+        };
+        self.refs.lock().unwrap().insert(key, sha);
+        Ok(())
+    }
+
     fn commit_files(&self, _msg: &str, files: &[&Path]) -> Result<String, AppError> {
         let mut committed = self.committed_files.lock().unwrap();
         for f in files {
@@ -112,6 +307,14 @@ impl Git for FakeGitWorkspace {
         Ok("fake-branch".to_string())
     }
 
+    fn get_default_branch(&self) -> Result<String, AppError> {
+        Ok("main".to_string())
+    }
+
+    fn blame_last_author(&self, _rev: &str, _path: &str) -> Result<String, AppError> {
+        Ok("Test Author".to_string())
+    }
+
     fn commit_exists(&self, _sha: &str) -> bool {
         true
     }
@@ -124,10 +327,26 @@ impl Git for FakeGitWorkspace {
         Ok("root".to_string())
     }
 
+    fn rev_parse(&self, _rev: &str) -> Result<String, AppError> {
+        Ok("fake-sha".to_string())
+    }
+
+    fn commit_message(&self, _sha: &str) -> Result<String, AppError> {
+        Ok(String::new())
+    }
+
+    fn log_range(&self, _from: &str, _to: &str) -> Result<Vec<CommitInfo>, AppError> {
+        Ok(Vec::new())
+    }
+
     fn has_changes(&self, _from: &str, _to: &str, _pathspec: &[&str]) -> Result<bool, AppError> {
         Ok(false)
     }
 
+    fn status(&self, _pathspec: &[&str]) -> Result<Vec<crate::ports::StatusEntry>, AppError> {
+        Ok(Vec::new())
+    }
+
     fn run_command(&self, _args: &[&str], _cwd: Option<&Path>) -> Result<String, AppError> {
         Ok(String::new())
     }
@@ -157,10 +376,55 @@ impl Git for FakeGitWorkspace {
         Ok(())
     }
 
+    fn fetch_prune(&self, _remote: &str) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    fn remote_exists(&self, _name: &str) -> Result<bool, AppError> {
+        Ok(true)
+    }
+
+    fn get_config(&self, _key: &str) -> Result<Option<String>, AppError> {
+        Ok(None)
+    }
+
+    fn set_config(&self, _key: &str, _value: &str) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    fn unset_config(&self, _key: &str) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    fn abort_merge(&self) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    fn cherry_pick(&self, _rev: &str) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    fn cherry_pick_abort(&self) -> Result<(), AppError> {
+        Ok(())
+    }
+
+    fn reset_hard_to(&self, _branch: &str, _rev: &str) -> Result<(), AppError> {
+        Ok(())
+    }
+
     fn delete_branch(&self, _branch: &str, _force: bool) -> Result<bool, AppError> {
         Ok(true)
     }
 
+    fn create_tag(
+        &self,
+        _name: &str,
+        _message: Option<&str>,
+        _rev: Option<&str>,
+    ) -> Result<(), AppError> {
+        Ok(())
+    }
+
     fn create_workspace(&self, _branch: &str) -> Result<Box<dyn GitWorkspace>, AppError> {
         Ok(Box::new(FakeGitWorkspace { path: self.path.clone() }))
     }
@@ -171,3 +435,22 @@ impl GitWorkspace for FakeGitWorkspace {
         &self.path
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cherry_pick_conflict_is_classified_and_abort_is_tracked() {
+        let git = FakeGit::new();
+        git.conflicting_cherry_picks.lock().unwrap().push("def456".to_string());
+
+        git.cherry_pick("abc123").unwrap();
+        let err = git.cherry_pick("def456").unwrap_err();
+        assert!(matches!(err, AppError::CherryPickConflict { ref rev, .. } if rev == "def456"));
+        git.cherry_pick_abort().unwrap();
+
+        assert_eq!(*git.cherry_picks.lock().unwrap(), vec!["abc123", "def456"]);
+        assert_eq!(*git.cherry_pick_aborts.lock().unwrap(), 1);
+    }
+}
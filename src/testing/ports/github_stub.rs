@@ -2,7 +2,10 @@ use std::sync::Mutex;
 use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering};
 
 use crate::domain::AppError;
-use crate::ports::{GitHub, IssueInfo, PrComment, PullRequestDetail, PullRequestInfo};
+use crate::ports::{
+    GitHub, IssueInfo, MergeStrategy, PrComment, PullRequestDetail, PullRequestInfo,
+    PullRequestSummary,
+};
 
 pub struct FakeGitHub {
     pub pr_detail: Mutex<PullRequestDetail>,
@@ -11,6 +14,11 @@ pub struct FakeGitHub {
     pub ensured_labels: Mutex<Vec<String>>,
     pub applied_labels: Mutex<Vec<(u64, String)>>,
     pub files: Mutex<Vec<String>>,
+    pub open_pull_requests: Mutex<Vec<PullRequestSummary>>,
+    /// `(head, base)` pairs passed to `create_pull_request`.
+    pub created_pull_requests: Mutex<Vec<(String, String)>>,
+    /// Bodies passed to `create_pull_request`, in call order.
+    pub created_pull_request_bodies: Mutex<Vec<String>>,
 
     // Auto-merge simulation
     pub automerge_calls: AtomicU32,
@@ -45,6 +53,9 @@ impl FakeGitHub {
             ensured_labels: Mutex::new(Vec::new()),
             applied_labels: Mutex::new(Vec::new()),
             files: Mutex::new(Vec::new()),
+            open_pull_requests: Mutex::new(Vec::new()),
+            created_pull_requests: Mutex::new(Vec::new()),
+            created_pull_request_bodies: Mutex::new(Vec::new()),
             automerge_calls: AtomicU32::new(0),
             remaining_transient_automerge_failures: AtomicU32::new(0),
             fatal_automerge_failure: AtomicBool::new(false),
@@ -65,6 +76,11 @@ impl FakeGitHub {
         self
     }
 
+    pub fn with_open_pull_requests(self, prs: Vec<PullRequestSummary>) -> Self {
+        *self.open_pull_requests.lock().unwrap() = prs;
+        self
+    }
+
     // Helper from process.rs tests
     pub fn jules_runtime_pr() -> Self {
         Self::new()
@@ -113,8 +129,10 @@ impl GitHub for FakeGitHub {
         head: &str,
         base: &str,
         _title: &str,
-        _body: &str,
+        body: &str,
     ) -> Result<PullRequestInfo, AppError> {
+        self.created_pull_requests.lock().unwrap().push((head.to_string(), base.to_string()));
+        self.created_pull_request_bodies.lock().unwrap().push(body.to_string());
         let number = self.next_pr_number.fetch_add(1, Ordering::SeqCst);
         Ok(PullRequestInfo {
             number,
@@ -147,6 +165,11 @@ impl GitHub for FakeGitHub {
         Ok(self.pr_detail.lock().unwrap().clone())
     }
 
+    fn list_open_pull_requests(&self, base: &str) -> Result<Vec<PullRequestSummary>, AppError> {
+        let prs = self.open_pull_requests.lock().unwrap();
+        Ok(prs.iter().filter(|pr| pr.base == base).cloned().collect())
+    }
+
     fn list_pr_comments(&self, _pr_number: u64) -> Result<Vec<PrComment>, AppError> {
         Ok(self.comments.lock().unwrap().clone())
     }
@@ -215,7 +238,7 @@ impl GitHub for FakeGitHub {
         Ok(())
     }
 
-    fn merge_pull_request(&self, pr_number: u64) -> Result<(), AppError> {
+    fn merge_pull_request(&self, pr_number: u64, _strategy: MergeStrategy) -> Result<(), AppError> {
         let _ = pr_number;
         Ok(())
     }
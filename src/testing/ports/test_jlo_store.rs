@@ -4,7 +4,9 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 
-use crate::domain::{AppError, Layer, RoleId};
+use sha2::{Digest, Sha256};
+
+use crate::domain::{AppError, IoErrorKind, Layer, RoleId};
 use crate::ports::{DiscoveredRole, JloStore};
 
 use super::test_files::TestFiles;
@@ -53,6 +55,20 @@ impl JloStore for MockJloStore {
         PathBuf::from(".jlo")
     }
 
+    fn config_path(&self) -> PathBuf {
+        PathBuf::from(".jlo/config.toml")
+    }
+
+    fn read_config(&self) -> Result<String, AppError> {
+        self.files
+            .files
+            .lock()
+            .unwrap()
+            .get(".jlo/config.toml")
+            .cloned()
+            .ok_or(AppError::ControlPlaneConfigMissing)
+    }
+
     fn jlo_write_version(&self, version: &str) -> Result<(), AppError> {
         *self.version.lock().unwrap() = Some(version.to_string());
         Ok(())
@@ -87,6 +103,15 @@ impl JloStore for MockJloStore {
         }
     }
 
+    fn role_exists(&self, layer: Layer, role: &str) -> bool {
+        let Ok(id) = RoleId::new(role) else {
+            return false;
+        };
+        let path = format!(".jlo/roles/{}/{}/role.yml", layer.dir_name(), role);
+        self.roles.lock().unwrap().contains_key(&(layer, id))
+            || self.files.files.lock().unwrap().contains_key(&path)
+    }
+
     fn role_path(&self, role: &DiscoveredRole) -> Option<PathBuf> {
         if self.roles.lock().unwrap().contains_key(&(role.layer, role.id.clone())) {
             Some(PathBuf::from(format!(".jlo/roles/{}/{}", role.layer.dir_name(), role.id)))
@@ -100,4 +125,13 @@ impl JloStore for MockJloStore {
         self.files.files.lock().unwrap().insert(path, content.to_string());
         Ok(())
     }
+
+    fn file_hash(&self, path: &str) -> Result<String, AppError> {
+        let files = self.files.files.lock().unwrap();
+        let content = files.get(path).ok_or_else(|| AppError::Io {
+            message: format!("File not found: {}", path),
+            kind: IoErrorKind::NotFound,
+        })?;
+        Ok(format!("{:x}", Sha256::digest(content.as_bytes())))
+    }
 }
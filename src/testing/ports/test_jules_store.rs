@@ -3,6 +3,8 @@
 use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
+use crate::adapters::catalogs::scaffold_assets::list_event_states;
+use crate::domain::exchange::events::paths::event_transition_paths;
 use crate::domain::{AppError, PromptAssetLoader};
 use crate::ports::{JulesStore, ScaffoldFile};
 
@@ -93,4 +95,28 @@ impl JulesStore for MockJulesStore {
     fn jules_read_version(&self) -> Result<Option<String>, AppError> {
         Ok(self.version.lock().unwrap().clone())
     }
+
+    fn move_event(&self, event: &str, from_state: &str, to_state: &str) -> Result<(), AppError> {
+        let (source, dest) = event_transition_paths(
+            &self.jules_path(),
+            event,
+            from_state,
+            to_state,
+            &list_event_states()?,
+        )?;
+        let mut files = self.files.files.lock().unwrap();
+        let source = source.to_string_lossy().to_string();
+        let dest = dest.to_string_lossy().to_string();
+        if files.contains_key(&dest) {
+            return Err(AppError::Validation(format!(
+                "Event '{}' already exists in '{}'",
+                event, to_state
+            )));
+        }
+        let content = files.remove(&source).ok_or_else(|| {
+            AppError::Validation(format!("Event '{}' not found in '{}'", event, from_state))
+        })?;
+        files.insert(dest, content);
+        Ok(())
+    }
 }
@@ -43,6 +43,38 @@ impl RepositoryFilesystem for MockRepositoryFs {
         Ok(())
     }
 
+    fn rename(&self, from: &str, to: &str) -> Result<(), AppError> {
+        let mut files = self.files.files.lock().unwrap();
+        let from_prefix = format!("{}/", from.trim_end_matches('/'));
+        let to_prefix = format!("{}/", to.trim_end_matches('/'));
+        if files.keys().any(|key| key == to || key.starts_with(&to_prefix)) {
+            return Err(AppError::Validation(format!(
+                "Cannot rename '{}' to '{}': destination already exists",
+                from, to
+            )));
+        }
+        let moved: Vec<String> = files
+            .keys()
+            .filter(|key| key.as_str() == from || key.starts_with(&from_prefix))
+            .cloned()
+            .collect();
+        if moved.is_empty() {
+            return Err(AppError::from(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "Mock file not found",
+            )));
+        }
+        for key in moved {
+            let content = files.remove(&key).unwrap_or_default();
+            let new_key = match key.strip_prefix(&from_prefix) {
+                Some(rest) => format!("{}{}", to_prefix, rest),
+                None => to.to_string(),
+            };
+            files.insert(new_key, content);
+        }
+        Ok(())
+    }
+
     fn list_dir(&self, path: &str) -> Result<Vec<PathBuf>, AppError> {
         let prefix = if path.ends_with('/') { path.to_string() } else { format!("{}/", path) };
         let path_obj = Path::new(path);
@@ -83,6 +83,10 @@ impl RepositoryFilesystem for TestStore {
         self.fs.remove_dir_all(path)
     }
 
+    fn rename(&self, from: &str, to: &str) -> Result<(), AppError> {
+        self.fs.rename(from, to)
+    }
+
     fn list_dir(&self, path: &str) -> Result<Vec<PathBuf>, AppError> {
         self.fs.list_dir(path)
     }
@@ -123,6 +127,14 @@ impl JloStore for TestStore {
         self.jlo.jlo_path()
     }
 
+    fn config_path(&self) -> PathBuf {
+        self.jlo.config_path()
+    }
+
+    fn read_config(&self) -> Result<String, AppError> {
+        self.jlo.read_config()
+    }
+
     fn jlo_write_version(&self, version: &str) -> Result<(), AppError> {
         self.jlo.jlo_write_version(version)
     }
@@ -139,6 +151,10 @@ impl JloStore for TestStore {
         self.jlo.find_role_fuzzy(query)
     }
 
+    fn role_exists(&self, layer: Layer, role: &str) -> bool {
+        self.jlo.role_exists(layer, role)
+    }
+
     fn role_path(&self, role: &DiscoveredRole) -> Option<PathBuf> {
         self.jlo.role_path(role)
     }
@@ -146,6 +162,10 @@ impl JloStore for TestStore {
     fn write_role(&self, layer: Layer, role_id: &str, content: &str) -> Result<(), AppError> {
         self.jlo.write_role(layer, role_id, content)
     }
+
+    fn file_hash(&self, path: &str) -> Result<String, AppError> {
+        self.jlo.file_hash(path)
+    }
 }
 
 // --- Delegate JulesStore to self.jules ---
@@ -170,6 +190,10 @@ impl JulesStore for TestStore {
     fn jules_read_version(&self) -> Result<Option<String>, AppError> {
         self.jules.jules_read_version()
     }
+
+    fn move_event(&self, event: &str, from_state: &str, to_state: &str) -> Result<(), AppError> {
+        self.jules.move_event(event, from_state, to_state)
+    }
 }
 
 // --- Delegate PromptAssetLoader to self.jules ---
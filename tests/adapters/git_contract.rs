@@ -36,6 +36,72 @@ fn git_contract_tests() {
         "Should not have changes between sha1 and sha1"
     );
 
+    // Working-tree status
+    assert!(git.status(&[]).unwrap().is_empty(), "Clean tree should have no status entries");
+
+    // Last author of a path comes from the local history of the given revision
+    assert_eq!(git.blame_last_author(&sha2, "test.txt").unwrap(), "Test User");
+    assert_eq!(git.blame_last_author(&sha1, "test.txt").unwrap(), "Test User");
+    assert!(git.blame_last_author(&sha2, "missing.txt").is_err(), "untracked path has no author");
+    fs::write(&file_path, "dirty").unwrap();
+    fs::write(root.join("untracked.txt"), "new").unwrap();
+    let status = git.status(&[]).unwrap();
+    assert_eq!(status.len(), 2, "{:?}", status);
+    assert!(status.iter().any(|e| e.path == "test.txt" && e.worktree_state == 'M'));
+    assert!(status.iter().any(|e| e.path == "untracked.txt" && e.is_untracked()));
+    assert_eq!(git.status(&["test.txt"]).unwrap().len(), 1, "pathspec should filter entries");
+    fs::write(&file_path, "changed").unwrap();
+    fs::remove_file(root.join("untracked.txt")).unwrap();
+
+    // Ref resolution
+    assert_eq!(git.rev_parse("main").unwrap(), sha2, "Branch should resolve to its tip SHA");
+    assert_eq!(git.rev_parse(&sha1[..7]).unwrap(), sha1, "Short SHA should resolve to full SHA");
+    assert!(git.rev_parse("no-such-ref").is_err(), "Unresolvable ref should error");
+
+    // Commit messages
+    assert_eq!(git.commit_message(&sha1).unwrap().trim(), "commit 1");
+    assert!(git.commit_message("no-such-ref").is_err(), "Unknown commit should error");
+
+    // Tags
+    git.create_tag("v1.0.0", Some("release 1.0.0"), Some(&sha1)).expect("annotated tag");
+    assert_eq!(git.rev_parse("v1.0.0^{commit}").unwrap(), sha1, "Tag should point at sha1");
+    let tag_type = git.run_command(&["cat-file", "-t", "v1.0.0"], None).unwrap();
+    assert_eq!(tag_type.trim(), "tag", "Message should create an annotated tag");
+    git.create_tag("head-mark", None, None).expect("lightweight tag");
+    assert_eq!(git.rev_parse("head-mark").unwrap(), sha2, "Tag without rev should mark HEAD");
+    let err = git.create_tag("v1.0.0", None, Some(&sha2)).expect_err("duplicate tag");
+    assert!(
+        matches!(err, jlo::domain::AppError::TagExists(ref name) if name == "v1.0.0"),
+        "{}",
+        err
+    );
+    assert_eq!(git.rev_parse("v1.0.0^{commit}").unwrap(), sha1, "Existing tag must not move");
+
+    // Cherry-pick
+    git.checkout_branch("pick-source", true).expect("checkout pick-source");
+    let picked_path = root.join("picked.txt");
+    fs::write(&picked_path, "picked").unwrap();
+    let picked = git.commit_files("picked commit", &[&picked_path]).expect("commit picked");
+    fs::write(&file_path, "source side").unwrap();
+    let conflicting = git.commit_files("conflicting commit", &[&file_path]).expect("commit");
+    git.checkout_branch("main", false).expect("checkout main");
+    fs::write(&file_path, "main side").unwrap();
+    let main_tip = git.commit_files("main change", &[&file_path]).expect("commit main");
+    git.cherry_pick(&picked).expect("clean cherry-pick");
+    assert!(root.join("picked.txt").exists(), "Picked file should land on main");
+    let err = git.cherry_pick(&conflicting).expect_err("conflicting cherry-pick");
+    assert!(
+        matches!(err, jlo::domain::AppError::CherryPickConflict { ref rev, .. } if *rev == conflicting),
+        "{}",
+        err
+    );
+    git.cherry_pick_abort().expect("abort cherry-pick");
+    git.cherry_pick_abort().expect("abort without cherry-pick is a no-op");
+    assert_eq!(fs::read_to_string(&file_path).unwrap(), "main side");
+    assert_eq!(git.rev_parse("HEAD~1").unwrap(), main_tip);
+    git.reset_hard_to("main", &sha2).expect("reset main");
+    git.delete_branch("pick-source", true).expect("delete pick-source");
+
     // 4. Ancestry
     let ancestor = git.get_nth_ancestor(&sha2, 1).expect("ancestor");
     assert_eq!(ancestor, Some(sha1.clone()), "Ancestor of sha2 should be sha1");
@@ -70,13 +136,43 @@ fn git_contract_tests() {
         .output()
         .expect("init remote");
 
+    assert!(!git.remote_exists("origin").unwrap(), "origin should not exist before add");
+
     // Add remote
     git.run_command(&["remote", "add", "origin", remote_dir.to_str().unwrap()], None)
         .expect("add remote");
+    assert!(git.remote_exists("origin").unwrap(), "origin should exist after add");
 
     // Push
     git.push_branch("main", false).expect("push main");
 
     // Fetch
     git.fetch("origin").expect("fetch origin");
+
+    // Fetch with prune drops remote-tracking refs deleted on the remote
+    git.run_command(&["branch", "stale-remote"], None).expect("create stale-remote");
+    git.push_branch("stale-remote", false).expect("push stale-remote");
+    git.fetch("origin").expect("fetch stale-remote");
+    git.run_command(&["push", "origin", "--delete", "stale-remote"], None)
+        .expect("delete remote branch");
+    git.run_command(&["update-ref", "refs/remotes/origin/stale-remote", "HEAD"], None)
+        .expect("restore stale tracking ref");
+    git.fetch_prune("origin").expect("fetch --prune origin");
+    let remote_branches = git.run_command(&["branch", "-r"], None).unwrap();
+    assert!(!remote_branches.contains("origin/stale-remote"), "{}", remote_branches);
+
+    // Default branch
+    assert!(git.get_default_branch().is_err(), "origin/HEAD is unknown until set");
+    git.run_command(&["remote", "set-head", "origin", "main"], None).expect("set origin HEAD");
+    assert_eq!(git.get_default_branch().unwrap(), "main");
+
+    // 8. Repository-local config
+    assert_eq!(git.get_config("jlo.contract").unwrap(), None, "unset key should be None");
+    git.set_config("jlo.contract", "value").expect("set config");
+    assert_eq!(git.get_config("jlo.contract").unwrap().as_deref(), Some("value"));
+    git.unset_config("jlo.contract").expect("unset config");
+    assert_eq!(git.get_config("jlo.contract").unwrap(), None, "unset key should be removed");
+    git.set_config("jlo.contract", "").expect("set empty config");
+    assert_eq!(git.get_config("jlo.contract").unwrap().as_deref(), Some(""), "empty is not unset");
+    git.unset_config("jlo.contract").expect("unset empty config");
 }
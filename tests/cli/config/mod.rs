@@ -0,0 +1 @@
+mod schema_describes_control_plane_config_contract;
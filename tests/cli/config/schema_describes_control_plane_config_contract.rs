@@ -0,0 +1,15 @@
+use crate::harness::TestContext;
+
+#[test]
+fn config_schema_describes_run_section() {
+    let ctx = TestContext::new();
+
+    let output = ctx.cli().args(["config", "schema"]).output().unwrap();
+    assert!(output.status.success());
+    let schema: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+
+    let run = &schema["properties"]["run"]["properties"];
+    assert_eq!(run["jlo_target_branch"]["type"], "string");
+    assert_eq!(run["jules_worker_branch"]["type"], "string");
+    assert_eq!(schema["additionalProperties"], false);
+}
@@ -1,3 +1,4 @@
+pub(crate) mod config;
 pub(crate) mod deinit;
 pub(crate) mod init;
 pub(crate) mod role;
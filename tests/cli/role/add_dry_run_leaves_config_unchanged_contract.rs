@@ -0,0 +1,22 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+#[test]
+fn role_add_dry_run_prints_diff_without_writing() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote();
+
+    let config_path = ctx.jlo_path().join("config.toml");
+    let before = std::fs::read_to_string(&config_path).unwrap();
+
+    ctx.cli()
+        .args(["role", "add", "observers", "pythonista", "--dry-run"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Would add role 'pythonista'"))
+        .stdout(predicate::str::contains("+  { name = \"pythonista\", enabled = true },"));
+
+    assert_eq!(std::fs::read_to_string(&config_path).unwrap(), before);
+    assert!(!ctx.jlo_path().join("roles/observers/pythonista").exists());
+}
@@ -0,0 +1,14 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+#[test]
+fn role_add_interactive_errors_without_terminal() {
+    let ctx = TestContext::new();
+    ctx.init_remote();
+
+    ctx.cli()
+        .args(["role", "add", "observers", "--interactive"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("pass the role explicitly"));
+}
@@ -0,0 +1,23 @@
+use crate::harness::TestContext;
+
+#[test]
+fn role_create_format_json_emits_outcome_with_created_path() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote();
+
+    let output = ctx
+        .cli()
+        .args(["role", "create", "observers", "my-obs", "--format", "json"])
+        .output()
+        .expect("run role create");
+    assert!(output.status.success());
+
+    let value: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be JSON");
+    assert_eq!(value["type"], "role");
+    assert_eq!(value["layer"], "observers");
+    assert_eq!(value["role"], "my-obs");
+    assert_eq!(value["path"], ".jlo/roles/observers/my-obs");
+    assert!(ctx.work_dir().join(".jlo/roles/observers/my-obs/role.yml").exists());
+}
@@ -0,0 +1,46 @@
+use crate::harness::TestContext;
+
+#[test]
+fn role_create_list_distinguishes_multi_and_single_role_layers() {
+    let ctx = TestContext::new();
+
+    let output = ctx.cli().args(["role", "create", "--list"]).output().expect("run create --list");
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let line_for = |layer: &str| {
+        stdout
+            .lines()
+            .find(|line| line.split_whitespace().next() == Some(layer))
+            .unwrap_or_else(|| panic!("missing {} in:\n{}", layer, stdout))
+            .to_string()
+    };
+
+    for layer in ["observers", "innovators"] {
+        let line = line_for(layer);
+        assert!(line.contains("multi-role"), "{}", line);
+        assert!(line.contains(&format!("jlo role create {} <role>", layer)), "{}", line);
+    }
+    for layer in ["narrator", "decider", "planner", "implementer", "integrator"] {
+        assert!(line_for(layer).contains("single-role"), "{}", line_for(layer));
+    }
+    assert!(line_for("implementer").contains("--requirement <path>"));
+}
+
+#[test]
+fn role_create_list_json_flags_custom_role_support() {
+    let ctx = TestContext::new();
+
+    let output = ctx
+        .cli()
+        .args(["role", "create", "--list", "--format", "json"])
+        .output()
+        .expect("run create --list --format json");
+    assert!(output.status.success());
+
+    let layers: Vec<serde_json::Value> = serde_json::from_slice(&output.stdout).unwrap();
+    let observers = layers.iter().find(|entry| entry["layer"] == "observers").unwrap();
+    let decider = layers.iter().find(|entry| entry["layer"] == "decider").unwrap();
+    assert_eq!(observers["custom_roles"], true);
+    assert_eq!(decider["custom_roles"], false);
+    assert_eq!(decider["kind"], "single-role");
+}
@@ -0,0 +1,47 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+fn scheduled_enabled(ctx: &TestContext, role: &str) -> Option<bool> {
+    let content = std::fs::read_to_string(ctx.jlo_path().join("config.toml")).unwrap();
+    let value: toml::Value = toml::from_str(&content).unwrap();
+    value["observers"]["roles"].as_array()?.iter().find_map(|entry| {
+        (entry.get("name")?.as_str()? == role).then(|| entry.get("enabled")?.as_bool())?
+    })
+}
+
+#[test]
+fn role_disable_and_enable_toggle_schedule_entry() {
+    let ctx = TestContext::new();
+    ctx.init_remote();
+    ctx.cli().args(["role", "create", "observers", "sentinel"]).assert().success();
+    assert_eq!(scheduled_enabled(&ctx, "sentinel"), Some(true));
+
+    ctx.cli()
+        .args(["role", "disable", "observers", "sentinel"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Disabled role 'sentinel'"));
+    assert_eq!(scheduled_enabled(&ctx, "sentinel"), Some(false));
+
+    ctx.cli()
+        .args(["role", "disable", "observers", "sentinel"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("already disabled"));
+    assert_eq!(scheduled_enabled(&ctx, "sentinel"), Some(false));
+
+    ctx.cli().args(["role", "enable", "observers", "sentinel"]).assert().success();
+    assert_eq!(scheduled_enabled(&ctx, "sentinel"), Some(true));
+}
+
+#[test]
+fn role_enable_rejects_unscheduled_role() {
+    let ctx = TestContext::new();
+    ctx.init_remote();
+
+    ctx.cli()
+        .args(["role", "enable", "observers", "ghost"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("'ghost' not found in config for layer 'observers'"));
+}
@@ -0,0 +1,30 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+#[test]
+fn role_list_available_shows_builtin_summaries() {
+    let ctx = TestContext::new();
+    ctx.init_remote();
+
+    ctx.cli()
+        .args(["role", "list", "observers", "--available"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(
+            "observers/taxonomy [language] - Establish shared vocabulary and naming consistency.",
+        ))
+        .stdout(predicate::str::contains("innovators/").not());
+}
+
+#[test]
+fn role_list_shows_installed_roles() {
+    let ctx = TestContext::new();
+    ctx.init_remote();
+    ctx.cli().args(["role", "create", "observers", "custom-lens"]).assert().success();
+
+    ctx.cli()
+        .args(["role", "list"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("observers/custom-lens"));
+}
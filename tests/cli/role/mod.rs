@@ -1,5 +1,9 @@
+mod add_dry_run_leaves_config_unchanged_contract;
+mod add_interactive_requires_terminal_contract;
 mod adds_role_and_updates_schedule_contract;
 mod adds_roles_and_updates_schedule_contract;
+mod create_emits_json_outcome_contract;
+mod create_list_shows_layer_kinds_contract;
 mod create_rejects_duplicate_role_contract;
 mod create_rejects_invalid_layer_contract;
 mod create_rejects_path_traversal_role_contract;
@@ -12,4 +16,7 @@ mod delete_rejects_role_not_in_schedule_contract;
 mod delete_rejects_single_role_layers_contract;
 mod delete_removes_role_and_schedule_contract;
 mod delete_requires_initialized_workspace_contract;
+mod enable_disable_toggles_schedule_contract;
+mod list_shows_builtin_summaries_contract;
+mod rename_moves_role_and_schedule_contract;
 mod short_aliases_contract;
@@ -0,0 +1,40 @@
+use crate::harness::TestContext;
+use crate::harness::scheduled_roles::read_scheduled_role_names;
+use predicates::prelude::*;
+
+#[test]
+fn role_rename_moves_role_directory_and_schedule_entry() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote();
+
+    ctx.cli().args(["role", "create", "observers", "old-name"]).assert().success();
+    ctx.cli().args(["role", "create", "observers", "taken"]).assert().success();
+    let old_yml = ctx.jlo_path().join("roles/observers/old-name/role.yml");
+    let content = std::fs::read_to_string(&old_yml).expect("created role.yml");
+    std::fs::write(&old_yml, content.replacen("role: ROLE_NAME", "role: old-name", 1))
+        .expect("write role.yml");
+
+    ctx.cli()
+        .args(["role", "rename", "observers", "old-name", "new-name"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Renamed"));
+
+    assert!(!ctx.jlo_path().join("roles/observers/old-name").exists());
+    let role_yml =
+        std::fs::read_to_string(ctx.jlo_path().join("roles/observers/new-name/role.yml"))
+            .expect("renamed role.yml should exist");
+    assert!(role_yml.contains("role: new-name"), "role.yml:\n{}", role_yml);
+
+    let roles = read_scheduled_role_names(ctx.work_dir(), "observers");
+    assert!(roles.contains(&"new-name".to_string()));
+    assert!(!roles.contains(&"old-name".to_string()));
+
+    ctx.cli()
+        .args(["role", "rename", "observers", "new-name", "taken"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already exists"));
+    assert!(ctx.jlo_path().join("roles/observers/new-name/role.yml").exists());
+}
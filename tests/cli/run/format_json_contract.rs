@@ -0,0 +1,100 @@
+use crate::harness::TestContext;
+use crate::harness::git_repository;
+use predicates::prelude::*;
+
+const EXPERIMENT_ROLE: &str = r#"role: experiment
+layer: observers
+constraint: []
+profile:
+  focus: "Experimental observer contract"
+  analysis_points:
+    - "Look for experimental patterns"
+"#;
+
+#[test]
+fn run_format_json_prompt_preview_prints_only_run_result_on_stdout() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    ctx.git_checkout_branch("jules", true);
+    std::fs::write(ctx.work_dir().join("experiment.yml"), EXPERIMENT_ROLE)
+        .expect("write role file");
+
+    let output = ctx
+        .cli()
+        .env_remove("GITHUB_ACTIONS")
+        .args(["run", "observers", "--role", "experiment", "--role-file", "experiment.yml"])
+        .args(["--prompt-preview", "--format", "json"])
+        .output()
+        .expect("run observers");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("stdout is one JSON value");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("=== Prompt Preview: Observers ==="), "stderr: {}", stderr);
+
+    assert_eq!(json["roles"], serde_json::json!(["experiment"]));
+    assert_eq!(json["prompt_preview"], true);
+    assert_eq!(json["sessions"], serde_json::json!([]));
+    assert!(json.get("cleanup_requirement").is_none());
+}
+
+#[test]
+fn run_format_json_mock_prints_only_run_result_on_stdout() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    git_repository::configure_user(ctx.work_dir());
+    git_repository::commit_all(ctx.work_dir(), "initial");
+    ctx.git_checkout_branch("jules", true);
+    let path = ctx.install_gh_stub();
+
+    let output = ctx
+        .cli()
+        .env("PATH", path)
+        .env("GH_TOKEN", "test-token")
+        .env("JULES_MOCK_TAG", "mock-format-json")
+        .env_remove("GITHUB_OUTPUT")
+        .args(["run", "narrator", "--mock", "--format", "json"])
+        .output()
+        .expect("run narrator");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("stdout is one JSON value");
+    assert_eq!(json["roles"], serde_json::json!(["narrator"]));
+    assert_eq!(json["sessions"], serde_json::json!([]));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("MOCK_TAG=mock-format-json"), "stderr: {}", stderr);
+}
+
+#[test]
+fn run_json_flag_is_shorthand_for_format_json() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    ctx.git_checkout_branch("jules", true);
+    std::fs::write(ctx.work_dir().join("experiment.yml"), EXPERIMENT_ROLE)
+        .expect("write role file");
+
+    let output = ctx
+        .cli()
+        .env_remove("GITHUB_ACTIONS")
+        .args(["run", "observers", "--role", "experiment", "--role-file", "experiment.yml"])
+        .args(["--prompt-preview", "--json"])
+        .output()
+        .expect("run observers");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    let json: serde_json::Value = serde_json::from_str(&stdout).expect("stdout is one JSON value");
+    assert_eq!(json["roles"], serde_json::json!(["experiment"]));
+    assert_eq!(json["prompt_preview"], true);
+
+    ctx.cli()
+        .args(["run", "narrator", "--prompt-preview", "--json", "--format", "text"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
@@ -1,6 +1,11 @@
+mod format_json_contract;
 mod implementer_prompt_preview_contract;
 mod narrator_prompt_preview_contract;
 mod narrator_skips_when_no_codebase_changes_contract;
+mod observers_role_file_override_contract;
 mod planner_prompt_preview_contract;
+mod planner_reads_requirement_from_stdin_contract;
+mod prompt_preview_reports_seed_ops_contract;
 mod rejects_missing_requirement_argument_contract;
 mod rejects_missing_requirement_file_contract;
+mod requires_confirmation_contract;
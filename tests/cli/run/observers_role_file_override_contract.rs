@@ -0,0 +1,93 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+const EXPERIMENT_ROLE: &str = r#"role: experiment
+layer: observers
+constraint: []
+profile:
+  focus: "Experimental observer contract"
+  analysis_points:
+    - "Look for experimental patterns"
+"#;
+
+#[test]
+fn run_observers_with_role_file_uses_override_definition() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    ctx.git_checkout_branch("jules", true);
+
+    let role_file = ctx.work_dir().join("experiment.yml");
+    std::fs::write(&role_file, EXPERIMENT_ROLE).expect("write role file");
+
+    // The role does not exist under .jlo/roles/; the override supplies it.
+    assert!(!ctx.work_dir().join(".jlo/roles/observers/experiment").exists());
+
+    ctx.cli()
+        .env_remove("GITHUB_ACTIONS")
+        .args(["run", "observers", "--role", "experiment", "--role-file", "experiment.yml"])
+        .arg("--prompt-preview")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Role config (override): experiment.yml"))
+        .stdout(predicate::str::contains("Would execute 1 session"));
+}
+
+#[test]
+fn run_observers_with_role_file_sends_override_definition_to_jules() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    ctx.git_checkout_branch("jules", true);
+
+    let mut server = mockito::Server::new();
+    let session = server
+        .mock("POST", "/sessions")
+        .match_body(mockito::Matcher::Regex("Experimental observer contract".to_string()))
+        .with_status(200)
+        .with_header("content-type", "application/json")
+        .with_body(r#"{"id": "sessions/role-file", "status": "created"}"#)
+        .expect(1)
+        .create();
+
+    let config_path = ctx.work_dir().join(".jlo/config.toml");
+    let config = std::fs::read_to_string(&config_path).expect("read config").replace(
+        "https://jules.googleapis.com/v1alpha/sessions",
+        &format!("{}/sessions", server.url()),
+    );
+    std::fs::write(&config_path, config).expect("write config");
+    std::fs::write(ctx.work_dir().join("experiment.yml"), EXPERIMENT_ROLE)
+        .expect("write role file");
+
+    ctx.cli()
+        .env_remove("GITHUB_ACTIONS")
+        .env("JULES_API_KEY", "test-key")
+        .env("GITHUB_REPOSITORY", "owner/repo")
+        .args(["run", "observers", "--role", "experiment", "--role-file", "experiment.yml"])
+        .arg("--yes")
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Created 1 Jules session(s)"));
+
+    session.assert();
+}
+
+#[test]
+fn run_observers_rejects_role_file_failing_schema() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    ctx.git_checkout_branch("jules", true);
+
+    std::fs::write(ctx.work_dir().join("broken.yml"), "role: other\nlayer: observers\n")
+        .expect("write role file");
+
+    ctx.cli()
+        .env_remove("GITHUB_ACTIONS")
+        .args(["run", "observers", "--role", "experiment", "--role-file", "broken.yml"])
+        .arg("--prompt-preview")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Role file broken.yml is invalid"))
+        .stderr(predicate::str::contains("Missing profile section"));
+}
@@ -0,0 +1,78 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+const REQUIREMENT: &str = r#"schema_version: 2
+id: "abc123"
+source_events: ["ev1234"]
+title: "Stdin requirement"
+label: "bugs"
+priority: "high"
+summary: "Requirement delivered through the CI event payload"
+goal: "Goal"
+problem: "Problem"
+impact: "Impact"
+desired_outcome: "Outcome"
+affected_areas: ["src/"]
+acceptance_criteria: ["Planner elaborates the requirement"]
+verification_criteria: ["cargo test"]
+implementation_ready: false
+planner_request_reason: "Needs planner elaboration"
+"#;
+
+#[test]
+fn run_planner_previews_requirement_from_stdin_without_staging_it() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    ctx.git_checkout_branch("jules", true);
+
+    ctx.cli()
+        .env_remove("GITHUB_ACTIONS")
+        .args(["run", "planner", "--requirement", "-", "--prompt-preview"])
+        .write_stdin(REQUIREMENT)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Prompt Preview: Planner"))
+        .stdout(predicate::str::contains(format!(
+            "Requirement content: {} chars",
+            REQUIREMENT.len()
+        )))
+        .stdout(predicate::str::contains("Would execute 1 session"));
+
+    assert!(!ctx.work_dir().join(".jules/exchange/requirements/abc123.yml").exists());
+}
+
+#[test]
+fn run_planner_accepts_input_as_requirement_alias() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    ctx.git_checkout_branch("jules", true);
+
+    ctx.cli()
+        .env_remove("GITHUB_ACTIONS")
+        .args(["run", "planner", "--input", "-", "--prompt-preview"])
+        .write_stdin(REQUIREMENT)
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("Prompt Preview: Planner"));
+}
+
+#[test]
+fn run_planner_rejects_invalid_requirement_from_stdin() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    ctx.git_checkout_branch("jules", true);
+
+    ctx.cli()
+        .env_remove("GITHUB_ACTIONS")
+        .args(["run", "planner", "--requirement", "-", "--prompt-preview"])
+        .write_stdin(REQUIREMENT.replace("label: \"bugs\"", "label: \"unknown\""))
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Requirement input is invalid"))
+        .stderr(predicate::str::contains("label 'unknown'"));
+
+    assert!(!ctx.work_dir().join(".jules/exchange/requirements/abc123.yml").exists());
+}
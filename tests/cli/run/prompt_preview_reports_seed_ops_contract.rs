@@ -0,0 +1,31 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+#[test]
+fn prompt_preview_lists_seed_ops_without_copying() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    ctx.git_checkout_branch("jules", true);
+
+    // A layer schema matching an optional include makes assembly emit a seed op.
+    std::fs::write(
+        ctx.work_dir().join(".jules/schemas/observers/changes.yml"),
+        "schema_version: 1\nsummary: \"\"\n",
+    )
+    .unwrap();
+    let seed_target = ctx.work_dir().join(".jules/exchange/changes.yml");
+    if seed_target.exists() {
+        std::fs::remove_file(&seed_target).unwrap();
+    }
+
+    ctx.cli()
+        .env_remove("GITHUB_ACTIONS")
+        .args(["run", "observers", "--role", "taxonomy", "--prompt-preview"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Seed ops (not applied in preview): 1"))
+        .stderr(predicate::str::contains(".jules/exchange/changes.yml (optional)"));
+
+    assert!(!seed_target.exists(), "preview must not materialize seed targets");
+}
@@ -13,6 +13,7 @@ fn run_implementer_rejects_missing_requirement_file() {
             "implementer",
             "--requirement",
             ".jules/exchange/requirements/nonexistent.yml",
+            "--yes",
         ])
         .assert()
         .failure()
@@ -0,0 +1,63 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+const MISSING_REQUIREMENT: &str = ".jules/exchange/requirements/nonexistent.yml";
+
+#[test]
+fn run_without_terminal_requires_explicit_yes() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    ctx.git_checkout_branch("jules", true);
+
+    ctx.cli()
+        .env_remove("JLO_ASSUME_YES")
+        .args(["run", "decider"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("without confirmation in a non-interactive context"))
+        .stderr(predicate::str::contains("--yes"));
+}
+
+#[test]
+fn run_validates_inputs_before_asking_for_confirmation() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    ctx.cli()
+        .env_remove("JLO_ASSUME_YES")
+        .args(["run", "implementer", "--requirement", MISSING_REQUIREMENT])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Requirement file not found"))
+        .stderr(predicate::str::contains("without confirmation").not());
+}
+
+#[test]
+fn run_with_yes_proceeds_past_confirmation() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    ctx.cli()
+        .env_remove("JLO_ASSUME_YES")
+        .args(["run", "implementer", "--requirement", MISSING_REQUIREMENT, "--yes"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Requirement file not found"));
+}
+
+#[test]
+fn run_honors_assume_yes_env() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    ctx.cli()
+        .env("JLO_ASSUME_YES", "1")
+        .args(["run", "implementer", "--requirement", MISSING_REQUIREMENT])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Requirement file not found"));
+}
@@ -0,0 +1,38 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+#[test]
+fn setup_gen_lock_writes_lockfile_that_check_verifies() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+
+    let setup_dir = ctx.work_dir().join(".jlo/setup");
+    std::fs::write(setup_dir.join("tools.yml"), "tools:\n  - uv\n  - just\n").unwrap();
+
+    ctx.cli()
+        .args(["setup", "gen", "--check"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("setup.lock not found"));
+
+    ctx.cli().args(["setup", "gen", "--lock"]).assert().success();
+
+    let lock: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(setup_dir.join("setup.lock")).unwrap())
+            .unwrap();
+    assert_eq!(lock["components"][0]["name"], "just");
+    assert_eq!(lock["components"][1]["name"], "uv");
+
+    ctx.cli()
+        .args(["setup", "gen", "--check"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("setup.lock is up to date"));
+
+    std::fs::write(setup_dir.join("tools.yml"), "tools:\n  - just\n").unwrap();
+    ctx.cli()
+        .args(["setup", "gen", "--check"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("out of date"));
+}
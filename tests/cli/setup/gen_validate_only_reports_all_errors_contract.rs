@@ -0,0 +1,34 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+#[test]
+fn setup_gen_validate_only_reports_every_error_without_writing_artifacts() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    let install_sh = ctx.work_dir().join(".jlo/setup/install.sh");
+    let install_before = std::fs::read_to_string(&install_sh).ok();
+    let tools_yml = ctx.work_dir().join(".jlo/setup/tools.yml");
+    std::fs::write(&tools_yml, "tools:\n  - just\n  - missing-one\n  - missing-two\n")
+        .expect("write tools.yml");
+
+    ctx.cli()
+        .args(["setup", "gen", "--validate-only"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("missing-one"))
+        .stderr(predicate::str::contains("missing-two"))
+        .stderr(predicate::str::contains("2 validation error(s)"));
+
+    assert_eq!(std::fs::read_to_string(&install_sh).ok(), install_before);
+
+    std::fs::write(&tools_yml, "tools:\n  - just\n").expect("write tools.yml");
+    ctx.cli()
+        .args(["setup", "gen", "--validate-only"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("tools.yml is valid"));
+
+    assert_eq!(std::fs::read_to_string(&install_sh).ok(), install_before);
+}
@@ -10,8 +10,44 @@ fn setup_list_shows_available_components() {
         .assert()
         .success()
         .stdout(predicate::str::contains("Available components:"))
-        .stdout(predicate::str::contains("gh"))
-        .stdout(predicate::str::contains("just"));
+        .stdout(predicate::str::is_match(r"(?m)^  gh - ").unwrap())
+        .stdout(predicate::str::is_match(r"(?m)^  just - ").unwrap());
+}
+
+#[test]
+fn setup_list_table_aligns_columns() {
+    let ctx = TestContext::new();
+
+    let output = ctx.cli().args(["setup", "list", "--format", "table"]).output().unwrap();
+    assert!(output.status.success());
+    let stdout = String::from_utf8(output.stdout).unwrap();
+    let lines: Vec<&str> = stdout.lines().collect();
+
+    assert!(lines[0].starts_with("NAME"));
+    assert!(lines.len() > 2);
+    let separator = lines[0].find(" | ").unwrap();
+    for line in &lines {
+        assert_eq!(line.find(" | "), Some(separator), "misaligned row: {}", line);
+        assert!(line.chars().count() <= 100, "row exceeds fixed width: {}", line);
+    }
+    assert!(lines.iter().any(|line| line.starts_with("gh ")));
+}
+
+#[test]
+fn setup_list_json_lists_components_with_dependencies() {
+    let ctx = TestContext::new();
+
+    let output = ctx.cli().args(["setup", "list", "--format", "json"]).output().unwrap();
+    assert!(output.status.success());
+    let components: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let just = components
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|component| component["name"] == "just")
+        .expect("just component");
+    assert!(just["summary"].is_string());
+    assert!(just["dependencies"].is_array());
 }
 
 #[test]
@@ -1,3 +1,5 @@
+mod gen_lock_check_contract;
+mod gen_validate_only_reports_all_errors_contract;
 mod generates_install_script_contract;
 mod init_creates_setup_assets_contract;
 mod list_surfaces_component_catalog_contract;
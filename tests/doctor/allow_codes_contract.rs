@@ -0,0 +1,94 @@
+use crate::harness::TestContext;
+
+fn write_tabbed_role(ctx: &TestContext) {
+    let role_dir = ctx.work_dir().join(".jlo/roles/observers/tabbed");
+    std::fs::create_dir_all(&role_dir).unwrap();
+    std::fs::write(
+        role_dir.join("role.yml"),
+        "role: tabbed\nlayer: observers\nprofile:\n\tfocus: \"Tab-indented fixture\"\n",
+    )
+    .unwrap();
+}
+
+#[test]
+fn doctor_diagnostics_carry_stable_codes() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    write_tabbed_role(&ctx);
+
+    let report = ctx.doctor_json(&["--strict"]);
+
+    let diagnostics = report["diagnostics"].as_array().unwrap();
+    assert!(diagnostics.iter().any(|d| d["code"] == "JLO-SCHEMA-001"), "{}", report);
+    assert!(diagnostics.iter().any(|d| d["code"] == "JLO-QUALITY-003"), "{}", report);
+}
+
+#[test]
+fn doctor_allow_silences_code_from_counts_and_exit() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    write_tabbed_role(&ctx);
+
+    let (code, report) = ctx.doctor_json_with_code(&[
+        "--strict",
+        "--allow",
+        "JLO-SCHEMA-001",
+        "--allow",
+        "JLO-QUALITY-003",
+    ]);
+
+    assert_eq!(code, Some(0), "{}", report);
+    assert_eq!(report["errors"], 0);
+    assert_eq!(report["warnings"], 0);
+    assert_eq!(report["diagnostics"], serde_json::json!([]));
+}
+
+#[test]
+fn doctor_allow_downgrades_errors_to_warnings() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    let event_dir = ctx.work_dir().join(".jules/exchange/events/pending");
+    std::fs::create_dir_all(&event_dir).unwrap();
+    std::fs::write(
+        event_dir.join("bad-event.yml"),
+        "schema_version: 1\nid: abc123\nrequirement_id: \"\"\ncreated_at: 2026/01/01\nauthor_role: tester\nconfidence: low\ntitle: Bad event\nstatement: A statement long enough to pass quality checks.\nevidence: []\n",
+    )
+    .unwrap();
+    let report = ctx.doctor_json(&[]);
+    let mut error_codes: Vec<String> = report["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["severity"] == "error")
+        .map(|d| d["code"].as_str().unwrap().to_string())
+        .collect();
+    error_codes.dedup();
+    assert!(!error_codes.is_empty(), "{}", report);
+
+    let allow: Vec<String> = error_codes.iter().map(|code| format!("{}=warning", code)).collect();
+    let mut args = Vec::new();
+    for entry in &allow {
+        args.extend(["--allow", entry.as_str()]);
+    }
+    let (code, report) = ctx.doctor_json_with_code(&args);
+
+    assert_eq!(code, Some(0), "{}", report);
+    assert_eq!(report["errors"], 0);
+    assert!(report["warnings"].as_u64().unwrap() > 0);
+}
+
+#[test]
+fn doctor_allow_rejects_unknown_codes() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    ctx.cli()
+        .args(["doctor", "--allow", "JLO-NOPE-001"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Unknown diagnostic code 'JLO-NOPE-001'"));
+}
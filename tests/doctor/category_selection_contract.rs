@@ -0,0 +1,93 @@
+use crate::harness::TestContext;
+
+fn categories(report: &serde_json::Value) -> Vec<String> {
+    let mut categories: Vec<String> = report["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|d| d["category"].as_str().unwrap().to_string())
+        .collect();
+    categories.sort();
+    categories.dedup();
+    categories
+}
+
+fn write_broken_fixtures(ctx: &TestContext) {
+    // Structure phase: a role directory without its prompt-entry role.yml.
+    std::fs::create_dir_all(ctx.work_dir().join(".jlo/roles/observers/ghost")).unwrap();
+    // Schema phase: an event missing required fields.
+    let event_dir = ctx.work_dir().join(".jules/exchange/events/pending");
+    std::fs::create_dir_all(&event_dir).unwrap();
+    std::fs::write(event_dir.join("bad-event.yml"), "schema_version: 1\n").unwrap();
+}
+
+#[test]
+fn doctor_skip_schema_keeps_structure_prompt_entry_checks() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    write_broken_fixtures(&ctx);
+
+    let report = ctx.doctor_json(&["--skip", "schema"]);
+
+    assert!(!categories(&report).contains(&"schema".to_string()), "{}", report);
+    let diagnostics = report["diagnostics"].as_array().unwrap();
+    assert!(
+        diagnostics.iter().any(|d| d["category"] == "structure"
+            && d["message"] == "Missing role.yml"
+            && d["file"].as_str().unwrap().ends_with("ghost/role.yml")),
+        "{}",
+        report
+    );
+}
+
+#[test]
+fn doctor_skip_schema_keeps_layer_contract_checks_in_structure() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    let report = ctx.doctor_json(&["--skip", "schema"]);
+    assert!(
+        report["diagnostics"].as_array().unwrap().iter().all(|d| d["code"] != "JLO-STRUCT-012"),
+        "embedded contracts should pass: {}",
+        report
+    );
+
+    // Contract diagnostics come from the structure phase, so --skip schema cannot hide them.
+    let output = ctx.cli().args(["doctor", "--list-rules", "--format", "json"]).output().unwrap();
+    let rules: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    let rule = rules
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|rule| rule["id"] == "layer-contracts")
+        .expect("layer-contracts rule should be listed");
+    assert_eq!(rule["category"], "structure");
+    assert_eq!(rule["codes"], serde_json::json!(["JLO-STRUCT-012"]));
+}
+
+#[test]
+fn doctor_only_runs_selected_categories() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    write_broken_fixtures(&ctx);
+
+    let report = ctx.doctor_json(&["--only", "schema"]);
+
+    assert_eq!(categories(&report), ["schema"], "{}", report);
+}
+
+#[test]
+fn doctor_rejects_only_with_skip() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    ctx.cli()
+        .args(["doctor", "--only", "schema", "--skip", "naming"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("--only and --skip cannot be combined"));
+}
@@ -0,0 +1,16 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+#[test]
+fn doctor_check_api_reports_missing_credentials() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    ctx.cli()
+        .env_remove("JULES_API_KEY")
+        .args(["doctor", "--check-api"])
+        .assert()
+        .code(1)
+        .stderr(predicate::str::contains("missing credentials: JULES_API_KEY is not set"));
+}
@@ -0,0 +1,30 @@
+use crate::harness::TestContext;
+
+fn doctor_stderr(ctx: &TestContext, args: &[&str]) -> String {
+    let output = ctx.cli().arg("doctor").args(args).output().expect("run doctor");
+    assert_eq!(output.status.code(), Some(1));
+    String::from_utf8(output.stderr).expect("utf-8 stderr")
+}
+
+#[test]
+fn doctor_never_emits_ansi_codes_when_not_a_terminal_or_disabled() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    ctx.write_invalid_event("bad-event");
+
+    for args in [&[][..], &["--color", "never"][..], &["--color", "auto"][..]] {
+        let stderr = doctor_stderr(&ctx, args);
+        assert!(stderr.contains("[ERROR]"), "expected diagnostics: {}", stderr);
+        assert!(!stderr.contains('\x1b'), "unexpected ANSI codes for {:?}: {:?}", args, stderr);
+    }
+}
+
+#[test]
+fn doctor_color_always_colors_severity_prefixes() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    ctx.write_invalid_event("bad-event");
+
+    let stderr = doctor_stderr(&ctx, &["--color", "always"]);
+    assert!(stderr.contains("\x1b[31m[ERROR]\x1b[0m"), "expected red errors: {:?}", stderr);
+}
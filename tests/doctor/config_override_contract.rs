@@ -0,0 +1,88 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+fn move_config_out_of_jlo(ctx: &TestContext) -> std::path::PathBuf {
+    let alt_dir = ctx.work_dir().join("configs");
+    std::fs::create_dir_all(&alt_dir).unwrap();
+    let alt_path = alt_dir.join("alt-config.toml");
+    std::fs::rename(ctx.work_dir().join(".jlo/config.toml"), &alt_path).unwrap();
+    alt_path
+}
+
+#[test]
+fn doctor_loads_config_from_non_standard_path() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    let alt_path = move_config_out_of_jlo(&ctx);
+
+    ctx.cli().arg("doctor").assert().failure().stderr(predicate::str::contains("config.toml"));
+
+    ctx.cli().arg("--config").arg(&alt_path).arg("doctor").assert().success();
+}
+
+#[test]
+fn config_override_must_parse() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    let broken = ctx.work_dir().join("broken.toml");
+    std::fs::write(&broken, "[run\n").unwrap();
+
+    ctx.cli()
+        .arg("--config")
+        .arg(&broken)
+        .arg("doctor")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("while loading --config"));
+}
+
+#[test]
+fn doctor_checks_section_order_of_the_override_config() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    let alt_path = move_config_out_of_jlo(&ctx);
+    let content = std::fs::read_to_string(&alt_path).unwrap();
+    // Move the trailing [jules_api] section to the top.
+    let (head, jules_api) = content.split_at(content.find("[jules_api]").unwrap());
+    std::fs::write(&alt_path, format!("{}\n{}", jules_api, head)).unwrap();
+
+    ctx.cli()
+        .arg("--config")
+        .arg(&alt_path)
+        .arg("doctor")
+        .assert()
+        .stderr(predicate::str::contains("alt-config.toml"))
+        .stderr(predicate::str::contains("top-level sections are not in canonical order"));
+}
+
+#[test]
+fn doctor_fix_does_not_rewrite_the_override_config() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    let alt_path = move_config_out_of_jlo(&ctx);
+    let content = std::fs::read_to_string(&alt_path).unwrap();
+    let (head, jules_api) = content.split_at(content.find("[jules_api]").unwrap());
+    let scrambled = format!("{}\n{}", jules_api, head);
+    std::fs::write(&alt_path, &scrambled).unwrap();
+
+    ctx.cli()
+        .arg("--config")
+        .arg(&alt_path)
+        .args(["doctor", "--fix"])
+        .assert()
+        .stderr(predicate::str::contains("does not rewrite a --config file"));
+
+    assert_eq!(std::fs::read_to_string(&alt_path).unwrap(), scrambled);
+}
+
+#[test]
+fn config_flag_is_rejected_by_commands_that_ignore_it() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+
+    ctx.cli()
+        .args(["--config", ".jlo/config.toml", "role", "create", "observers", "extra"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--config is only supported by"));
+}
@@ -0,0 +1,31 @@
+use crate::harness::TestContext;
+
+fn control_branch_warnings(ctx: &TestContext) -> Vec<serde_json::Value> {
+    ctx.doctor_json(&[])["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["code"] == "JLO-STRUCT-011")
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn doctor_warns_about_exchange_events_on_target_branch() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    let event_dir = ctx.work_dir().join(".jules/exchange/events/pending");
+    std::fs::create_dir_all(&event_dir).unwrap();
+    std::fs::write(event_dir.join("stray-event.yml"), "schema_version: 1\nid: abc123\n").unwrap();
+
+    let warnings = control_branch_warnings(&ctx);
+
+    assert_eq!(warnings.len(), 1, "{:?}", warnings);
+    assert_eq!(warnings[0]["severity"], "warning");
+    assert!(warnings[0]["file"].as_str().unwrap().ends_with("stray-event.yml"));
+    assert!(warnings[0]["message"].as_str().unwrap().contains("control branch 'main'"));
+
+    ctx.git_checkout_branch("jules", true);
+    assert!(control_branch_warnings(&ctx).is_empty());
+}
@@ -0,0 +1,47 @@
+use crate::harness::TestContext;
+
+fn missing_dir_errors(report: &serde_json::Value) -> usize {
+    report["diagnostics"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .filter(|d| d["code"] == "JLO-STRUCT-003")
+        .count()
+}
+
+#[test]
+fn doctor_fix_creates_missing_exchange_directories() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    let exchange = ctx.work_dir().join(".jules/exchange");
+    std::fs::remove_dir_all(exchange.join("events/pending")).unwrap();
+    std::fs::remove_dir_all(exchange.join("requirements")).unwrap();
+
+    let report = ctx.doctor_json(&[]);
+    assert!(missing_dir_errors(&report) >= 2, "{}", report);
+    assert!(!exchange.join("events/pending").exists());
+
+    let report = ctx.doctor_json(&["--fix"]);
+
+    assert_eq!(missing_dir_errors(&report), 0, "{}", report);
+    assert!(exchange.join("events/pending").is_dir());
+    assert!(exchange.join("requirements").is_dir());
+    let fixes: Vec<&str> =
+        report["applied_fixes"].as_array().unwrap().iter().map(|f| f.as_str().unwrap()).collect();
+    assert!(fixes.iter().any(|f| f.ends_with("events/pending: created missing directory")));
+    assert!(fixes.iter().any(|f| f.ends_with("requirements: created missing directory")));
+}
+
+#[test]
+fn doctor_fix_leaves_layout_alone_when_structure_is_skipped() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    let pending = ctx.work_dir().join(".jules/exchange/events/pending");
+    std::fs::remove_dir_all(&pending).unwrap();
+
+    ctx.doctor_json(&["--fix", "--skip", "structure"]);
+
+    assert!(!pending.exists());
+}
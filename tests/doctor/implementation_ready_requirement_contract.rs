@@ -0,0 +1,71 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+const READY_COMPLETE: &str = r#"schema_version: 2
+id: abc123
+source_events: ["def456"]
+title: Fix flaky cache
+label: bugs
+priority: high
+summary: Cache invalidation is flaky
+goal: Stable cache
+problem: Entries expire early
+impact: Requests slow down whenever the cache is cold
+desired_outcome: Entries live for their TTL
+affected_areas: ["src/cache.rs"]
+acceptance_criteria: ["TTL honored"]
+verification_criteria: ["cargo test cache"]
+implementation_ready: true
+planner_request_reason: ""
+"#;
+
+const READY_INCOMPLETE: &str = r#"schema_version: 2
+id: abc123
+source_events: ["def456"]
+title: Fix flaky cache
+label: bugs
+priority: high
+summary: Cache invalidation is flaky
+goal: Stable cache
+problem: Entries expire early
+impact: Requests slow down whenever the cache is cold
+desired_outcome: Entries live for their TTL
+affected_areas: ["src/cache.rs"]
+acceptance_criteria: [""]
+verification_criteria: ["cargo test cache"]
+implementation_ready: true
+planner_request_reason: ""
+"#;
+
+fn write_requirement(ctx: &TestContext, content: &str) {
+    let events_dir = ctx.work_dir().join(".jules/exchange/events/decided");
+    std::fs::create_dir_all(&events_dir).unwrap();
+    let event = include_str!("../../src/assets/mock/observer_event.yml")
+        .replace("mock01", "def456")
+        .replace("requirement_id: \"\"", "requirement_id: \"abc123\"");
+    std::fs::write(events_dir.join("def456.yml"), event).unwrap();
+
+    let requirements_dir = ctx.work_dir().join(".jules/exchange/requirements");
+    std::fs::create_dir_all(&requirements_dir).unwrap();
+    std::fs::write(requirements_dir.join("fix-flaky-cache.yml"), content).unwrap();
+}
+
+#[test]
+fn doctor_accepts_complete_implementation_ready_requirement() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    write_requirement(&ctx, READY_COMPLETE);
+
+    ctx.cli().args(["doctor"]).assert().success();
+}
+
+#[test]
+fn doctor_lists_blank_fields_of_implementation_ready_requirement() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    write_requirement(&ctx, READY_INCOMPLETE);
+
+    ctx.cli().args(["doctor"]).assert().code(1).stderr(predicate::str::contains(
+        "implementation_ready is true but these fields have blank entries: acceptance_criteria",
+    ));
+}
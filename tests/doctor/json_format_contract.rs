@@ -0,0 +1,45 @@
+use crate::harness::TestContext;
+
+#[test]
+fn doctor_json_reports_diagnostics_and_exit_code() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    ctx.write_invalid_event("bad-event");
+
+    let (code, report) = ctx.doctor_json_with_code(&[]);
+
+    assert_eq!(code, Some(1));
+    assert_eq!(report["schema_version"], 1);
+    assert_eq!(report["exit_code"], 1);
+    let diagnostics = report["diagnostics"].as_array().unwrap();
+    let error_count = diagnostics.iter().filter(|d| d["severity"] == "error").count();
+    assert_eq!(report["errors"].as_u64(), Some(error_count as u64));
+    let event_error = diagnostics
+        .iter()
+        .find(|d| d["file"].as_str().is_some_and(|file| file.ends_with("bad-event.yml")))
+        .expect("bad event should be reported");
+    assert_eq!(event_error["category"], "schema");
+    assert!(event_error["message"].as_str().is_some_and(|m| !m.is_empty()));
+    assert_eq!(report["applied_fixes"], serde_json::json!([]));
+}
+
+#[test]
+fn doctor_json_lists_applied_fixes() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    let role_dir = ctx.work_dir().join(".jlo/roles/observers/tabbed");
+    std::fs::create_dir_all(&role_dir).unwrap();
+    std::fs::write(
+        role_dir.join("role.yml"),
+        "role: tabbed\nlayer: observers\nprofile:\n\tfocus: \"Tab-indented fixture\"\n",
+    )
+    .unwrap();
+
+    let report = ctx.doctor_json(&["--fix"]);
+
+    let fixes = report["applied_fixes"].as_array().unwrap();
+    assert_eq!(fixes.len(), 1, "{}", report);
+    assert!(fixes[0].as_str().unwrap().contains("converted leading tabs to spaces"));
+}
@@ -0,0 +1,42 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+#[test]
+fn doctor_list_rules_prints_rules_without_workspace() {
+    let ctx = TestContext::new();
+
+    ctx.cli()
+        .args(["doctor", "--list-rules"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("stale-workspace"))
+        .stdout(predicate::str::contains("tab-indentation"))
+        .stdout(predicate::str::contains("[JLO-QUALITY-003]"));
+}
+
+#[test]
+fn doctor_list_rules_json_includes_categories_and_severity() {
+    let ctx = TestContext::new();
+
+    let output = ctx
+        .cli()
+        .args(["doctor", "--list-rules", "--format", "json"])
+        .output()
+        .expect("run doctor --list-rules");
+    assert!(output.status.success());
+
+    let rules: Vec<serde_json::Value> =
+        serde_json::from_slice(&output.stdout).expect("stdout should be JSON");
+    let find = |id: &str| rules.iter().find(|rule| rule["id"] == id).cloned().unwrap();
+
+    assert_eq!(find("required-layout")["category"], "structure");
+    assert_eq!(find("event-schema")["category"], "schema");
+    assert_eq!(find("proposal-filename")["category"], "naming");
+    assert_eq!(find("pipeline-upstream")["category"], "semantic");
+    assert_eq!(find("pipeline-upstream")["severity"], "warning");
+    assert_eq!(find("content-length")["category"], "quality");
+    assert_eq!(
+        find("version-pin")["codes"],
+        serde_json::json!(["JLO-STRUCT-005", "JLO-STRUCT-006"])
+    );
+}
@@ -1,2 +1,19 @@
+mod allow_codes_contract;
+mod category_selection_contract;
+mod check_api_contract;
+mod color_output_contract;
+mod config_override_contract;
+mod control_branch_artifacts_contract;
+mod fix_exchange_layout_contract;
+mod implementation_ready_requirement_contract;
+mod json_format_contract;
+mod list_rules_contract;
 mod mock_fixture_validity_contract;
+mod parallel_jobs_contract;
+mod rejects_invalid_cron_contract;
+mod rejects_invalid_priority_contract;
 mod reports_schema_errors_contract;
+mod sarif_format_contract;
+mod summary_json_contract;
+mod tab_indented_role_contract;
+mod workflow_drift_contract;
@@ -0,0 +1,41 @@
+use crate::harness::TestContext;
+
+#[test]
+fn doctor_report_is_identical_across_job_counts() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    for name in ["delta", "alpha", "charlie", "bravo", "echo", "foxtrot"] {
+        ctx.write_invalid_event(&format!("{}-event", name));
+    }
+
+    let sequential = ctx.doctor_json(&["--jobs", "1"]);
+    let parallel = ctx.doctor_json(&["--jobs", "4"]);
+
+    assert!(sequential["errors"].as_u64().unwrap() >= 6, "{}", sequential);
+    assert_eq!(sequential, parallel);
+}
+
+#[test]
+fn doctor_default_jobs_come_from_jlo_max_concurrency() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    ctx.cli()
+        .env("JLO_MAX_CONCURRENCY", "many")
+        .args(["doctor"])
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("JLO_MAX_CONCURRENCY must be a positive integer"));
+    ctx.cli().env("JLO_MAX_CONCURRENCY", "many").args(["doctor", "--jobs", "2"]).assert().success();
+}
+
+#[test]
+fn doctor_rejects_zero_jobs() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    ctx.cli().args(["doctor", "--jobs", "0"]).assert().failure();
+}
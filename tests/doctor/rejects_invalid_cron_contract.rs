@@ -0,0 +1,39 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+fn set_cron(ctx: &TestContext, cron: &str) {
+    let config_path = ctx.work_dir().join(".jlo/config.toml");
+    let content = std::fs::read_to_string(&config_path).unwrap();
+    let updated: Vec<String> = content
+        .lines()
+        .map(|line| {
+            if line.starts_with("cron = ") {
+                format!("cron = [\"{}\"]", cron)
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    std::fs::write(&config_path, updated.join("\n") + "\n").unwrap();
+}
+
+#[test]
+fn doctor_accepts_valid_cron() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    set_cron(&ctx, "0 20 * * *");
+
+    ctx.cli().args(["doctor"]).assert().success();
+}
+
+#[test]
+fn doctor_rejects_cron_with_out_of_range_hour() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    set_cron(&ctx, "0 99 * * *");
+
+    ctx.cli().args(["doctor"]).assert().code(1).stderr(
+        predicate::str::contains("workflow.cron entry '0 99 * * *' is invalid")
+            .and(predicate::str::contains("hour value 99 is out of range 0-23")),
+    );
+}
@@ -0,0 +1,34 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+const BAD_PRIORITY_REQUIREMENT: &str = r#"schema_version: 2
+id: abc123
+source_events: ["def456"]
+title: Fix flaky cache
+label: bugs
+priority: urgent
+summary: Cache invalidation is flaky
+goal: Stable cache
+problem: Entries expire early
+impact: Slow requests
+desired_outcome: Entries live for their TTL
+affected_areas: ["src/cache.rs"]
+acceptance_criteria: ["TTL honored"]
+verification_criteria: ["cargo test cache"]
+implementation_ready: true
+planner_request_reason: ""
+"#;
+
+#[test]
+fn doctor_rejects_requirement_priority_outside_enum() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+
+    let requirements_dir = ctx.work_dir().join(".jules/exchange/requirements");
+    std::fs::create_dir_all(&requirements_dir).unwrap();
+    std::fs::write(requirements_dir.join("fix-flaky-cache.yml"), BAD_PRIORITY_REQUIREMENT).unwrap();
+
+    ctx.cli().args(["doctor"]).assert().code(1).stderr(predicate::str::contains(
+        "priority 'urgent' is invalid (allowed: low, medium, high)",
+    ));
+}
@@ -0,0 +1,33 @@
+use crate::harness::TestContext;
+
+#[test]
+fn doctor_sarif_reports_results_and_keeps_exit_code() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    ctx.write_invalid_event("bad-event");
+
+    let output = ctx
+        .cli()
+        .args(["doctor", "--format", "sarif"])
+        .output()
+        .expect("run doctor --format sarif");
+    assert_eq!(output.status.code(), Some(1));
+
+    let log: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout should be a SARIF log");
+    assert_eq!(log["version"], "2.1.0");
+    let run = &log["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "jlo");
+    let result = run["results"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|result| {
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"]
+                == ".jules/exchange/events/pending/bad-event.yml"
+        })
+        .expect("bad event should be a SARIF result");
+    assert_eq!(result["ruleId"], "schema");
+    assert_eq!(result["level"], "error");
+}
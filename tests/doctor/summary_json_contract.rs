@@ -0,0 +1,49 @@
+use crate::harness::TestContext;
+
+#[test]
+fn doctor_summary_json_records_counts_regardless_of_exit_code() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    ctx.write_invalid_event("bad-event");
+
+    let summary_path = ctx.work_dir().join("reports/doctor-summary.json");
+    ctx.cli().args(["doctor", "--summary-json"]).arg(&summary_path).assert().code(1);
+
+    let content = std::fs::read_to_string(&summary_path).expect("summary should be written");
+    let summary: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+    let errors = summary["errors"].as_u64().unwrap();
+    let warnings = summary["warnings"].as_u64().unwrap();
+    assert!(errors >= 1, "expected at least one error: {}", content);
+
+    let by_rule = summary["by_rule"].as_object().unwrap();
+    assert!(!by_rule.is_empty());
+    assert!(by_rule.keys().all(|code| code.starts_with("JLO-")), "{}", content);
+    let total: u64 = by_rule.values().map(|v| v.as_u64().unwrap()).sum();
+    assert_eq!(total, errors + warnings);
+
+    let by_category = summary["by_category"].as_object().unwrap();
+    assert!(by_category.get("schema").and_then(|v| v.as_u64()).unwrap_or(0) >= 1);
+    let total: u64 = by_category.values().map(|v| v.as_u64().unwrap()).sum();
+    assert_eq!(total, errors + warnings);
+    assert!(summary["timestamp"].as_str().is_some_and(|t| !t.is_empty()));
+}
+
+#[test]
+fn doctor_summary_json_reports_zero_counts_on_clean_workspace() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    let summary_path = ctx.work_dir().join("doctor-summary.json");
+    ctx.cli().args(["doctor", "--summary-json"]).arg(&summary_path).assert().success();
+
+    let summary: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&summary_path).unwrap()).unwrap();
+    assert_eq!(summary["errors"], 0);
+    assert_eq!(summary["warnings"], 0);
+    assert!(summary["by_rule"].as_object().unwrap().is_empty());
+    assert!(summary["by_category"].as_object().unwrap().is_empty());
+}
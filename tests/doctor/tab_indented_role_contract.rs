@@ -0,0 +1,45 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+fn write_tab_indented_role(ctx: &TestContext) -> std::path::PathBuf {
+    let role_dir = ctx.work_dir().join(".jlo/roles/observers/tabbed");
+    std::fs::create_dir_all(&role_dir).unwrap();
+    let role_yml = role_dir.join("role.yml");
+    std::fs::write(
+        &role_yml,
+        "role: tabbed\nlayer: observers\nprofile:\n\tfocus: \"Tab-indented fixture\"\n",
+    )
+    .unwrap();
+    role_yml
+}
+
+#[test]
+fn doctor_strict_warns_on_tab_indented_role_yml() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    write_tab_indented_role(&ctx);
+
+    ctx.cli()
+        .args(["doctor", "--strict"])
+        .assert()
+        .stderr(predicate::str::contains("line 4: indentation contains a tab character"));
+}
+
+#[test]
+fn doctor_fix_converts_leading_tabs_in_role_yml() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+    let role_yml = write_tab_indented_role(&ctx);
+
+    ctx.cli()
+        .args(["doctor", "--fix"])
+        .assert()
+        .stdout(predicate::str::contains("converted leading tabs to spaces"))
+        .stderr(predicate::str::contains("indentation contains a tab").not());
+
+    let content = std::fs::read_to_string(&role_yml).unwrap();
+    assert!(!content.contains('\t'));
+    assert!(content.contains("  focus: \"Tab-indented fixture\""));
+}
@@ -0,0 +1,68 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+use std::fs;
+
+#[test]
+fn doctor_check_workflows_accepts_freshly_installed_scaffold() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    ctx.cli()
+        .args(["doctor", "--check-workflows"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Workflow differs").not());
+}
+
+#[test]
+fn doctor_check_workflows_warns_on_edited_workflow() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    let workflows_dir = ctx.work_dir().join(".github/workflows");
+    let edited = fs::read_dir(&workflows_dir)
+        .expect("read workflows dir")
+        .map(|entry| entry.expect("dir entry").path())
+        .find(|path| path.file_name().unwrap().to_string_lossy().starts_with("jules-"))
+        .expect("installed jules workflow");
+    let mut content = fs::read_to_string(&edited).expect("read workflow");
+    content.push_str("# local edit\n");
+    fs::write(&edited, content).expect("write workflow");
+    let name = edited.file_name().unwrap().to_string_lossy().to_string();
+
+    // Drift is a warning: it fails only under --strict.
+    ctx.cli().args(["doctor"]).assert().success();
+    ctx.cli()
+        .args(["doctor", "--check-workflows", "--strict"])
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains(name))
+        .stderr(predicate::str::contains("Workflow differs from the jlo"))
+        .stderr(predicate::str::contains("runner mode 'remote'"))
+        .stderr(predicate::str::contains("jlo upgrade"));
+}
+
+#[test]
+fn doctor_check_workflows_reports_unreadable_workflow_separately_from_missing() {
+    let ctx = TestContext::new();
+
+    ctx.init_remote_and_bootstrap();
+
+    let workflows_dir = ctx.work_dir().join(".github/workflows");
+    let unreadable = fs::read_dir(&workflows_dir)
+        .expect("read workflows dir")
+        .map(|entry| entry.expect("dir entry").path())
+        .find(|path| path.file_name().unwrap().to_string_lossy().starts_with("jules-"))
+        .expect("installed jules workflow");
+    fs::remove_file(&unreadable).expect("remove workflow");
+    fs::create_dir(&unreadable).expect("replace workflow with directory");
+
+    ctx.cli()
+        .args(["doctor", "--check-workflows"])
+        .assert()
+        .success()
+        .stderr(predicate::str::contains("Cannot read managed workflow file"))
+        .stderr(predicate::str::contains("Managed workflow file is missing").not());
+}
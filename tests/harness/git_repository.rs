@@ -56,3 +56,17 @@ pub(crate) fn commit_all(repo_dir: &Path, message: &str) {
         String::from_utf8_lossy(&output.stderr)
     );
 }
+
+pub(crate) fn status_porcelain(repo_dir: &Path) -> String {
+    let output = std::process::Command::new("git")
+        .args(["status", "--porcelain"])
+        .current_dir(repo_dir)
+        .output()
+        .expect("git status failed");
+    assert!(
+        output.status.success(),
+        "git status failed: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
@@ -80,6 +80,22 @@ impl TestContext {
         &self.work_dir
     }
 
+    /// Install a stub `gh` so mock prerequisite checks pass without the real CLI.
+    ///
+    /// Returns a `PATH` value with the stub directory first.
+    pub(crate) fn install_gh_stub(&self) -> String {
+        use std::os::unix::fs::PermissionsExt;
+
+        let bin_dir = self.home().join("bin");
+        fs::create_dir_all(&bin_dir).expect("create bin dir");
+        let gh = bin_dir.join("gh");
+        fs::write(&gh, "#!/bin/sh\nexit 0\n").expect("write gh stub");
+        fs::set_permissions(&gh, fs::Permissions::from_mode(0o755)).expect("chmod gh stub");
+
+        let path = std::env::var("PATH").unwrap_or_default();
+        format!("{}:{}", bin_dir.display(), path)
+    }
+
     /// Build a command for invoking the compiled `jlo` binary within the default workspace.
     pub(crate) fn cli(&self) -> Command {
         self.cli_in(self.work_dir())
@@ -92,6 +108,24 @@ impl TestContext {
         cmd
     }
 
+    /// Run `jlo doctor --format json` with extra `args` and parse the report on stdout.
+    pub(crate) fn doctor_json(&self, args: &[&str]) -> serde_json::Value {
+        self.doctor_json_with_code(args).1
+    }
+
+    /// Like [`Self::doctor_json`], also returning the process exit code.
+    pub(crate) fn doctor_json_with_code(&self, args: &[&str]) -> (Option<i32>, serde_json::Value) {
+        let output = self
+            .cli()
+            .args(["doctor", "--format", "json"])
+            .args(args)
+            .output()
+            .expect("run doctor --format json");
+        let report =
+            serde_json::from_slice(&output.stdout).expect("stdout should be a JSON report");
+        (output.status.code(), report)
+    }
+
     /// Run `jlo init remote` and assert success.
     pub(crate) fn init_remote(&self) {
         self.cli().args(["init", "remote"]).assert().success();
@@ -158,6 +192,19 @@ impl TestContext {
         self.exchange_path().join("events")
     }
 
+    /// Write a pending observer event named `<name>.yml` that fails schema validation.
+    pub(crate) fn write_invalid_event(&self, name: &str) -> PathBuf {
+        let event_dir = self.events_path().join("pending");
+        fs::create_dir_all(&event_dir).expect("Failed to create pending events directory");
+        let path = event_dir.join(format!("{}.yml", name));
+        fs::write(
+            &path,
+            "schema_version: 1\nid: abc123\nrequirement_id: \"\"\ncreated_at: 2026-01-01\nauthor_role: tester\nconfidence: low\ntitle: Bad event\nstatement: too short\nevidence: []\n",
+        )
+        .expect("Failed to write invalid event");
+        path
+    }
+
     /// Path to the `.jules/exchange/requirements` directory.
     pub(crate) fn requirements_path(&self) -> PathBuf {
         self.exchange_path().join("requirements")
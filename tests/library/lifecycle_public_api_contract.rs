@@ -27,7 +27,8 @@ fn public_api_lifecycle_happy_path_contract() {
     assert!(root.join(".jules").exists());
 
     let doctor_outcome =
-        doctor_at(root.clone(), DoctorOptions { strict: false }).expect("doctor failed");
+        doctor_at(root.clone(), DoctorOptions { strict: false, ..Default::default() })
+            .expect("doctor failed");
     assert_eq!(doctor_outcome.exit_code, 0);
 
     let outcome =
@@ -0,0 +1,85 @@
+use crate::harness::TestContext;
+use crate::harness::git_repository;
+
+/// Bootstrapped repository with an `origin` and a `gh` stub that answers `pr create`.
+fn mock_fail_context() -> (TestContext, String, std::path::PathBuf) {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    git_repository::configure_user(ctx.work_dir());
+    git_repository::commit_all(ctx.work_dir(), "initial");
+    ctx.git_checkout_branch("jules", true);
+    let origin = ctx.home().join("origin.git");
+    let status = std::process::Command::new("git")
+        .args(["init", "--bare", "-q"])
+        .arg(&origin)
+        .status()
+        .expect("init bare origin");
+    assert!(status.success());
+    git_repository::add_origin_remote(ctx.work_dir(), origin.to_str().unwrap());
+    let status = std::process::Command::new("git")
+        .args(["push", "-q", "origin", "HEAD:jules", "HEAD:main"])
+        .current_dir(ctx.work_dir())
+        .status()
+        .expect("push to origin");
+    assert!(status.success());
+
+    let path = ctx.install_gh_stub();
+    // Mock observers open a PR; answer `gh pr create` with a PR URL.
+    std::fs::write(
+        ctx.home().join("bin/gh"),
+        "#!/bin/sh\nif [ \"$1 $2\" = \"pr create\" ]; then echo https://github.com/test/test/pull/7; fi\nexit 0\n",
+    )
+    .expect("write gh stub");
+    let github_output = ctx.home().join("github_output");
+    std::fs::write(&github_output, "").expect("create GITHUB_OUTPUT");
+    (ctx, path, github_output)
+}
+
+fn run_failing_mock_observers(extra_args: &[&str]) -> std::process::Output {
+    let (ctx, path, github_output) = mock_fail_context();
+    ctx.cli()
+        .env("PATH", path)
+        .env("GH_TOKEN", "test-token")
+        .env("JULES_MOCK_TAG", "mock-fail")
+        .env("JULES_MOCK_FAIL", "taxonomy")
+        .env("GITHUB_OUTPUT", &github_output)
+        .args(["workflow", "run", "observers", "--mock"])
+        .args(extra_args)
+        .output()
+        .expect("run workflow")
+}
+
+#[test]
+fn workflow_run_stops_at_failure_injected_by_jules_mock_fail() {
+    let output = run_failing_mock_observers(&[]);
+
+    assert!(!output.status.success(), "injected failure must fail the run");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("simulated mock failure for 'taxonomy'"),
+        "stderr should report the injected failure: {stderr}"
+    );
+    // Like a real run, the layer stops at the first failed role.
+    assert!(
+        !stderr.contains("Executing: observers --role cov (mock)"),
+        "later observers must not run without --continue-on-error: {stderr}"
+    );
+}
+
+#[test]
+fn workflow_run_continue_on_error_runs_remaining_roles_after_injected_failure() {
+    let output = run_failing_mock_observers(&["--continue-on-error"]);
+
+    assert!(!output.status.success(), "injected failure must fail the run");
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(
+        stderr.contains("Observer role 'taxonomy' failed")
+            && stderr.contains("simulated mock failure for 'taxonomy'"),
+        "stderr should report the injected failure: {stderr}"
+    );
+    assert!(
+        stderr.contains("Executing: observers --role cov (mock)"),
+        "later observers should still run: {stderr}"
+    );
+    assert!(stderr.contains("observer role(s) failed"), "stderr: {stderr}");
+}
@@ -1 +1,5 @@
+mod mock_fail_contract;
 mod mock_mode_contract;
+mod workflow_run_all_rejects_layer_flags_contract;
+mod workflow_run_label_contract;
+mod workflow_run_print_prompts_contract;
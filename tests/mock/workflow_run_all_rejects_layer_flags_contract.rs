@@ -0,0 +1,14 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+
+#[test]
+fn workflow_run_all_rejects_explain_routing() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+
+    ctx.cli()
+        .args(["workflow", "run", "all", "--mock", "--explain-routing"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("--explain-routing is not supported with 'all'"));
+}
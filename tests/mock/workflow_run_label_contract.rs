@@ -0,0 +1,32 @@
+use crate::harness::TestContext;
+use crate::harness::git_repository;
+
+#[test]
+fn workflow_run_label_is_echoed_in_output() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    git_repository::configure_user(ctx.work_dir());
+    git_repository::commit_all(ctx.work_dir(), "initial");
+    ctx.git_checkout_branch("jules", true);
+
+    let path = ctx.install_gh_stub();
+    let github_output = ctx.home().join("github_output");
+    std::fs::write(&github_output, "").expect("create GITHUB_OUTPUT");
+
+    let output = ctx
+        .cli()
+        .env("PATH", path)
+        .env("GH_TOKEN", "test-token")
+        .env("JULES_MOCK_TAG", "mock-label")
+        .env("GITHUB_OUTPUT", &github_output)
+        .args(["workflow", "run", "narrator", "--mock", "--label", "matrix-frontend"])
+        .output()
+        .expect("run workflow");
+
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let json_line = stdout.lines().last().expect("workflow output line");
+    let parsed: serde_json::Value = serde_json::from_str(json_line).expect("stdout JSON");
+    assert_eq!(parsed["label"], "matrix-frontend");
+}
@@ -0,0 +1,56 @@
+use crate::harness::TestContext;
+use crate::harness::git_repository;
+
+#[test]
+fn workflow_run_print_prompts_writes_prompts_to_stderr_only() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    git_repository::configure_user(ctx.work_dir());
+    git_repository::commit_all(ctx.work_dir(), "initial");
+    ctx.git_checkout_branch("jules", true);
+
+    let path = ctx.install_gh_stub();
+    let github_output = ctx.home().join("github_output");
+    std::fs::write(&github_output, "").expect("create GITHUB_OUTPUT");
+
+    let output = ctx
+        .cli()
+        .env("PATH", path)
+        .env("GH_TOKEN", "test-token")
+        .env("JULES_MOCK_TAG", "mock-print-prompts")
+        .env("GITHUB_OUTPUT", &github_output)
+        .args(["workflow", "run", "narrator", "--mock", "--print-prompts"])
+        .output()
+        .expect("run workflow");
+
+    assert!(output.status.success(), "stderr: {}", String::from_utf8_lossy(&output.stderr));
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    assert!(stderr.contains("=== Prompt: Narrator ==="), "stderr: {}", stderr);
+    assert!(stderr.contains("=== End Prompt ==="));
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(!stdout.contains("=== Prompt"), "prompts must not leak to stdout: {}", stdout);
+    let parsed: serde_json::Value =
+        serde_json::from_str(&stdout).expect("stdout is one JSON value");
+    assert_eq!(parsed["layer"], "narrator");
+    assert_eq!(parsed["mock_tag"], "mock-print-prompts");
+
+    // Printing prompts only reports seed ops; nothing is copied into the worktree.
+    let status = git_repository::status_porcelain(ctx.work_dir());
+    assert!(status.is_empty(), "print-prompts must not write seed files: {}", status);
+}
+
+#[test]
+fn workflow_run_print_prompts_requires_mock() {
+    let ctx = TestContext::new();
+
+    let output = ctx
+        .cli()
+        .args(["workflow", "run", "narrator", "--print-prompts"])
+        .output()
+        .expect("run workflow");
+
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("--mock"));
+}
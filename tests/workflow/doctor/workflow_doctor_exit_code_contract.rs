@@ -11,7 +11,8 @@ fn workflow_doctor_succeeds_on_clean_workspace() {
         .args(["workflow", "doctor"])
         .assert()
         .success()
-        .stdout(predicate::str::contains("\"ok\":true"));
+        .stdout(predicate::str::contains("\"ok\":true"))
+        .stdout(predicate::str::contains("\"failed_checks\":[]"));
 }
 
 #[test]
@@ -38,10 +39,21 @@ evidence:
     )
     .expect("write bad event");
 
-    ctx.cli()
-        .args(["workflow", "doctor"])
-        .assert()
-        .failure()
-        .stdout(predicate::str::contains("\"ok\":false"))
-        .stderr(predicate::str::contains("evidence[0].loc is required"));
+    let output = ctx.cli().args(["workflow", "doctor"]).assert().failure().get_output().clone();
+    assert!(String::from_utf8_lossy(&output.stderr).contains("evidence[0].loc is required"));
+
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    let json: serde_json::Value = serde_json::from_str(stdout.trim()).expect("json output");
+    assert_eq!(json["ok"], false);
+    let failed = json["failed_checks"].as_array().expect("failed_checks array");
+    let check = failed
+        .iter()
+        .find(|check| {
+            check["message"].as_str().unwrap_or("").contains("evidence[0].loc is required")
+        })
+        .expect("missing evidence loc reported as a failed check");
+    assert_eq!(check["code"], "JLO-SCHEMA-002");
+    assert_eq!(check["severity"], "error");
+    assert_eq!(check["category"], "schema");
+    assert!(check["file"].as_str().unwrap().ends_with("bad.yml"));
 }
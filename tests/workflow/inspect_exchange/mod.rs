@@ -0,0 +1 @@
+mod ndjson_format_contract;
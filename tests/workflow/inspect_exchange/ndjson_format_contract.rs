@@ -0,0 +1,88 @@
+use crate::harness::TestContext;
+use predicates::prelude::*;
+use std::fs;
+
+fn seed_artifacts(ctx: &TestContext) {
+    let exchange_dir = ctx.jules_path().join("exchange");
+    for (state, file, id) in [
+        ("pending", "one.yml", "abc123"),
+        ("pending", "two.yml", "bcd234"),
+        ("decided", "three.yml", "cde345"),
+    ] {
+        let dir = exchange_dir.join("events").join(state);
+        fs::create_dir_all(&dir).expect("create event state dir");
+        fs::write(dir.join(file), format!("id: {}\n", id)).expect("write event");
+    }
+
+    let requirements_dir = exchange_dir.join("requirements");
+    fs::create_dir_all(&requirements_dir).expect("create requirements dir");
+    fs::write(
+        requirements_dir.join("bug-fix.yml"),
+        "id: abc123\nlabel: bugs\nsource_events:\n  - abc123\nimplementation_ready: true\n",
+    )
+    .expect("write requirement");
+}
+
+#[test]
+fn ndjson_emits_one_line_per_artifact() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    seed_artifacts(&ctx);
+
+    let output = ctx
+        .cli()
+        .args(["workflow", "inspect-exchange", "--format", "ndjson"])
+        .output()
+        .expect("run inspect-exchange");
+    assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+
+    let stdout = String::from_utf8(output.stdout).expect("utf8 stdout");
+    let kinds: Vec<String> = stdout
+        .lines()
+        .map(|line| {
+            let value: serde_json::Value = serde_json::from_str(line).expect("ndjson line");
+            value["kind"].as_str().expect("kind").to_string()
+        })
+        .collect();
+
+    assert_eq!(kinds.len(), 4);
+    assert_eq!(kinds.iter().filter(|kind| *kind == "event").count(), 3);
+    assert_eq!(kinds.iter().filter(|kind| *kind == "requirement").count(), 1);
+}
+
+#[test]
+fn ndjson_rejects_count_only() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+
+    ctx.cli()
+        .args(["workflow", "inspect-exchange", "--format", "ndjson", "--count-only"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+}
+
+#[test]
+fn stream_is_hidden_alias_of_ndjson_format() {
+    let ctx = TestContext::new();
+    ctx.init_remote_and_bootstrap();
+    seed_artifacts(&ctx);
+
+    let run = |args: &[&str]| {
+        let output = ctx.cli().args(["workflow", "inspect-exchange"]).args(args).output().unwrap();
+        assert!(output.status.success(), "{}", String::from_utf8_lossy(&output.stderr));
+        String::from_utf8(output.stdout).unwrap()
+    };
+    assert_eq!(run(&["--stream"]), run(&["--format", "ndjson"]));
+
+    ctx.cli()
+        .args(["workflow", "inspect-exchange", "--stream", "--format", "json"])
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("cannot be used with"));
+    ctx.cli()
+        .args(["workflow", "inspect-exchange", "--help"])
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("--stream").not());
+}
@@ -1,3 +1,4 @@
 pub(crate) mod bootstrap;
 pub(crate) mod doctor;
+pub(crate) mod inspect_exchange;
 pub(crate) mod scaffold;
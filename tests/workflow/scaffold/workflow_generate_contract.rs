@@ -67,3 +67,26 @@ fn workflow_generate_overwrites_by_default() {
         "Generated workflow file should exist after overwrite"
     );
 }
+
+#[test]
+fn workflow_generate_rejects_template_vars_shadowing_builtins() {
+    let ctx = TestContext::new();
+
+    jlo_config::write_jlo_config(ctx.work_dir(), &[jlo_config::DEFAULT_TEST_CRON], 30);
+
+    let output_dir = ctx.work_dir().join(".tmp/workflow-scaffold-generate/template-vars");
+    ctx.cli()
+        .args(["workflow", "generate", "remote", "--template-vars", r#"{"team":"platform"}"#])
+        .arg("--output-dir")
+        .arg(&output_dir)
+        .assert()
+        .success();
+
+    ctx.cli()
+        .args(["workflow", "generate", "remote", "--template-vars", r#"{"worker_branch":"x"}"#])
+        .arg("--output-dir")
+        .arg(&output_dir)
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("'worker_branch' conflicts"));
+}
@@ -15,6 +15,10 @@ fn installed_workflow_scaffold_includes_mock_support() {
     assert!(!workflow.contains("workflow_call:"), "Should not support workflow_call trigger");
     assert!(workflow.contains("MOCK_MODE:"), "Should set MOCK_MODE env var");
     assert!(workflow.contains("JULES_MOCK_TAG:"), "Should set JULES_MOCK_TAG env var");
+    assert!(
+        workflow.contains("format('mock-run-{0}-{1}', github.run_id, github.run_attempt)"),
+        "Re-runs must get a fresh mock tag so the tag-reuse guard does not reject them"
+    );
     assert!(workflow.contains("JLO_RUN_FLAGS:"), "Should set JLO_RUN_FLAGS env var");
     assert!(
         workflow.contains("cleanup-mock-artifacts:"),